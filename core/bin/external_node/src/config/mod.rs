@@ -11,7 +11,7 @@ use anyhow::Context;
 use serde::Deserialize;
 use zksync_config::{
     configs::{
-        api::{MaxResponseSize, MaxResponseSizeOverrides},
+        api::{MaxResponseSize, MaxResponseSizeOverrides, RateLimitMethodCosts},
         consensus::{ConsensusConfig, ConsensusSecrets},
         en_config::ENConfig,
         GeneralConfig, Secrets,
@@ -284,6 +284,15 @@ pub(crate) struct OptionalENConfig {
     /// Limit for fee history block range.
     #[serde(default = "OptionalENConfig::default_fee_history_limit")]
     pub fee_history_limit: u64,
+    /// Maximum number of blocks in a single `eth_simulateV1` request.
+    #[serde(default = "OptionalENConfig::default_simulate_blocks_limit")]
+    pub simulate_blocks_limit: usize,
+    /// Maximum total number of calls in a single `eth_simulateV1` request.
+    #[serde(default = "OptionalENConfig::default_simulate_calls_limit")]
+    pub simulate_calls_limit: usize,
+    /// Maximum total gas limit of calls in a single `eth_simulateV1` request.
+    #[serde(default = "OptionalENConfig::default_simulate_gas_limit")]
+    pub simulate_gas_limit: u64,
     /// Maximum number of requests in a single batch JSON RPC request. Default is 500.
     #[serde(default = "OptionalENConfig::default_max_batch_request_size")]
     pub max_batch_request_size: usize,
@@ -293,6 +302,16 @@ pub(crate) struct OptionalENConfig {
     /// Method-specific overrides in MiBs for the maximum response body size.
     #[serde(default = "MaxResponseSizeOverrides::empty")]
     max_response_body_size_overrides_mb: MaxResponseSizeOverrides,
+    /// Maximum number of requests per minute from a single client for HTTP and WS servers. If not set,
+    /// per-client rate limiting is disabled.
+    pub client_requests_per_minute_limit: Option<NonZeroU32>,
+    /// Whether to identify clients by the `X-Forwarded-For` header for per-client rate limiting. Should only be enabled
+    /// if the node is behind a trusted reverse proxy.
+    #[serde(default)]
+    pub trust_forwarded_for_header: bool,
+    /// Method-specific costs for per-client rate limiting, e.g. `eth_getLogs=10,debug_*=20`.
+    #[serde(default = "RateLimitMethodCosts::empty")]
+    pub rate_limit_method_costs: RateLimitMethodCosts,
 
     // Other API config settings
     /// Interval between polling DB for Web3 subscriptions.
@@ -411,6 +430,11 @@ pub(crate) struct OptionalENConfig {
     /// Enables the stale keys repair task for the Merkle tree.
     #[serde(default)]
     pub merkle_tree_repair_stale_keys: bool,
+    /// Restores the Merkle tree from the latest checkpoint exported by the main node if the tree is not initialized.
+    /// Checkpoints are fetched from the snapshots object store and are verified against Postgres; if a checkpoint
+    /// cannot be verified (e.g., Postgres doesn't have the corresponding L1 batch yet), the tree is initialized as usual.
+    #[serde(default)]
+    pub merkle_tree_restore_from_checkpoint: bool,
 
     // Postgres config (new parameters)
     /// Threshold in milliseconds for the DB connection lifetime to denote it as long-living and log its details.
@@ -441,6 +465,19 @@ pub(crate) struct OptionalENConfig {
     /// Number of requests per second allocated for the main node HTTP client. Default is 100 requests.
     #[serde(default = "OptionalENConfig::default_main_node_rate_limit_rps")]
     pub main_node_rate_limit_rps: NonZeroUsize,
+    /// Fallback main node URLs in the order of preference. If specified, calls to the main node fail over
+    /// to these URLs when the main node is unavailable at `main_node_url`. The main node rate limit
+    /// is applied to each URL separately.
+    #[serde(default)]
+    pub main_node_fallback_urls: Vec<SensitiveUrl>,
+    /// Fallback L1 RPC URLs in the order of preference. If specified, L1 calls fail over to these URLs
+    /// when the provider at `eth_client_url` is unavailable.
+    #[serde(default)]
+    pub eth_client_fallback_urls: Vec<SensitiveUrl>,
+    /// Whether to cross-check block hashes returned by different L1 RPC providers. Has no effect
+    /// if `eth_client_fallback_urls` are not specified.
+    #[serde(default)]
+    pub eth_client_cross_check_block_hashes: bool,
 
     #[serde(default)]
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
@@ -486,10 +523,24 @@ pub(crate) struct OptionalENConfig {
     /// Minimum time between current block.timestamp and the end of the asserted range for TimestampAsserter
     #[serde(default = "OptionalENConfig::default_timestamp_asserter_min_time_till_end_sec")]
     pub timestamp_asserter_min_time_till_end_sec: u32,
+    /// Enables automatic rollback on reorg detection. If enabled, the node restarts once the reorg detector
+    /// localizes a reorg; on restart, Postgres, the Merkle tree and the state keeper cache are reverted
+    /// to the last correct L1 batch, and the node resumes syncing. If disabled, the node shuts down,
+    /// and the rollback is performed once the node is restarted by the operator.
+    #[serde(default)]
+    pub auto_rollback_on_reorg: bool,
+    /// Maximum number of L1 batches that can be reverted automatically if `auto_rollback_on_reorg` is enabled.
+    /// Deeper rollbacks require operator intervention. The default value is 100 batches.
+    #[serde(default = "OptionalENConfig::default_max_auto_rollback_depth")]
+    pub max_auto_rollback_depth: u32,
 }
 
 impl OptionalENConfig {
-    fn from_configs(general_config: &GeneralConfig, enconfig: &ENConfig) -> anyhow::Result<Self> {
+    fn from_configs(
+        general_config: &GeneralConfig,
+        enconfig: &ENConfig,
+        secrets: &Secrets,
+    ) -> anyhow::Result<Self> {
         let api_namespaces = load_config!(general_config.api_config, web3_json_rpc.api_namespaces)
             .map(|a: Vec<String>| a.iter().map(|a| a.parse()).collect::<Result<_, _>>())
             .transpose()?;
@@ -524,6 +575,21 @@ impl OptionalENConfig {
                 web3_json_rpc.fee_history_limit,
                 default_fee_history_limit
             ),
+            simulate_blocks_limit: load_optional_config_or_default!(
+                general_config.api_config,
+                web3_json_rpc.simulate_blocks_limit,
+                default_simulate_blocks_limit
+            ),
+            simulate_calls_limit: load_optional_config_or_default!(
+                general_config.api_config,
+                web3_json_rpc.simulate_calls_limit,
+                default_simulate_calls_limit
+            ),
+            simulate_gas_limit: load_optional_config_or_default!(
+                general_config.api_config,
+                web3_json_rpc.simulate_gas_limit,
+                default_simulate_gas_limit
+            ),
             max_batch_request_size: load_optional_config_or_default!(
                 general_config.api_config,
                 web3_json_rpc.max_batch_request_size,
@@ -539,6 +605,20 @@ impl OptionalENConfig {
                 web3_json_rpc.max_response_body_size_overrides_mb,
                 default_max_response_body_size_overrides_mb
             ),
+            client_requests_per_minute_limit: load_config!(
+                general_config.api_config,
+                web3_json_rpc.client_requests_per_minute_limit
+            ),
+            trust_forwarded_for_header: general_config
+                .api_config
+                .as_ref()
+                .map(|a| a.web3_json_rpc.trust_forwarded_for_header)
+                .unwrap_or_default(),
+            rate_limit_method_costs: load_config_or_default!(
+                general_config.api_config,
+                web3_json_rpc.rate_limit_method_costs,
+                default_rate_limit_method_costs
+            ),
             pubsub_polling_interval_ms: load_optional_config_or_default!(
                 general_config.api_config,
                 web3_json_rpc.pubsub_polling_interval,
@@ -719,6 +799,16 @@ impl OptionalENConfig {
             main_node_rate_limit_rps: enconfig
                 .main_node_rate_limit_rps
                 .unwrap_or_else(Self::default_main_node_rate_limit_rps),
+            main_node_fallback_urls: enconfig.main_node_fallback_urls.clone(),
+            eth_client_fallback_urls: secrets
+                .l1
+                .as_ref()
+                .map(|l1| l1.fallback_l1_rpc_urls.clone())
+                .unwrap_or_default(),
+            eth_client_cross_check_block_hashes: secrets
+                .l1
+                .as_ref()
+                .is_some_and(|l1| l1.cross_check_l1_block_hashes),
             api_namespaces,
             contracts_diamond_proxy_addr: None,
             gateway_url: enconfig.gateway_url.clone(),
@@ -728,6 +818,13 @@ impl OptionalENConfig {
                 .as_ref()
                 .map(|x| x.min_time_till_end_sec)
                 .unwrap_or_else(Self::default_timestamp_asserter_min_time_till_end_sec),
+            auto_rollback_on_reorg: enconfig.auto_rollback_on_reorg.unwrap_or_default(),
+            max_auto_rollback_depth: enconfig
+                .max_auto_rollback_depth
+                .unwrap_or_else(Self::default_max_auto_rollback_depth),
+            merkle_tree_restore_from_checkpoint: enconfig
+                .merkle_tree_restore_from_checkpoint
+                .unwrap_or_default(),
         })
     }
 
@@ -814,6 +911,18 @@ impl OptionalENConfig {
         1_024
     }
 
+    const fn default_simulate_blocks_limit() -> usize {
+        256
+    }
+
+    const fn default_simulate_calls_limit() -> usize {
+        1_000
+    }
+
+    const fn default_simulate_gas_limit() -> u64 {
+        1_000_000_000
+    }
+
     const fn default_max_batch_request_size() -> usize {
         500 // The default limit is chosen to be reasonably permissive.
     }
@@ -826,6 +935,10 @@ impl OptionalENConfig {
         MaxResponseSizeOverrides::empty()
     }
 
+    fn default_rate_limit_method_costs() -> RateLimitMethodCosts {
+        RateLimitMethodCosts::empty()
+    }
+
     const fn default_l2_block_seal_queue_capacity() -> usize {
         10
     }
@@ -866,6 +979,10 @@ impl OptionalENConfig {
         60
     }
 
+    const fn default_max_auto_rollback_depth() -> u32 {
+        100
+    }
+
     fn from_env() -> anyhow::Result<Self> {
         let mut result: OptionalENConfig = envy::prefixed("EN_")
             .from_env()
@@ -874,6 +991,12 @@ impl OptionalENConfig {
         Ok(result)
     }
 
+    /// Returns the max depth of a rollback performed on node start, or `None` if the depth is not limited.
+    pub fn max_rollback_depth(&self) -> Option<u32> {
+        self.auto_rollback_on_reorg
+            .then_some(self.max_auto_rollback_depth)
+    }
+
     pub fn polling_interval(&self) -> Duration {
         Duration::from_millis(self.pubsub_polling_interval_ms)
     }
@@ -1340,7 +1463,11 @@ impl ExternalNodeConfig<()> {
             &external_node_config,
             &secrets_config,
         )?;
-        let optional = OptionalENConfig::from_configs(&general_config, &external_node_config)?;
+        let optional = OptionalENConfig::from_configs(
+            &general_config,
+            &external_node_config,
+            &secrets_config,
+        )?;
         let postgres = PostgresConfig {
             database_url: secrets_config
                 .database
@@ -1468,6 +1595,9 @@ impl From<&ExternalNodeConfig> for InternalApiConfig {
             l2_testnet_paymaster_addr: config.remote.l2_testnet_paymaster_addr,
             req_entities_limit: config.optional.req_entities_limit,
             fee_history_limit: config.optional.fee_history_limit,
+            simulate_blocks_limit: config.optional.simulate_blocks_limit,
+            simulate_calls_limit: config.optional.simulate_calls_limit,
+            simulate_gas_limit: config.optional.simulate_gas_limit,
             base_token_address: Some(config.remote.base_token_addr),
             filters_disabled: config.optional.filters_disabled,
             dummy_verifier: config.remote.dummy_verifier,
@@ -1506,6 +1636,9 @@ impl From<&ExternalNodeConfig> for TxSenderConfig {
                     ),
                 }
             }),
+            // Transactions are proxied to the main node, which enforces these limits.
+            max_pending_txs_per_account: None,
+            replacement_fee_bump_percent: None,
         }
     }
 }
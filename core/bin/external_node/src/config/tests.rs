@@ -75,6 +75,7 @@ fn parsing_optional_config_from_empty_env() {
     assert_eq!(config.filters_limit, 10_000);
     assert_eq!(config.subscriptions_limit, 10_000);
     assert_eq!(config.fee_history_limit, 1_024);
+    assert_eq!(config.simulate_blocks_limit, 256);
     assert_eq!(config.polling_interval(), Duration::from_millis(200));
     assert_eq!(config.max_tx_size_bytes, 1_000_000);
     assert_eq!(
@@ -112,6 +113,7 @@ fn parsing_optional_config_from_env() {
         ("EN_FILTERS_LIMIT", "5000"),
         ("EN_SUBSCRIPTIONS_LIMIT", "20000"),
         ("EN_FEE_HISTORY_LIMIT", "1000"),
+        ("EN_SIMULATE_CALLS_LIMIT", "50"),
         ("EN_PUBSUB_POLLING_INTERVAL", "500"),
         ("EN_MAX_TX_SIZE", "1048576"),
         ("EN_METADATA_CALCULATOR_DELAY", "50"),
@@ -127,8 +129,17 @@ fn parsing_optional_config_from_env() {
             "EN_MAX_RESPONSE_BODY_SIZE_OVERRIDES_MB",
             "zks_getProof=100,eth_call=2",
         ),
+        ("EN_CLIENT_REQUESTS_PER_MINUTE_LIMIT", "600"),
+        ("EN_TRUST_FORWARDED_FOR_HEADER", "true"),
+        ("EN_RATE_LIMIT_METHOD_COSTS", "eth_getLogs=10,debug_*=20"),
         ("EN_L1_BATCH_COMMIT_DATA_GENERATOR_MODE", "Validium"),
         ("EN_TIMESTAMP_ASSERTER_MIN_TIME_TILL_END_SEC", "2"),
+        (
+            "EN_MAIN_NODE_FALLBACK_URLS",
+            "http://main-node-1.local/,http://main-node-2.local/",
+        ),
+        ("EN_ETH_CLIENT_FALLBACK_URLS", "http://l1-fallback.local/"),
+        ("EN_ETH_CLIENT_CROSS_CHECK_BLOCK_HASHES", "true"),
     ];
     let env_vars = env_vars
         .into_iter()
@@ -139,6 +150,7 @@ fn parsing_optional_config_from_env() {
     assert_eq!(config.filters_limit, 5_000);
     assert_eq!(config.subscriptions_limit, 20_000);
     assert_eq!(config.fee_history_limit, 1_000);
+    assert_eq!(config.simulate_calls_limit, 50);
     assert_eq!(config.polling_interval(), Duration::from_millis(500));
     assert_eq!(config.max_tx_size_bytes, BYTES_IN_MEGABYTE);
     assert_eq!(
@@ -170,10 +182,33 @@ fn parsing_optional_config_from_env() {
             )
         ])
     );
+    assert_eq!(
+        config.client_requests_per_minute_limit,
+        NonZeroU32::new(600)
+    );
+    assert!(config.trust_forwarded_for_header);
+    assert_eq!(
+        config.rate_limit_method_costs,
+        RateLimitMethodCosts::from_iter([
+            ("eth_getLogs", NonZeroU32::new(10).unwrap()),
+            ("debug_*", NonZeroU32::new(20).unwrap()),
+        ])
+    );
     assert_eq!(
         config.l1_batch_commit_data_generator_mode,
         L1BatchCommitmentMode::Validium
     );
+    let main_node_fallback_urls: Vec<_> = config
+        .main_node_fallback_urls
+        .iter()
+        .map(SensitiveUrl::expose_str)
+        .collect();
+    assert_eq!(
+        main_node_fallback_urls,
+        ["http://main-node-1.local/", "http://main-node-2.local/"]
+    );
+    assert_eq!(config.eth_client_fallback_urls.len(), 1);
+    assert!(config.eth_client_cross_check_block_hashes);
 }
 
 #[test]
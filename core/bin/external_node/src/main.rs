@@ -1,9 +1,11 @@
-use std::{collections::HashSet, str::FromStr};
+use std::{collections::HashSet, str::FromStr, time::Duration};
 
 use anyhow::Context as _;
 use clap::Parser;
 use node_builder::ExternalNodeBuilder;
-use zksync_web3_decl::client::{Client, DynClient, L2};
+use zksync_node_framework::service::{TaskError, ZkStackServiceError};
+use zksync_types::L1BatchNumber;
+use zksync_web3_decl::client::{Client, DynClient, FailoverClient, L2};
 
 use crate::config::{generate_consensus_secrets, ExternalNodeConfig};
 
@@ -14,6 +16,14 @@ mod node_builder;
 #[cfg(test)]
 mod tests;
 
+/// Maximum number of node restarts caused by reorgs during the process lifetime. If it's exceeded,
+/// the node exits and needs operator attention.
+const MAX_REORG_RESTARTS: u32 = 10;
+/// Delay before the first node restart caused by a reorg; it's doubled after each restart.
+const INITIAL_RESTART_BACKOFF: Duration = Duration::from_secs(1);
+/// Maximum delay between node restarts.
+const MAX_RESTART_BACKOFF: Duration = Duration::from_secs(60);
+
 #[derive(Debug, Clone, clap::Subcommand)]
 enum Command {
     /// Generates consensus secret keys to use in the secrets file.
@@ -116,6 +126,8 @@ fn tokio_runtime() -> anyhow::Result<tokio::runtime::Runtime> {
 }
 
 fn main() -> anyhow::Result<()> {
+    // This runtime is used for the observability stack and for fetching the remote config;
+    // each node service instance gets a dedicated runtime.
     let runtime = tokio_runtime()?;
 
     // Initial setup.
@@ -128,6 +140,58 @@ fn main() -> anyhow::Result<()> {
         return Ok(());
     }
 
+    let config = load_local_config(&opt)?;
+    let mut guard = {
+        // Observability stack implicitly spawns several tokio tasks, so we need to call this method
+        // from within tokio context.
+        let _rt_guard = runtime.enter();
+        config.observability.build_observability()?
+    };
+
+    let components: Vec<_> = opt.components.0.iter().copied().collect();
+    let mut local_config = Some(config);
+    let mut restarts = 0;
+    let result = loop {
+        // On restart, the config is reloaded in the same way as if the node was restarted by the operator.
+        let config = match local_config.take() {
+            Some(config) => config,
+            None => load_local_config(&opt)?,
+        };
+        let config = runtime.block_on(fetch_remote_config(config))?;
+        let auto_rollback_on_reorg = config.optional.auto_rollback_on_reorg;
+
+        let node =
+            ExternalNodeBuilder::on_runtime(tokio_runtime()?, config).build(components.clone())?;
+        match node.run(None) {
+            Err(err) if auto_rollback_on_reorg => {
+                let Some(last_correct_l1_batch) = detected_reorg(&err) else {
+                    break Err(err.into());
+                };
+                if restarts >= MAX_REORG_RESTARTS {
+                    break Err(anyhow::Error::from(err).context(format!(
+                        "node was restarted {restarts} times because of reorgs; giving up"
+                    )));
+                }
+
+                let backoff = restart_backoff(restarts);
+                restarts += 1;
+                tracing::warn!(
+                    "Reorg detected; restarting the node in {backoff:?} to roll back to L1 batch #{last_correct_l1_batch} \
+                     (restart {restarts}/{MAX_REORG_RESTARTS})"
+                );
+                std::thread::sleep(backoff);
+            }
+            result => break result.map_err(Into::into),
+        }
+    };
+
+    // Make sure that the shutdown happens in the `tokio` context.
+    let _rt_guard = runtime.enter();
+    guard.shutdown();
+    result
+}
+
+fn load_local_config(opt: &Cli) -> anyhow::Result<ExternalNodeConfig<()>> {
     let mut config = if let Some(config_path) = opt.config_path.clone() {
         let secrets_path = opt.secrets_path.clone().unwrap();
         let external_node_config_path = opt.external_node_config_path.clone().unwrap();
@@ -150,29 +214,58 @@ fn main() -> anyhow::Result<()> {
     if !opt.enable_consensus {
         config.consensus = None;
     }
-    let guard = {
-        // Observability stack implicitly spawns several tokio tasks, so we need to call this method
-        // from within tokio context.
-        let _rt_guard = runtime.enter();
-        config.observability.build_observability()?
-    };
+    Ok(config)
+}
 
-    // Build L1 and L2 clients.
+async fn fetch_remote_config(config: ExternalNodeConfig<()>) -> anyhow::Result<ExternalNodeConfig> {
+    // Build L2 client.
     let main_node_url = &config.required.main_node_url;
     tracing::info!("Main node URL is: {main_node_url:?}");
-    let main_node_client = Client::http(main_node_url.clone())
-        .context("failed creating JSON-RPC client for main node")?
-        .for_network(config.required.l2_chain_id.into())
-        .with_allowed_requests_per_second(config.optional.main_node_rate_limit_rps)
-        .build();
-    let main_node_client = Box::new(main_node_client) as Box<DynClient<L2>>;
-
-    let config = runtime
-        .block_on(config.fetch_remote(main_node_client.as_ref()))
-        .context("failed fetching remote part of node config from main node")?;
-
-    let node = ExternalNodeBuilder::on_runtime(runtime, config)
-        .build(opt.components.0.into_iter().collect())?;
-    node.run(guard)?;
-    anyhow::Ok(())
+    let fallback_urls = &config.optional.main_node_fallback_urls;
+    let main_node_client: Box<DynClient<L2>> = if fallback_urls.is_empty() {
+        let client = Client::http(main_node_url.clone())
+            .context("failed creating JSON-RPC client for main node")?
+            .for_network(config.required.l2_chain_id.into())
+            .with_allowed_requests_per_second(config.optional.main_node_rate_limit_rps)
+            .build();
+        Box::new(client)
+    } else {
+        tracing::info!("Main node fallback URLs are: {fallback_urls:?}");
+        let mut urls = vec![main_node_url.clone()];
+        urls.extend(fallback_urls.iter().cloned());
+        let client = FailoverClient::http(urls)
+            .context("failed creating failover JSON-RPC client for main node")?
+            .for_network(config.required.l2_chain_id.into())
+            .with_allowed_requests_per_second(config.optional.main_node_rate_limit_rps)
+            .build();
+        Box::new(client)
+    };
+
+    config
+        .fetch_remote(main_node_client.as_ref())
+        .await
+        .context("failed fetching remote part of node config from main node")
+}
+
+/// Returns the delay before a node restart given the number of previous restarts.
+fn restart_backoff(restarts: u32) -> Duration {
+    INITIAL_RESTART_BACKOFF
+        .saturating_mul(2_u32.saturating_pow(restarts))
+        .min(MAX_RESTART_BACKOFF)
+}
+
+/// Returns the last correct L1 batch if the node service has stopped because the reorg detector has detected a reorg.
+fn detected_reorg(err: &ZkStackServiceError) -> Option<L1BatchNumber> {
+    let ZkStackServiceError::Task(errors) = err else {
+        return None;
+    };
+    errors.0.iter().find_map(|err| match err {
+        TaskError::TaskFailed(_, err) => match err.downcast_ref()? {
+            zksync_reorg_detector::Error::ReorgDetected(last_correct_l1_batch) => {
+                Some(*last_correct_l1_batch)
+            }
+            _ => None,
+        },
+        _ => None,
+    })
 }
@@ -29,7 +29,9 @@ use zksync_node_framework::{
         main_node_fee_params_fetcher::MainNodeFeeParamsFetcherLayer,
         metadata_calculator::{MetadataCalculatorLayer, TreeApiServerLayer},
         node_storage_init::{
-            external_node_strategy::{ExternalNodeInitStrategyLayer, SnapshotRecoveryConfig},
+            external_node_strategy::{
+                ExternalNodeInitStrategyLayer, SnapshotRecoveryConfig, TreeCheckpointRestoreConfig,
+            },
             NodeStorageInitializerLayer,
         },
         pools_layer::PoolsLayerBuilder,
@@ -145,7 +147,8 @@ impl ExternalNodeBuilder {
             self.config.required.main_node_url.clone(),
             self.config.optional.main_node_rate_limit_rps,
             self.config.required.l2_chain_id,
-        );
+        )
+        .with_fallback_urls(self.config.optional.main_node_fallback_urls.clone());
         self.node.add_layer(layer);
         Ok(self)
     }
@@ -183,6 +186,10 @@ impl ExternalNodeBuilder {
             self.config.required.eth_client_url.clone(),
             // TODO(EVM-676): add this config for external node
             Default::default(),
+        )
+        .with_fallback_urls(
+            self.config.optional.eth_client_fallback_urls.clone(),
+            self.config.optional.eth_client_cross_check_block_hashes,
         );
         self.node.add_layer(query_eth_client_layer);
         Ok(self)
@@ -235,6 +242,7 @@ impl ExternalNodeBuilder {
                 .experimental
                 .state_keeper_db_block_cache_capacity(),
             max_open_files: self.config.experimental.state_keeper_db_max_open_files,
+            read_only: false,
         };
         let state_keeper_layer = StateKeeperLayer::new(
             self.config.required.state_cache_path.clone(),
@@ -479,7 +487,10 @@ impl ExternalNodeBuilder {
                 .bridge_addresses_refresh_interval(),
             polling_interval: Some(self.config.optional.polling_interval()),
             websocket_requests_per_minute_limit: None, // To be set by WS server layer method if required.
-            replication_lag_limit: None,               // TODO: Support replication lag limit
+            client_requests_per_minute_limit: self.config.optional.client_requests_per_minute_limit,
+            rate_limit_method_costs: Some(self.config.optional.rate_limit_method_costs.clone()),
+            trust_forwarded_for_header: self.config.optional.trust_forwarded_for_header,
+            replication_lag_limit: None, // TODO: Support replication lag limit
         }
     }
 
@@ -549,6 +560,20 @@ impl ExternalNodeBuilder {
                         .snapshots_recovery_drop_storage_key_preimages,
                     object_store_config: config.optional.snapshots_recovery_object_store.clone(),
                 });
+        let tree_checkpoint_restore_config = if config.optional.merkle_tree_restore_from_checkpoint
+        {
+            let object_store_config = config
+                .optional
+                .snapshots_recovery_object_store
+                .clone()
+                .context("Snapshot object store is required to restore Merkle tree")?;
+            Some(TreeCheckpointRestoreConfig {
+                db_path: config.required.merkle_tree_path.clone(),
+                object_store_config,
+            })
+        } else {
+            None
+        };
         self.node.add_layer(ExternalNodeInitStrategyLayer {
             l2_chain_id: self.config.required.l2_chain_id,
             max_postgres_concurrency: self
@@ -556,6 +581,8 @@ impl ExternalNodeBuilder {
                 .optional
                 .snapshots_recovery_postgres_max_concurrency,
             snapshot_recovery_config,
+            tree_checkpoint_restore_config,
+            max_rollback_depth: config.optional.max_rollback_depth(),
         });
         let mut layer = NodeStorageInitializerLayer::new();
         if matches!(kind, LayerKind::Precondition) {
@@ -134,6 +134,15 @@ async fn node_reacts_to_stop_signal_during_initial_reorg_detection() {
     node_handle.await.unwrap().unwrap();
 }
 
+#[test]
+fn restart_backoff_grows_exponentially_and_is_capped() {
+    assert_eq!(restart_backoff(0), INITIAL_RESTART_BACKOFF);
+    assert_eq!(restart_backoff(1), INITIAL_RESTART_BACKOFF * 2);
+    assert_eq!(restart_backoff(3), INITIAL_RESTART_BACKOFF * 8);
+    assert_eq!(restart_backoff(MAX_REORG_RESTARTS), MAX_RESTART_BACKOFF);
+    assert_eq!(restart_backoff(u32::MAX), MAX_RESTART_BACKOFF);
+}
+
 #[tokio::test]
 async fn running_tree_without_core_is_not_allowed() {
     let _guard = zksync_vlog::ObservabilityBuilder::new().try_build().ok(); // Enable logging to simplify debugging
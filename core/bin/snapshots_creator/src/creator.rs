@@ -25,6 +25,8 @@ use crate::tests::HandleEvent;
 struct SnapshotProgress {
     version: SnapshotVersion,
     l1_batch_number: L1BatchNumber,
+    /// L1 batch of the base snapshot if the snapshot is incremental.
+    base_l1_batch_number: Option<L1BatchNumber>,
     /// `true` if the snapshot is new (i.e., its progress is not recovered from Postgres).
     is_new_snapshot: bool,
     chunk_count: u64,
@@ -32,10 +34,16 @@ struct SnapshotProgress {
 }
 
 impl SnapshotProgress {
-    fn new(version: SnapshotVersion, l1_batch_number: L1BatchNumber, chunk_count: u64) -> Self {
+    fn new(
+        version: SnapshotVersion,
+        l1_batch_number: L1BatchNumber,
+        base_l1_batch_number: Option<L1BatchNumber>,
+        chunk_count: u64,
+    ) -> Self {
         Self {
             version,
             l1_batch_number,
+            base_l1_batch_number,
             is_new_snapshot: true,
             chunk_count,
             remaining_chunk_ids: (0..chunk_count).collect(),
@@ -53,6 +61,7 @@ impl SnapshotProgress {
         Self {
             version: snapshot.version,
             l1_batch_number: snapshot.l1_batch_number,
+            base_l1_batch_number: snapshot.base_l1_batch_number,
             is_new_snapshot: false,
             chunk_count: snapshot.storage_logs_filepaths.len() as u64,
             remaining_chunk_ids,
@@ -81,6 +90,7 @@ impl SnapshotCreator {
         &self,
         semaphore: &Semaphore,
         progress: &SnapshotProgress,
+        base_l2_block_number: Option<L2BlockNumber>,
         l2_block_number: L2BlockNumber,
         chunk_id: u64,
     ) -> anyhow::Result<()> {
@@ -120,12 +130,22 @@ impl SnapshotCreator {
                 self.store_storage_logs_chunk(l1_batch_number, chunk_id, logs)
                     .await?
             }
-            SnapshotVersion::Version1 => {
-                let logs = conn
-                    .snapshots_creator_dal()
-                    .get_storage_logs_chunk(l2_block_number, l1_batch_number, hashed_keys_range)
-                    .await
-                    .context("error fetching storage logs")?;
+            SnapshotVersion::Version1 | SnapshotVersion::Version2 => {
+                let logs = if let Some(base_l2_block_number) = base_l2_block_number {
+                    conn.snapshots_creator_dal()
+                        .get_changed_storage_logs_chunk(
+                            base_l2_block_number,
+                            l2_block_number,
+                            l1_batch_number,
+                            hashed_keys_range,
+                        )
+                        .await
+                } else {
+                    conn.snapshots_creator_dal()
+                        .get_storage_logs_chunk(l2_block_number, l1_batch_number, hashed_keys_range)
+                        .await
+                };
+                let logs = logs.context("error fetching storage logs")?;
                 drop(conn);
 
                 let latency = latency.observe();
@@ -188,6 +208,7 @@ impl SnapshotCreator {
 
     async fn process_factory_deps(
         &self,
+        base_l2_block_number: Option<L2BlockNumber>,
         l2_block_number: L2BlockNumber,
         l1_batch_number: L1BatchNumber,
     ) -> anyhow::Result<String> {
@@ -196,10 +217,15 @@ impl SnapshotCreator {
         tracing::info!("Loading factory deps from Postgres...");
         let latency =
             METRICS.factory_deps_processing_duration[&FactoryDepsStage::LoadFromPostgres].start();
-        let factory_deps = conn
-            .snapshots_creator_dal()
-            .get_all_factory_deps(l2_block_number)
-            .await?;
+        let factory_deps = if let Some(base_l2_block_number) = base_l2_block_number {
+            conn.snapshots_creator_dal()
+                .get_factory_deps_since(base_l2_block_number, l2_block_number)
+                .await?
+        } else {
+            conn.snapshots_creator_dal()
+                .get_all_factory_deps(l2_block_number)
+                .await?
+        };
         drop(conn);
         let latency = latency.observe();
         tracing::info!("Loaded {} factory deps in {latency:?}", factory_deps.len());
@@ -232,10 +258,80 @@ impl SnapshotCreator {
         Ok(output_filepath)
     }
 
+    /// Selects the base snapshot for an incremental snapshot at `l1_batch_number`. This is the newest complete snapshot
+    /// before `l1_batch_number`, provided that the chain of incremental snapshots ending with the created snapshot
+    /// doesn't exceed the configured length. Returns `Ok(None)` if a full snapshot should be created instead.
+    async fn select_base_snapshot(
+        config: &SnapshotsCreatorConfig,
+        l1_batch_number: L1BatchNumber,
+        conn: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<Option<SnapshotMetadata>> {
+        let complete_snapshots = conn.snapshots_dal().get_all_complete_snapshots().await?;
+        let base_l1_batch_number = complete_snapshots
+            .snapshots_l1_batch_numbers
+            .into_iter()
+            .find(|&number| number < l1_batch_number);
+        let Some(base_l1_batch_number) = base_l1_batch_number else {
+            tracing::info!("No complete snapshots before L1 batch #{l1_batch_number}; creating a full snapshot");
+            return Ok(None);
+        };
+        let base_snapshot = conn
+            .snapshots_dal()
+            .get_snapshot_metadata(base_l1_batch_number)
+            .await?
+            .with_context(|| {
+                format!("snapshot for L1 batch #{base_l1_batch_number} disappeared")
+            })?;
+        if !base_snapshot.version.can_be_base() {
+            tracing::info!(
+                "Snapshot for L1 batch #{base_l1_batch_number} has version {:?} that cannot be used as a base; \
+                 creating a full snapshot",
+                base_snapshot.version
+            );
+            return Ok(None);
+        }
+
+        // The chain length includes the created snapshot.
+        let mut chain_length = 1;
+        let mut parent_l1_batch_number = base_snapshot.base_l1_batch_number;
+        while let Some(number) = parent_l1_batch_number {
+            chain_length += 1;
+            parent_l1_batch_number = conn
+                .snapshots_dal()
+                .get_snapshot_metadata(number)
+                .await?
+                .with_context(|| format!("base snapshot for L1 batch #{number} is missing"))?
+                .base_l1_batch_number;
+        }
+        if chain_length > config.max_incremental_chain_length {
+            tracing::info!(
+                "Incremental snapshot on top of snapshot for L1 batch #{base_l1_batch_number} would result in a chain \
+                 of {chain_length} incremental snapshots, exceeding the limit ({}); creating a full snapshot",
+                config.max_incremental_chain_length
+            );
+            return Ok(None);
+        }
+
+        // Changed storage logs are selected based on L2 blocks, so the base L1 batch must not be pruned.
+        let base_l2_blocks = conn
+            .blocks_dal()
+            .get_l2_block_range_of_l1_batch(base_l1_batch_number)
+            .await?;
+        if base_l2_blocks.is_none() {
+            tracing::info!(
+                "L2 blocks for base snapshot L1 batch #{base_l1_batch_number} are missing (e.g., pruned); \
+                 creating a full snapshot"
+            );
+            return Ok(None);
+        }
+        Ok(Some(base_snapshot))
+    }
+
     /// Returns `Ok(None)` if the created snapshot would coincide with `latest_snapshot`.
     async fn initialize_snapshot_progress(
         config: &SnapshotsCreatorConfig,
         l1_batch_number: L1BatchNumber,
+        base_snapshot: Option<SnapshotMetadata>,
         min_chunk_count: u64,
         conn: &mut Connection<'_, Core>,
     ) -> anyhow::Result<Option<SnapshotProgress>> {
@@ -254,6 +350,22 @@ impl SnapshotCreator {
                 )
             })?;
 
+        if let Some(base_snapshot) = base_snapshot {
+            // Incremental snapshots must use the same chunking as their base, so that chunks can be applied on top of each other.
+            let chunk_count = base_snapshot.storage_logs_filepaths.len() as u64;
+            tracing::info!(
+                "Selected incremental snapshot for L1 batch {l1_batch_number} on top of snapshot for L1 batch {}: \
+                 {chunk_count} chunks",
+                base_snapshot.l1_batch_number
+            );
+            return Ok(Some(SnapshotProgress::new(
+                snapshot_version,
+                l1_batch_number,
+                Some(base_snapshot.l1_batch_number),
+                chunk_count,
+            )));
+        }
+
         let distinct_storage_logs_keys_count = conn
             .snapshots_creator_dal()
             .get_distinct_storage_logs_keys_count(l1_batch_number)
@@ -271,6 +383,7 @@ impl SnapshotCreator {
         Ok(Some(SnapshotProgress::new(
             snapshot_version,
             l1_batch_number,
+            None,
             chunk_count,
         )))
     }
@@ -323,7 +436,6 @@ impl SnapshotCreator {
                 });
             (requested_l1_batch_number, existing_snapshot)
         };
-        drop(master_conn);
 
         match existing_snapshot {
             Some(snapshot) if snapshot.is_complete() => {
@@ -332,9 +444,18 @@ impl SnapshotCreator {
             }
             Some(snapshot) => Ok(Some(SnapshotProgress::from_existing_snapshot(&snapshot))),
             None => {
+                let base_snapshot = if config.version == u16::from(SnapshotVersion::Version2) {
+                    Self::select_base_snapshot(config, requested_l1_batch_number, &mut master_conn)
+                        .await?
+                } else {
+                    None
+                };
+                drop(master_conn);
+
                 Self::initialize_snapshot_progress(
                     config,
                     requested_l1_batch_number,
+                    base_snapshot,
                     min_chunk_count,
                     &mut self.connect_to_replica().await?,
                 )
@@ -368,6 +489,19 @@ impl SnapshotCreator {
             .get_l2_block_range_of_l1_batch(progress.l1_batch_number)
             .await?
             .context("No L2 blocks for L1 batch")?;
+        let base_l2_block_number = if let Some(base_l1_batch_number) = progress.base_l1_batch_number
+        {
+            let (_, last_l2_block_number) = conn
+                .blocks_dal()
+                .get_l2_block_range_of_l1_batch(base_l1_batch_number)
+                .await?
+                .with_context(|| {
+                    format!("No L2 blocks for base snapshot L1 batch #{base_l1_batch_number}")
+                })?;
+            Some(last_l2_block_number)
+        } else {
+            None
+        };
         drop(conn);
 
         METRICS.storage_logs_chunks_count.set(progress.chunk_count);
@@ -376,10 +510,19 @@ impl SnapshotCreator {
             L1 batch {}",
             progress.l1_batch_number
         );
+        if let Some(base_l2_block_number) = base_l2_block_number {
+            tracing::info!(
+                "Snapshot is incremental and only includes changes after L2 block {base_l2_block_number}"
+            );
+        }
 
         if progress.is_new_snapshot {
             let factory_deps_output_file = self
-                .process_factory_deps(last_l2_block_number_in_batch, progress.l1_batch_number)
+                .process_factory_deps(
+                    base_l2_block_number,
+                    last_l2_block_number_in_batch,
+                    progress.l1_batch_number,
+                )
                 .await?;
 
             let mut master_conn = self
@@ -391,6 +534,7 @@ impl SnapshotCreator {
                 .add_snapshot(
                     progress.version,
                     progress.l1_batch_number,
+                    progress.base_l1_batch_number,
                     progress.chunk_count,
                     &factory_deps_output_file,
                 )
@@ -409,6 +553,7 @@ impl SnapshotCreator {
                 self.process_storage_logs_single_chunk(
                     &semaphore,
                     &progress,
+                    base_l2_block_number,
                     last_l2_block_number_in_batch,
                     chunk_id,
                 )
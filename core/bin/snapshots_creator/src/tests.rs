@@ -18,7 +18,7 @@ use zksync_types::{
     block::{L1BatchHeader, L1BatchTreeData, L2BlockHeader},
     snapshots::{
         SnapshotFactoryDependencies, SnapshotFactoryDependency, SnapshotStorageLog,
-        SnapshotStorageLogsChunk, SnapshotStorageLogsStorageKey,
+        SnapshotStorageLogsChunk, SnapshotStorageLogsStorageKey, SnapshotVersion,
     },
     AccountTreeId, Address, L1BatchNumber, L2BlockNumber, ProtocolVersion, StorageKey, StorageLog,
     H256,
@@ -31,6 +31,7 @@ const TEST_CONFIG: SnapshotsCreatorConfig = SnapshotsCreatorConfig {
     l1_batch_number: None,
     storage_logs_chunk_size: 1_000_000,
     concurrent_queries_count: 10,
+    max_incremental_chain_length: SnapshotsCreatorConfig::default_max_incremental_chain_length(),
     object_store: None,
 };
 const SEQUENTIAL_TEST_CONFIG: SnapshotsCreatorConfig = SnapshotsCreatorConfig {
@@ -449,6 +450,130 @@ async fn persisting_snapshot_logs_for_v0_snapshot() {
     assert_eq!(actual_logs, expected_outputs.storage_logs);
 }
 
+#[tokio::test]
+async fn persisting_incremental_snapshot() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut rng = thread_rng();
+    let object_store = MockObjectStore::arc();
+    let mut conn = pool.connection().await.unwrap();
+    let expected_outputs = prepare_postgres(&mut rng, &mut conn, 10).await;
+
+    let base_l1_batch_number = L1BatchNumber(4);
+    let config = SnapshotsCreatorConfig {
+        version: 2,
+        l1_batch_number: Some(base_l1_batch_number),
+        ..TEST_CONFIG
+    };
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(config, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+    let base_metadata = conn
+        .snapshots_dal()
+        .get_snapshot_metadata(base_l1_batch_number)
+        .await
+        .unwrap()
+        .expect("No snapshot metadata");
+    assert_eq!(base_metadata.version, SnapshotVersion::Version2);
+    assert_eq!(base_metadata.base_l1_batch_number, None);
+    assert_storage_logs(&*object_store, base_l1_batch_number, &expected_outputs).await;
+
+    let config = SnapshotsCreatorConfig {
+        version: 2,
+        ..TEST_CONFIG
+    };
+    // Use a different minimum chunk count to check that the chunk count is inherited from the base snapshot.
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(config, MIN_CHUNK_COUNT + 5)
+        .await
+        .unwrap();
+    let snapshot_l1_batch_number = L1BatchNumber(8);
+    let snapshot_metadata = conn
+        .snapshots_dal()
+        .get_snapshot_metadata(snapshot_l1_batch_number)
+        .await
+        .unwrap()
+        .expect("No snapshot metadata");
+    assert!(snapshot_metadata.is_complete(), "{snapshot_metadata:#?}");
+    assert_eq!(
+        snapshot_metadata.base_l1_batch_number,
+        Some(base_l1_batch_number)
+    );
+    assert_eq!(
+        snapshot_metadata.storage_logs_filepaths.len(),
+        MIN_CHUNK_COUNT as usize
+    );
+
+    // All storage logs generated by `prepare_postgres()` are initial writes, so the incremental snapshot
+    // must contain exactly the logs initially written after the base snapshot.
+    let mut actual_logs = HashSet::new();
+    for chunk_id in 0..MIN_CHUNK_COUNT {
+        let key = SnapshotStorageLogsStorageKey {
+            l1_batch_number: snapshot_l1_batch_number,
+            chunk_id,
+        };
+        let chunk: SnapshotStorageLogsChunk = object_store.get(key).await.unwrap();
+        actual_logs.extend(chunk.storage_logs);
+    }
+    let expected_logs: HashSet<_> = expected_outputs
+        .storage_logs
+        .iter()
+        .filter(|log| log.l1_batch_number_of_initial_write > base_l1_batch_number)
+        .cloned()
+        .collect();
+    assert_eq!(actual_logs, expected_logs);
+
+    let SnapshotFactoryDependencies { factory_deps } =
+        object_store.get(snapshot_l1_batch_number).await.unwrap();
+    let SnapshotFactoryDependencies {
+        factory_deps: base_factory_deps,
+    } = object_store.get(base_l1_batch_number).await.unwrap();
+    assert!(!factory_deps.is_empty());
+    assert!(factory_deps
+        .iter()
+        .all(|dep| !base_factory_deps.contains(dep)));
+    let all_deps: HashSet<_> = factory_deps.into_iter().chain(base_factory_deps).collect();
+    assert_eq!(all_deps, expected_outputs.deps);
+}
+
+#[tokio::test]
+async fn incremental_snapshot_chain_is_limited() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut rng = thread_rng();
+    let object_store = MockObjectStore::arc();
+    let mut conn = pool.connection().await.unwrap();
+    prepare_postgres(&mut rng, &mut conn, 10).await;
+
+    let config = SnapshotsCreatorConfig {
+        version: 2,
+        max_incremental_chain_length: 1,
+        ..TEST_CONFIG
+    };
+    for snapshot_l1_batch_number in [2, 4, 6] {
+        let config = SnapshotsCreatorConfig {
+            l1_batch_number: Some(L1BatchNumber(snapshot_l1_batch_number)),
+            ..config.clone()
+        };
+        SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+            .run(config, MIN_CHUNK_COUNT)
+            .await
+            .unwrap();
+    }
+
+    let mut base_l1_batch_numbers = vec![];
+    for snapshot_l1_batch_number in [2, 4, 6] {
+        let snapshot_metadata = conn
+            .snapshots_dal()
+            .get_snapshot_metadata(L1BatchNumber(snapshot_l1_batch_number))
+            .await
+            .unwrap()
+            .expect("No snapshot metadata");
+        assert!(snapshot_metadata.is_complete(), "{snapshot_metadata:#?}");
+        base_l1_batch_numbers.push(snapshot_metadata.base_l1_batch_number);
+    }
+    assert_eq!(base_l1_batch_numbers, [None, Some(L1BatchNumber(2)), None]);
+}
+
 #[test_casing(2, [false, true])]
 #[tokio::test]
 async fn recovery_workflow(specify_batch_after_recovery: bool) {
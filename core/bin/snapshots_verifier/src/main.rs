@@ -0,0 +1,129 @@
+//! Snapshot verifier utility. Checks that a snapshot published in an object store is complete and correct
+//! without performing full node recovery.
+//!
+//! The verifier downloads the snapshot header and the L1 batch root hash from the main node, loads all storage logs
+//! chunks and factory dependencies from the object store, and then:
+//!
+//! - checks that the chunks cover the entire hashed key space without overlaps;
+//! - checks that enumeration indices of storage logs are unique and contiguous;
+//! - checks that factory dependencies are present for all bytecodes referenced by storage logs;
+//! - rebuilds the Merkle tree from the storage logs and compares its root hash with the L1 batch root hash.
+//!
+//! Incremental snapshots are verified by applying them on top of the chain of their base snapshots.
+//! The verification result is output as a JSON report.
+
+use std::{fs, path::PathBuf};
+
+use anyhow::Context as _;
+use clap::Parser;
+use zksync_config::configs::ObservabilityConfig;
+use zksync_env_config::{object_store::SnapshotsObjectStoreConfig, FromEnv};
+use zksync_merkle_tree::{PatchSet, RocksDBWrapper};
+use zksync_object_store::ObjectStoreFactory;
+use zksync_types::{url::SensitiveUrl, L1BatchNumber};
+use zksync_web3_decl::client::{Client, DynClient, L2};
+
+use crate::{report::VerificationReport, verifier::SnapshotVerifier};
+
+mod report;
+#[cfg(test)]
+mod tests;
+mod verifier;
+
+#[derive(Debug, Parser)]
+#[command(
+    author = "Matter Labs",
+    version,
+    about = "ZKsync snapshot verifier",
+    long_about = None
+)]
+struct Cli {
+    /// URL of the main node JSON-RPC API used to fetch snapshot headers and L1 batch root hashes.
+    #[arg(long)]
+    main_node_url: SensitiveUrl,
+    /// L1 batch of the verified snapshot. If not specified, the newest snapshot is verified.
+    #[arg(long = "l1-batch")]
+    l1_batch: Option<u32>,
+    /// Path to an empty directory to rebuild the Merkle tree in. If not specified, the tree is rebuilt in memory,
+    /// which is only feasible for small snapshots.
+    #[arg(long)]
+    merkle_tree_path: Option<PathBuf>,
+    /// Maximum number of storage logs chunks loaded concurrently.
+    #[arg(long, default_value_t = 10)]
+    concurrency: usize,
+    /// Path to write the JSON report to. If not specified, the report is printed to stdout.
+    #[arg(long)]
+    report_path: Option<PathBuf>,
+}
+
+impl Cli {
+    async fn run(self) -> anyhow::Result<()> {
+        let object_store_config = SnapshotsObjectStoreConfig::from_env()
+            .context("SnapshotsObjectStoreConfig::from_env()")?;
+        let blob_store = ObjectStoreFactory::new(object_store_config.0)
+            .create_store()
+            .await?;
+        let main_node_client: Box<DynClient<L2>> = Box::new(
+            Client::http(self.main_node_url.clone())
+                .context("failed creating JSON-RPC client for main node")?
+                .build(),
+        );
+
+        let verifier = SnapshotVerifier {
+            main_node_client: Box::new(main_node_client),
+            blob_store,
+            concurrency: self.concurrency,
+        };
+        let l1_batch_number = self.l1_batch.map(L1BatchNumber);
+        let report = if let Some(path) = &self.merkle_tree_path {
+            let is_empty = !path.exists()
+                || fs::read_dir(path)
+                    .with_context(|| format!("cannot read Merkle tree directory {path:?}"))?
+                    .next()
+                    .is_none();
+            anyhow::ensure!(
+                is_empty,
+                "Merkle tree directory {path:?} is not empty; the verifier requires an empty directory"
+            );
+            tracing::info!("Rebuilding Merkle tree in RocksDB at {path:?}");
+            let db =
+                RocksDBWrapper::new(path).context("failed initializing Merkle tree RocksDB")?;
+            verifier.verify(l1_batch_number, db).await?
+        } else {
+            tracing::info!("Rebuilding Merkle tree in memory");
+            verifier
+                .verify(l1_batch_number, PatchSet::default())
+                .await?
+        };
+
+        self.output_report(&report)?;
+        anyhow::ensure!(
+            report.is_valid,
+            "snapshot for L1 batch #{} is invalid: {} error(s) found",
+            report.l1_batch_number,
+            report.errors.len() + report.omitted_error_count
+        );
+        tracing::info!("Snapshot for L1 batch #{} is valid", report.l1_batch_number);
+        Ok(())
+    }
+
+    fn output_report(&self, report: &VerificationReport) -> anyhow::Result<()> {
+        let report = serde_json::to_string_pretty(report)?;
+        if let Some(path) = &self.report_path {
+            fs::write(path, report)
+                .with_context(|| format!("failed writing report to {path:?}"))?;
+        } else {
+            println!("{report}");
+        }
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let observability_config =
+        ObservabilityConfig::from_env().context("ObservabilityConfig::from_env()")?;
+    let _observability_guard = observability_config.install()?;
+
+    Cli::parse().run().await
+}
@@ -0,0 +1,108 @@
+//! Machine-readable verification report.
+
+use serde::Serialize;
+use zksync_types::{L1BatchNumber, L2BlockNumber, H256};
+
+/// Maximum number of errors included into the report. Further errors are only counted.
+const MAX_REPORTED_ERRORS: usize = 1_000;
+
+/// Problem with the verified snapshot.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum VerificationError {
+    /// Snapshot header doesn't list storage log chunks with IDs `0..chunk_count` in order.
+    UnexpectedChunkIds { chunk_ids: Vec<u64> },
+    /// Storage logs chunk is missing in the object store.
+    MissingChunk {
+        l1_batch_number: L1BatchNumber,
+        chunk_id: u64,
+    },
+    /// Factory dependencies are missing in the object store.
+    MissingFactoryDeps { l1_batch_number: L1BatchNumber },
+    /// Factory dependency bytecode doesn't pass basic validity checks (e.g., has an invalid length).
+    InvalidFactoryDep { index: usize, error: String },
+    /// Bytecode hash referenced by the storage logs and marked as known on L2 has no corresponding factory dependency.
+    MissingFactoryDep { bytecode_hash: H256 },
+    /// Storage log key is outside the hashed key range of its chunk.
+    KeyOutsideChunkRange { chunk_id: u64, key: H256 },
+    /// Storage log key is present several times in the same chunk.
+    DuplicateKey { chunk_id: u64, key: H256 },
+    /// Storage log in an incremental snapshot has an enumeration index or initial write L1 batch
+    /// differing from the base snapshot log.
+    InconsistentIncrementalLog { chunk_id: u64, key: H256 },
+    /// Storage log has a zero or an implausibly large enumeration index.
+    InvalidEnumerationIndex { key: H256, enumeration_index: u64 },
+    /// Enumeration index is assigned to several storage logs.
+    DuplicateEnumerationIndex { key: H256, enumeration_index: u64 },
+    /// Enumeration indices of storage logs do not form a contiguous `1..=max_enumeration_index` range.
+    MissingEnumerationIndices { count: u64 },
+    /// Storage log is initially written after the snapshot L1 batch.
+    InitialWriteFromFuture {
+        key: H256,
+        l1_batch_number_of_initial_write: L1BatchNumber,
+    },
+    /// Merkle tree root hash rebuilt from the storage logs differs from the L1 batch root hash.
+    RootHashMismatch { expected: H256, actual: H256 },
+}
+
+/// Report produced by the snapshot verifier.
+#[derive(Debug, Serialize)]
+pub(crate) struct VerificationReport {
+    pub is_valid: bool,
+    pub l1_batch_number: L1BatchNumber,
+    pub l2_block_number: L2BlockNumber,
+    pub version: u16,
+    /// L1 batches of base snapshots applied before the verified snapshot, starting from the full snapshot.
+    /// Empty if the verified snapshot is not incremental.
+    pub base_l1_batch_numbers: Vec<L1BatchNumber>,
+    pub storage_logs_chunk_count: usize,
+    /// Number of storage logs in the snapshot state (i.e., after applying all incremental snapshots).
+    pub storage_log_count: u64,
+    pub factory_dep_count: usize,
+    pub max_enumeration_index: u64,
+    pub expected_root_hash: H256,
+    /// Root hash of the rebuilt Merkle tree. `None` if storage logs chunks are missing, so the tree cannot be rebuilt.
+    pub actual_root_hash: Option<H256>,
+    pub errors: Vec<VerificationError>,
+    /// Number of errors not included into `errors` because of the size limit.
+    pub omitted_error_count: usize,
+}
+
+impl VerificationReport {
+    pub fn new(
+        l1_batch_number: L1BatchNumber,
+        l2_block_number: L2BlockNumber,
+        version: u16,
+        expected_root_hash: H256,
+    ) -> Self {
+        Self {
+            is_valid: false,
+            l1_batch_number,
+            l2_block_number,
+            version,
+            base_l1_batch_numbers: vec![],
+            storage_logs_chunk_count: 0,
+            storage_log_count: 0,
+            factory_dep_count: 0,
+            max_enumeration_index: 0,
+            expected_root_hash,
+            actual_root_hash: None,
+            errors: vec![],
+            omitted_error_count: 0,
+        }
+    }
+
+    pub fn push_error(&mut self, err: VerificationError) {
+        if self.errors.len() < MAX_REPORTED_ERRORS {
+            tracing::warn!("Snapshot verification error: {err:?}");
+            self.errors.push(err);
+        } else {
+            self.omitted_error_count += 1;
+        }
+    }
+
+    pub fn finish(mut self) -> Self {
+        self.is_valid = self.errors.is_empty() && self.omitted_error_count == 0;
+        self
+    }
+}
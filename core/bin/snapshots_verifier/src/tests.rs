@@ -0,0 +1,450 @@
+//! Tests for the snapshot verifier.
+
+use std::{collections::HashMap, sync::Arc};
+
+use assert_matches::assert_matches;
+use async_trait::async_trait;
+use zksync_merkle_tree::{MerkleTree, PatchSet, TreeEntry};
+use zksync_object_store::{MockObjectStore, ObjectStore};
+use zksync_snapshots_applier::SnapshotsApplierMainNodeClient;
+use zksync_types::{
+    api,
+    bytecode::BytecodeHash,
+    get_code_key, get_known_code_key,
+    snapshots::{
+        uniform_hashed_keys_chunk, SnapshotFactoryDependencies, SnapshotFactoryDependency,
+        SnapshotHeader, SnapshotStorageLog, SnapshotStorageLogsChunk,
+        SnapshotStorageLogsChunkMetadata, SnapshotStorageLogsStorageKey, SnapshotVersion,
+    },
+    tokens::TokenInfo,
+    Address, L1BatchNumber, L2BlockNumber, H256, U256,
+};
+use zksync_web3_decl::error::EnrichedClientResult;
+
+use crate::{
+    report::{VerificationError, VerificationReport},
+    verifier::SnapshotVerifier,
+};
+
+const CHUNK_COUNT: u64 = 3;
+
+#[derive(Debug, Default)]
+struct MockMainNodeClient {
+    snapshots: HashMap<L1BatchNumber, SnapshotHeader>,
+    root_hashes: HashMap<L1BatchNumber, H256>,
+}
+
+#[async_trait]
+impl SnapshotsApplierMainNodeClient for MockMainNodeClient {
+    async fn fetch_l1_batch_details(
+        &self,
+        number: L1BatchNumber,
+    ) -> EnrichedClientResult<Option<api::L1BatchDetails>> {
+        Ok(self
+            .root_hashes
+            .get(&number)
+            .map(|&root_hash| api::L1BatchDetails {
+                number,
+                base: api::BlockDetailsBase {
+                    timestamp: 0,
+                    l1_tx_count: 0,
+                    l2_tx_count: 0,
+                    root_hash: Some(root_hash),
+                    status: api::BlockStatus::Sealed,
+                    commit_tx_hash: None,
+                    committed_at: None,
+                    prove_tx_hash: None,
+                    proven_at: None,
+                    execute_tx_hash: None,
+                    executed_at: None,
+                    l1_gas_price: 0,
+                    l2_fair_gas_price: 0,
+                    fair_pubdata_price: None,
+                    base_system_contracts_hashes: Default::default(),
+                },
+            }))
+    }
+
+    async fn fetch_l2_block_details(
+        &self,
+        _number: L2BlockNumber,
+    ) -> EnrichedClientResult<Option<api::BlockDetails>> {
+        Ok(None)
+    }
+
+    async fn fetch_newest_snapshot_l1_batch_number(
+        &self,
+    ) -> EnrichedClientResult<Option<L1BatchNumber>> {
+        Ok(self.snapshots.keys().max().copied())
+    }
+
+    async fn fetch_snapshot(
+        &self,
+        l1_batch_number: L1BatchNumber,
+    ) -> EnrichedClientResult<Option<SnapshotHeader>> {
+        Ok(self.snapshots.get(&l1_batch_number).cloned())
+    }
+
+    async fn fetch_tokens(
+        &self,
+        _at_l2_block: L2BlockNumber,
+    ) -> EnrichedClientResult<Vec<TokenInfo>> {
+        Ok(vec![])
+    }
+}
+
+#[derive(Debug)]
+struct TestSnapshot {
+    l1_batch_number: L1BatchNumber,
+    base_l1_batch_number: Option<L1BatchNumber>,
+    storage_logs: Vec<SnapshotStorageLog>,
+    bytecodes: Vec<Vec<u8>>,
+}
+
+impl TestSnapshot {
+    fn new(l1_batch_number: L1BatchNumber, first_index: u64, log_count: u64) -> Self {
+        let storage_logs = (0..log_count)
+            .map(|i| SnapshotStorageLog {
+                key: H256::random(),
+                value: H256::random(),
+                l1_batch_number_of_initial_write: l1_batch_number,
+                enumeration_index: first_index + i,
+            })
+            .collect();
+        Self {
+            l1_batch_number,
+            base_l1_batch_number: None,
+            storage_logs,
+            bytecodes: vec![],
+        }
+    }
+
+    /// Adds a contract deployment: a factory dep together with code and known code storage logs.
+    fn deploy_contract(&mut self, bytecode: Vec<u8>, with_factory_dep: bool) -> H256 {
+        let bytecode_hash = BytecodeHash::for_bytecode(&bytecode).value();
+        let next_index = self.next_enumeration_index();
+        let code_key = get_code_key(&Address::random()).hashed_key();
+        let known_code_key = get_known_code_key(&bytecode_hash).hashed_key();
+        self.storage_logs.extend([
+            SnapshotStorageLog {
+                key: code_key,
+                value: bytecode_hash,
+                l1_batch_number_of_initial_write: self.l1_batch_number,
+                enumeration_index: next_index,
+            },
+            SnapshotStorageLog {
+                key: known_code_key,
+                value: H256::from_low_u64_be(1),
+                l1_batch_number_of_initial_write: self.l1_batch_number,
+                enumeration_index: next_index + 1,
+            },
+        ]);
+        if with_factory_dep {
+            self.bytecodes.push(bytecode);
+        }
+        bytecode_hash
+    }
+
+    fn next_enumeration_index(&self) -> u64 {
+        self.storage_logs
+            .iter()
+            .map(|log| log.enumeration_index)
+            .max()
+            .unwrap_or(0)
+            + 1
+    }
+
+    async fn persist(&self, object_store: &dyn ObjectStore, client: &mut MockMainNodeClient) {
+        for chunk_id in 0..CHUNK_COUNT {
+            let hashed_keys_range = uniform_hashed_keys_chunk(chunk_id, CHUNK_COUNT);
+            let chunk = SnapshotStorageLogsChunk {
+                storage_logs: self
+                    .storage_logs
+                    .iter()
+                    .filter(|log| hashed_keys_range.contains(&log.key))
+                    .cloned()
+                    .collect(),
+            };
+            let key = SnapshotStorageLogsStorageKey {
+                l1_batch_number: self.l1_batch_number,
+                chunk_id,
+            };
+            object_store.put(key, &chunk).await.unwrap();
+        }
+
+        let factory_deps = SnapshotFactoryDependencies {
+            factory_deps: self
+                .bytecodes
+                .iter()
+                .map(|bytecode| SnapshotFactoryDependency {
+                    bytecode: bytecode.clone().into(),
+                })
+                .collect(),
+        };
+        object_store
+            .put(self.l1_batch_number, &factory_deps)
+            .await
+            .unwrap();
+
+        let version = if self.base_l1_batch_number.is_some() {
+            SnapshotVersion::Version2
+        } else {
+            SnapshotVersion::Version1
+        };
+        let header = SnapshotHeader {
+            version: version.into(),
+            l1_batch_number: self.l1_batch_number,
+            l2_block_number: L2BlockNumber(self.l1_batch_number.0 * 2),
+            base_l1_batch_number: self.base_l1_batch_number,
+            storage_logs_chunks: (0..CHUNK_COUNT)
+                .map(|chunk_id| SnapshotStorageLogsChunkMetadata {
+                    chunk_id,
+                    filepath: format!("file{chunk_id}"),
+                })
+                .collect(),
+            factory_deps_filepath: "factory_deps".to_owned(),
+        };
+        client.snapshots.insert(self.l1_batch_number, header);
+    }
+}
+
+/// Computes the root hash using a conventional Merkle tree, inserting logs in the enumeration index order.
+fn compute_root_hash<'a>(storage_logs: impl IntoIterator<Item = &'a SnapshotStorageLog>) -> H256 {
+    let mut entries: Vec<_> = storage_logs
+        .into_iter()
+        .map(|log| {
+            let key = U256::from_little_endian(log.key.as_bytes());
+            TreeEntry::new(key, log.enumeration_index, log.value)
+        })
+        .collect();
+    entries.sort_unstable_by_key(|entry| entry.leaf_index);
+
+    let mut tree = MerkleTree::new(PatchSet::default()).unwrap();
+    tree.extend(entries).unwrap().root_hash
+}
+
+async fn verify(
+    client: MockMainNodeClient,
+    object_store: Arc<dyn ObjectStore>,
+) -> VerificationReport {
+    let verifier = SnapshotVerifier {
+        main_node_client: Box::new(client),
+        blob_store: object_store,
+        concurrency: 2,
+    };
+    verifier.verify(None, PatchSet::default()).await.unwrap()
+}
+
+#[tokio::test]
+async fn verifying_valid_snapshot() {
+    let object_store = MockObjectStore::arc();
+    let mut client = MockMainNodeClient::default();
+    let mut snapshot = TestSnapshot::new(L1BatchNumber(10), 1, 100);
+    snapshot.deploy_contract(vec![1; 32], true);
+    snapshot.deploy_contract(vec![2; 96], true);
+    snapshot.persist(object_store.as_ref(), &mut client).await;
+    let root_hash = compute_root_hash(&snapshot.storage_logs);
+    client
+        .root_hashes
+        .insert(snapshot.l1_batch_number, root_hash);
+
+    let report = verify(client, object_store).await;
+    assert!(report.is_valid, "{report:#?}");
+    assert_eq!(report.errors, []);
+    assert_eq!(report.l1_batch_number, snapshot.l1_batch_number);
+    assert_eq!(report.base_l1_batch_numbers, []);
+    assert_eq!(report.storage_logs_chunk_count, CHUNK_COUNT as usize);
+    assert_eq!(report.storage_log_count, 104);
+    assert_eq!(report.max_enumeration_index, 104);
+    assert_eq!(report.factory_dep_count, 2);
+    assert_eq!(report.actual_root_hash, Some(root_hash));
+}
+
+#[tokio::test]
+async fn verifying_snapshot_with_wrong_root_hash() {
+    let object_store = MockObjectStore::arc();
+    let mut client = MockMainNodeClient::default();
+    let snapshot = TestSnapshot::new(L1BatchNumber(10), 1, 100);
+    snapshot.persist(object_store.as_ref(), &mut client).await;
+    let root_hash = compute_root_hash(&snapshot.storage_logs);
+    client
+        .root_hashes
+        .insert(snapshot.l1_batch_number, H256::repeat_byte(1));
+
+    let report = verify(client, object_store).await;
+    assert!(!report.is_valid);
+    assert_eq!(
+        report.errors,
+        [VerificationError::RootHashMismatch {
+            expected: H256::repeat_byte(1),
+            actual: root_hash,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn verifying_snapshot_with_missing_chunk() {
+    let object_store = MockObjectStore::arc();
+    let mut client = MockMainNodeClient::default();
+    let snapshot = TestSnapshot::new(L1BatchNumber(10), 1, 100);
+    snapshot.persist(object_store.as_ref(), &mut client).await;
+    let root_hash = compute_root_hash(&snapshot.storage_logs);
+    client
+        .root_hashes
+        .insert(snapshot.l1_batch_number, root_hash);
+    let missing_key = SnapshotStorageLogsStorageKey {
+        l1_batch_number: snapshot.l1_batch_number,
+        chunk_id: 1,
+    };
+    object_store
+        .remove::<SnapshotStorageLogsChunk>(missing_key)
+        .await
+        .unwrap();
+
+    let report = verify(client, object_store).await;
+    assert!(!report.is_valid);
+    assert_eq!(
+        report.errors,
+        [VerificationError::MissingChunk {
+            l1_batch_number: snapshot.l1_batch_number,
+            chunk_id: 1,
+        }]
+    );
+    assert_eq!(report.actual_root_hash, None);
+}
+
+#[tokio::test]
+async fn verifying_snapshot_with_missing_factory_dep() {
+    let object_store = MockObjectStore::arc();
+    let mut client = MockMainNodeClient::default();
+    let mut snapshot = TestSnapshot::new(L1BatchNumber(10), 1, 100);
+    snapshot.deploy_contract(vec![1; 32], true);
+    let missing_bytecode_hash = snapshot.deploy_contract(vec![2; 32], false);
+    snapshot.persist(object_store.as_ref(), &mut client).await;
+    let root_hash = compute_root_hash(&snapshot.storage_logs);
+    client
+        .root_hashes
+        .insert(snapshot.l1_batch_number, root_hash);
+
+    let report = verify(client, object_store).await;
+    assert!(!report.is_valid);
+    assert_eq!(
+        report.errors,
+        [VerificationError::MissingFactoryDep {
+            bytecode_hash: missing_bytecode_hash,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn verifying_snapshot_with_duplicate_enumeration_index() {
+    let object_store = MockObjectStore::arc();
+    let mut client = MockMainNodeClient::default();
+    let mut snapshot = TestSnapshot::new(L1BatchNumber(10), 1, 100);
+    snapshot.storage_logs[1].enumeration_index = 1;
+    snapshot.persist(object_store.as_ref(), &mut client).await;
+    client
+        .root_hashes
+        .insert(snapshot.l1_batch_number, H256::zero());
+
+    let report = verify(client, object_store).await;
+    assert!(!report.is_valid);
+    let duplicate_index_errors: Vec<_> = report
+        .errors
+        .iter()
+        .filter(|err| matches!(err, VerificationError::DuplicateEnumerationIndex { .. }))
+        .collect();
+    assert_matches!(
+        duplicate_index_errors.as_slice(),
+        [VerificationError::DuplicateEnumerationIndex {
+            enumeration_index: 1,
+            ..
+        }]
+    );
+    assert!(report
+        .errors
+        .contains(&VerificationError::MissingEnumerationIndices { count: 1 }));
+}
+
+#[tokio::test]
+async fn verifying_snapshot_with_key_outside_chunk_range() {
+    let object_store = MockObjectStore::arc();
+    let mut client = MockMainNodeClient::default();
+    let snapshot = TestSnapshot::new(L1BatchNumber(10), 1, 100);
+    snapshot.persist(object_store.as_ref(), &mut client).await;
+    client
+        .root_hashes
+        .insert(snapshot.l1_batch_number, H256::zero());
+
+    // Move the first chunk to the second one.
+    let key = |chunk_id| SnapshotStorageLogsStorageKey {
+        l1_batch_number: snapshot.l1_batch_number,
+        chunk_id,
+    };
+    let first_chunk: SnapshotStorageLogsChunk = object_store.get(key(0)).await.unwrap();
+    object_store.put(key(1), &first_chunk).await.unwrap();
+    let moved_key = first_chunk.storage_logs[0].key;
+
+    let report = verify(client, object_store).await;
+    assert!(!report.is_valid);
+    assert!(
+        report
+            .errors
+            .contains(&VerificationError::KeyOutsideChunkRange {
+                chunk_id: 1,
+                key: moved_key,
+            }),
+        "{report:#?}"
+    );
+}
+
+#[tokio::test]
+async fn verifying_incremental_snapshot() {
+    let object_store = MockObjectStore::arc();
+    let mut client = MockMainNodeClient::default();
+    let mut base_snapshot = TestSnapshot::new(L1BatchNumber(10), 1, 100);
+    base_snapshot.deploy_contract(vec![1; 32], true);
+    base_snapshot
+        .persist(object_store.as_ref(), &mut client)
+        .await;
+
+    let next_index = base_snapshot.next_enumeration_index();
+    let mut snapshot = TestSnapshot::new(L1BatchNumber(20), next_index, 50);
+    snapshot.base_l1_batch_number = Some(base_snapshot.l1_batch_number);
+    snapshot
+        .storage_logs
+        .extend(
+            base_snapshot
+                .storage_logs
+                .iter()
+                .step_by(3)
+                .map(|log| SnapshotStorageLog {
+                    value: H256::random(),
+                    ..log.clone()
+                }),
+        );
+    snapshot.deploy_contract(vec![2; 32], true);
+    snapshot.persist(object_store.as_ref(), &mut client).await;
+
+    let mut state: HashMap<_, _> = base_snapshot
+        .storage_logs
+        .iter()
+        .map(|log| (log.key, log))
+        .collect();
+    state.extend(snapshot.storage_logs.iter().map(|log| (log.key, log)));
+    let root_hash = compute_root_hash(state.values().copied());
+    client
+        .root_hashes
+        .insert(snapshot.l1_batch_number, root_hash);
+
+    let report = verify(client, object_store).await;
+    assert!(report.is_valid, "{report:#?}");
+    assert_eq!(report.l1_batch_number, snapshot.l1_batch_number);
+    assert_eq!(
+        report.base_l1_batch_numbers,
+        [base_snapshot.l1_batch_number]
+    );
+    assert_eq!(report.storage_log_count, state.len() as u64);
+    assert_eq!(report.factory_dep_count, 2);
+    assert_eq!(report.actual_root_hash, Some(root_hash));
+}
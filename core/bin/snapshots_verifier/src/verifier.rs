@@ -0,0 +1,472 @@
+//! Snapshot verification logic.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use anyhow::Context as _;
+use futures::{stream, StreamExt};
+use zksync_merkle_tree::{recovery::MerkleTreeRecovery, PruneDatabase, TreeEntry};
+use zksync_object_store::{ObjectStore, ObjectStoreError, StoredObject};
+use zksync_snapshots_applier::SnapshotsApplierMainNodeClient;
+use zksync_types::{
+    bytecode::{validate_bytecode, BytecodeHash, BytecodeMarker},
+    get_known_code_key,
+    snapshots::{
+        uniform_hashed_keys_chunk, SnapshotFactoryDependencies, SnapshotHeader, SnapshotStorageLog,
+        SnapshotStorageLogsChunk, SnapshotStorageLogsStorageKey, SnapshotVersion,
+    },
+    L1BatchNumber, StorageKey, H256, U256,
+};
+
+use crate::report::{VerificationError, VerificationReport};
+
+/// Maximum supported enumeration index. Larger indices are reported as invalid; this bounds the memory
+/// used to check index uniqueness (~512 MiB).
+const MAX_ENUMERATION_INDEX: u64 = u32::MAX as u64;
+
+/// Snapshot which storage logs and factory deps are loaded during verification.
+#[derive(Debug, Clone, Copy)]
+struct SnapshotLayer {
+    l1_batch_number: L1BatchNumber,
+    version: SnapshotVersion,
+}
+
+/// Storage logs chunk after applying all snapshot layers.
+#[derive(Debug)]
+struct LoadedChunk {
+    chunk_id: u64,
+    /// `None` if the chunk is missing for at least one of the layers.
+    storage_logs: Option<Vec<SnapshotStorageLog>>,
+    errors: Vec<VerificationError>,
+}
+
+/// Set of enumeration indices encountered in the snapshot.
+#[derive(Debug, Default)]
+struct EnumerationIndices {
+    bits: Vec<u64>,
+    count: u64,
+    max: u64,
+}
+
+impl EnumerationIndices {
+    /// Returns `false` if the index is already present in the set.
+    fn insert(&mut self, index: u64) -> bool {
+        let (word_idx, bit_idx) = ((index / 64) as usize, index % 64);
+        if word_idx >= self.bits.len() {
+            self.bits.resize((word_idx + 1).next_power_of_two(), 0);
+        }
+        let mask = 1_u64 << bit_idx;
+        if self.bits[word_idx] & mask != 0 {
+            return false;
+        }
+        self.bits[word_idx] |= mask;
+        self.count += 1;
+        self.max = self.max.max(index);
+        true
+    }
+}
+
+/// Verifies application-level snapshots stored in an object store.
+#[derive(Debug)]
+pub(crate) struct SnapshotVerifier {
+    pub main_node_client: Box<dyn SnapshotsApplierMainNodeClient>,
+    pub blob_store: Arc<dyn ObjectStore>,
+    /// Maximum number of storage logs chunks loaded concurrently.
+    pub concurrency: usize,
+}
+
+impl SnapshotVerifier {
+    /// Verifies the snapshot for the specified L1 batch (or the newest snapshot, if the batch is not specified).
+    /// The Merkle tree for the snapshot is rebuilt in `tree_db`, which must be empty.
+    ///
+    /// Problems with the snapshot data are recorded in the returned report. An error is returned if the snapshot
+    /// cannot be verified at all, e.g. if the main node is unreachable or the snapshot header is missing.
+    pub async fn verify<DB>(
+        &self,
+        l1_batch_number: Option<L1BatchNumber>,
+        tree_db: DB,
+    ) -> anyhow::Result<VerificationReport>
+    where
+        DB: PruneDatabase + Send + 'static,
+    {
+        let l1_batch_number = match l1_batch_number {
+            Some(number) => number,
+            None => self
+                .main_node_client
+                .fetch_newest_snapshot_l1_batch_number()
+                .await?
+                .context("no snapshots on main node")?,
+        };
+        let header = self
+            .main_node_client
+            .fetch_snapshot(l1_batch_number)
+            .await?
+            .with_context(|| {
+                format!("snapshot for L1 batch #{l1_batch_number} is not present on main node")
+            })?;
+        let l1_batch = self
+            .main_node_client
+            .fetch_l1_batch_details(l1_batch_number)
+            .await?
+            .with_context(|| format!("L1 batch #{l1_batch_number} is missing on main node"))?;
+        let expected_root_hash = l1_batch
+            .base
+            .root_hash
+            .context("snapshot L1 batch fetched from main node doesn't have root hash set")?;
+        tracing::info!(
+            "Verifying snapshot for L1 batch #{l1_batch_number}, L2 block #{}, version {}, with {} storage logs chunk(s)",
+            header.l2_block_number,
+            header.version,
+            header.storage_logs_chunks.len()
+        );
+
+        let mut report = VerificationReport::new(
+            l1_batch_number,
+            header.l2_block_number,
+            header.version,
+            expected_root_hash,
+        );
+        let layers = self.fetch_snapshot_layers(&header).await?;
+        report.base_l1_batch_numbers = layers[..layers.len() - 1]
+            .iter()
+            .map(|layer| layer.l1_batch_number)
+            .collect();
+        report.storage_logs_chunk_count = header.storage_logs_chunks.len();
+        let chunk_ids: Vec<_> = header
+            .storage_logs_chunks
+            .iter()
+            .map(|chunk| chunk.chunk_id)
+            .collect();
+        if !chunk_ids.iter().copied().eq(0..chunk_ids.len() as u64) {
+            report.push_error(VerificationError::UnexpectedChunkIds { chunk_ids });
+        }
+
+        let factory_dep_digests = self.load_factory_deps(&layers, &mut report).await?;
+        tracing::info!(
+            "Loaded {} factory deps; loading storage logs",
+            report.factory_dep_count
+        );
+
+        let chunk_count = header.storage_logs_chunks.len() as u64;
+        let mut tree = MerkleTreeRecovery::new(tree_db, l1_batch_number.0.into())?;
+        let mut enumeration_indices = EnumerationIndices::default();
+        let mut unresolved_bytecode_hashes = HashSet::new();
+        let mut is_tree_complete = true;
+
+        let mut chunks = stream::iter(0..chunk_count)
+            .map(|chunk_id| self.load_chunk(&layers, chunk_id, chunk_count))
+            .buffered(self.concurrency);
+        while let Some(chunk) = chunks.next().await {
+            let LoadedChunk {
+                chunk_id,
+                storage_logs,
+                errors,
+            } = chunk?;
+            for err in errors {
+                report.push_error(err);
+            }
+            let Some(storage_logs) = storage_logs else {
+                is_tree_complete = false;
+                continue;
+            };
+
+            let mut tree_entries = Vec::with_capacity(storage_logs.len());
+            for log in &storage_logs {
+                if log.enumeration_index == 0 || log.enumeration_index > MAX_ENUMERATION_INDEX {
+                    report.push_error(VerificationError::InvalidEnumerationIndex {
+                        key: log.key,
+                        enumeration_index: log.enumeration_index,
+                    });
+                } else if !enumeration_indices.insert(log.enumeration_index) {
+                    report.push_error(VerificationError::DuplicateEnumerationIndex {
+                        key: log.key,
+                        enumeration_index: log.enumeration_index,
+                    });
+                }
+                if log.l1_batch_number_of_initial_write > l1_batch_number {
+                    report.push_error(VerificationError::InitialWriteFromFuture {
+                        key: log.key,
+                        l1_batch_number_of_initial_write: log.l1_batch_number_of_initial_write,
+                    });
+                }
+                if let Some(bytecode_hash) = bytecode_hash_candidate(log.value) {
+                    if !factory_dep_digests.contains(&bytecode_digest(bytecode_hash)) {
+                        unresolved_bytecode_hashes.insert(bytecode_hash);
+                    }
+                }
+                tree_entries.push(TreeEntry::new(
+                    tree_key(log.key),
+                    log.enumeration_index,
+                    log.value,
+                ));
+            }
+            report.storage_log_count += storage_logs.len() as u64;
+
+            tree = tokio::task::spawn_blocking(move || {
+                tree.extend_random(tree_entries)?;
+                anyhow::Ok(tree)
+            })
+            .await
+            .context("extending Merkle tree panicked")??;
+            tracing::info!("Processed storage logs chunk {chunk_id} / {chunk_count}");
+        }
+
+        report.max_enumeration_index = enumeration_indices.max;
+        if is_tree_complete && enumeration_indices.count < enumeration_indices.max {
+            report.push_error(VerificationError::MissingEnumerationIndices {
+                count: enumeration_indices.max - enumeration_indices.count,
+            });
+        }
+
+        // Values that look like bytecode hashes may be arbitrary storage values. A bytecode hash is only reported
+        // as missing if it's marked as known in the `KnownCodesStorage` system contract.
+        tracing::info!(
+            "Checking {} potential bytecode hashes without factory deps",
+            unresolved_bytecode_hashes.len()
+        );
+        let unresolved_bytecode_hashes: Vec<_> = unresolved_bytecode_hashes.into_iter().collect();
+        let known_code_keys: Vec<_> = unresolved_bytecode_hashes
+            .iter()
+            .map(|hash| tree_key(get_known_code_key(hash).hashed_key()))
+            .collect();
+        let known_code_entries = tree.entries(&known_code_keys);
+        for (bytecode_hash, entry) in unresolved_bytecode_hashes
+            .into_iter()
+            .zip(known_code_entries)
+        {
+            if !entry.is_empty() && !entry.value.is_zero() {
+                report.push_error(VerificationError::MissingFactoryDep { bytecode_hash });
+            }
+        }
+
+        if is_tree_complete {
+            let actual_root_hash = tree.root_hash();
+            report.actual_root_hash = Some(actual_root_hash);
+            if actual_root_hash != expected_root_hash {
+                report.push_error(VerificationError::RootHashMismatch {
+                    expected: expected_root_hash,
+                    actual: actual_root_hash,
+                });
+            }
+        }
+        Ok(report.finish())
+    }
+
+    /// Resolves the chain of base snapshots. Returned layers are ordered from the full snapshot to the verified one.
+    async fn fetch_snapshot_layers(
+        &self,
+        header: &SnapshotHeader,
+    ) -> anyhow::Result<Vec<SnapshotLayer>> {
+        let mut layers = vec![SnapshotLayer {
+            l1_batch_number: header.l1_batch_number,
+            version: parse_snapshot_version(header.version)?,
+        }];
+        let mut base_l1_batch_number = header.base_l1_batch_number;
+
+        while let Some(l1_batch_number) = base_l1_batch_number {
+            let child = *layers.last().unwrap(); // `unwrap()` is safe: `layers` is non-empty
+            anyhow::ensure!(
+                child.version == SnapshotVersion::Version2 && l1_batch_number < child.l1_batch_number,
+                "snapshot for L1 batch #{} with version {:?} cannot have base snapshot for L1 batch #{l1_batch_number}",
+                child.l1_batch_number,
+                child.version
+            );
+
+            let base_header = self
+                .main_node_client
+                .fetch_snapshot(l1_batch_number)
+                .await?
+                .with_context(|| {
+                    format!(
+                        "base snapshot for L1 batch #{l1_batch_number} is not present on main node"
+                    )
+                })?;
+            let version = parse_snapshot_version(base_header.version)?;
+            anyhow::ensure!(
+                version.can_be_base(),
+                "base snapshot for L1 batch #{l1_batch_number} has version {version:?} that doesn't support incremental snapshots"
+            );
+            anyhow::ensure!(
+                base_header.storage_logs_chunks.len() == header.storage_logs_chunks.len(),
+                "base snapshot for L1 batch #{l1_batch_number} has {} storage logs chunks, while the verified snapshot has {}",
+                base_header.storage_logs_chunks.len(),
+                header.storage_logs_chunks.len()
+            );
+            layers.push(SnapshotLayer {
+                l1_batch_number,
+                version,
+            });
+            base_l1_batch_number = base_header.base_l1_batch_number;
+        }
+
+        layers.reverse();
+        Ok(layers)
+    }
+
+    /// Returns digests of all loaded factory deps (see [`bytecode_digest()`]).
+    async fn load_factory_deps(
+        &self,
+        layers: &[SnapshotLayer],
+        report: &mut VerificationReport,
+    ) -> anyhow::Result<HashSet<[u8; 28]>> {
+        let mut digests = HashSet::new();
+        for layer in layers {
+            let l1_batch_number = layer.l1_batch_number;
+            let factory_deps: Option<SnapshotFactoryDependencies> =
+                get_optional(self.blob_store.as_ref(), l1_batch_number)
+                    .await
+                    .with_context(|| {
+                        format!("cannot fetch factory deps for L1 batch #{l1_batch_number}")
+                    })?;
+            let Some(factory_deps) = factory_deps else {
+                report.push_error(VerificationError::MissingFactoryDeps { l1_batch_number });
+                continue;
+            };
+
+            for dep in &factory_deps.factory_deps {
+                let index = report.factory_dep_count;
+                report.factory_dep_count += 1;
+                if let Err(err) = validate_bytecode(&dep.bytecode.0) {
+                    report.push_error(VerificationError::InvalidFactoryDep {
+                        index,
+                        error: err.to_string(),
+                    });
+                    continue;
+                }
+                let hash = BytecodeHash::for_bytecode(&dep.bytecode.0).value();
+                digests.insert(bytecode_digest(hash));
+            }
+        }
+        Ok(digests)
+    }
+
+    async fn load_chunk(
+        &self,
+        layers: &[SnapshotLayer],
+        chunk_id: u64,
+        chunk_count: u64,
+    ) -> anyhow::Result<LoadedChunk> {
+        let hashed_keys_range = uniform_hashed_keys_chunk(chunk_id, chunk_count);
+        let mut errors = vec![];
+        let mut logs_by_key = HashMap::<H256, SnapshotStorageLog>::new();
+        let mut is_complete = true;
+
+        for (i, layer) in layers.iter().enumerate() {
+            let key = SnapshotStorageLogsStorageKey {
+                l1_batch_number: layer.l1_batch_number,
+                chunk_id,
+            };
+            let storage_logs = match layer.version {
+                SnapshotVersion::Version0 => {
+                    let chunk: Option<SnapshotStorageLogsChunk<StorageKey>> =
+                        get_optional(self.blob_store.as_ref(), key).await?;
+                    chunk.map(|chunk| {
+                        chunk
+                            .storage_logs
+                            .into_iter()
+                            .map(SnapshotStorageLog::drop_key_preimage)
+                            .collect::<Vec<_>>()
+                    })
+                }
+                SnapshotVersion::Version1 | SnapshotVersion::Version2 => {
+                    let chunk: Option<SnapshotStorageLogsChunk> =
+                        get_optional(self.blob_store.as_ref(), key).await?;
+                    chunk.map(|chunk| chunk.storage_logs)
+                }
+            };
+            let Some(storage_logs) = storage_logs else {
+                errors.push(VerificationError::MissingChunk {
+                    l1_batch_number: layer.l1_batch_number,
+                    chunk_id,
+                });
+                is_complete = false;
+                continue;
+            };
+
+            let is_base_layer = i == 0;
+            let mut layer_keys = HashSet::with_capacity(storage_logs.len());
+            for log in storage_logs {
+                // Logs with problematic keys are not included into the tree so that they don't affect other chunks.
+                if !hashed_keys_range.contains(&log.key) {
+                    errors.push(VerificationError::KeyOutsideChunkRange {
+                        chunk_id,
+                        key: log.key,
+                    });
+                    continue;
+                }
+                if !layer_keys.insert(log.key) {
+                    errors.push(VerificationError::DuplicateKey {
+                        chunk_id,
+                        key: log.key,
+                    });
+                    continue;
+                }
+                if !is_base_layer {
+                    if let Some(prev_log) = logs_by_key.get(&log.key) {
+                        if prev_log.enumeration_index != log.enumeration_index
+                            || prev_log.l1_batch_number_of_initial_write
+                                != log.l1_batch_number_of_initial_write
+                        {
+                            errors.push(VerificationError::InconsistentIncrementalLog {
+                                chunk_id,
+                                key: log.key,
+                            });
+                        }
+                    }
+                }
+                logs_by_key.insert(log.key, log);
+            }
+        }
+
+        Ok(LoadedChunk {
+            chunk_id,
+            storage_logs: is_complete.then(|| logs_by_key.into_values().collect()),
+            errors,
+        })
+    }
+}
+
+fn parse_snapshot_version(raw_version: u16) -> anyhow::Result<SnapshotVersion> {
+    SnapshotVersion::try_from(raw_version)
+        .with_context(|| format!("unrecognized snapshot version: {raw_version}"))
+}
+
+/// Loads an object from the store, returning `Ok(None)` if it's missing.
+async fn get_optional<V: StoredObject>(
+    blob_store: &dyn ObjectStore,
+    key: V::Key<'_>,
+) -> anyhow::Result<Option<V>> {
+    match blob_store.get(key).await {
+        Ok(value) => Ok(Some(value)),
+        Err(ObjectStoreError::KeyNotFound(_)) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Converts a hashed key to the format used by the Merkle tree (little-endian [`U256`]).
+fn tree_key(hashed_key: H256) -> U256 {
+    U256::from_little_endian(hashed_key.as_bytes())
+}
+
+/// Checks whether a storage value looks like a bytecode hash stored in the `AccountCodeStorage` system contract.
+/// If it does, returns the hash with the "being constructed" flag cleared.
+fn bytecode_hash_candidate(value: H256) -> Option<H256> {
+    BytecodeMarker::new(value)?;
+    let bytes = value.as_bytes();
+    let is_constructed_flag_valid = bytes[1] == 0 || bytes[1] == 1;
+    let has_length = bytes[2] != 0 || bytes[3] != 0;
+    if !is_constructed_flag_valid || !has_length {
+        return None;
+    }
+    let mut hash = value;
+    hash.0[1] = 0;
+    Some(hash)
+}
+
+/// Returns the part of a bytecode hash that only depends on the bytecode contents (i.e., the truncated SHA-256 digest
+/// without the bytecode marker, constructed flag and length). This allows comparing hashes regardless of the bytecode kind.
+fn bytecode_digest(hash: H256) -> [u8; 28] {
+    hash.as_bytes()[4..].try_into().unwrap()
+}
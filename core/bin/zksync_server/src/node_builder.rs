@@ -28,8 +28,10 @@ use zksync_node_framework::{
         consensus::MainNodeConsensusLayer,
         contract_verification_api::ContractVerificationApiLayer,
         da_clients::{
-            avail::AvailWiringLayer, celestia::CelestiaWiringLayer, eigen::EigenWiringLayer,
+            avail::AvailWiringLayer, celestia::CelestiaWiringLayer,
+            composite::CompositeDAClientWiringLayer, eigen::EigenWiringLayer,
             no_da::NoDAClientWiringLayer, object_store::ObjectStorageClientWiringLayer,
+            sidecar::SidecarWiringLayer,
         },
         da_dispatcher::DataAvailabilityDispatcherLayer,
         eth_sender::{EthTxAggregatorLayer, EthTxManagerLayer},
@@ -52,6 +54,7 @@ use zksync_node_framework::{
         prometheus_exporter::PrometheusExporterLayer,
         proof_data_handler::ProofDataHandlerLayer,
         query_eth_client::QueryEthClientLayer,
+        remote_signing_eth_client::RemoteSigningEthClientLayer,
         sigint::SigintHandlerLayer,
         state_keeper::{
             main_batch_executor::MainBatchExecutorLayer, mempool_io::MempoolIOLayer,
@@ -143,8 +146,17 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
-    fn add_pk_signing_client_layer(mut self) -> anyhow::Result<Self> {
+    fn add_signing_client_layer(mut self) -> anyhow::Result<Self> {
         let eth_config = try_load_config!(self.configs.eth);
+        if eth_config.remote_signer.is_some() {
+            self.node.add_layer(RemoteSigningEthClientLayer::new(
+                eth_config,
+                self.contracts_config.clone(),
+                self.genesis_config.settlement_layer_id(),
+            ));
+            return Ok(self);
+        }
+
         let wallets = try_load_config!(self.wallets.eth_sender);
         self.node.add_layer(PKSigningEthClientLayer::new(
             eth_config,
@@ -166,6 +178,10 @@ impl MainNodeBuilder {
                 .as_ref()
                 .and_then(|x| Some(x.gas_adjuster?.settlement_mode))
                 .unwrap_or(SettlementMode::SettlesToL1),
+        )
+        .with_fallback_urls(
+            eth_config.fallback_l1_rpc_urls,
+            eth_config.cross_check_l1_block_hashes,
         );
         self.node.add_layer(query_eth_client_layer);
         Ok(self)
@@ -225,6 +241,9 @@ impl MainNodeBuilder {
             &state_keeper_env_config,
         );
         let mut layer = MetadataCalculatorLayer::new(metadata_calculator_config);
+        if let Some(interval) = merkle_tree_env_config.checkpoint_interval() {
+            layer = layer.with_checkpoint_interval(interval);
+        }
         if with_tree_api {
             let merkle_tree_api_config = try_load_config!(self.configs.api_config).merkle_tree;
             layer = layer.with_tree_api_config(merkle_tree_api_config);
@@ -267,6 +286,7 @@ impl MainNodeBuilder {
                 .experimental
                 .state_keeper_db_block_cache_capacity(),
             max_open_files: db_config.experimental.state_keeper_db_max_open_files,
+            read_only: false,
         };
         let state_keeper_layer =
             StateKeeperLayer::new(db_config.state_keeper_db_path, rocksdb_options);
@@ -332,18 +352,31 @@ impl MainNodeBuilder {
             .unwrap_or_default();
 
         // On main node we always use master pool sink.
-        self.node.add_layer(MasterPoolSinkLayer);
-
+        let mut tx_sink_layer = MasterPoolSinkLayer::default();
+        if let Some(percent) = self
+            .configs
+            .mempool_config
+            .as_ref()
+            .and_then(|config| config.replacement_fee_bump_percent)
+        {
+            tx_sink_layer = tx_sink_layer.with_replacement_fee_bump_percent(percent);
+        }
+        self.node.add_layer(tx_sink_layer);
+
+        let mut tx_sender_config = TxSenderConfig::new(
+            &sk_config,
+            &rpc_config,
+            try_load_config!(self.wallets.state_keeper)
+                .fee_account
+                .address(),
+            self.genesis_config.l2_chain_id,
+            timestamp_asserter_params,
+        );
+        if let Some(mempool_config) = &self.configs.mempool_config {
+            tx_sender_config = tx_sender_config.with_mempool_limits(mempool_config);
+        }
         let layer = TxSenderLayer::new(
-            TxSenderConfig::new(
-                &sk_config,
-                &rpc_config,
-                try_load_config!(self.wallets.state_keeper)
-                    .fee_account
-                    .address(),
-                self.genesis_config.l2_chain_id,
-                timestamp_asserter_params,
-            ),
+            tx_sender_config,
             postgres_storage_caches_config,
             rpc_config.vm_concurrency_limit(),
         );
@@ -392,6 +425,9 @@ impl MainNodeBuilder {
             subscriptions_limit: Some(rpc_config.subscriptions_limit()),
             batch_request_size_limit: Some(rpc_config.max_batch_request_size()),
             response_body_size_limit: Some(rpc_config.max_response_body_size()),
+            client_requests_per_minute_limit: rpc_config.client_requests_per_minute_limit,
+            rate_limit_method_costs: Some(rpc_config.rate_limit_method_costs.clone()),
+            trust_forwarded_for_header: rpc_config.trust_forwarded_for_header,
             with_extended_tracing: rpc_config.extended_api_tracing,
             ..Default::default()
         };
@@ -432,6 +468,9 @@ impl MainNodeBuilder {
             websocket_requests_per_minute_limit: Some(
                 rpc_config.websocket_requests_per_minute_limit(),
             ),
+            client_requests_per_minute_limit: rpc_config.client_requests_per_minute_limit,
+            rate_limit_method_costs: Some(rpc_config.rate_limit_method_costs.clone()),
+            trust_forwarded_for_header: rpc_config.trust_forwarded_for_header,
             replication_lag_limit: circuit_breaker_config.replication_lag_limit(),
             with_extended_tracing: rpc_config.extended_api_tracing,
             ..Default::default()
@@ -546,6 +585,15 @@ impl MainNodeBuilder {
                 self.node
                     .add_layer(ObjectStorageClientWiringLayer::new(config));
             }
+
+            (DAClientConfig::Sidecar(config), _) => {
+                self.node.add_layer(SidecarWiringLayer::new(config));
+            }
+
+            (DAClientConfig::Composite(config), DataAvailabilitySecrets::Composite(secret)) => {
+                self.node
+                    .add_layer(CompositeDAClientWiringLayer::new(config, secret));
+            }
             _ => bail!("invalid pair of da_client and da_secrets"),
         }
 
@@ -753,7 +801,7 @@ impl MainNodeBuilder {
                 }
                 Component::EthTxAggregator => {
                     self = self
-                        .add_pk_signing_client_layer()?
+                        .add_signing_client_layer()?
                         .add_eth_tx_aggregator_layer()?;
                 }
                 Component::EthTxManager => {
@@ -29,3 +29,26 @@ pub struct DataAvailabilityBlob {
     pub inclusion_data: Option<Vec<u8>>,
     pub sent_at: DateTime<Utc>,
 }
+
+/// Represents a part of the L1 batch pubdata dispatched to the data availability layer as a separate blob,
+/// because the whole pubdata exceeds the blob size limit of the DA client.
+#[derive(Debug, Clone)]
+pub struct DataAvailabilityBlobPart {
+    pub l1_batch_number: L1BatchNumber,
+    pub part_index: u32,
+    pub blob_id: String,
+    pub inclusion_data: Option<Vec<u8>>,
+    pub sent_at: DateTime<Utc>,
+}
+
+/// Represents a DA blob for an L1 batch that was abandoned and re-dispatched, because it wasn't included
+/// in the data availability layer within the inclusion timeout.
+#[derive(Debug, Clone)]
+pub struct DataAvailabilityDispatchAttempt {
+    pub l1_batch_number: L1BatchNumber,
+    /// 1-based index of the attempt.
+    pub attempt: u32,
+    pub blob_id: String,
+    pub sent_at: DateTime<Utc>,
+    pub abandoned_at: DateTime<Utc>,
+}
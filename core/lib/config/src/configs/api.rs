@@ -123,6 +123,103 @@ impl<'de> Deserialize<'de> for MaxResponseSizeOverrides {
     }
 }
 
+/// Costs of specific RPC methods for per-client rate limiting, measured in requests.
+///
+/// Keys are either full method names (e.g., `eth_getLogs`) or prefixes ending with `*` (e.g., `debug_*`).
+/// Full method names take precedence over prefixes; if several prefixes match a method, the longest one is used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitMethodCosts(HashMap<String, NonZeroU32>);
+
+impl<S: Into<String>> FromIterator<(S, NonZeroU32)> for RateLimitMethodCosts {
+    fn from_iter<I: IntoIterator<Item = (S, NonZeroU32)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(method_name, cost)| (method_name.into(), cost))
+                .collect(),
+        )
+    }
+}
+
+impl FromStr for RateLimitMethodCosts {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut costs = HashMap::new();
+        for part in s.split(',') {
+            let (method_name, cost) = part
+                .split_once('=')
+                .with_context(|| format!("Part `{part}` doesn't have form <method_name>=<int>"))?;
+            let method_name = method_name.trim();
+            if method_name.is_empty() {
+                anyhow::bail!("Part `{part}` has an empty method name");
+            }
+
+            let cost = cost.trim();
+            let cost: NonZeroU32 = cost.parse().with_context(|| {
+                format!("`{cost}` specified for method `{method_name}` is not a valid cost")
+            })?;
+
+            if let Some(prev_cost) = costs.insert(method_name.to_owned(), cost) {
+                anyhow::bail!("Cost for `{method_name}` is redefined from {prev_cost} to {cost}");
+            }
+        }
+        Ok(Self(costs))
+    }
+}
+
+impl RateLimitMethodCosts {
+    pub fn empty() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Gets the cost for the specified method. Methods without a configured cost cost a single request.
+    pub fn get(&self, method_name: &str) -> NonZeroU32 {
+        if let Some(&cost) = self.0.get(method_name) {
+            return cost;
+        }
+        self.0
+            .iter()
+            .filter_map(|(pattern, &cost)| {
+                let prefix = pattern.strip_suffix('*')?;
+                method_name
+                    .starts_with(prefix)
+                    .then_some((prefix.len(), cost))
+            })
+            .max_by_key(|(prefix_len, _)| *prefix_len)
+            .map_or(NonZeroU32::MIN, |(_, cost)| cost)
+    }
+
+    /// Iterates over all configured costs.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, NonZeroU32)> + '_ {
+        self.0
+            .iter()
+            .map(|(method_name, &cost)| (method_name.as_str(), cost))
+    }
+}
+
+impl<'de> Deserialize<'de> for RateLimitMethodCosts {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ParseVisitor;
+
+        impl<'v> de::Visitor<'v> for ParseVisitor {
+            type Value = RateLimitMethodCosts;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("comma-separated list of <method_name>=<cost> tuples, such as: eth_getLogs=10,debug_*=20")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(ParseVisitor)
+    }
+}
+
 /// Response size limits for JSON-RPC servers.
 #[derive(Debug)]
 pub struct MaxResponseSize {
@@ -195,6 +292,13 @@ pub struct Web3JsonRpcConfig {
     pub latest_values_max_block_lag: Option<NonZeroU32>,
     /// Limit for fee history block range.
     pub fee_history_limit: Option<u64>,
+    /// Maximum number of blocks in a single `eth_simulateV1` request. Default is 256.
+    pub simulate_blocks_limit: Option<usize>,
+    /// Maximum total number of calls in a single `eth_simulateV1` request. Default is 1,000.
+    pub simulate_calls_limit: Option<usize>,
+    /// Maximum total gas limit of calls in a single `eth_simulateV1` request. Calls without the specified gas limit
+    /// get the default `eth_call` gas limit capped by the remaining budget. Default is 1,000,000,000.
+    pub simulate_gas_limit: Option<u64>,
     /// Maximum number of requests in a single batch JSON RPC request. Default is 500.
     pub max_batch_request_size: Option<usize>,
     /// Maximum response body size in MiBs. Default is 10 MiB.
@@ -204,8 +308,21 @@ pub struct Web3JsonRpcConfig {
     pub max_response_body_size_overrides_mb: MaxResponseSizeOverrides,
     /// Maximum number of requests per minute for the WebSocket server.
     /// The value is per active connection.
-    /// Note: For HTTP, rate limiting is expected to be configured on the infra level.
+    /// See also `client_requests_per_minute_limit`, which applies to both HTTP and WebSocket servers.
     pub websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    /// Maximum number of requests per minute from a single client. Applies to both HTTP and WebSocket servers.
+    /// Clients are identified by their IP address taken from the `X-Forwarded-For` header if `trust_forwarded_for_header`
+    /// is set, and by the peer IP address of their connection otherwise. If not set, per-client rate limiting is disabled.
+    pub client_requests_per_minute_limit: Option<NonZeroU32>,
+    /// Whether to identify clients by the `X-Forwarded-For` header for per-client rate limiting. Should only be enabled
+    /// if the server is behind a reverse proxy that appends the client IP address to this header; otherwise,
+    /// clients can trivially circumvent rate limiting by spoofing the header.
+    #[serde(default)]
+    pub trust_forwarded_for_header: bool,
+    /// Method-specific costs for per-client rate limiting (e.g., `eth_getLogs=10,debug_*=20`). Methods not mentioned
+    /// here cost a single request.
+    #[serde(default = "RateLimitMethodCosts::empty")]
+    pub rate_limit_method_costs: RateLimitMethodCosts,
     /// Tree API url, currently used to proxy `getProof` calls to the tree
     pub tree_api_url: Option<String>,
     /// Polling period for mempool cache update - how often the mempool cache is updated from the database.
@@ -254,10 +371,16 @@ impl Web3JsonRpcConfig {
             latest_values_cache_size_mb: None,
             latest_values_max_block_lag: None,
             fee_history_limit: None,
+            simulate_blocks_limit: None,
+            simulate_calls_limit: None,
+            simulate_gas_limit: None,
             max_batch_request_size: None,
             max_response_body_size_mb: None,
             max_response_body_size_overrides_mb: MaxResponseSizeOverrides::empty(),
             websocket_requests_per_minute_limit: None,
+            client_requests_per_minute_limit: None,
+            trust_forwarded_for_header: false,
+            rate_limit_method_costs: RateLimitMethodCosts::empty(),
             mempool_cache_update_interval: None,
             mempool_cache_size: None,
             tree_api_url: None,
@@ -322,6 +445,18 @@ impl Web3JsonRpcConfig {
         self.fee_history_limit.unwrap_or(1024)
     }
 
+    pub fn simulate_blocks_limit(&self) -> usize {
+        self.simulate_blocks_limit.unwrap_or(256)
+    }
+
+    pub fn simulate_calls_limit(&self) -> usize {
+        self.simulate_calls_limit.unwrap_or(1_000)
+    }
+
+    pub fn simulate_gas_limit(&self) -> u64 {
+        self.simulate_gas_limit.unwrap_or(1_000_000_000)
+    }
+
     pub fn max_batch_request_size(&self) -> usize {
         // The default limit is chosen to be reasonably permissive.
         self.max_batch_request_size.unwrap_or(500)
@@ -431,4 +566,24 @@ mod tests {
         assert_eq!(scaled.get("zks_getProof"), Some(32_000));
         assert_eq!(scaled.get("eth_blockNumber"), None);
     }
+
+    #[test]
+    fn working_with_rate_limit_method_costs() {
+        let costs: RateLimitMethodCosts = "eth_getLogs=10, debug_*=20,debug_traceCall = 50,eth_*=2"
+            .parse()
+            .unwrap();
+        assert_eq!(costs.iter().len(), 4);
+        assert_eq!(costs.get("eth_getLogs").get(), 10);
+        assert_eq!(costs.get("eth_call").get(), 2);
+        assert_eq!(costs.get("debug_traceCall").get(), 50);
+        assert_eq!(costs.get("debug_traceBlockByNumber").get(), 20);
+        assert_eq!(costs.get("zks_getProof").get(), 1);
+
+        let err = "eth_call=0".parse::<RateLimitMethodCosts>().unwrap_err();
+        assert!(format!("{err:#}").contains("not a valid cost"), "{err:#}");
+        let err = "eth_call=1,eth_call=2"
+            .parse::<RateLimitMethodCosts>()
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("redefined"), "{err:#}");
+    }
 }
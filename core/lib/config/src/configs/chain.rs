@@ -221,6 +221,18 @@ impl CircuitBreakerConfig {
     }
 }
 
+/// Ordering of L2 transactions in the mempool. Regardless of the ordering, transactions from the same account
+/// are always executed in the nonce order.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+pub enum MempoolOrdering {
+    /// Transactions are executed in the order they were received.
+    #[default]
+    Fifo,
+    /// Transactions with higher `max_fee_per_gas` are executed first. Transactions with equal fees
+    /// are executed in the order they were received.
+    FeePriority,
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct MempoolConfig {
     pub sync_interval_ms: u64,
@@ -229,6 +241,17 @@ pub struct MempoolConfig {
     pub stuck_tx_timeout: u64,
     pub remove_stuck_txs: bool,
     pub delay_interval: u64,
+    /// Ordering of L2 transactions in the mempool.
+    #[serde(default)]
+    pub ordering: MempoolOrdering,
+    /// Maximum number of pending transactions per account. If not set, accounts are only limited
+    /// by the overall mempool `capacity`.
+    #[serde(default)]
+    pub max_pending_txs_per_account: Option<u32>,
+    /// Minimum bump (in percent) of both `max_fee_per_gas` and `max_priority_fee_per_gas` required
+    /// to replace a pending transaction with the same nonce. If not set, replacements are accepted regardless of fees.
+    #[serde(default)]
+    pub replacement_fee_bump_percent: Option<u32>,
 }
 
 impl MempoolConfig {
@@ -0,0 +1,19 @@
+use crate::configs::{da_client::DAClientConfig, secrets::DataAvailabilitySecrets};
+
+/// Configuration of the composite DA client, which dispatches every blob to several DA layers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompositeConfig {
+    /// DA layers the blobs are dispatched to, ordered by preference: inclusion data is taken from the first layer
+    /// that confirms inclusion. Nested composite configurations are not supported.
+    pub clients: Vec<DAClientConfig>,
+    /// Number of DA layers that must accept a blob for it to be considered dispatched, and confirm its inclusion
+    /// for it to be considered included. No particular layer is required to do so.
+    pub quorum: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompositeSecrets {
+    /// Secrets for each of the DA layers in the order of `CompositeConfig::clients`;
+    /// `None` for the layers that don't require secrets.
+    pub secrets: Vec<Option<DataAvailabilitySecrets>>,
+}
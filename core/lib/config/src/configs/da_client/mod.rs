@@ -1,13 +1,19 @@
-use crate::{AvailConfig, CelestiaConfig, EigenConfig, ObjectStoreConfig};
+use crate::{
+    AvailConfig, CelestiaConfig, CompositeConfig, EigenConfig, ObjectStoreConfig, SidecarConfig,
+};
 
 pub mod avail;
 pub mod celestia;
+pub mod composite;
 pub mod eigen;
+pub mod sidecar;
 
 pub const AVAIL_CLIENT_CONFIG_NAME: &str = "Avail";
 pub const CELESTIA_CLIENT_CONFIG_NAME: &str = "Celestia";
+pub const COMPOSITE_CLIENT_CONFIG_NAME: &str = "Composite";
 pub const EIGEN_CLIENT_CONFIG_NAME: &str = "Eigen";
 pub const OBJECT_STORE_CLIENT_CONFIG_NAME: &str = "ObjectStore";
+pub const SIDECAR_CLIENT_CONFIG_NAME: &str = "Sidecar";
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum DAClientConfig {
@@ -15,4 +21,6 @@ pub enum DAClientConfig {
     Celestia(CelestiaConfig),
     Eigen(EigenConfig),
     ObjectStore(ObjectStoreConfig),
+    Sidecar(SidecarConfig),
+    Composite(CompositeConfig),
 }
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use zksync_basic_types::url::SensitiveUrl;
+
+/// Configuration of the DA client communicating with an external DA sidecar via the HTTP sidecar protocol.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct SidecarConfig {
+    /// Base URL of the sidecar HTTP API, e.g. `http://localhost:3100`. May contain credentials.
+    pub api_url: SensitiveUrl,
+    /// Timeout for a single request to the sidecar.
+    pub timeout_ms: u64,
+}
@@ -6,6 +6,8 @@ pub const DEFAULT_POLLING_INTERVAL_MS: u32 = 5000;
 pub const DEFAULT_MAX_ROWS_TO_DISPATCH: u32 = 100;
 pub const DEFAULT_MAX_RETRIES: u16 = 5;
 pub const DEFAULT_USE_DUMMY_INCLUSION_DATA: bool = false;
+pub const DEFAULT_MAX_CONCURRENT_INCLUSION_REQUESTS: u32 = 10;
+pub const DEFAULT_SPLIT_OVERSIZED_BLOBS: bool = false;
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct DADispatcherConfig {
@@ -19,6 +21,15 @@ pub struct DADispatcherConfig {
     // TODO: run a verification task to check if the L1 contract expects the inclusion proofs to
     // avoid the scenario where contracts expect real proofs, and server is using dummy proofs.
     pub use_dummy_inclusion_data: Option<bool>,
+    /// Time after which a dispatched blob that is still not included is abandoned, and the pubdata is dispatched again.
+    /// If not set, blobs are never re-dispatched.
+    pub inclusion_timeout_ms: Option<u64>,
+    /// The maximum number of blobs to request inclusion data for concurrently.
+    pub max_concurrent_inclusion_requests: Option<u32>,
+    /// Split pubdata exceeding the blob size limit of the DA client into several blobs. The L1 batch
+    /// is considered included once all its blobs are included; the inclusion data of the blobs is combined
+    /// into an ABI-encoded `bytes[]` array ordered by the blob index.
+    pub split_oversized_blobs: Option<bool>,
 }
 
 impl DADispatcherConfig {
@@ -28,6 +39,9 @@ impl DADispatcherConfig {
             max_rows_to_dispatch: Some(DEFAULT_MAX_ROWS_TO_DISPATCH),
             max_retries: Some(DEFAULT_MAX_RETRIES),
             use_dummy_inclusion_data: Some(DEFAULT_USE_DUMMY_INCLUSION_DATA),
+            inclusion_timeout_ms: None,
+            max_concurrent_inclusion_requests: Some(DEFAULT_MAX_CONCURRENT_INCLUSION_REQUESTS),
+            split_oversized_blobs: Some(DEFAULT_SPLIT_OVERSIZED_BLOBS),
         }
     }
 
@@ -51,4 +65,18 @@ impl DADispatcherConfig {
         self.use_dummy_inclusion_data
             .unwrap_or(DEFAULT_USE_DUMMY_INCLUSION_DATA)
     }
+
+    pub fn inclusion_timeout(&self) -> Option<Duration> {
+        self.inclusion_timeout_ms.map(Duration::from_millis)
+    }
+
+    pub fn max_concurrent_inclusion_requests(&self) -> usize {
+        self.max_concurrent_inclusion_requests
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_INCLUSION_REQUESTS) as usize
+    }
+
+    pub fn split_oversized_blobs(&self) -> bool {
+        self.split_oversized_blobs
+            .unwrap_or(DEFAULT_SPLIT_OVERSIZED_BLOBS)
+    }
 }
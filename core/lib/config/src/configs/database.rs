@@ -46,6 +46,11 @@ pub struct MerkleTreeConfig {
     /// Maximum number of L1 batches to be processed by the Merkle tree at a time.
     #[serde(default = "MerkleTreeConfig::default_max_l1_batches_per_iter")]
     pub max_l1_batches_per_iter: usize,
+    /// Interval between exporting Merkle tree checkpoints to the object store. If not specified,
+    /// checkpoints are not exported. Checkpoints are exported to the generic object store of the node;
+    /// external nodes read them from their snapshots object store, so both must point to the same bucket.
+    #[serde(default)]
+    pub checkpoint_interval_sec: Option<u64>,
 }
 
 impl Default for MerkleTreeConfig {
@@ -58,6 +63,7 @@ impl Default for MerkleTreeConfig {
             memtable_capacity_mb: Self::default_memtable_capacity_mb(),
             stalled_writes_timeout_sec: Self::default_stalled_writes_timeout_sec(),
             max_l1_batches_per_iter: Self::default_max_l1_batches_per_iter(),
+            checkpoint_interval_sec: None,
         }
     }
 }
@@ -101,6 +107,11 @@ impl MerkleTreeConfig {
     pub fn stalled_writes_timeout(&self) -> Duration {
         Duration::from_secs(self.stalled_writes_timeout_sec)
     }
+
+    /// Returns the interval between exporting Merkle tree checkpoints, or `None` if checkpoints are disabled.
+    pub fn checkpoint_interval(&self) -> Option<Duration> {
+        self.checkpoint_interval_sec.map(Duration::from_secs)
+    }
 }
 
 /// Database configuration.
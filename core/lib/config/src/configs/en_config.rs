@@ -17,7 +17,17 @@ pub struct ENConfig {
     // Main node configuration
     pub main_node_url: SensitiveUrl,
     pub main_node_rate_limit_rps: Option<NonZeroUsize>,
+    /// Fallback main node URLs in the order of preference.
+    #[serde(default)]
+    pub main_node_fallback_urls: Vec<SensitiveUrl>,
 
     pub gateway_url: Option<SensitiveUrl>,
     pub bridge_addresses_refresh_interval_sec: Option<NonZeroU64>,
+
+    // Reorg handling
+    pub auto_rollback_on_reorg: Option<bool>,
+    pub max_auto_rollback_depth: Option<u32>,
+
+    /// Whether to restore the Merkle tree from the latest checkpoint if the tree is not initialized.
+    pub merkle_tree_restore_from_checkpoint: Option<bool>,
 }
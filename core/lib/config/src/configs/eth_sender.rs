@@ -2,7 +2,9 @@ use std::time::Duration;
 
 use anyhow::Context as _;
 use serde::Deserialize;
-use zksync_basic_types::{pubdata_da::PubdataSendingMode, settlement::SettlementMode, H256};
+use zksync_basic_types::{
+    pubdata_da::PubdataSendingMode, settlement::SettlementMode, url::SensitiveUrl, Address, H256,
+};
 use zksync_crypto_primitives::K256PrivateKey;
 
 use crate::EthWatchConfig;
@@ -15,6 +17,9 @@ pub struct EthConfig {
     /// Options related to the `GasAdjuster` submodule.
     pub gas_adjuster: Option<GasAdjusterConfig>,
     pub watcher: Option<EthWatchConfig>,
+    /// Remote signer used to sign L1 transactions instead of the operator private keys.
+    /// If not set, transactions are signed using private keys from the wallets config.
+    pub remote_signer: Option<RemoteSignerConfig>,
 }
 
 impl EthConfig {
@@ -43,6 +48,13 @@ impl EthConfig {
                 tx_aggregation_paused: false,
                 tx_aggregation_only_prove_and_execute: false,
                 time_in_mempool_in_l1_blocks_cap: 1800,
+                max_commit_txs_in_flight: None,
+                max_prove_txs_in_flight: None,
+                max_execute_txs_in_flight: None,
+                fee_bumping_mode: FeeBumpingMode::Legacy,
+                fee_bump_percent: SenderConfig::default_fee_bump_percent(),
+                max_fee_bump_multiplier: SenderConfig::default_max_fee_bump_multiplier(),
+                fee_bump_percentile: SenderConfig::default_fee_bump_percentile(),
             }),
             gas_adjuster: Some(GasAdjusterConfig {
                 default_priority_fee_per_gas: 1000000000,
@@ -63,6 +75,7 @@ impl EthConfig {
                 confirmations_for_eth_event: None,
                 eth_node_poll_interval: 0,
             }),
+            remote_signer: None,
         }
     }
 }
@@ -80,6 +93,24 @@ pub enum ProofLoadingMode {
     FriProofFromGcs,
 }
 
+/// Strategy used to compute fees for L1 transactions on the first send and on each resend.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeeBumpingMode {
+    /// Bumps base fee by 10% and priority fee by 20% on each resend (at least to the values
+    /// suggested by the gas adjuster); fees of blob transactions are doubled. Resends are skipped
+    /// if the suggested base fee is below what the next block may require.
+    #[default]
+    Legacy,
+    /// Adds `fee_bump_percent` of the suggested fees to the previously used fees on each resend.
+    Linear,
+    /// Multiplies the previously used fees by `1 + fee_bump_percent / 100` on each resend.
+    /// Fees are capped at `max_fee_bump_multiplier` times the suggested fees.
+    Exponential,
+    /// Uses the `fee_bump_percentile`-th percentile of base fees over recent L1 blocks
+    /// as the base fee if it exceeds the suggested one.
+    Percentile,
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct SenderConfig {
     pub aggregated_proof_sizes: Vec<usize>,
@@ -123,6 +154,29 @@ pub struct SenderConfig {
     /// Cap of time in mempool for price calculations
     #[serde(default = "SenderConfig::default_time_in_mempool_in_l1_blocks_cap")]
     pub time_in_mempool_in_l1_blocks_cap: u32,
+
+    /// The maximum number of unconfirmed commit transactions sent by the dedicated commit (blob) operator.
+    /// If not specified, `max_txs_in_flight` is used.
+    pub max_commit_txs_in_flight: Option<u64>,
+    /// The maximum number of unconfirmed proof transactions sent by the dedicated prove operator.
+    /// If not specified, `max_txs_in_flight` is used.
+    pub max_prove_txs_in_flight: Option<u64>,
+    /// The maximum number of unconfirmed execute transactions sent by the dedicated execute operator.
+    /// If not specified, `max_txs_in_flight` is used.
+    pub max_execute_txs_in_flight: Option<u64>,
+
+    /// Strategy used to compute fees on the first send and on resends of L1 transactions.
+    #[serde(default)]
+    pub fee_bumping_mode: FeeBumpingMode,
+    /// Fee increase step in percent used by `Linear` and `Exponential` fee bumping modes.
+    #[serde(default = "SenderConfig::default_fee_bump_percent")]
+    pub fee_bump_percent: u32,
+    /// Maximum ratio between the used fees and the suggested ones for the `Exponential` fee bumping mode.
+    #[serde(default = "SenderConfig::default_max_fee_bump_multiplier")]
+    pub max_fee_bump_multiplier: u32,
+    /// Percentile of recent L1 block base fees used by the `Percentile` fee bumping mode.
+    #[serde(default = "SenderConfig::default_fee_bump_percentile")]
+    pub fee_bump_percentile: u32,
 }
 
 impl SenderConfig {
@@ -158,6 +212,22 @@ impl SenderConfig {
             .map(|pk| pk.parse().unwrap())
     }
 
+    // Don't load the dedicated prove operator private key, if it's not required
+    #[deprecated]
+    pub fn private_key_prove(&self) -> Option<H256> {
+        std::env::var("ETH_SENDER_SENDER_OPERATOR_PROVE_PRIVATE_KEY")
+            .ok()
+            .map(|pk| pk.parse().unwrap())
+    }
+
+    // Don't load the dedicated execute operator private key, if it's not required
+    #[deprecated]
+    pub fn private_key_execute(&self) -> Option<H256> {
+        std::env::var("ETH_SENDER_SENDER_OPERATOR_EXECUTE_PRIVATE_KEY")
+            .ok()
+            .map(|pk| pk.parse().unwrap())
+    }
+
     const fn default_tx_aggregation_paused() -> bool {
         false
     }
@@ -171,6 +241,18 @@ impl SenderConfig {
         // 1,001 ^ 1800 ~= 6, so by default we cap exponential price formula at roughly median * 6
         blocks_per_hour * 6
     }
+
+    pub const fn default_fee_bump_percent() -> u32 {
+        20
+    }
+
+    pub const fn default_max_fee_bump_multiplier() -> u32 {
+        10
+    }
+
+    pub const fn default_fee_bump_percentile() -> u32 {
+        75
+    }
 }
 
 #[derive(Debug, Deserialize, Copy, Clone, PartialEq, Default)]
@@ -239,3 +321,32 @@ impl GasAdjusterConfig {
         1.001
     }
 }
+
+/// Configuration of a remote signer compatible with the [web3signer](https://docs.web3signer.consensys.io/) JSON-RPC API.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct RemoteSignerConfig {
+    /// URL of the signer JSON-RPC endpoint.
+    pub url: SensitiveUrl,
+    /// Address of the operator account. The signer must hold the key for this account.
+    pub operator_address: Address,
+    /// Address of the account used to send blob transactions, if any. The signer must hold the key for this account.
+    pub blob_operator_address: Option<Address>,
+    /// Address of the account used to send proof transactions, if any. The signer must hold the key for this account.
+    pub prove_operator_address: Option<Address>,
+    /// Address of the account used to send execute transactions, if any. The signer must hold the key for this account.
+    pub execute_operator_address: Option<Address>,
+    /// Timeout for a single signing request in milliseconds.
+    #[serde(default = "RemoteSignerConfig::default_request_timeout_ms")]
+    pub request_timeout_ms: u64,
+}
+
+impl RemoteSignerConfig {
+    pub const fn default_request_timeout_ms() -> u64 {
+        10_000
+    }
+
+    /// Converts `self.request_timeout_ms` into `Duration`.
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+}
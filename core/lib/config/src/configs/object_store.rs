@@ -16,6 +16,18 @@ pub struct ObjectStoreConfig {
     /// **Important.** Mirroring logic assumes that objects in the underlying store are immutable. If this is not the case,
     /// the mirrored objects may become stale.
     pub local_mirror_path: Option<String>,
+    /// zstd compression level for stored objects. If specified, objects are stored with a header containing
+    /// an integrity checksum, and objects in [`Self::compressed_buckets`] are compressed. Objects stored without
+    /// the header (e.g., before compression was enabled) can still be read.
+    ///
+    /// If not specified, objects are stored as-is.
+    pub compression_level: Option<i32>,
+    /// Names of buckets (e.g., `witness_inputs`) in which objects are compressed if [`Self::compression_level`] is set.
+    /// If empty, objects in all non-public buckets are compressed. Objects in public buckets (`storage_logs_snapshots`
+    /// and `merkle_tree_checkpoints`), which can be read by third parties, are stored as-is unless these buckets
+    /// are explicitly listed.
+    #[serde(default)]
+    pub compressed_buckets: Vec<String>,
 }
 
 impl ObjectStoreConfig {
@@ -40,4 +52,26 @@ pub enum ObjectStoreMode {
     FileBacked {
         file_backed_base_path: String,
     },
+    /// AWS S3 or an S3-compatible store with ambient authentication (via the standard AWS env variables,
+    /// or the EC2 instance metadata service).
+    S3 {
+        /// Name of the S3 bucket.
+        bucket_base_url: String,
+        s3_region: String,
+        /// Custom endpoint URL for S3-compatible stores (e.g., MinIO). If specified, path-style addressing is used.
+        s3_endpoint: Option<String>,
+    },
+    /// Same as [`Self::S3`], but with static credentials read from a file in the AWS shared credentials format.
+    S3WithCredentialFile {
+        bucket_base_url: String,
+        s3_region: String,
+        s3_endpoint: Option<String>,
+        s3_credential_file_path: String,
+    },
+    /// Same as [`Self::S3`], but with anonymous access. Only works for public buckets for read operations.
+    S3AnonymousReadOnly {
+        bucket_base_url: String,
+        s3_region: String,
+        s3_endpoint: Option<String>,
+    },
 }
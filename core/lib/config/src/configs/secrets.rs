@@ -3,7 +3,10 @@ use zksync_basic_types::url::SensitiveUrl;
 
 use crate::configs::{
     consensus::ConsensusSecrets,
-    da_client::{avail::AvailSecrets, celestia::CelestiaSecrets, eigen::EigenSecrets},
+    da_client::{
+        avail::AvailSecrets, celestia::CelestiaSecrets, composite::CompositeSecrets,
+        eigen::EigenSecrets,
+    },
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -16,6 +19,12 @@ pub struct DatabaseSecrets {
 #[derive(Debug, Clone, PartialEq)]
 pub struct L1Secrets {
     pub l1_rpc_url: SensitiveUrl,
+    /// Fallback L1 RPC URLs in the order of preference. If specified, L1 calls fail over to these URLs
+    /// when the provider at `l1_rpc_url` is unavailable.
+    pub fallback_l1_rpc_urls: Vec<SensitiveUrl>,
+    /// Whether to cross-check block hashes returned by different L1 RPC providers.
+    /// Requires at least one fallback URL to have an effect.
+    pub cross_check_l1_block_hashes: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -23,6 +32,7 @@ pub enum DataAvailabilitySecrets {
     Avail(AvailSecrets),
     Celestia(CelestiaSecrets),
     Eigen(EigenSecrets),
+    Composite(CompositeSecrets),
 }
 
 #[derive(Debug, Clone, PartialEq)]
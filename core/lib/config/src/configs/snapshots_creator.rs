@@ -20,6 +20,11 @@ pub struct SnapshotsCreatorConfig {
     pub storage_logs_chunk_size: u64,
     #[serde(default = "SnapshotsCreatorConfig::concurrent_queries_count")]
     pub concurrent_queries_count: u32,
+    /// Maximum number of incremental snapshots on top of a full snapshot. Only used for version 2 snapshots;
+    /// if the newest complete snapshot is already at the end of a chain of this length, a full snapshot will be created.
+    /// Setting this to 0 disables incremental snapshots.
+    #[serde(default = "SnapshotsCreatorConfig::default_max_incremental_chain_length")]
+    pub max_incremental_chain_length: u32,
     pub object_store: Option<ObjectStoreConfig>,
 }
 
@@ -31,4 +36,8 @@ impl SnapshotsCreatorConfig {
     const fn concurrent_queries_count() -> u32 {
         25
     }
+
+    pub const fn default_max_incremental_chain_length() -> u32 {
+        10
+    }
 }
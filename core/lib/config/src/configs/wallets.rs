@@ -62,6 +62,10 @@ impl Wallet {
 pub struct EthSender {
     pub operator: Wallet,
     pub blob_operator: Option<Wallet>,
+    /// Dedicated operator for sending `PublishProofOnchain` transactions. If not set, the main operator is used.
+    pub prove_operator: Option<Wallet>,
+    /// Dedicated operator for sending `Execute` transactions. If not set, the main operator is used.
+    pub execute_operator: Option<Wallet>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -89,6 +93,8 @@ impl Wallets {
                 blob_operator: Some(
                     Wallet::from_private_key_bytes(H256::repeat_byte(0x2), None).unwrap(),
                 ),
+                prove_operator: None,
+                execute_operator: None,
             }),
             state_keeper: Some(StateKeeper {
                 fee_account: AddressWallet::from_address(H160::repeat_byte(0x3)),
@@ -1,10 +1,10 @@
 #![allow(clippy::upper_case_acronyms, clippy::derive_partial_eq_without_eq)]
 
 pub use crate::configs::{
-    ApiConfig, AvailConfig, BaseTokenAdjusterConfig, CelestiaConfig, ContractVerifierConfig,
-    ContractsConfig, DAClientConfig, DADispatcherConfig, DBConfig, EigenConfig, EthConfig,
-    EthWatchConfig, ExternalProofIntegrationApiConfig, GasAdjusterConfig, GenesisConfig,
-    ObjectStoreConfig, PostgresConfig, SnapshotsCreatorConfig,
+    ApiConfig, AvailConfig, BaseTokenAdjusterConfig, CelestiaConfig, CompositeConfig,
+    ContractVerifierConfig, ContractsConfig, DAClientConfig, DADispatcherConfig, DBConfig,
+    EigenConfig, EthConfig, EthWatchConfig, ExternalProofIntegrationApiConfig, GasAdjusterConfig,
+    GenesisConfig, ObjectStoreConfig, PostgresConfig, SidecarConfig, SnapshotsCreatorConfig,
 };
 
 pub mod configs;
@@ -1,4 +1,4 @@
-use std::num::NonZeroUsize;
+use std::num::{NonZeroU32, NonZeroUsize};
 
 use rand::{distributions::Distribution, Rng};
 use secrecy::Secret;
@@ -58,6 +58,16 @@ impl Distribution<configs::chain::FeeModelVersion> for EncodeDist {
     }
 }
 
+impl Distribution<configs::chain::MempoolOrdering> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::chain::MempoolOrdering {
+        type T = configs::chain::MempoolOrdering;
+        match rng.gen_range(0..2) {
+            0 => T::Fifo,
+            _ => T::FeePriority,
+        }
+    }
+}
+
 impl Distribution<configs::ApiConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::ApiConfig {
         configs::ApiConfig {
@@ -94,6 +104,9 @@ impl Distribution<configs::api::Web3JsonRpcConfig> for EncodeDist {
             latest_values_cache_size_mb: self.sample(rng),
             latest_values_max_block_lag: self.sample(rng),
             fee_history_limit: self.sample(rng),
+            simulate_blocks_limit: self.sample(rng),
+            simulate_calls_limit: self.sample(rng),
+            simulate_gas_limit: self.sample(rng),
             max_batch_request_size: self.sample(rng),
             max_response_body_size_mb: self.sample(rng),
             max_response_body_size_overrides_mb: [
@@ -109,6 +122,20 @@ impl Distribution<configs::api::Web3JsonRpcConfig> for EncodeDist {
             .into_iter()
             .collect(),
             websocket_requests_per_minute_limit: self.sample(rng),
+            client_requests_per_minute_limit: self.sample(rng),
+            trust_forwarded_for_header: self.sample(rng),
+            rate_limit_method_costs: [
+                (
+                    "eth_getLogs",
+                    NonZeroU32::new(self.sample(rng)).unwrap_or(NonZeroU32::MIN),
+                ),
+                (
+                    "debug_*",
+                    NonZeroU32::new(self.sample(rng)).unwrap_or(NonZeroU32::MIN),
+                ),
+            ]
+            .into_iter()
+            .collect(),
             tree_api_url: self.sample(rng),
             mempool_cache_update_interval: self.sample(rng),
             mempool_cache_size: self.sample(rng),
@@ -233,6 +260,9 @@ impl Distribution<configs::chain::MempoolConfig> for EncodeDist {
             stuck_tx_timeout: self.sample(rng),
             remove_stuck_txs: self.sample(rng),
             delay_interval: self.sample(rng),
+            ordering: self.sample(rng),
+            max_pending_txs_per_account: self.sample(rng),
+            replacement_fee_bump_percent: self.sample(rng),
         }
     }
 }
@@ -293,6 +323,7 @@ impl Distribution<configs::database::MerkleTreeConfig> for EncodeDist {
             memtable_capacity_mb: self.sample(rng),
             stalled_writes_timeout_sec: self.sample(rng),
             max_l1_batches_per_iter: self.sample(rng),
+            checkpoint_interval_sec: self.sample(rng),
         }
     }
 }
@@ -371,6 +402,20 @@ impl Distribution<configs::EthConfig> for EncodeDist {
             sender: self.sample(rng),
             gas_adjuster: self.sample(rng),
             watcher: self.sample(rng),
+            remote_signer: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::eth_sender::RemoteSignerConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::eth_sender::RemoteSignerConfig {
+        configs::eth_sender::RemoteSignerConfig {
+            url: format!("localhost:{}", rng.gen::<u16>()).parse().unwrap(),
+            operator_address: rng.gen(),
+            blob_operator_address: self.sample_opt(|| rng.gen()),
+            prove_operator_address: self.sample_opt(|| rng.gen()),
+            execute_operator_address: self.sample_opt(|| rng.gen()),
+            request_timeout_ms: self.sample(rng),
         }
     }
 }
@@ -396,6 +441,18 @@ impl Distribution<configs::eth_sender::ProofLoadingMode> for EncodeDist {
     }
 }
 
+impl Distribution<configs::eth_sender::FeeBumpingMode> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::eth_sender::FeeBumpingMode {
+        type T = configs::eth_sender::FeeBumpingMode;
+        match rng.gen_range(0..4) {
+            0 => T::Legacy,
+            1 => T::Linear,
+            2 => T::Exponential,
+            _ => T::Percentile,
+        }
+    }
+}
+
 impl Distribution<configs::eth_sender::SenderConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::eth_sender::SenderConfig {
         configs::eth_sender::SenderConfig {
@@ -419,6 +476,13 @@ impl Distribution<configs::eth_sender::SenderConfig> for EncodeDist {
             tx_aggregation_paused: false,
             tx_aggregation_only_prove_and_execute: false,
             time_in_mempool_in_l1_blocks_cap: self.sample(rng),
+            max_commit_txs_in_flight: self.sample(rng),
+            max_prove_txs_in_flight: self.sample(rng),
+            max_execute_txs_in_flight: self.sample(rng),
+            fee_bumping_mode: self.sample(rng),
+            fee_bump_percent: self.sample(rng),
+            max_fee_bump_multiplier: self.sample(rng),
+            fee_bump_percentile: self.sample(rng),
         }
     }
 }
@@ -644,7 +708,7 @@ impl Distribution<configs::house_keeper::HouseKeeperConfig> for EncodeDist {
 impl Distribution<configs::object_store::ObjectStoreMode> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::object_store::ObjectStoreMode {
         type T = configs::object_store::ObjectStoreMode;
-        match rng.gen_range(0..4) {
+        match rng.gen_range(0..7) {
             0 => T::GCS {
                 bucket_base_url: self.sample(rng),
             },
@@ -655,6 +719,22 @@ impl Distribution<configs::object_store::ObjectStoreMode> for EncodeDist {
             2 => T::FileBacked {
                 file_backed_base_path: self.sample(rng),
             },
+            3 => T::S3 {
+                bucket_base_url: self.sample(rng),
+                s3_region: self.sample(rng),
+                s3_endpoint: self.sample(rng),
+            },
+            4 => T::S3WithCredentialFile {
+                bucket_base_url: self.sample(rng),
+                s3_region: self.sample(rng),
+                s3_endpoint: self.sample(rng),
+                s3_credential_file_path: self.sample(rng),
+            },
+            5 => T::S3AnonymousReadOnly {
+                bucket_base_url: self.sample(rng),
+                s3_region: self.sample(rng),
+                s3_endpoint: self.sample(rng),
+            },
             _ => T::GCSAnonymousReadOnly {
                 bucket_base_url: self.sample(rng),
             },
@@ -668,6 +748,8 @@ impl Distribution<configs::ObjectStoreConfig> for EncodeDist {
             mode: self.sample(rng),
             max_retries: self.sample(rng),
             local_mirror_path: self.sample(rng),
+            compression_level: self.sample(rng),
+            compressed_buckets: self.sample_collect(rng),
         }
     }
 }
@@ -691,9 +773,10 @@ impl Distribution<configs::SnapshotsCreatorConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::SnapshotsCreatorConfig {
         configs::SnapshotsCreatorConfig {
             l1_batch_number: self.sample_opt(|| L1BatchNumber(rng.gen())),
-            version: if rng.gen() { 0 } else { 1 },
+            version: rng.gen_range(0..=2),
             storage_logs_chunk_size: self.sample(rng),
             concurrent_queries_count: self.sample(rng),
+            max_incremental_chain_length: self.sample(rng),
             object_store: self.sample(rng),
         }
     }
@@ -852,6 +935,10 @@ impl Distribution<configs::secrets::L1Secrets> for EncodeDist {
         use configs::secrets::L1Secrets;
         L1Secrets {
             l1_rpc_url: format!("localhost:{}", rng.gen::<u16>()).parse().unwrap(),
+            fallback_l1_rpc_urls: (0..rng.gen_range(0..3))
+                .map(|_| format!("localhost:{}", rng.gen::<u16>()).parse().unwrap())
+                .collect(),
+            cross_check_l1_block_hashes: self.sample(rng),
         }
     }
 }
@@ -904,6 +991,8 @@ impl Distribution<configs::wallets::EthSender> for EncodeDist {
         configs::wallets::EthSender {
             operator: self.sample(rng),
             blob_operator: self.sample_opt(|| self.sample(rng)),
+            prove_operator: self.sample_opt(|| self.sample(rng)),
+            execute_operator: self.sample_opt(|| self.sample(rng)),
         }
     }
 }
@@ -938,9 +1027,15 @@ impl Distribution<configs::en_config::ENConfig> for EncodeDist {
                 _ => L1BatchCommitmentMode::Validium,
             },
             main_node_rate_limit_rps: self.sample_opt(|| rng.gen()),
+            main_node_fallback_urls: (0..rng.gen_range(0..3))
+                .map(|_| format!("localhost:{}", rng.gen::<u16>()).parse().unwrap())
+                .collect(),
             gateway_url: self
                 .sample_opt(|| format!("localhost:{}", rng.gen::<u16>()).parse().unwrap()),
             bridge_addresses_refresh_interval_sec: self.sample_opt(|| rng.gen()),
+            auto_rollback_on_reorg: self.sample(rng),
+            max_auto_rollback_depth: self.sample(rng),
+            merkle_tree_restore_from_checkpoint: self.sample(rng),
         }
     }
 }
@@ -974,6 +1069,9 @@ impl Distribution<configs::da_dispatcher::DADispatcherConfig> for EncodeDist {
             max_rows_to_dispatch: self.sample(rng),
             max_retries: self.sample(rng),
             use_dummy_inclusion_data: self.sample(rng),
+            inclusion_timeout_ms: self.sample(rng),
+            max_concurrent_inclusion_requests: self.sample(rng),
+            split_oversized_blobs: self.sample(rng),
         }
     }
 }
@@ -1,3 +1,4 @@
+use serde_json::Value;
 use zksync_basic_types::{web3::keccak256, Address, H256, U256};
 
 use crate::eip712_signature::typed_structure::{EncodedStructureMember, StructMember};
@@ -13,6 +14,10 @@ impl StructMember for String {
     fn encode_member_data(&self) -> H256 {
         keccak256(self.as_bytes()).into()
     }
+
+    fn encode_member_json(&self) -> Value {
+        Value::String(self.clone())
+    }
 }
 
 impl StructMember for Address {
@@ -26,6 +31,10 @@ impl StructMember for Address {
     fn encode_member_data(&self) -> H256 {
         H256::from(*self)
     }
+
+    fn encode_member_json(&self) -> Value {
+        Value::String(format!("{self:?}"))
+    }
 }
 
 impl StructMember for &[u8] {
@@ -39,6 +48,10 @@ impl StructMember for &[u8] {
     fn encode_member_data(&self) -> H256 {
         keccak256(self).into()
     }
+
+    fn encode_member_json(&self) -> Value {
+        Value::String(format!("0x{}", hex::encode(self)))
+    }
 }
 
 impl StructMember for &[H256] {
@@ -56,6 +69,12 @@ impl StructMember for &[H256] {
             .collect();
         keccak256(&bytes).into()
     }
+
+    fn encode_member_json(&self) -> Value {
+        self.iter()
+            .map(|hash| Value::String(format!("{hash:?}")))
+            .collect()
+    }
 }
 
 impl StructMember for U256 {
@@ -72,6 +91,10 @@ impl StructMember for U256 {
 
         bytes.into()
     }
+
+    fn encode_member_json(&self) -> Value {
+        Value::String(format!("{self:#x}"))
+    }
 }
 
 impl StructMember for H256 {
@@ -85,6 +108,11 @@ impl StructMember for H256 {
     fn encode_member_data(&self) -> H256 {
         *self
     }
+
+    fn encode_member_json(&self) -> Value {
+        // Encoded as `uint256`, so we use the corresponding numeric representation.
+        U256::from_big_endian(self.as_bytes()).encode_member_json()
+    }
 }
 
 macro_rules! impl_primitive {
@@ -102,6 +130,9 @@ macro_rules! impl_primitive {
 
                 bytes.into()
             }
+            fn encode_member_json(&self) -> Value {
+                Value::String(format!("{self:#x}"))
+            }
         }
     };
 }
@@ -2,7 +2,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use zksync_basic_types::{web3::keccak256, L2ChainId, H256, U256};
 
-use crate::eip712_signature::struct_builder::{EncodeBuilder, StructBuilder, TypeBuilder};
+use crate::eip712_signature::struct_builder::{
+    EncodeBuilder, JsonBuilder, StructBuilder, TypeBuilder,
+};
 
 #[derive(Debug, Clone)]
 pub struct EncodedStructureMember {
@@ -81,6 +83,9 @@ pub trait StructMember {
     fn get_inner_members(&self) -> Vec<EncodedStructureMember>;
 
     fn encode_member_data(&self) -> H256;
+
+    /// Encodes the member value as JSON, as expected by the `eth_signTypedData` RPC call.
+    fn encode_member_json(&self) -> Value;
 }
 
 impl<TypedStructure: EIP712TypedStructure> StructMember for TypedStructure {
@@ -97,6 +102,10 @@ impl<TypedStructure: EIP712TypedStructure> StructMember for TypedStructure {
     fn encode_member_data(&self) -> H256 {
         self.hash_struct()
     }
+
+    fn encode_member_json(&self) -> Value {
+        self.get_json_data()
+    }
 }
 
 /// Interface for defining the structure for the EIP712 signature.
@@ -142,6 +151,14 @@ pub trait EIP712TypedStructure {
 
         builder.get_json_types(Self::TYPE_NAME)
     }
+
+    /// Returns the structure data as a JSON object `{ member_name₁: value₁, ... }`.
+    fn get_json_data(&self) -> Value {
+        let mut builder = JsonBuilder::new();
+        self.build_structure(&mut builder);
+
+        builder.into_json()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -4,7 +4,7 @@ use crate::eip712_signature::typed_structure::{EIP712TypedStructure, Eip712Domai
 
 /// Formats the data that needs to be signed in json according to the standard eip-712.
 /// Compatible with `eth_signTypedData` RPC call.
-pub fn get_eip712_json<T: EIP712TypedStructure + serde::Serialize>(
+pub fn get_eip712_json<T: EIP712TypedStructure>(
     eip712_domain: &Eip712Domain,
     typed_struct: &T,
 ) -> Value {
@@ -24,8 +24,8 @@ pub fn get_eip712_json<T: EIP712TypedStructure + serde::Serialize>(
 
     serde_json::json!({
         "primaryType": T::TYPE_NAME,
-        "domain": serde_json::to_value(eip712_domain).expect("serialization fail"),
-        "message": serde_json::to_value(typed_struct).expect("serialization fail"),
+        "domain": eip712_domain.get_json_data(),
+        "message": typed_struct.get_json_data(),
         "types": serde_json::to_value(types).expect("serialization fail"),
     })
 }
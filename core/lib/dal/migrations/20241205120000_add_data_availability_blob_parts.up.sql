@@ -0,0 +1,16 @@
+-- Parts of L1 batch pubdata that didn't fit into a single DA blob and was split into several blobs.
+-- The corresponding `data_availability` row is inserted once all parts are dispatched, and references
+-- the blob ID of the first part.
+CREATE TABLE IF NOT EXISTS data_availability_blob_parts
+(
+    l1_batch_number BIGINT    NOT NULL REFERENCES l1_batches (number) ON DELETE CASCADE,
+    part_index      INT       NOT NULL,
+
+    blob_id         TEXT      NOT NULL,
+    sent_at         TIMESTAMP NOT NULL,
+
+    created_at      TIMESTAMP NOT NULL,
+    updated_at      TIMESTAMP NOT NULL,
+
+    PRIMARY KEY (l1_batch_number, part_index)
+);
@@ -0,0 +1,11 @@
+-- History of DA blobs that were abandoned because they weren't included within the inclusion timeout.
+CREATE TABLE IF NOT EXISTS data_availability_dispatch_attempts
+(
+    l1_batch_number BIGINT    NOT NULL REFERENCES l1_batches (number) ON DELETE CASCADE,
+    attempt         INT       NOT NULL,
+    blob_id         TEXT      NOT NULL,
+    sent_at         TIMESTAMP NOT NULL,
+    abandoned_at    TIMESTAMP NOT NULL,
+    created_at      TIMESTAMP NOT NULL,
+    PRIMARY KEY (l1_batch_number, attempt)
+);
@@ -0,0 +1,2 @@
+ALTER TABLE snapshots
+    DROP COLUMN base_l1_batch_number;
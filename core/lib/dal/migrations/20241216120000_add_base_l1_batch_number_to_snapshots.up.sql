@@ -0,0 +1,3 @@
+-- Base snapshot for incremental snapshots; `NULL` for full snapshots.
+ALTER TABLE snapshots
+    ADD COLUMN base_l1_batch_number BIGINT REFERENCES snapshots (l1_batch_number);
@@ -0,0 +1,2 @@
+ALTER TABLE data_availability_blob_parts
+    DROP COLUMN IF EXISTS inclusion_data;
@@ -0,0 +1,3 @@
+-- Inclusion data is tracked for each part separately; the L1 batch is considered included once all its parts are.
+ALTER TABLE data_availability_blob_parts
+    ADD COLUMN IF NOT EXISTS inclusion_data BYTEA;
@@ -41,6 +41,23 @@ use crate::{
     Core, CoreDal,
 };
 
+/// Numbers of rows in Postgres tables that are deleted (or, for transactions, reset) when rolling back
+/// the node state to a certain L1 batch.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RollbackRowCounts {
+    pub l1_batches: u64,
+    pub l2_blocks: u64,
+    pub transactions: u64,
+    pub call_traces: u64,
+    pub events: u64,
+    pub l2_to_l1_logs: u64,
+    pub factory_deps: u64,
+    pub storage_logs: u64,
+    pub initial_writes: u64,
+    pub vm_runner_protective_reads: u64,
+    pub vm_runner_bwip: u64,
+}
+
 #[derive(Debug)]
 pub struct BlocksDal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
@@ -2215,6 +2232,136 @@ impl BlocksDal<'_, '_> {
         Ok(())
     }
 
+    /// Counts rows that would be affected by rolling back the storage so that the specified L1 batch
+    /// and L2 block are the last ones left. Doesn't modify the storage.
+    pub async fn get_rollback_row_counts(
+        &mut self,
+        last_l1_batch_to_keep: L1BatchNumber,
+        last_l2_block_to_keep: L2BlockNumber,
+    ) -> DalResult<RollbackRowCounts> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        l1_batches
+                    WHERE
+                        number > $1
+                ) AS "l1_batches!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        miniblocks
+                    WHERE
+                        number > $2
+                ) AS "l2_blocks!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        transactions
+                    WHERE
+                        miniblock_number > $2
+                ) AS "transactions!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        call_traces
+                    WHERE
+                        tx_hash IN (
+                            SELECT
+                                hash
+                            FROM
+                                transactions
+                            WHERE
+                                miniblock_number > $2
+                        )
+                ) AS "call_traces!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        events
+                    WHERE
+                        miniblock_number > $2
+                ) AS "events!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        l2_to_l1_logs
+                    WHERE
+                        miniblock_number > $2
+                ) AS "l2_to_l1_logs!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        factory_deps
+                    WHERE
+                        miniblock_number > $2
+                ) AS "factory_deps!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        storage_logs
+                    WHERE
+                        miniblock_number > $2
+                ) AS "storage_logs!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        initial_writes
+                    WHERE
+                        l1_batch_number > $1
+                ) AS "initial_writes!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        vm_runner_protective_reads
+                    WHERE
+                        l1_batch_number > $1
+                ) AS "vm_runner_protective_reads!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        vm_runner_bwip
+                    WHERE
+                        l1_batch_number > $1
+                ) AS "vm_runner_bwip!"
+            "#,
+            i64::from(last_l1_batch_to_keep.0),
+            i64::from(last_l2_block_to_keep.0)
+        )
+        .instrument("get_rollback_row_counts")
+        .with_arg("last_l1_batch_to_keep", &last_l1_batch_to_keep)
+        .with_arg("last_l2_block_to_keep", &last_l2_block_to_keep)
+        .fetch_one(self.storage)
+        .await?;
+
+        Ok(RollbackRowCounts {
+            l1_batches: row.l1_batches as u64,
+            l2_blocks: row.l2_blocks as u64,
+            transactions: row.transactions as u64,
+            call_traces: row.call_traces as u64,
+            events: row.events as u64,
+            l2_to_l1_logs: row.l2_to_l1_logs as u64,
+            factory_deps: row.factory_deps as u64,
+            storage_logs: row.storage_logs as u64,
+            initial_writes: row.initial_writes as u64,
+            vm_runner_protective_reads: row.vm_runner_protective_reads as u64,
+            vm_runner_bwip: row.vm_runner_bwip as u64,
+        })
+    }
+
     async fn delete_logs_inner(&mut self) -> DalResult<()> {
         sqlx::query!(
             r#"
@@ -3,7 +3,11 @@ use zksync_db_connection::{
     error::DalResult,
     instrument::{InstrumentExt, Instrumented},
 };
-use zksync_types::{pubdata_da::DataAvailabilityBlob, L1BatchNumber};
+use zksync_types::{
+    api,
+    pubdata_da::{DataAvailabilityBlob, DataAvailabilityBlobPart, DataAvailabilityDispatchAttempt},
+    L1BatchNumber,
+};
 
 use crate::{
     models::storage_data_availability::{L1BatchDA, StorageDABlob},
@@ -147,6 +151,181 @@ impl DataAvailabilityDal<'_, '_> {
         Ok(())
     }
 
+    /// Inserts the blob_id for a part of the L1 batch pubdata that was split into several DA blobs.
+    /// If the part is already present, verifies that its blob_id matches the one provided in the function arguments.
+    pub async fn insert_l1_batch_da_blob_part(
+        &mut self,
+        number: L1BatchNumber,
+        part_index: u32,
+        blob_id: &str,
+        sent_at: chrono::NaiveDateTime,
+    ) -> DalResult<()> {
+        let update_result = sqlx::query!(
+            r#"
+            INSERT INTO
+            data_availability_blob_parts (
+                l1_batch_number, part_index, blob_id, sent_at, created_at, updated_at
+            )
+            VALUES
+            ($1, $2, $3, $4, NOW(), NOW())
+            ON CONFLICT DO NOTHING
+            "#,
+            i64::from(number.0),
+            part_index as i32,
+            blob_id,
+            sent_at,
+        )
+        .instrument("insert_l1_batch_da_blob_part")
+        .with_arg("number", &number)
+        .with_arg("part_index", &part_index)
+        .with_arg("blob_id", &blob_id)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        if update_result.rows_affected() == 0 {
+            let instrumentation = Instrumented::new("get_matching_batch_da_blob_part_id")
+                .with_arg("number", &number)
+                .with_arg("part_index", &part_index);
+
+            let query = sqlx::query!(
+                r#"
+                SELECT
+                    blob_id
+                FROM
+                    data_availability_blob_parts
+                WHERE
+                    l1_batch_number = $1
+                    AND part_index = $2
+                "#,
+                i64::from(number.0),
+                part_index as i32,
+            );
+
+            let matched: String = instrumentation
+                .clone()
+                .with(query)
+                .report_latency()
+                .fetch_one(self.storage)
+                .await?
+                .blob_id;
+
+            if matched != blob_id {
+                let err = instrumentation.constraint_error(anyhow::anyhow!(
+                    "Error storing DA blob id. DA blob_id {blob_id} for part #{part_index} of L1 batch #{number} does not match the expected value"
+                ));
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns parts of the L1 batch pubdata dispatched as separate DA blobs, ordered by their index.
+    /// Returns an empty vector if the pubdata was dispatched as a single blob.
+    pub async fn get_l1_batch_da_blob_parts(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Vec<DataAvailabilityBlobPart>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                part_index,
+                blob_id,
+                inclusion_data,
+                sent_at
+            FROM
+                data_availability_blob_parts
+            WHERE
+                l1_batch_number = $1
+            ORDER BY
+                part_index
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("get_l1_batch_da_blob_parts")
+        .with_arg("number", &number)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| DataAvailabilityBlobPart {
+                l1_batch_number: number,
+                part_index: row.part_index as u32,
+                blob_id: row.blob_id,
+                inclusion_data: row.inclusion_data,
+                sent_at: row.sent_at.and_utc(),
+            })
+            .collect())
+    }
+
+    /// Saves the inclusion data for a part of the L1 batch pubdata that was split into several DA blobs.
+    /// If the inclusion data is already present, verifies that it matches the one provided in the function arguments.
+    pub async fn save_l1_batch_da_blob_part_inclusion_data(
+        &mut self,
+        number: L1BatchNumber,
+        part_index: u32,
+        da_inclusion_data: &[u8],
+    ) -> DalResult<()> {
+        let update_result = sqlx::query!(
+            r#"
+            UPDATE data_availability_blob_parts
+            SET
+                inclusion_data = $1,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $2
+                AND part_index = $3
+                AND inclusion_data IS NULL
+            "#,
+            da_inclusion_data,
+            i64::from(number.0),
+            part_index as i32,
+        )
+        .instrument("save_l1_batch_da_blob_part_inclusion_data")
+        .with_arg("number", &number)
+        .with_arg("part_index", &part_index)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        if update_result.rows_affected() == 0 {
+            let instrumentation = Instrumented::new("get_matching_batch_da_blob_part_data")
+                .with_arg("number", &number)
+                .with_arg("part_index", &part_index);
+
+            let query = sqlx::query!(
+                r#"
+                SELECT
+                    inclusion_data
+                FROM
+                    data_availability_blob_parts
+                WHERE
+                    l1_batch_number = $1
+                    AND part_index = $2
+                "#,
+                i64::from(number.0),
+                part_index as i32,
+            );
+
+            let matched: Option<Vec<u8>> = instrumentation
+                .clone()
+                .with(query)
+                .report_latency()
+                .fetch_one(self.storage)
+                .await?
+                .inclusion_data;
+
+            if matched.as_deref() != Some(da_inclusion_data) {
+                let err = instrumentation.constraint_error(anyhow::anyhow!(
+                    "Error storing DA inclusion data. DA data for part #{part_index} of L1 batch #{number} does not match the one provided before"
+                ));
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
     /// Assumes that the L1 batches are sorted by number, and returns the first one that is ready for DA dispatch.
     pub async fn get_first_da_blob_awaiting_inclusion(
         &mut self,
@@ -175,6 +354,209 @@ impl DataAvailabilityDal<'_, '_> {
         .map(DataAvailabilityBlob::from))
     }
 
+    /// Returns up to `limit` blobs awaiting inclusion, ordered by the L1 batch number.
+    pub async fn get_da_blobs_awaiting_inclusion(
+        &mut self,
+        limit: usize,
+    ) -> DalResult<Vec<DataAvailabilityBlob>> {
+        let rows = sqlx::query_as!(
+            StorageDABlob,
+            r#"
+            SELECT
+                l1_batch_number,
+                blob_id,
+                inclusion_data,
+                sent_at
+            FROM
+                data_availability
+            WHERE
+                inclusion_data IS NULL
+            ORDER BY
+                l1_batch_number
+            LIMIT
+                $1
+            "#,
+            limit as i64,
+        )
+        .instrument("get_da_blobs_awaiting_inclusion")
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows.into_iter().map(DataAvailabilityBlob::from).collect())
+    }
+
+    /// Returns the DA blob for the given L1 batch, regardless of whether it's included.
+    pub async fn get_l1_batch_da_blob(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Option<DataAvailabilityBlob>> {
+        Ok(sqlx::query_as!(
+            StorageDABlob,
+            r#"
+            SELECT
+                l1_batch_number,
+                blob_id,
+                inclusion_data,
+                sent_at
+            FROM
+                data_availability
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("get_l1_batch_da_blob")
+        .with_arg("number", &number)
+        .fetch_optional(self.storage)
+        .await?
+        .map(DataAvailabilityBlob::from))
+    }
+
+    /// Abandons the DA blob for the given L1 batch if it has the specified `blob_id` and is not included yet,
+    /// so that the batch is returned by [`Self::get_ready_for_da_dispatch_l1_batches()`] again.
+    /// The abandoned blob is moved to the dispatch attempts history, and blob parts for the batch are removed.
+    ///
+    /// Returns `false` if the blob wasn't abandoned (e.g., because it was included concurrently).
+    pub async fn abandon_l1_batch_da_blob(
+        &mut self,
+        number: L1BatchNumber,
+        blob_id: &str,
+    ) -> DalResult<bool> {
+        let mut transaction = self.storage.start_transaction().await?;
+        let insert_result = sqlx::query!(
+            r#"
+            WITH
+            abandoned AS (
+                DELETE FROM data_availability
+                WHERE
+                    l1_batch_number = $1
+                    AND blob_id = $2
+                    AND inclusion_data IS NULL
+                RETURNING
+                l1_batch_number,
+                blob_id,
+                sent_at
+            )
+            
+            INSERT INTO
+            data_availability_dispatch_attempts (
+                l1_batch_number, attempt, blob_id, sent_at, abandoned_at, created_at
+            )
+            SELECT
+                l1_batch_number,
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        data_availability_dispatch_attempts
+                    WHERE
+                        l1_batch_number = $1
+                ) + 1,
+                blob_id,
+                sent_at,
+                NOW(),
+                NOW()
+            FROM
+                abandoned
+            "#,
+            i64::from(number.0),
+            blob_id,
+        )
+        .instrument("abandon_l1_batch_da_blob")
+        .with_arg("number", &number)
+        .with_arg("blob_id", &blob_id)
+        .report_latency()
+        .execute(&mut transaction)
+        .await?;
+
+        if insert_result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query!(
+            r#"
+            DELETE FROM data_availability_blob_parts
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("abandon_l1_batch_da_blob#remove_parts")
+        .with_arg("number", &number)
+        .execute(&mut transaction)
+        .await?;
+
+        transaction.commit().await?;
+        Ok(true)
+    }
+
+    /// Returns the history of abandoned DA blobs for the given L1 batch ordered by the attempt index.
+    pub async fn get_l1_batch_da_dispatch_attempts(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Vec<DataAvailabilityDispatchAttempt>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                attempt,
+                blob_id,
+                sent_at,
+                abandoned_at
+            FROM
+                data_availability_dispatch_attempts
+            WHERE
+                l1_batch_number = $1
+            ORDER BY
+                attempt
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("get_l1_batch_da_dispatch_attempts")
+        .with_arg("number", &number)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| DataAvailabilityDispatchAttempt {
+                l1_batch_number: number,
+                attempt: row.attempt as u32,
+                blob_id: row.blob_id,
+                sent_at: row.sent_at.and_utc(),
+                abandoned_at: row.abandoned_at.and_utc(),
+            })
+            .collect())
+    }
+
+    /// Returns the data availability state of the given L1 batch, including the history of abandoned blobs.
+    /// Returns `None` if the batch was never dispatched.
+    pub async fn get_l1_batch_da_details(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Option<api::L1BatchDataAvailabilityDetails>> {
+        let blob = self.get_l1_batch_da_blob(number).await?;
+        let attempts = self.get_l1_batch_da_dispatch_attempts(number).await?;
+        if blob.is_none() && attempts.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(api::L1BatchDataAvailabilityDetails {
+            number,
+            blob_id: blob.as_ref().map(|blob| blob.blob_id.clone()),
+            sent_at: blob.as_ref().map(|blob| blob.sent_at),
+            inclusion_data: blob.and_then(|blob| blob.inclusion_data).map(Into::into),
+            abandoned_blobs: attempts
+                .into_iter()
+                .map(|attempt| api::AbandonedDataAvailabilityBlob {
+                    blob_id: attempt.blob_id,
+                    sent_at: attempt.sent_at,
+                    abandoned_at: attempt.abandoned_at,
+                })
+                .collect(),
+        }))
+    }
+
     /// Fetches the pubdata and `l1_batch_number` for the L1 batches that are ready for DA dispatch.
     pub async fn get_ready_for_da_dispatch_l1_batches(
         &mut self,
@@ -16,6 +16,17 @@ use crate::{
     Core,
 };
 
+/// Brief information about an L1 transaction created by the node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EthTxBrief {
+    pub id: u32,
+    pub nonce: u64,
+    pub tx_type: AggregatedActionType,
+    pub sent_at_block: Option<u32>,
+    pub is_confirmed: bool,
+    pub has_failed: bool,
+}
+
 #[derive(Debug)]
 pub struct EthSenderDal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
@@ -621,9 +632,10 @@ impl EthSenderDal<'_, '_> {
     ///
     /// # Params
     /// * `from_address`: an optional value indicating that nonce must be returned for a custom
-    ///   operator address which is not the "main" one. For example, a separate custom operator
-    ///   sends the blob transactions. For such a case this should be `Some`. For requesting the
-    ///   none of the main operator this parameter should be set to `None`.
+    ///   operator address which is not the "main" one. For example, separate custom operators
+    ///   may send commit (blob), proof and execute transactions; each of them has an independent
+    ///   nonce sequence. For such a case this should be `Some`. For requesting the
+    ///   nonce of the main operator this parameter should be set to `None`.
     pub async fn get_next_nonce(
         &mut self,
         from_address: Option<Address>,
@@ -706,6 +718,73 @@ impl EthSenderDal<'_, '_> {
         Ok(())
     }
 
+    /// Returns L1 transactions referenced by L1 batches after `last_batch_to_keep`, i.e., ones that would be deleted
+    /// by [`Self::delete_eth_txs()`].
+    pub async fn get_eth_txs_to_delete(
+        &mut self,
+        last_batch_to_keep: L1BatchNumber,
+    ) -> sqlx::Result<Vec<EthTxBrief>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                id,
+                nonce,
+                tx_type,
+                sent_at_block,
+                confirmed_eth_tx_history_id IS NOT NULL AS "is_confirmed!",
+                has_failed
+            FROM
+                eth_txs
+            WHERE
+                id IN (
+                    (
+                        SELECT
+                            eth_commit_tx_id
+                        FROM
+                            l1_batches
+                        WHERE
+                            number > $1
+                    )
+                    UNION
+                    (
+                        SELECT
+                            eth_prove_tx_id
+                        FROM
+                            l1_batches
+                        WHERE
+                            number > $1
+                    )
+                    UNION
+                    (
+                        SELECT
+                            eth_execute_tx_id
+                        FROM
+                            l1_batches
+                        WHERE
+                            number > $1
+                    )
+                )
+            ORDER BY
+                id
+            "#,
+            i64::from(last_batch_to_keep.0)
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| EthTxBrief {
+                id: row.id as u32,
+                nonce: row.nonce as u64,
+                tx_type: AggregatedActionType::from_str(&row.tx_type).expect("Wrong agg type"),
+                sent_at_block: row.sent_at_block.map(|block| block as u32),
+                is_confirmed: row.is_confirmed,
+                has_failed: row.has_failed,
+            })
+            .collect())
+    }
+
     pub async fn delete_eth_txs(&mut self, last_batch_to_keep: L1BatchNumber) -> sqlx::Result<()> {
         sqlx::query!(
             r#"
@@ -14,6 +14,7 @@ use crate::Core;
 struct StorageSnapshotMetadata {
     version: i32,
     l1_batch_number: i64,
+    base_l1_batch_number: Option<i64>,
     storage_logs_filepaths: Vec<String>,
     factory_deps_filepath: String,
 }
@@ -28,6 +29,9 @@ impl TryFrom<StorageSnapshotMetadata> for SnapshotMetadata {
         Ok(Self {
             version,
             l1_batch_number: L1BatchNumber(row.l1_batch_number as u32),
+            base_l1_batch_number: row
+                .base_l1_batch_number
+                .map(|number| L1BatchNumber(number as u32)),
             storage_logs_filepaths: row
                 .storage_logs_filepaths
                 .into_iter()
@@ -48,6 +52,7 @@ impl SnapshotsDal<'_, '_> {
         &mut self,
         version: SnapshotVersion,
         l1_batch_number: L1BatchNumber,
+        base_l1_batch_number: Option<L1BatchNumber>,
         storage_logs_chunk_count: u64,
         factory_deps_filepaths: &str,
     ) -> DalResult<()> {
@@ -57,22 +62,25 @@ impl SnapshotsDal<'_, '_> {
             snapshots (
                 version,
                 l1_batch_number,
+                base_l1_batch_number,
                 storage_logs_filepaths,
                 factory_deps_filepath,
                 created_at,
                 updated_at
             )
             VALUES
-            ($1, $2, ARRAY_FILL(''::TEXT, ARRAY[$3::INTEGER]), $4, NOW(), NOW())
+            ($1, $2, $3, ARRAY_FILL(''::TEXT, ARRAY[$4::INTEGER]), $5, NOW(), NOW())
             "#,
             version as i32,
             l1_batch_number.0 as i32,
+            base_l1_batch_number.map(|number| i64::from(number.0)),
             storage_logs_chunk_count as i32,
             factory_deps_filepaths,
         )
         .instrument("add_snapshot")
         .with_arg("version", &version)
         .with_arg("l1_batch_number", &l1_batch_number)
+        .with_arg("base_l1_batch_number", &base_l1_batch_number)
         .report_latency()
         .execute(self.storage)
         .await?;
@@ -142,6 +150,7 @@ impl SnapshotsDal<'_, '_> {
             SELECT
                 VERSION,
                 L1_BATCH_NUMBER,
+                BASE_L1_BATCH_NUMBER,
                 FACTORY_DEPS_FILEPATH,
                 STORAGE_LOGS_FILEPATHS
             FROM
@@ -169,6 +178,7 @@ impl SnapshotsDal<'_, '_> {
             SELECT
                 VERSION,
                 L1_BATCH_NUMBER,
+                BASE_L1_BATCH_NUMBER,
                 FACTORY_DEPS_FILEPATH,
                 STORAGE_LOGS_FILEPATHS
             FROM
@@ -186,6 +196,40 @@ impl SnapshotsDal<'_, '_> {
         .await
     }
 
+    /// Returns metadata for all snapshots after the specified L1 batch number, i.e., ones that would be deleted
+    /// by [`Self::delete_snapshots_after()`].
+    pub async fn get_snapshots_after(
+        &mut self,
+        last_retained_l1_batch_number: L1BatchNumber,
+    ) -> DalResult<Vec<SnapshotMetadata>> {
+        sqlx::query_as!(
+            StorageSnapshotMetadata,
+            r#"
+            SELECT
+                version,
+                l1_batch_number,
+                base_l1_batch_number,
+                factory_deps_filepath,
+                storage_logs_filepaths
+            FROM
+                snapshots
+            WHERE
+                l1_batch_number > $1
+            ORDER BY
+                l1_batch_number
+            "#,
+            last_retained_l1_batch_number.0 as i32
+        )
+        .try_map(SnapshotMetadata::try_from)
+        .instrument("get_snapshots_after")
+        .with_arg(
+            "last_retained_l1_batch_number",
+            &last_retained_l1_batch_number,
+        )
+        .fetch_all(self.storage)
+        .await
+    }
+
     /// Deletes all snapshots after the specified L1 batch number and returns their metadata.
     pub async fn delete_snapshots_after(
         &mut self,
@@ -200,6 +244,7 @@ impl SnapshotsDal<'_, '_> {
             RETURNING
             version,
             l1_batch_number,
+            base_l1_batch_number,
             factory_deps_filepath,
             storage_logs_filepaths
             "#,
@@ -231,6 +276,7 @@ mod tests {
         dal.add_snapshot(
             SnapshotVersion::Version0,
             l1_batch_number,
+            None,
             2,
             "gs:///bucket/factory_deps.bin",
         )
@@ -267,6 +313,58 @@ mod tests {
         assert_eq!(snapshot_metadata.l1_batch_number, l1_batch_number);
     }
 
+    #[tokio::test]
+    async fn adding_incremental_snapshot() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let mut dal = conn.snapshots_dal();
+        let base_l1_batch_number = L1BatchNumber(100);
+        let l1_batch_number = L1BatchNumber(120);
+        dal.add_snapshot(
+            SnapshotVersion::Version2,
+            base_l1_batch_number,
+            None,
+            2,
+            "gs:///bucket/factory_deps.bin",
+        )
+        .await
+        .unwrap();
+        dal.add_snapshot(
+            SnapshotVersion::Version2,
+            l1_batch_number,
+            Some(base_l1_batch_number),
+            2,
+            "gs:///bucket/factory_deps_delta.bin",
+        )
+        .await
+        .unwrap();
+
+        let base_metadata = dal
+            .get_snapshot_metadata(base_l1_batch_number)
+            .await
+            .unwrap()
+            .expect("snapshot is not persisted");
+        assert_eq!(base_metadata.base_l1_batch_number, None);
+        let snapshot_metadata = dal
+            .get_newest_snapshot_metadata()
+            .await
+            .unwrap()
+            .expect("snapshot is not persisted");
+        assert_eq!(snapshot_metadata.l1_batch_number, l1_batch_number);
+        assert_eq!(snapshot_metadata.version, SnapshotVersion::Version2);
+        assert_eq!(
+            snapshot_metadata.base_l1_batch_number,
+            Some(base_l1_batch_number)
+        );
+
+        // Incremental snapshots always follow their base snapshots, so they are deleted together.
+        let deleted_snapshots = dal
+            .delete_snapshots_after(base_l1_batch_number - 1)
+            .await
+            .unwrap();
+        assert_eq!(deleted_snapshots.len(), 2);
+    }
+
     #[tokio::test]
     async fn deleting_snapshots() {
         let pool = ConnectionPool::<Core>::test_pool().await;
@@ -276,6 +374,7 @@ mod tests {
         dal.add_snapshot(
             SnapshotVersion::Version0,
             l1_batch_number,
+            None,
             2,
             "gs:///bucket/factory_deps.bin",
         )
@@ -339,6 +438,7 @@ mod tests {
         dal.add_snapshot(
             SnapshotVersion::Version0,
             l1_batch_number,
+            None,
             2,
             "gs:///bucket/factory_deps.bin",
         )
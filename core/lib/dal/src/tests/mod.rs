@@ -26,7 +26,7 @@ use crate::{
     protocol_versions_dal::ProtocolVersionsDal,
     transactions_dal::{L2TxSubmissionResult, TransactionsDal},
     transactions_web3_dal::TransactionsWeb3Dal,
-    Core,
+    Core, CoreDal,
 };
 
 const DEFAULT_GAS_PER_PUBDATA: u32 = 100;
@@ -269,6 +269,120 @@ async fn workflow_with_submit_tx_diff_hashes() {
     assert_eq!(result, L2TxSubmissionResult::Replaced);
 }
 
+fn mock_replacement_transaction(tx: &L2Tx, max_fee_per_gas_percent: u32) -> L2Tx {
+    let mut replacement = mock_l2_transaction();
+    replacement.common_data.nonce = tx.common_data.nonce;
+    replacement.common_data.initiator_address = tx.common_data.initiator_address;
+    replacement.common_data.fee.max_fee_per_gas =
+        tx.common_data.fee.max_fee_per_gas * max_fee_per_gas_percent / 100;
+    replacement
+}
+
+#[tokio::test]
+async fn replacing_transactions_with_min_fee_bump() {
+    let connection_pool = ConnectionPool::<Core>::test_pool().await;
+    let storage = &mut connection_pool.connection().await.unwrap();
+    let mut transactions_dal = TransactionsDal { storage };
+
+    let tx = mock_l2_transaction();
+    let result = transactions_dal
+        .insert_transaction_l2_with_min_fee_bump(
+            &tx,
+            mock_tx_execution_metrics(),
+            ValidationTraces::default(),
+            Some(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result, L2TxSubmissionResult::Added);
+
+    let underpriced_tx = mock_replacement_transaction(&tx, 109);
+    let result = transactions_dal
+        .insert_transaction_l2_with_min_fee_bump(
+            &underpriced_tx,
+            mock_tx_execution_metrics(),
+            ValidationTraces::default(),
+            Some(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result, L2TxSubmissionResult::ReplacementUnderpriced);
+
+    let replacement_tx = mock_replacement_transaction(&tx, 110);
+    let result = transactions_dal
+        .insert_transaction_l2_with_min_fee_bump(
+            &replacement_tx,
+            mock_tx_execution_metrics(),
+            ValidationTraces::default(),
+            Some(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result, L2TxSubmissionResult::Replaced);
+}
+
+#[tokio::test]
+async fn concurrent_transaction_replacements() {
+    let connection_pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = connection_pool.connection().await.unwrap();
+    let tx = mock_l2_transaction();
+    let result = storage
+        .transactions_dal()
+        .insert_transaction_l2(
+            &tx,
+            mock_tx_execution_metrics(),
+            ValidationTraces::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result, L2TxSubmissionResult::Added);
+
+    // Both replacements bump fees sufficiently compared to the original transaction, but not compared to each other.
+    let replacements = [
+        mock_replacement_transaction(&tx, 110),
+        mock_replacement_transaction(&tx, 115),
+    ];
+    let mut first_storage = connection_pool.connection().await.unwrap();
+    let mut second_storage = connection_pool.connection().await.unwrap();
+    let (first_result, second_result) = tokio::join!(
+        first_storage
+            .transactions_dal()
+            .insert_transaction_l2_with_min_fee_bump(
+                &replacements[0],
+                mock_tx_execution_metrics(),
+                ValidationTraces::default(),
+                Some(10),
+            ),
+        second_storage
+            .transactions_dal()
+            .insert_transaction_l2_with_min_fee_bump(
+                &replacements[1],
+                mock_tx_execution_metrics(),
+                ValidationTraces::default(),
+                Some(10),
+            ),
+    );
+    let results = [first_result.unwrap(), second_result.unwrap()];
+    let winner_idx = results
+        .iter()
+        .position(|result| *result == L2TxSubmissionResult::Replaced)
+        .expect("no replacement succeeded");
+    assert_eq!(
+        results[1 - winner_idx],
+        L2TxSubmissionResult::ReplacementUnderpriced,
+        "{results:?}"
+    );
+
+    // The DB must contain the winning replacement.
+    let pending_txs = storage
+        .transactions_web3_dal()
+        .pending_transactions_by_initiator_account(tx.initiator_account(), 0)
+        .await
+        .unwrap();
+    let expected_fee = replacements[winner_idx].common_data.fee.clone();
+    assert_eq!(pending_txs, [(tx.common_data.nonce, expected_fee)]);
+}
+
 #[tokio::test]
 async fn remove_stuck_txs() {
     let connection_pool = ConnectionPool::<Core>::test_pool().await;
@@ -36,6 +36,9 @@ pub enum L2TxSubmissionResult {
     Duplicate,
     Proxied,
     InsertionInProgress,
+    /// A pending transaction with the same initiator and nonce exists, and the new transaction
+    /// doesn't bump its fees sufficiently to replace it.
+    ReplacementUnderpriced,
 }
 
 impl fmt::Display for L2TxSubmissionResult {
@@ -47,6 +50,7 @@ impl fmt::Display for L2TxSubmissionResult {
             Self::Duplicate => "duplicate",
             Self::Proxied => "proxied",
             Self::InsertionInProgress => "insertion_in_progress",
+            Self::ReplacementUnderpriced => "replacement_underpriced",
         })
     }
 }
@@ -266,6 +270,21 @@ impl TransactionsDal<'_, '_> {
         tx: &L2Tx,
         exec_info: TransactionExecutionMetrics,
         validation_traces: ValidationTraces,
+    ) -> DalResult<L2TxSubmissionResult> {
+        self.insert_transaction_l2_with_min_fee_bump(tx, exec_info, validation_traces, None)
+            .await
+    }
+
+    /// Same as [`Self::insert_transaction_l2()`], but if `min_fee_bump_percent` is set, a pending transaction
+    /// with the same initiator and nonce is only replaced if both `max_fee_per_gas` and `max_priority_fee_per_gas`
+    /// are bumped by at least this percentage. The check is a part of the upsert, so it's atomic w.r.t. concurrent
+    /// replacements.
+    pub async fn insert_transaction_l2_with_min_fee_bump(
+        &mut self,
+        tx: &L2Tx,
+        exec_info: TransactionExecutionMetrics,
+        validation_traces: ValidationTraces,
+        min_fee_bump_percent: Option<u32>,
     ) -> DalResult<L2TxSubmissionResult> {
         let tx_hash = tx.hash();
         let is_duplicate = sqlx::query!(
@@ -430,6 +449,13 @@ impl TransactionsDal<'_, '_> {
             WHERE
             transactions.is_priority = FALSE
             AND transactions.miniblock_number IS NULL
+            AND (
+                $22::INT IS NULL
+                OR (
+                    $6 * 100 >= transactions.max_fee_per_gas * (100 + $22::INT)
+                    AND $7 * 100 >= transactions.max_priority_fee_per_gas * (100 + $22::INT)
+                )
+            )
             RETURNING
             (
                 SELECT
@@ -462,6 +488,7 @@ impl TransactionsDal<'_, '_> {
             received_at,
             timestamp_asserter_range_start,
             timestamp_asserter_range_end,
+            min_fee_bump_percent.map(|percent| percent as i32),
         )
         .instrument("insert_transaction_l2")
         .with_arg("tx_hash", &tx_hash)
@@ -473,6 +500,17 @@ impl TransactionsDal<'_, '_> {
             Ok(option_query_result) => match option_query_result {
                 Some(true) => L2TxSubmissionResult::Replaced,
                 Some(false) => L2TxSubmissionResult::Added,
+                // The `WHERE` clause conditions for `DO UPDATE` were not met; check which one has failed.
+                None if min_fee_bump_percent.is_some() => {
+                    if self
+                        .has_pending_transaction(initiator_address, nonce)
+                        .await?
+                    {
+                        L2TxSubmissionResult::ReplacementUnderpriced
+                    } else {
+                        L2TxSubmissionResult::AlreadyExecuted
+                    }
+                }
                 None => L2TxSubmissionResult::AlreadyExecuted,
             },
             Err(err) => {
@@ -508,6 +546,36 @@ impl TransactionsDal<'_, '_> {
         Ok(l2_tx_insertion_result)
     }
 
+    /// Checks whether there's a pending (i.e., not included into an L2 block) L2 transaction with the specified
+    /// initiator and nonce.
+    async fn has_pending_transaction(
+        &mut self,
+        initiator_address: Address,
+        nonce: i64,
+    ) -> DalResult<bool> {
+        Ok(sqlx::query!(
+            r#"
+            SELECT
+                TRUE
+            FROM
+                transactions
+            WHERE
+                initiator_address = $1
+                AND nonce = $2
+                AND is_priority = FALSE
+                AND miniblock_number IS NULL
+            "#,
+            initiator_address.as_bytes(),
+            nonce,
+        )
+        .instrument("has_pending_transaction")
+        .with_arg("initiator_address", &initiator_address)
+        .with_arg("nonce", &nonce)
+        .fetch_optional(self.storage)
+        .await?
+        .is_some())
+    }
+
     pub async fn mark_txs_as_executed_in_l1_batch(
         &mut self,
         l1_batch_number: L1BatchNumber,
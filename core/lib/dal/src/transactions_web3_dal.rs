@@ -9,15 +9,18 @@ use zksync_db_connection::{
     interpolate_query, match_query_as,
 };
 use zksync_types::{
-    api, api::TransactionReceipt, block::build_bloom, Address, BloomInput, L2BlockNumber,
-    L2ChainId, Transaction, CONTRACT_DEPLOYER_ADDRESS, H256, U256,
+    api, api::TransactionReceipt, block::build_bloom, fee::Fee, Address, BloomInput, L2BlockNumber,
+    L2ChainId, Nonce, Transaction, CONTRACT_DEPLOYER_ADDRESS, H256, U256,
 };
 use zksync_vm_interface::VmEvent;
 
 use crate::{
-    models::storage_transaction::{
-        StorageApiTransaction, StorageTransaction, StorageTransactionDetails,
-        StorageTransactionExecutionInfo, StorageTransactionReceipt,
+    models::{
+        bigdecimal_to_u256,
+        storage_transaction::{
+            StorageApiTransaction, StorageTransaction, StorageTransactionDetails,
+            StorageTransactionExecutionInfo, StorageTransactionReceipt,
+        },
     },
     Core, CoreDal,
 };
@@ -425,6 +428,56 @@ impl TransactionsWeb3Dal<'_, '_> {
         Ok(U256::from(pending_nonce))
     }
 
+    /// Returns nonces and fees of pending (i.e., not yet included into an L2 block and not rejected) L2 transactions
+    /// sent by `initiator_address`, ordered by nonce. `committed_next_nonce` should equal the nonce
+    /// for `initiator_address` in the storage.
+    pub async fn pending_transactions_by_initiator_account(
+        &mut self,
+        initiator_address: Address,
+        committed_next_nonce: u64,
+    ) -> DalResult<Vec<(Nonce, Fee)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                nonce AS "nonce!",
+                gas_limit AS "gas_limit!",
+                max_fee_per_gas AS "max_fee_per_gas!",
+                max_priority_fee_per_gas AS "max_priority_fee_per_gas!",
+                gas_per_pubdata_limit AS "gas_per_pubdata_limit!"
+            FROM
+                transactions
+            WHERE
+                initiator_address = $1
+                AND nonce >= $2
+                AND is_priority = FALSE
+                AND miniblock_number IS NULL
+                AND error IS NULL
+            ORDER BY
+                nonce
+            "#,
+            initiator_address.as_bytes(),
+            committed_next_nonce as i64
+        )
+        .instrument("pending_transactions_by_initiator_account")
+        .with_arg("initiator_address", &initiator_address)
+        .with_arg("committed_next_nonce", &committed_next_nonce)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let fee = Fee {
+                    gas_limit: bigdecimal_to_u256(row.gas_limit),
+                    max_fee_per_gas: bigdecimal_to_u256(row.max_fee_per_gas),
+                    max_priority_fee_per_gas: bigdecimal_to_u256(row.max_priority_fee_per_gas),
+                    gas_per_pubdata_limit: bigdecimal_to_u256(row.gas_per_pubdata_limit),
+                };
+                (Nonce(row.nonce as u32), fee)
+            })
+            .collect())
+    }
+
     /// Returns the server transactions (not API ones) from a L2 block range.
     pub async fn get_raw_l2_blocks_transactions(
         &mut self,
@@ -806,6 +859,76 @@ mod tests {
         assert_eq!(next_nonce, 2.into());
     }
 
+    #[tokio::test]
+    async fn getting_pending_transactions_by_initiator_account() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let initiator = Address::repeat_byte(1);
+        let mut tx_by_nonce = HashMap::new();
+        for nonce in [0, 1, 2] {
+            let mut tx = mock_l2_transaction();
+            tx.common_data.nonce = Nonce(nonce);
+            tx.common_data.initiator_address = initiator;
+            tx.common_data.fee.max_fee_per_gas += nonce.into();
+            tx_by_nonce.insert(nonce, tx.clone());
+            conn.transactions_dal()
+                .insert_transaction_l2(
+                    &tx,
+                    TransactionExecutionMetrics::default(),
+                    ValidationTraces::default(),
+                )
+                .await
+                .unwrap();
+        }
+
+        let pending_txs = conn
+            .transactions_web3_dal()
+            .pending_transactions_by_initiator_account(initiator, 0)
+            .await
+            .unwrap();
+        let expected_txs: Vec<_> = (0..3)
+            .map(|nonce| (Nonce(nonce), tx_by_nonce[&nonce].common_data.fee.clone()))
+            .collect();
+        assert_eq!(pending_txs, expected_txs);
+
+        let pending_txs = conn
+            .transactions_web3_dal()
+            .pending_transactions_by_initiator_account(initiator, 2)
+            .await
+            .unwrap();
+        assert_eq!(pending_txs, expected_txs[2..]);
+
+        // Rejected and executed transactions are not pending.
+        conn.transactions_dal()
+            .mark_tx_as_rejected(tx_by_nonce[&1].hash(), "oops")
+            .await
+            .unwrap();
+        let l2_block = create_l2_block_header(1);
+        conn.blocks_dal().insert_l2_block(&l2_block).await.unwrap();
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l2_block(
+                l2_block.number,
+                &[mock_execution_result(tx_by_nonce[&0].clone())],
+                1.into(),
+                ProtocolVersionId::latest(),
+                false,
+            )
+            .await
+            .unwrap();
+
+        let pending_txs = conn
+            .transactions_web3_dal()
+            .pending_transactions_by_initiator_account(initiator, 0)
+            .await
+            .unwrap();
+        assert_eq!(pending_txs, expected_txs[2..]);
+    }
+
     #[tokio::test]
     async fn getting_next_nonce_by_initiator_account_after_snapshot_recovery() {
         // Emulate snapshot recovery: no transactions with past nonces are present in the storage
@@ -78,6 +78,9 @@ mod tests {
                 latest_values_cache_size_mb: Some(256),
                 latest_values_max_block_lag: Some(NonZeroU32::new(50).unwrap()),
                 fee_history_limit: Some(100),
+                simulate_blocks_limit: Some(16),
+                simulate_calls_limit: Some(100),
+                simulate_gas_limit: Some(500_000_000),
                 max_batch_request_size: Some(200),
                 max_response_body_size_mb: Some(10),
                 max_response_body_size_overrides_mb: [
@@ -88,6 +91,14 @@ mod tests {
                 .into_iter()
                 .collect(),
                 websocket_requests_per_minute_limit: Some(NonZeroU32::new(10).unwrap()),
+                client_requests_per_minute_limit: Some(NonZeroU32::new(100).unwrap()),
+                trust_forwarded_for_header: true,
+                rate_limit_method_costs: [
+                    ("eth_getLogs", NonZeroU32::new(10).unwrap()),
+                    ("debug_*", NonZeroU32::new(20).unwrap()),
+                ]
+                .into_iter()
+                .collect(),
                 tree_api_url: None,
                 mempool_cache_update_interval: Some(50),
                 mempool_cache_size: Some(10000),
@@ -139,8 +150,14 @@ mod tests {
             API_WEB3_JSON_RPC_LATEST_VALUES_CACHE_SIZE_MB=256
             API_WEB3_JSON_RPC_LATEST_VALUES_MAX_BLOCK_LAG=50
             API_WEB3_JSON_RPC_FEE_HISTORY_LIMIT=100
+            API_WEB3_JSON_RPC_SIMULATE_BLOCKS_LIMIT=16
+            API_WEB3_JSON_RPC_SIMULATE_CALLS_LIMIT=100
+            API_WEB3_JSON_RPC_SIMULATE_GAS_LIMIT=500000000
             API_WEB3_JSON_RPC_MAX_BATCH_REQUEST_SIZE=200
             API_WEB3_JSON_RPC_WEBSOCKET_REQUESTS_PER_MINUTE_LIMIT=10
+            API_WEB3_JSON_RPC_CLIENT_REQUESTS_PER_MINUTE_LIMIT=100
+            API_WEB3_JSON_RPC_TRUST_FORWARDED_FOR_HEADER=true
+            API_WEB3_JSON_RPC_RATE_LIMIT_METHOD_COSTS="eth_getLogs=10, debug_*=20"
             API_WEB3_JSON_RPC_MEMPOOL_CACHE_SIZE=10000
             API_WEB3_JSON_RPC_MEMPOOL_CACHE_UPDATE_INTERVAL=50
             API_CONTRACT_VERIFICATION_PORT="3070"
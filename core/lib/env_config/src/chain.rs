@@ -37,7 +37,7 @@ impl FromEnv for MempoolConfig {
 #[cfg(test)]
 mod tests {
     use zksync_basic_types::{commitment::L1BatchCommitmentMode, L2ChainId};
-    use zksync_config::configs::chain::FeeModelVersion;
+    use zksync_config::configs::chain::{FeeModelVersion, MempoolOrdering};
 
     use super::*;
     use crate::test_utils::{addr, hash, EnvMutex};
@@ -172,6 +172,9 @@ mod tests {
             stuck_tx_timeout: 10,
             remove_stuck_txs: true,
             delay_interval: 100,
+            ordering: MempoolOrdering::FeePriority,
+            max_pending_txs_per_account: Some(16),
+            replacement_fee_bump_percent: Some(10),
         }
     }
 
@@ -185,6 +188,9 @@ mod tests {
             CHAIN_MEMPOOL_REMOVE_STUCK_TXS="true"
             CHAIN_MEMPOOL_DELAY_INTERVAL="100"
             CHAIN_MEMPOOL_CAPACITY="1000000"
+            CHAIN_MEMPOOL_ORDERING="FeePriority"
+            CHAIN_MEMPOOL_MAX_PENDING_TXS_PER_ACCOUNT="16"
+            CHAIN_MEMPOOL_REPLACEMENT_FEE_BUMP_PERCENT="10"
         "#;
         lock.set_env(config);
 
@@ -1,14 +1,17 @@
 use std::env;
 
+use anyhow::Context as _;
 use zksync_config::configs::{
     da_client::{
         avail::{
             AvailClientConfig, AvailSecrets, AVAIL_FULL_CLIENT_NAME, AVAIL_GAS_RELAY_CLIENT_NAME,
         },
         celestia::CelestiaSecrets,
+        composite::{CompositeConfig, CompositeSecrets},
         eigen::EigenSecrets,
         DAClientConfig, AVAIL_CLIENT_CONFIG_NAME, CELESTIA_CLIENT_CONFIG_NAME,
-        EIGEN_CLIENT_CONFIG_NAME, OBJECT_STORE_CLIENT_CONFIG_NAME,
+        COMPOSITE_CLIENT_CONFIG_NAME, EIGEN_CLIENT_CONFIG_NAME, OBJECT_STORE_CLIENT_CONFIG_NAME,
+        SIDECAR_CLIENT_CONFIG_NAME,
     },
     secrets::DataAvailabilitySecrets,
     AvailConfig,
@@ -20,72 +23,128 @@ impl FromEnv for DAClientConfig {
     fn from_env() -> anyhow::Result<Self> {
         let client_tag = env::var("DA_CLIENT")?;
         let config = match client_tag.as_str() {
-            AVAIL_CLIENT_CONFIG_NAME => Self::Avail(AvailConfig {
-                bridge_api_url: env::var("DA_BRIDGE_API_URL").ok().unwrap(),
-                timeout_ms: env::var("DA_TIMEOUT_MS")?.parse()?,
-                config: match env::var("DA_AVAIL_CLIENT_TYPE")?.as_str() {
-                    AVAIL_FULL_CLIENT_NAME => {
-                        AvailClientConfig::FullClient(envy_load("da_avail_full_client", "DA_")?)
-                    }
-                    AVAIL_GAS_RELAY_CLIENT_NAME => {
-                        AvailClientConfig::GasRelay(envy_load("da_avail_gas_relay", "DA_")?)
-                    }
-                    _ => anyhow::bail!("Unknown Avail DA client type"),
-                },
+            COMPOSITE_CLIENT_CONFIG_NAME => Self::Composite(CompositeConfig {
+                clients: composite_client_tags()?
+                    .iter()
+                    .enumerate()
+                    .map(|(i, tag)| {
+                        da_client_config_from_env(tag, &composite_client_prefix(i))
+                            .with_context(|| format!("composite DA client #{i}"))
+                    })
+                    .collect::<anyhow::Result<_>>()?,
+                quorum: env::var("DA_COMPOSITE_QUORUM")?.parse()?,
             }),
-            CELESTIA_CLIENT_CONFIG_NAME => Self::Celestia(envy_load("da_celestia_config", "DA_")?),
-            EIGEN_CLIENT_CONFIG_NAME => Self::Eigen(envy_load("da_eigen_config", "DA_")?),
-            OBJECT_STORE_CLIENT_CONFIG_NAME => {
-                Self::ObjectStore(envy_load("da_object_store", "DA_")?)
-            }
-            _ => anyhow::bail!("Unknown DA client name: {}", client_tag),
+            _ => da_client_config_from_env(&client_tag, "DA_")?,
         };
 
         Ok(config)
     }
 }
 
+/// Returns DA client names for the composite DA client, e.g. `DA_COMPOSITE_CLIENTS="Celestia,ObjectStore"`.
+fn composite_client_tags() -> anyhow::Result<Vec<String>> {
+    let tags = env::var("DA_COMPOSITE_CLIENTS").context("DA_COMPOSITE_CLIENTS")?;
+    Ok(tags.split(',').map(|tag| tag.trim().to_owned()).collect())
+}
+
+/// Env variables for the composite DA client backends are prefixed with their index, e.g. `DA_COMPOSITE_0_`.
+fn composite_client_prefix(index: usize) -> String {
+    format!("DA_COMPOSITE_{index}_")
+}
+
+fn da_client_config_from_env(client_tag: &str, prefix: &str) -> anyhow::Result<DAClientConfig> {
+    let config = match client_tag {
+        AVAIL_CLIENT_CONFIG_NAME => DAClientConfig::Avail(AvailConfig {
+            bridge_api_url: env::var(format!("{prefix}BRIDGE_API_URL"))?,
+            timeout_ms: env::var(format!("{prefix}TIMEOUT_MS"))?.parse()?,
+            config: match env::var(format!("{prefix}AVAIL_CLIENT_TYPE"))?.as_str() {
+                AVAIL_FULL_CLIENT_NAME => {
+                    AvailClientConfig::FullClient(envy_load("da_avail_full_client", prefix)?)
+                }
+                AVAIL_GAS_RELAY_CLIENT_NAME => {
+                    AvailClientConfig::GasRelay(envy_load("da_avail_gas_relay", prefix)?)
+                }
+                _ => anyhow::bail!("Unknown Avail DA client type"),
+            },
+        }),
+        CELESTIA_CLIENT_CONFIG_NAME => {
+            DAClientConfig::Celestia(envy_load("da_celestia_config", prefix)?)
+        }
+        EIGEN_CLIENT_CONFIG_NAME => DAClientConfig::Eigen(envy_load("da_eigen_config", prefix)?),
+        OBJECT_STORE_CLIENT_CONFIG_NAME => {
+            DAClientConfig::ObjectStore(envy_load("da_object_store", prefix)?)
+        }
+        SIDECAR_CLIENT_CONFIG_NAME => DAClientConfig::Sidecar(envy_load("da_sidecar", prefix)?),
+        COMPOSITE_CLIENT_CONFIG_NAME => {
+            anyhow::bail!("Nested composite DA clients are not supported")
+        }
+        _ => anyhow::bail!("Unknown DA client name: {}", client_tag),
+    };
+    Ok(config)
+}
+
 impl FromEnv for DataAvailabilitySecrets {
     fn from_env() -> anyhow::Result<Self> {
         let client_tag = std::env::var("DA_CLIENT")?;
-        let secrets = match client_tag.as_str() {
-            AVAIL_CLIENT_CONFIG_NAME => {
-                let seed_phrase: Option<zksync_basic_types::secrets::SeedPhrase> =
-                    env::var("DA_SECRETS_SEED_PHRASE")
-                        .ok()
-                        .map(|s| s.parse().unwrap());
-                let gas_relay_api_key: Option<zksync_basic_types::secrets::APIKey> =
-                    env::var("DA_SECRETS_GAS_RELAY_API_KEY")
-                        .ok()
-                        .map(|s| s.parse().unwrap());
-                if seed_phrase.is_none() && gas_relay_api_key.is_none() {
-                    anyhow::bail!("No secrets provided for Avail DA client");
-                }
-                Self::Avail(AvailSecrets {
-                    seed_phrase,
-                    gas_relay_api_key,
+        if client_tag == COMPOSITE_CLIENT_CONFIG_NAME {
+            let secrets = composite_client_tags()?
+                .iter()
+                .enumerate()
+                .map(|(i, tag)| {
+                    da_secrets_from_env(tag, &composite_client_prefix(i))
+                        .with_context(|| format!("composite DA client #{i}"))
                 })
+                .collect::<anyhow::Result<_>>()?;
+            return Ok(Self::Composite(CompositeSecrets { secrets }));
+        }
+
+        da_secrets_from_env(&client_tag, "DA_")?
+            .with_context(|| format!("DA client {client_tag} doesn't have secrets"))
+    }
+}
+
+fn da_secrets_from_env(
+    client_tag: &str,
+    prefix: &str,
+) -> anyhow::Result<Option<DataAvailabilitySecrets>> {
+    let secrets = match client_tag {
+        AVAIL_CLIENT_CONFIG_NAME => {
+            let seed_phrase: Option<zksync_basic_types::secrets::SeedPhrase> =
+                env::var(format!("{prefix}SECRETS_SEED_PHRASE"))
+                    .ok()
+                    .map(|s| s.parse().unwrap());
+            let gas_relay_api_key: Option<zksync_basic_types::secrets::APIKey> =
+                env::var(format!("{prefix}SECRETS_GAS_RELAY_API_KEY"))
+                    .ok()
+                    .map(|s| s.parse().unwrap());
+            if seed_phrase.is_none() && gas_relay_api_key.is_none() {
+                anyhow::bail!("No secrets provided for Avail DA client");
             }
-            CELESTIA_CLIENT_CONFIG_NAME => {
-                let private_key = env::var("DA_SECRETS_PRIVATE_KEY")
-                    .map_err(|e| anyhow::format_err!("Celestia private key not found: {}", e))?
-                    .parse()
-                    .map_err(|e| anyhow::format_err!("failed to parse the private key: {}", e))?;
-                Self::Celestia(CelestiaSecrets { private_key })
-            }
-            EIGEN_CLIENT_CONFIG_NAME => {
-                let private_key = env::var("DA_SECRETS_PRIVATE_KEY")
-                    .map_err(|e| anyhow::format_err!("Eigen private key not found: {}", e))?
-                    .parse()
-                    .map_err(|e| anyhow::format_err!("failed to parse the private key: {}", e))?;
-                Self::Eigen(EigenSecrets { private_key })
-            }
+            DataAvailabilitySecrets::Avail(AvailSecrets {
+                seed_phrase,
+                gas_relay_api_key,
+            })
+        }
+        CELESTIA_CLIENT_CONFIG_NAME => {
+            let private_key = env::var(format!("{prefix}SECRETS_PRIVATE_KEY"))
+                .map_err(|e| anyhow::format_err!("Celestia private key not found: {}", e))?
+                .parse()
+                .map_err(|e| anyhow::format_err!("failed to parse the private key: {}", e))?;
+            DataAvailabilitySecrets::Celestia(CelestiaSecrets { private_key })
+        }
+        EIGEN_CLIENT_CONFIG_NAME => {
+            let private_key = env::var(format!("{prefix}SECRETS_PRIVATE_KEY"))
+                .map_err(|e| anyhow::format_err!("Eigen private key not found: {}", e))?
+                .parse()
+                .map_err(|e| anyhow::format_err!("failed to parse the private key: {}", e))?;
+            DataAvailabilitySecrets::Eigen(EigenSecrets { private_key })
+        }
+        OBJECT_STORE_CLIENT_CONFIG_NAME | SIDECAR_CLIENT_CONFIG_NAME => return Ok(None),
 
-            _ => anyhow::bail!("Unknown DA client name: {}", client_tag),
-        };
+        _ => anyhow::bail!("Unknown DA client name: {}", client_tag),
+    };
 
-        Ok(secrets)
-    }
+    Ok(Some(secrets))
 }
 
 #[cfg(test)]
@@ -98,7 +157,7 @@ mod tests {
             },
             object_store::ObjectStoreMode::GCS,
         },
-        AvailConfig, CelestiaConfig, EigenConfig, ObjectStoreConfig,
+        AvailConfig, CelestiaConfig, EigenConfig, ObjectStoreConfig, SidecarConfig,
     };
 
     use super::*;
@@ -113,6 +172,8 @@ mod tests {
             },
             max_retries,
             local_mirror_path: None,
+            compression_level: None,
+            compressed_buckets: vec![],
         })
     }
 
@@ -285,4 +346,79 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn from_env_composite_client() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            DA_CLIENT="Composite"
+            DA_COMPOSITE_CLIENTS="Celestia, ObjectStore"
+            DA_COMPOSITE_QUORUM="1"
+
+            DA_COMPOSITE_0_API_NODE_URL="localhost:12345"
+            DA_COMPOSITE_0_NAMESPACE="0x1234567890abcdef"
+            DA_COMPOSITE_0_CHAIN_ID="mocha-4"
+            DA_COMPOSITE_0_TIMEOUT_MS="7000"
+            DA_COMPOSITE_0_SECRETS_PRIVATE_KEY="f55baf7c0e4e33b1d78fbf52f069c426bc36cff1aceb9bc8f45d14c07f034d73"
+
+            DA_COMPOSITE_1_BUCKET_BASE_URL="sometestpath"
+            DA_COMPOSITE_1_MODE="GCS"
+            DA_COMPOSITE_1_MAX_RETRIES="5"
+        "#;
+        lock.set_env(config);
+
+        let actual = DAClientConfig::from_env().unwrap();
+        assert_eq!(
+            actual,
+            DAClientConfig::Composite(CompositeConfig {
+                clients: vec![
+                    expected_celestia_da_layer_config(
+                        "localhost:12345",
+                        "0x1234567890abcdef",
+                        "mocha-4",
+                        7000
+                    ),
+                    expected_object_store_da_client_config("sometestpath".to_string(), 5),
+                ],
+                quorum: 1,
+            })
+        );
+
+        let DataAvailabilitySecrets::Composite(actual) =
+            DataAvailabilitySecrets::from_env().unwrap()
+        else {
+            panic!("expected composite secrets")
+        };
+        let [Some(DataAvailabilitySecrets::Celestia(celestia_secrets)), None] =
+            actual.secrets.as_slice()
+        else {
+            panic!("unexpected secrets: {actual:?}");
+        };
+        assert_eq!(
+            celestia_secrets.private_key,
+            "f55baf7c0e4e33b1d78fbf52f069c426bc36cff1aceb9bc8f45d14c07f034d73"
+                .parse()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn from_env_sidecar_client() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            DA_CLIENT="Sidecar"
+            DA_API_URL="http://localhost:3100"
+            DA_TIMEOUT_MS="5000"
+        "#;
+        lock.set_env(config);
+
+        let actual = DAClientConfig::from_env().unwrap();
+        assert_eq!(
+            actual,
+            DAClientConfig::Sidecar(SidecarConfig {
+                api_url: "http://localhost:3100".parse().unwrap(),
+                timeout_ms: 5000,
+            })
+        );
+    }
 }
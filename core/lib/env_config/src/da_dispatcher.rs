@@ -27,6 +27,9 @@ mod tests {
             max_rows_to_dispatch: Some(rows_limit),
             max_retries: Some(max_retries),
             use_dummy_inclusion_data: Some(true),
+            inclusion_timeout_ms: Some(600_000),
+            max_concurrent_inclusion_requests: Some(20),
+            split_oversized_blobs: Some(true),
         }
     }
 
@@ -38,6 +41,9 @@ mod tests {
             DA_DISPATCHER_MAX_ROWS_TO_DISPATCH=60
             DA_DISPATCHER_MAX_RETRIES=7
             DA_DISPATCHER_USE_DUMMY_INCLUSION_DATA="true"
+            DA_DISPATCHER_INCLUSION_TIMEOUT_MS=600000
+            DA_DISPATCHER_MAX_CONCURRENT_INCLUSION_REQUESTS=20
+            DA_DISPATCHER_SPLIT_OVERSIZED_BLOBS="true"
         "#;
         lock.set_env(config);
         let actual = DADispatcherConfig::from_env().unwrap();
@@ -1,6 +1,9 @@
 use anyhow::Context as _;
 use zksync_config::{
-    configs::{eth_sender::SenderConfig, L1Secrets},
+    configs::{
+        eth_sender::{RemoteSignerConfig, SenderConfig},
+        L1Secrets,
+    },
     EthConfig, EthWatchConfig, GasAdjusterConfig,
 };
 
@@ -12,6 +15,7 @@ impl FromEnv for EthConfig {
             sender: SenderConfig::from_env().ok(),
             gas_adjuster: GasAdjusterConfig::from_env().ok(),
             watcher: EthWatchConfig::from_env().ok(),
+            remote_signer: RemoteSignerConfig::from_env().ok(),
         })
     }
 }
@@ -23,6 +27,21 @@ impl FromEnv for L1Secrets {
                 .context("ETH_CLIENT_WEB3_URL")?
                 .parse()
                 .context("ETH_CLIENT_WEB3_URL")?,
+            fallback_l1_rpc_urls: match std::env::var("ETH_CLIENT_WEB3_FALLBACK_URLS") {
+                Ok(urls) => urls
+                    .split(',')
+                    .map(|url| url.trim().parse())
+                    .collect::<Result<_, _>>()
+                    .context("ETH_CLIENT_WEB3_FALLBACK_URLS")?,
+                Err(_) => vec![],
+            },
+            cross_check_l1_block_hashes: match std::env::var("ETH_CLIENT_CROSS_CHECK_BLOCK_HASHES")
+            {
+                Ok(value) => value
+                    .parse()
+                    .context("ETH_CLIENT_CROSS_CHECK_BLOCK_HASHES")?,
+                Err(_) => false,
+            },
         })
     }
 }
@@ -33,6 +52,12 @@ impl FromEnv for SenderConfig {
     }
 }
 
+impl FromEnv for RemoteSignerConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("eth_sender.remote_signer", "ETH_SENDER_REMOTE_SIGNER_")
+    }
+}
+
 impl FromEnv for GasAdjusterConfig {
     fn from_env() -> anyhow::Result<Self> {
         envy_load("eth_sender.gas_adjuster", "ETH_SENDER_GAS_ADJUSTER_")
@@ -42,10 +67,10 @@ impl FromEnv for GasAdjusterConfig {
 #[cfg(test)]
 mod tests {
     use zksync_basic_types::pubdata_da::PubdataSendingMode;
-    use zksync_config::configs::eth_sender::ProofSendingMode;
+    use zksync_config::configs::eth_sender::{FeeBumpingMode, ProofSendingMode};
 
     use super::*;
-    use crate::test_utils::{hash, EnvMutex};
+    use crate::test_utils::{addr, hash, EnvMutex};
 
     static MUTEX: EnvMutex = EnvMutex::new();
 
@@ -74,6 +99,13 @@ mod tests {
                     tx_aggregation_only_prove_and_execute: false,
                     tx_aggregation_paused: false,
                     time_in_mempool_in_l1_blocks_cap: 2000,
+                    max_commit_txs_in_flight: None,
+                    max_prove_txs_in_flight: Some(5),
+                    max_execute_txs_in_flight: None,
+                    fee_bumping_mode: FeeBumpingMode::Exponential,
+                    fee_bump_percent: 15,
+                    max_fee_bump_multiplier: 10,
+                    fee_bump_percentile: 75,
                 }),
                 gas_adjuster: Some(GasAdjusterConfig {
                     default_priority_fee_per_gas: 20000000000,
@@ -94,9 +126,22 @@ mod tests {
                     confirmations_for_eth_event: Some(0),
                     eth_node_poll_interval: 300,
                 }),
+                remote_signer: Some(RemoteSignerConfig {
+                    url: "http://127.0.0.1:9000".parse().unwrap(),
+                    operator_address: addr("1111111111111111111111111111111111111111"),
+                    blob_operator_address: None,
+                    prove_operator_address: Some(addr("2222222222222222222222222222222222222222")),
+                    execute_operator_address: None,
+                    request_timeout_ms: 5_000,
+                }),
             },
             L1Secrets {
                 l1_rpc_url: "http://127.0.0.1:8545".to_string().parse().unwrap(),
+                fallback_l1_rpc_urls: vec![
+                    "http://127.0.0.1:8546".parse().unwrap(),
+                    "http://127.0.0.1:8547".parse().unwrap(),
+                ],
+                cross_check_l1_block_hashes: true,
             },
         )
     }
@@ -112,6 +157,9 @@ mod tests {
             ETH_SENDER_SENDER_TX_POLL_PERIOD="3"
             ETH_SENDER_SENDER_AGGREGATE_TX_POLL_PERIOD="3"
             ETH_SENDER_SENDER_MAX_TXS_IN_FLIGHT="3"
+            ETH_SENDER_SENDER_MAX_PROVE_TXS_IN_FLIGHT="5"
+            ETH_SENDER_SENDER_FEE_BUMPING_MODE="Exponential"
+            ETH_SENDER_SENDER_FEE_BUMP_PERCENT="15"
             ETH_SENDER_SENDER_OPERATOR_PRIVATE_KEY="0x27593fea79697e947890ecbecce7901b0008345e5d7259710d0dd5e500d040be"
             ETH_SENDER_SENDER_PROOF_SENDING_MODE="SkipEveryProof"
             ETH_SENDER_GAS_ADJUSTER_DEFAULT_PRIORITY_FEE_PER_GAS="20000000000"
@@ -140,6 +188,12 @@ mod tests {
             ETH_WATCH_CONFIRMATIONS_FOR_ETH_EVENT="0"
             ETH_WATCH_ETH_NODE_POLL_INTERVAL="300"
             ETH_CLIENT_WEB3_URL="http://127.0.0.1:8545"
+            ETH_CLIENT_WEB3_FALLBACK_URLS="http://127.0.0.1:8546,http://127.0.0.1:8547"
+            ETH_CLIENT_CROSS_CHECK_BLOCK_HASHES="true"
+            ETH_SENDER_REMOTE_SIGNER_URL="http://127.0.0.1:9000"
+            ETH_SENDER_REMOTE_SIGNER_OPERATOR_ADDRESS="0x1111111111111111111111111111111111111111"
+            ETH_SENDER_REMOTE_SIGNER_PROVE_OPERATOR_ADDRESS="0x2222222222222222222222222222222222222222"
+            ETH_SENDER_REMOTE_SIGNER_REQUEST_TIMEOUT_MS="5000"
 
         "#;
         lock.set_env(config);
@@ -57,6 +57,8 @@ mod tests {
             },
             max_retries: 5,
             local_mirror_path: Some("/var/cache".to_owned()),
+            compression_level: None,
+            compressed_buckets: vec![],
         }
     }
 
@@ -92,6 +94,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn s3_config_from_env() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            OBJECT_STORE_MODE="S3WithCredentialFile"
+            OBJECT_STORE_BUCKET_BASE_URL="zksync-artifacts"
+            OBJECT_STORE_S3_REGION="eu-central-1"
+            OBJECT_STORE_S3_ENDPOINT="http://localhost:9000"
+            OBJECT_STORE_S3_CREDENTIAL_FILE_PATH="/path/to/credentials"
+        "#;
+        lock.set_env(config);
+        let actual = ObjectStoreConfig::from_env().unwrap();
+        assert_eq!(
+            actual.mode,
+            ObjectStoreMode::S3WithCredentialFile {
+                bucket_base_url: "zksync-artifacts".to_owned(),
+                s3_region: "eu-central-1".to_owned(),
+                s3_endpoint: Some("http://localhost:9000".to_owned()),
+                s3_credential_file_path: "/path/to/credentials".to_owned(),
+            }
+        );
+
+        lock.remove_env(&["OBJECT_STORE_S3_ENDPOINT"]);
+        let config = r#"
+            OBJECT_STORE_MODE="S3"
+            OBJECT_STORE_BUCKET_BASE_URL="zksync-artifacts"
+            OBJECT_STORE_S3_REGION="eu-central-1"
+        "#;
+        lock.set_env(config);
+        let actual = ObjectStoreConfig::from_env().unwrap();
+        assert_eq!(
+            actual.mode,
+            ObjectStoreMode::S3 {
+                bucket_base_url: "zksync-artifacts".to_owned(),
+                s3_region: "eu-central-1".to_owned(),
+                s3_endpoint: None,
+            }
+        );
+    }
+
+    #[test]
+    fn compression_config_from_env() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            OBJECT_STORE_MODE="FileBacked"
+            OBJECT_STORE_FILE_BACKED_BASE_PATH="artifacts"
+            OBJECT_STORE_COMPRESSION_LEVEL="3"
+            OBJECT_STORE_COMPRESSED_BUCKETS="witness_inputs,vm_dumps"
+        "#;
+        lock.set_env(config);
+        let actual = ObjectStoreConfig::from_env().unwrap();
+        assert_eq!(actual.compression_level, Some(3));
+        assert_eq!(actual.compressed_buckets, ["witness_inputs", "vm_dumps"]);
+    }
+
     #[test]
     fn public_bucket_config_from_env() {
         let mut lock = MUTEX.lock();
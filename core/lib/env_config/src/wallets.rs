@@ -25,6 +25,14 @@ impl FromEnv for Wallets {
             "ETH_SENDER_SENDER_OPERATOR_BLOBS_PRIVATE_KEY",
             "Malformed blob operator pk",
         )?;
+        let prove_operator = pk_from_env(
+            "ETH_SENDER_SENDER_OPERATOR_PROVE_PRIVATE_KEY",
+            "Malformed prove operator pk",
+        )?;
+        let execute_operator = pk_from_env(
+            "ETH_SENDER_SENDER_OPERATOR_EXECUTE_PRIVATE_KEY",
+            "Malformed execute operator pk",
+        )?;
 
         let eth_sender = if let Some(operator) = operator {
             let operator = Wallet::from_private_key_bytes(operator, None)?;
@@ -33,9 +41,17 @@ impl FromEnv for Wallets {
             } else {
                 None
             };
+            let prove_operator = prove_operator
+                .map(|pk| Wallet::from_private_key_bytes(pk, None))
+                .transpose()?;
+            let execute_operator = execute_operator
+                .map(|pk| Wallet::from_private_key_bytes(pk, None))
+                .transpose()?;
             Some(EthSender {
                 operator,
                 blob_operator,
+                prove_operator,
+                execute_operator,
             })
         } else {
             None
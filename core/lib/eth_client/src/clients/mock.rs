@@ -132,9 +132,15 @@ impl MockSettlementLayerInner {
         self.executed_txs.insert(tx_hash, status);
     }
 
-    fn get_transaction_count(&self, address: Address, block: web3::BlockNumber) -> U256 {
-        if address != MOCK_SENDER_ACCOUNT {
-            unimplemented!("Getting nonce for custom account is not supported");
+    fn get_transaction_count(
+        &self,
+        sender_account: Address,
+        address: Address,
+        block: web3::BlockNumber,
+    ) -> U256 {
+        if address != sender_account {
+            // Other accounts never send transactions via the mock.
+            return U256::zero();
         }
 
         match block {
@@ -235,6 +241,7 @@ pub struct MockSettlementLayerBuilder<Net: SupportedMockSLNetwork = L1> {
     /// If true, the mock will not check the ordering nonces of the transactions.
     /// This is useful for testing the cases when the transactions are executed out of order.
     non_ordering_confirmations: bool,
+    sender_account: Address,
     inner: Arc<RwLock<MockSettlementLayerInner>>,
     call_handler: Box<CallHandler>,
     _network: PhantomData<Net>,
@@ -251,6 +258,7 @@ impl<Net: SupportedMockSLNetwork> fmt::Debug for MockSettlementLayerBuilder<Net>
                 "non_ordering_confirmations",
                 &self.non_ordering_confirmations,
             )
+            .field("sender_account", &self.sender_account)
             .field("inner", &self.inner)
             .finish_non_exhaustive()
     }
@@ -263,6 +271,7 @@ impl<Net: SupportedMockSLNetwork> Default for MockSettlementLayerBuilder<Net> {
             max_priority_fee_per_gas: 10.into(),
             base_fee_history: vec![],
             non_ordering_confirmations: false,
+            sender_account: MOCK_SENDER_ACCOUNT,
             inner: Arc::default(),
             call_handler: Box::new(|call, block_id| {
                 panic!("Unexpected eth_call: {call:?}, {block_id:?}");
@@ -288,6 +297,15 @@ impl<Net: SupportedMockSLNetwork> MockSettlementLayerBuilder<Net> {
         }
     }
 
+    /// Sets the account used to sign transactions. Nonces are only tracked for this account;
+    /// nonces for other accounts are always zero.
+    pub fn with_sender_account(self, sender_account: Address) -> Self {
+        Self {
+            sender_account,
+            ..self
+        }
+    }
+
     /// Sets the `eth_call` handler. There are "standard" calls that will not be routed to the handler
     /// (e.g., calls to determine transaction failure reason).
     pub fn with_call_handler<F>(self, call_handler: F) -> Self
@@ -355,8 +373,10 @@ impl<Net: SupportedMockSLNetwork> MockSettlementLayerBuilder<Net> {
             })
             .method("eth_getTransactionCount", {
                 let inner = self.inner.clone();
+                let sender_account = self.sender_account;
                 move |address, block| {
-                    Ok(inner.read().unwrap().get_transaction_count(address, block))
+                    let inner = inner.read().unwrap();
+                    Ok(inner.get_transaction_count(sender_account, address, block))
                 }
             })
             .method("eth_gasPrice", move || Ok(self.max_fee_per_gas))
@@ -398,6 +418,7 @@ impl<Net: SupportedMockSLNetwork> MockSettlementLayerBuilder<Net> {
             max_fee_per_gas: self.max_fee_per_gas,
             max_priority_fee_per_gas: self.max_priority_fee_per_gas,
             non_ordering_confirmations: self.non_ordering_confirmations,
+            sender_account: self.sender_account,
             inner: self.inner.clone(),
             client: Net::build_client(self),
         }
@@ -493,6 +514,7 @@ pub struct MockSettlementLayer<Net: Network = L1> {
     max_fee_per_gas: U256,
     max_priority_fee_per_gas: U256,
     non_ordering_confirmations: bool,
+    sender_account: Address,
     inner: Arc<RwLock<MockSettlementLayerInner>>,
     client: MockClient<Net>,
 }
@@ -628,7 +650,7 @@ impl<Net: SupportedMockSLNetwork + SupportedMockSLNetwork> BoundEthInterface
     }
 
     fn sender_account(&self) -> Address {
-        MOCK_SENDER_ACCOUNT
+        self.sender_account
     }
 
     async fn sign_prepared_tx_for_addr(
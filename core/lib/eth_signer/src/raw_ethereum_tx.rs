@@ -9,12 +9,13 @@
 //! In the case where it will be possible to use only the web3 library without copy-paste, the changes will be small and simple
 //! Link to @Deniallugo's PR to web3: https://github.com/tomusdrw/rust-web3/pull/630
 
-use rlp::RlpStream;
+use rlp::{Rlp, RlpStream};
 use zksync_basic_types::{
+    u256_to_h256,
     web3::{keccak256, AccessList, Signature, SignedTransaction},
     Address, H256, U256, U64,
 };
-use zksync_crypto_primitives::K256PrivateKey;
+use zksync_crypto_primitives::{K256PrivateKey, PackedEthSignature};
 
 const LEGACY_TX_ID: u64 = 0;
 const ACCESSLISTS_TX_ID: u64 = 1;
@@ -71,6 +72,87 @@ pub struct Transaction {
 }
 
 impl Transaction {
+    /// Creates a transaction from the provided parameters.
+    pub(crate) fn new(raw_tx: TransactionParameters) -> Self {
+        // According to the code in web3 <https://docs.rs/web3/latest/src/web3/api/accounts.rs.html#86>
+        // We should use `max_fee_per_gas` as `gas_price` if we use EIP1559
+        Self {
+            to: raw_tx.to,
+            nonce: raw_tx.nonce,
+            gas: raw_tx.gas,
+            gas_price: raw_tx.max_fee_per_gas,
+            value: raw_tx.value,
+            data: raw_tx.data,
+            transaction_type: raw_tx.transaction_type,
+            access_list: raw_tx.access_list.unwrap_or_default(),
+            max_priority_fee_per_gas: raw_tx.max_priority_fee_per_gas,
+            max_fee_per_blob_gas: raw_tx.max_fee_per_blob_gas,
+            blob_versioned_hashes: raw_tx.blob_versioned_hashes,
+        }
+    }
+
+    fn is_legacy(&self) -> bool {
+        matches!(
+            self.transaction_type.map(|t| t.as_u64()),
+            Some(LEGACY_TX_ID) | None
+        )
+    }
+
+    /// Recovers the address that has signed this transaction for the specified chain. Returns an error
+    /// if `signed_tx` is not an encoding of this transaction (e.g., has another nonce or recipient),
+    /// or if the signature is invalid.
+    pub(crate) fn recover_signer(
+        &self,
+        chain_id: u64,
+        signed_tx: &[u8],
+    ) -> Result<Address, String> {
+        // Typed transactions are prefixed with their type byte; legacy transactions are RLP lists.
+        let payload = if self.is_legacy() {
+            signed_tx
+        } else {
+            signed_tx.get(1..).ok_or("transaction is empty")?
+        };
+        let rlp = Rlp::new(payload);
+        let item_count = rlp
+            .item_count()
+            .map_err(|err| format!("malformed transaction: {err}"))?;
+        if item_count < 3 {
+            return Err(format!("transaction has too few fields: {item_count}"));
+        }
+        let parse_err = |err: rlp::DecoderError| format!("malformed signature: {err}");
+        let v: u64 = rlp.val_at(item_count - 3).map_err(parse_err)?;
+        let r: U256 = rlp.val_at(item_count - 2).map_err(parse_err)?;
+        let s: U256 = rlp.val_at(item_count - 1).map_err(parse_err)?;
+        let signature = Signature {
+            v,
+            r: u256_to_h256(r),
+            s: u256_to_h256(s),
+        };
+
+        // Re-encoding the transaction with the parsed signature checks that all transaction fields match.
+        if self.encode(chain_id, Some(&signature)) != signed_tx {
+            return Err("signed transaction differs from the requested one".to_owned());
+        }
+
+        let recovery_id = if self.is_legacy() {
+            match PackedEthSignature::unpack_v(v) {
+                Ok((recovery_id, Some(signed_chain_id))) if signed_chain_id == chain_id => {
+                    recovery_id
+                }
+                _ => return Err(format!("unexpected `v` value {v} for chain {chain_id}")),
+            }
+        } else {
+            match v {
+                0 | 1 => v as u8,
+                _ => return Err(format!("unexpected `v` value {v}")),
+            }
+        };
+        let message_hash = H256(keccak256(&self.encode(chain_id, None)));
+        PackedEthSignature::from_rsv(&signature.r, &signature.s, recovery_id)
+            .signature_recover_signer(&message_hash)
+            .map_err(|err| format!("invalid signature: {err}"))
+    }
+
     fn rlp_append_legacy(&self, stream: &mut RlpStream) {
         stream.append(&self.nonce);
         stream.append(&self.gas_price);
@@ -242,10 +324,7 @@ impl Transaction {
 
     /// Sign and return a raw signed transaction.
     pub fn sign(self, private_key: &K256PrivateKey, chain_id: u64) -> SignedTransaction {
-        let adjust_v_value = matches!(
-            self.transaction_type.map(|t| t.as_u64()),
-            Some(LEGACY_TX_ID) | None
-        );
+        let adjust_v_value = self.is_legacy();
 
         let encoded = self.encode(chain_id, None);
         let message_hash = H256(keccak256(encoded.as_ref()));
@@ -0,0 +1,507 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use zksync_basic_types::{
+    url::SensitiveUrl,
+    web3::{AccessList, Bytes},
+    Address, H256, U256, U64,
+};
+use zksync_crypto_primitives::{
+    eip712_signature::utils::get_eip712_json, EIP712TypedStructure, Eip712Domain,
+    PackedEthSignature,
+};
+
+use crate::{
+    raw_ethereum_tx::{Transaction, TransactionParameters},
+    EthereumSigner, SignerError,
+};
+
+const EIP1559_TX_TYPE: u64 = 2;
+
+/// Transaction in the format accepted by the `eth_signTransaction` method.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RemoteTransaction {
+    from: Address,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    to: Option<Address>,
+    gas: U256,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gas_price: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_fee_per_gas: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_priority_fee_per_gas: Option<U256>,
+    value: U256,
+    data: Bytes,
+    nonce: U256,
+    chain_id: U64,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    transaction_type: Option<U64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    access_list: Option<AccessList>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_fee_per_blob_gas: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    blob_versioned_hashes: Option<Vec<H256>>,
+}
+
+impl RemoteTransaction {
+    fn new(from: Address, raw_tx: TransactionParameters) -> Self {
+        // Legacy and access list transactions only have a gas price. Consistently with `PrivateKeySigner`,
+        // we use `max_fee_per_gas` for it.
+        let is_dynamic_fee = raw_tx
+            .transaction_type
+            .is_some_and(|ty| ty.as_u64() >= EIP1559_TX_TYPE);
+        let (gas_price, max_fee_per_gas, max_priority_fee_per_gas) = if is_dynamic_fee {
+            (
+                None,
+                Some(raw_tx.max_fee_per_gas),
+                Some(raw_tx.max_priority_fee_per_gas),
+            )
+        } else {
+            (Some(raw_tx.max_fee_per_gas), None, None)
+        };
+
+        Self {
+            from,
+            to: raw_tx.to,
+            gas: raw_tx.gas,
+            gas_price,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            value: raw_tx.value,
+            data: raw_tx.data.into(),
+            nonce: raw_tx.nonce,
+            chain_id: raw_tx.chain_id.into(),
+            transaction_type: raw_tx.transaction_type,
+            access_list: raw_tx.access_list,
+            max_fee_per_blob_gas: raw_tx.max_fee_per_blob_gas,
+            blob_versioned_hashes: raw_tx.blob_versioned_hashes,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JsonRpcRequest<'a, P> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'a str,
+    params: P,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+/// Signer delegating signing to a remote service implementing the [web3signer] JSON-RPC API
+/// (`eth_signTransaction`, `eth_signTypedData` and `eth_accounts` methods).
+///
+/// Each signer instance is bound to a single account, which must be managed by the remote service.
+///
+/// [web3signer]: https://docs.web3signer.consensys.io/
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    url: SensitiveUrl,
+    address: Address,
+}
+
+impl RemoteSigner {
+    pub fn new(url: SensitiveUrl, address: Address, request_timeout: Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(request_timeout)
+            .build()
+            .expect("failed creating HTTP client");
+        Self {
+            client,
+            url,
+            address,
+        }
+    }
+
+    /// Gets the Ethereum address of the account used by this signer.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Checks that the remote signer is reachable and manages the account used by this signer.
+    pub async fn check_account(&self) -> Result<(), SignerError> {
+        let accounts: Vec<Address> = self.call("eth_accounts", [(); 0]).await?;
+        if !accounts.contains(&self.address) {
+            return Err(SignerError::SigningFailed(format!(
+                "remote signer does not manage account {:?}",
+                self.address
+            )));
+        }
+        Ok(())
+    }
+
+    async fn call<P: Serialize, T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: P,
+    ) -> Result<T, SignerError> {
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0",
+            id: 1,
+            method,
+            params,
+        };
+        let response = self
+            .client
+            .post(self.url.expose_url().clone())
+            .json(&request)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|err| SignerError::RemoteRequestFailed(format!("{method}: {err}")))?;
+        let response: JsonRpcResponse<T> = response
+            .json()
+            .await
+            .map_err(|err| SignerError::RemoteRequestFailed(format!("{method}: {err}")))?;
+
+        if let Some(err) = response.error {
+            return Err(SignerError::SigningFailed(format!(
+                "{method} returned error {}: {}",
+                err.code, err.message
+            )));
+        }
+        response.result.ok_or_else(|| {
+            SignerError::RemoteRequestFailed(format!("{method}: response has no result"))
+        })
+    }
+}
+
+#[async_trait]
+impl EthereumSigner for RemoteSigner {
+    async fn sign_typed_data<S: EIP712TypedStructure + Sync>(
+        &self,
+        domain: &Eip712Domain,
+        typed_struct: &S,
+    ) -> Result<PackedEthSignature, SignerError> {
+        let typed_data = get_eip712_json(domain, typed_struct);
+        let signature: Bytes = self
+            .call("eth_signTypedData", (self.address, typed_data))
+            .await?;
+        let signature = PackedEthSignature::deserialize_packed(&signature.0)
+            .map_err(|err| SignerError::SigningFailed(format!("malformed signature: {err}")))?;
+
+        // Check that the signer has signed the same data; this guards against discrepancies in encoding the typed data as JSON.
+        let signed_bytes = PackedEthSignature::typed_data_to_signed_bytes(domain, typed_struct);
+        let recovered_signer = signature
+            .signature_recover_signer(&signed_bytes)
+            .map_err(|err| SignerError::SigningFailed(format!("invalid signature: {err}")))?;
+        if recovered_signer != self.address {
+            return Err(SignerError::SigningFailed(format!(
+                "signature is produced by {recovered_signer:?} instead of expected {:?}",
+                self.address
+            )));
+        }
+        Ok(signature)
+    }
+
+    async fn sign_transaction(
+        &self,
+        raw_tx: TransactionParameters,
+    ) -> Result<Vec<u8>, SignerError> {
+        let chain_id = raw_tx.chain_id;
+        let expected_tx = Transaction::new(raw_tx.clone());
+        let tx = RemoteTransaction::new(self.address, raw_tx);
+        let signed_tx: Bytes = self.call("eth_signTransaction", [tx]).await?;
+        let signed_tx = signed_tx.0;
+
+        // Check that the signer has signed the requested transaction with the expected account.
+        let recovered_signer = expected_tx
+            .recover_signer(chain_id, &signed_tx)
+            .map_err(|err| {
+                SignerError::SigningFailed(format!(
+                    "remote signer returned invalid transaction: {err}"
+                ))
+            })?;
+        if recovered_signer != self.address {
+            return Err(SignerError::SigningFailed(format!(
+                "transaction is signed by {recovered_signer:?} instead of expected {:?}",
+                self.address
+            )));
+        }
+        Ok(signed_tx)
+    }
+
+    async fn get_address(&self) -> Result<Address, SignerError> {
+        Ok(self.address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::SocketAddr, sync::Arc};
+
+    use axum::{extract::State, routing::post, Json, Router};
+    use serde_json::{json, Value};
+    use zksync_basic_types::{L2ChainId, H160};
+    use zksync_crypto_primitives::K256PrivateKey;
+
+    use super::*;
+    use crate::PrivateKeySigner;
+
+    #[derive(Debug, Clone)]
+    struct Message {
+        text: String,
+        amount: U256,
+    }
+
+    impl EIP712TypedStructure for Message {
+        const TYPE_NAME: &'static str = "Message";
+
+        fn build_structure<B: zksync_crypto_primitives::StructBuilder>(&self, builder: &mut B) {
+            builder.add_member("text", &self.text);
+            builder.add_member("amount", &self.amount);
+        }
+    }
+
+    fn test_message() -> (Eip712Domain, Message) {
+        let domain = Eip712Domain::new(L2ChainId::default());
+        let message = Message {
+            text: "Hello".to_owned(),
+            amount: 123.into(),
+        };
+        (domain, message)
+    }
+
+    /// Mock signer backed by a local private key. Since the mock cannot restore a typed structure from JSON,
+    /// it signs the typed data only if it matches the expected one.
+    ///
+    /// To test misbehaving signers, the mock can report an account not matching the private key,
+    /// and modify transactions before signing them.
+    struct MockSigner {
+        signer: PrivateKeySigner,
+        account: Address,
+        modify_tx: fn(&mut TransactionParameters),
+        expected_typed_data: Value,
+        typed_data_signature: PackedEthSignature,
+    }
+
+    impl MockSigner {
+        fn handle(&self, method: &str, params: Value) -> Result<Value, String> {
+            Ok(match method {
+                "eth_accounts" => json!([self.account]),
+                "eth_signTypedData" => {
+                    let (address, typed_data): (Address, Value) =
+                        serde_json::from_value(params).map_err(|err| err.to_string())?;
+                    if address != self.account {
+                        return Err(format!("unknown account {address:?}"));
+                    }
+                    if typed_data != self.expected_typed_data {
+                        return Err(format!("unexpected typed data: {typed_data}"));
+                    }
+                    let signature = self.typed_data_signature.serialize_packed();
+                    json!(Bytes(signature.to_vec()))
+                }
+                "eth_signTransaction" => {
+                    let [tx]: [RemoteTransaction; 1] =
+                        serde_json::from_value(params).map_err(|err| err.to_string())?;
+                    if tx.from != self.account {
+                        return Err(format!("unknown account {:?}", tx.from));
+                    }
+                    let mut raw_tx = TransactionParameters {
+                        nonce: tx.nonce,
+                        to: tx.to,
+                        gas: tx.gas,
+                        gas_price: None,
+                        value: tx.value,
+                        data: tx.data.0,
+                        chain_id: tx.chain_id.as_u64(),
+                        transaction_type: tx.transaction_type,
+                        access_list: tx.access_list,
+                        max_fee_per_gas: tx.max_fee_per_gas.or(tx.gas_price).unwrap_or_default(),
+                        max_priority_fee_per_gas: tx.max_priority_fee_per_gas.unwrap_or_default(),
+                        max_fee_per_blob_gas: tx.max_fee_per_blob_gas,
+                        blob_versioned_hashes: tx.blob_versioned_hashes,
+                    };
+                    (self.modify_tx)(&mut raw_tx);
+                    json!(Bytes(self.signer.sign_transaction(raw_tx)))
+                }
+                _ => return Err(format!("unknown method {method}")),
+            })
+        }
+    }
+
+    async fn handle_request(
+        State(mock): State<Arc<MockSigner>>,
+        Json(request): Json<Value>,
+    ) -> Json<Value> {
+        let method = request["method"].as_str().unwrap_or_default();
+        let response = match mock.handle(method, request["params"].clone()) {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+            Err(message) => json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "error": { "code": -32000, "message": message },
+            }),
+        };
+        Json(response)
+    }
+
+    async fn start_mock_signer(private_key: K256PrivateKey) -> SocketAddr {
+        let account = private_key.address();
+        start_custom_mock_signer(private_key, account, |_| {}).await
+    }
+
+    async fn start_custom_mock_signer(
+        private_key: K256PrivateKey,
+        account: Address,
+        modify_tx: fn(&mut TransactionParameters),
+    ) -> SocketAddr {
+        let signer = PrivateKeySigner::new(private_key);
+        let (domain, message) = test_message();
+        let mock = MockSigner {
+            expected_typed_data: get_eip712_json(&domain, &message),
+            typed_data_signature: signer.sign_typed_data(&domain, &message).unwrap(),
+            signer,
+            account,
+            modify_tx,
+        };
+        let app = Router::new()
+            .route("/", post(handle_request))
+            .with_state(Arc::new(mock));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let local_addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        local_addr
+    }
+
+    fn test_private_key() -> K256PrivateKey {
+        K256PrivateKey::from_bytes(H256::repeat_byte(5)).unwrap()
+    }
+
+    fn create_signer(addr: SocketAddr, address: Address) -> RemoteSigner {
+        let url = format!("http://{addr}/").parse().unwrap();
+        RemoteSigner::new(url, address, Duration::from_secs(5))
+    }
+
+    fn test_transactions() -> Vec<TransactionParameters> {
+        let base_tx = TransactionParameters {
+            nonce: 3.into(),
+            to: Some(H160::repeat_byte(0x11)),
+            gas: 100_000.into(),
+            gas_price: None,
+            value: 1.into(),
+            data: vec![1, 2, 3],
+            chain_id: 9,
+            transaction_type: None,
+            access_list: None,
+            max_fee_per_gas: 2_000_000_000.into(),
+            max_priority_fee_per_gas: 1_000_000_000.into(),
+            max_fee_per_blob_gas: None,
+            blob_versioned_hashes: None,
+        };
+        vec![
+            base_tx.clone(),
+            TransactionParameters {
+                transaction_type: Some(1.into()),
+                ..base_tx.clone()
+            },
+            TransactionParameters {
+                transaction_type: Some(2.into()),
+                ..base_tx.clone()
+            },
+            TransactionParameters {
+                transaction_type: Some(3.into()),
+                max_fee_per_blob_gas: Some(5.into()),
+                blob_versioned_hashes: Some(vec![H256::repeat_byte(1), H256::repeat_byte(2)]),
+                ..base_tx
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn signing_transactions() {
+        let private_key = test_private_key();
+        let local_signer = PrivateKeySigner::new(private_key.clone());
+        let addr = start_mock_signer(private_key).await;
+        let signer = create_signer(addr, local_signer.address());
+        signer.check_account().await.unwrap();
+
+        for tx in test_transactions() {
+            let expected = local_signer.sign_transaction(tx.clone());
+            let signed = EthereumSigner::sign_transaction(&signer, tx.clone())
+                .await
+                .unwrap();
+            assert_eq!(signed, expected, "{tx:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn signing_typed_data() {
+        let private_key = test_private_key();
+        let local_signer = PrivateKeySigner::new(private_key.clone());
+        let addr = start_mock_signer(private_key).await;
+        let signer = create_signer(addr, local_signer.address());
+
+        let (domain, message) = test_message();
+        let signature = EthereumSigner::sign_typed_data(&signer, &domain, &message)
+            .await
+            .unwrap();
+        let expected = local_signer.sign_typed_data(&domain, &message).unwrap();
+        assert_eq!(signature, expected);
+    }
+
+    #[tokio::test]
+    async fn signing_with_unknown_account() {
+        let addr = start_mock_signer(test_private_key()).await;
+        let signer = create_signer(addr, Address::repeat_byte(0xff));
+
+        let err = signer.check_account().await.unwrap_err();
+        assert!(err.to_string().contains("does not manage"), "{err}");
+        let tx = test_transactions().pop().unwrap();
+        let err = EthereumSigner::sign_transaction(&signer, tx)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown account"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn signing_modified_transaction() {
+        let private_key = test_private_key();
+        let account = private_key.address();
+        let addr =
+            start_custom_mock_signer(private_key, account, |tx| tx.nonce += U256::one()).await;
+        let signer = create_signer(addr, account);
+
+        for tx in test_transactions() {
+            let err = EthereumSigner::sign_transaction(&signer, tx)
+                .await
+                .unwrap_err();
+            assert!(
+                err.to_string().contains("differs from the requested"),
+                "{err}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn signing_transaction_with_wrong_key() {
+        let account = test_private_key().address();
+        let other_key = K256PrivateKey::from_bytes(H256::repeat_byte(6)).unwrap();
+        let addr = start_custom_mock_signer(other_key, account, |_| {}).await;
+        let signer = create_signer(addr, account);
+
+        for tx in test_transactions() {
+            let err = EthereumSigner::sign_transaction(&signer, tx)
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("is signed by"), "{err}");
+        }
+    }
+}
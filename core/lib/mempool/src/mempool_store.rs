@@ -1,11 +1,19 @@
 use std::collections::{hash_map, BTreeSet, HashMap};
 
+use zksync_config::configs::chain::MempoolOrdering;
 use zksync_types::{
     l1::L1Tx, l2::L2Tx, Address, ExecuteTransactionCommon, Nonce, PriorityOpId, Transaction,
-    TransactionTimeRangeConstraint,
+    TransactionTimeRangeConstraint, H256,
 };
 
-use crate::types::{AccountTransactions, L2TxFilter, MempoolScore};
+use crate::types::{AccountLimits, AccountTransactions, L2TxFilter, MempoolScore};
+
+/// L2 transaction rejected by the mempool because it violates per-account limits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedTransaction {
+    pub hash: H256,
+    pub reason: String,
+}
 
 #[derive(Debug)]
 pub struct MempoolInfo {
@@ -34,6 +42,9 @@ pub struct MempoolStore {
     /// Number of L2 transactions in the mempool.
     size: u64,
     capacity: u64,
+    /// Ordering of L2 transactions in `l2_priority_queue`.
+    ordering: MempoolOrdering,
+    account_limits: AccountLimits,
 }
 
 impl MempoolStore {
@@ -46,18 +57,43 @@ impl MempoolStore {
             stashed_accounts: vec![],
             size: 0,
             capacity,
+            ordering: MempoolOrdering::default(),
+            account_limits: AccountLimits::default(),
         }
     }
 
+    /// Sets the ordering of L2 transactions. Must be called before any transactions are inserted.
+    #[must_use]
+    pub fn with_ordering(mut self, ordering: MempoolOrdering) -> Self {
+        assert!(
+            self.l2_transactions_per_account.is_empty(),
+            "ordering must be set for an empty mempool"
+        );
+        self.ordering = ordering;
+        self
+    }
+
+    /// Limits the number of L2 transactions per account. Transactions exceeding the limit are not inserted
+    /// and are returned from [`Self::insert()`], unless they have the next expected nonce for the account.
+    #[must_use]
+    pub fn with_max_pending_txs_per_account(mut self, limit: usize) -> Self {
+        self.account_limits.max_transactions = Some(limit);
+        self
+    }
+
     /// Inserts batch of new transactions to mempool
     /// `initial_nonces` provides current committed nonce information to mempool
     /// variable is used only if account is not present in mempool yet and we have to bootstrap it
-    /// in other cases mempool relies on state keeper and its internal state to keep that info up to date
+    /// in other cases mempool relies on state keeper and its internal state to keep that info up to date.
+    ///
+    /// Returns L2 transactions that were not inserted because they violate per-account limits. The caller
+    /// is responsible for marking these transactions as rejected in the storage.
     pub fn insert(
         &mut self,
         transactions: Vec<(Transaction, TransactionTimeRangeConstraint)>,
         initial_nonces: HashMap<Address, Nonce>,
-    ) {
+    ) -> Vec<RejectedTransaction> {
+        let mut rejected = vec![];
         for (transaction, constraint) in transactions {
             let Transaction {
                 common_data,
@@ -79,22 +115,25 @@ impl MempoolStore {
                 }
                 ExecuteTransactionCommon::L2(data) => {
                     tracing::trace!("inserting L2 transaction {}", data.nonce);
-                    self.insert_l2_transaction(
-                        L2Tx {
-                            execute,
-                            common_data: data,
-                            received_timestamp_ms,
-                            raw_bytes,
-                        },
-                        constraint,
-                        &initial_nonces,
-                    );
+                    let tx = L2Tx {
+                        execute,
+                        common_data: data,
+                        received_timestamp_ms,
+                        raw_bytes,
+                    };
+                    let hash = tx.hash();
+                    if let Some(reason) =
+                        self.insert_l2_transaction(tx, constraint, &initial_nonces)
+                    {
+                        rejected.push(RejectedTransaction { hash, reason });
+                    }
                 }
                 ExecuteTransactionCommon::ProtocolUpgrade(_) => {
                     panic!("Protocol upgrade tx is not supposed to be inserted into mempool");
                 }
             }
         }
+        rejected
     }
 
     #[cfg(test)]
@@ -102,31 +141,35 @@ impl MempoolStore {
         &mut self,
         transactions: Vec<Transaction>,
         initial_nonces: HashMap<Address, Nonce>,
-    ) {
+    ) -> Vec<RejectedTransaction> {
         self.insert(
             transactions
                 .into_iter()
                 .map(|x| (x, TransactionTimeRangeConstraint::default()))
                 .collect(),
             initial_nonces,
-        );
+        )
     }
 
+    /// Returns the rejection reason if the transaction was not inserted because of account limits.
     fn insert_l2_transaction(
         &mut self,
         transaction: L2Tx,
         constraint: TransactionTimeRangeConstraint,
         initial_nonces: &HashMap<Address, Nonce>,
-    ) {
+    ) -> Option<String> {
         let account = transaction.initiator_account();
 
         let metadata = match self.l2_transactions_per_account.entry(account) {
-            hash_map::Entry::Occupied(mut txs) => txs.get_mut().insert(transaction, constraint),
+            hash_map::Entry::Occupied(mut txs) => {
+                txs.get_mut()
+                    .insert(transaction, constraint, self.account_limits)
+            }
             hash_map::Entry::Vacant(entry) => {
                 let account_nonce = initial_nonces.get(&account).cloned().unwrap_or(Nonce(0));
                 entry
-                    .insert(AccountTransactions::new(account_nonce))
-                    .insert(transaction, constraint)
+                    .insert(AccountTransactions::new(account_nonce, self.ordering))
+                    .insert(transaction, constraint, self.account_limits)
             }
         };
         if let Some(score) = metadata.previous_score {
@@ -138,6 +181,7 @@ impl MempoolStore {
         if metadata.is_new {
             self.size += 1;
         }
+        metadata.rejection_reason
     }
 
     /// Returns `true` if there is a transaction in the mempool satisfying the filter.
@@ -3,6 +3,8 @@ use std::{
     iter::FromIterator,
 };
 
+use test_casing::test_casing;
+use zksync_config::configs::chain::MempoolOrdering;
 use zksync_types::{
     fee::Fee,
     helpers::unix_timestamp_ms,
@@ -14,9 +16,12 @@ use zksync_types::{
 
 use crate::{mempool_store::MempoolStore, types::L2TxFilter};
 
+const ORDERINGS: [MempoolOrdering; 2] = [MempoolOrdering::Fifo, MempoolOrdering::FeePriority];
+
+#[test_casing(2, ORDERINGS)]
 #[test]
-fn basic_flow() {
-    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+fn basic_flow(ordering: MempoolOrdering) {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100).with_ordering(ordering);
     let account0 = Address::random();
     let account1 = Address::random();
     let transactions = vec![
@@ -61,9 +66,10 @@ fn basic_flow() {
     assert_eq!(mempool.next_transaction(&L2TxFilter::default()), None);
 }
 
+#[test_casing(2, ORDERINGS)]
 #[test]
-fn missing_txns() {
-    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+fn missing_txns(ordering: MempoolOrdering) {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100).with_ordering(ordering);
     let account = Address::random();
     let transactions = vec![
         gen_l2_tx(account, Nonce(6)),
@@ -144,9 +150,10 @@ fn l1_txns_priority_id() {
     }
 }
 
+#[test_casing(2, ORDERINGS)]
 #[test]
-fn rejected_tx() {
-    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+fn rejected_tx(ordering: MempoolOrdering) {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100).with_ordering(ordering);
     let account = Address::random();
     let transactions = vec![
         gen_l2_tx(account, Nonce(0)),
@@ -184,9 +191,10 @@ fn rejected_tx() {
     );
 }
 
+#[test_casing(2, ORDERINGS)]
 #[test]
-fn replace_tx() {
-    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+fn replace_tx(ordering: MempoolOrdering) {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100).with_ordering(ordering);
     let account = Address::random();
     mempool.insert_without_constraints(vec![gen_l2_tx(account, Nonce(0))], HashMap::new());
     // replace it
@@ -202,9 +210,10 @@ fn replace_tx() {
     assert!(mempool.next_transaction(&L2TxFilter::default()).is_none());
 }
 
+#[test_casing(2, ORDERINGS)]
 #[test]
-fn two_ready_txs() {
-    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+fn two_ready_txs(ordering: MempoolOrdering) {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100).with_ordering(ordering);
     let account0 = Address::random();
     let account1 = Address::random();
     let transactions = vec![gen_l2_tx(account0, Nonce(0)), gen_l2_tx(account1, Nonce(0))];
@@ -218,9 +227,10 @@ fn two_ready_txs() {
     );
 }
 
+#[test_casing(2, ORDERINGS)]
 #[test]
-fn mempool_size() {
-    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+fn mempool_size(ordering: MempoolOrdering) {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100).with_ordering(ordering);
     let account0 = Address::random();
     let account1 = Address::random();
     let transactions = vec![
@@ -242,8 +252,9 @@ fn mempool_size() {
 }
 
 /// Checks whether filtering transactions based on their fee works as expected.
+#[test_casing(2, ORDERINGS)]
 #[test]
-fn filtering() {
+fn filtering(ordering: MempoolOrdering) {
     // Filter to find transactions with non-zero `gas_per_pubdata` values.
     let filter_non_zero = L2TxFilter {
         fee_input: Default::default(),
@@ -257,7 +268,7 @@ fn filtering() {
         gas_per_pubdata: 0u32,
     };
 
-    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100).with_ordering(ordering);
     let account0 = Address::random();
     let account1 = Address::random();
 
@@ -287,8 +298,9 @@ fn filtering() {
     assert_eq!(mempool.next_transaction(&filter_zero), None);
 }
 
+#[test_casing(2, ORDERINGS)]
 #[test]
-fn stashed_accounts() {
+fn stashed_accounts(ordering: MempoolOrdering) {
     let filter_non_zero = L2TxFilter {
         fee_input: Default::default(),
         fee_per_gas: 0u64,
@@ -300,7 +312,7 @@ fn stashed_accounts() {
         fee_per_gas: 0u64,
         gas_per_pubdata: 0u32,
     };
-    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100).with_ordering(ordering);
     let account0 = Address::random();
     let account1 = Address::random();
 
@@ -321,9 +333,10 @@ fn stashed_accounts() {
     assert!(mempool.next_transaction(&filter_zero).is_none());
 }
 
+#[test_casing(2, ORDERINGS)]
 #[test]
-fn mempool_capacity() {
-    let mut mempool = MempoolStore::new(PriorityOpId(0), 4);
+fn mempool_capacity(ordering: MempoolOrdering) {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 4).with_ordering(ordering);
     let account0 = Address::random();
     let account1 = Address::random();
     let account2 = Address::random();
@@ -364,9 +377,10 @@ fn mempool_capacity() {
     assert!(!mempool.has_next(&L2TxFilter::default()));
 }
 
+#[test_casing(2, ORDERINGS)]
 #[test]
-fn mempool_does_not_purge_all_accounts() {
-    let mut mempool = MempoolStore::new(PriorityOpId(0), 1);
+fn mempool_does_not_purge_all_accounts(ordering: MempoolOrdering) {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 1).with_ordering(ordering);
     let account0 = Address::random();
     let account1 = Address::random();
     let transactions = vec![
@@ -392,6 +406,227 @@ fn mempool_does_not_purge_all_accounts() {
     assert!(!mempool.has_next(&L2TxFilter::default()));
 }
 
+#[test]
+fn fee_priority_ordering() {
+    let mut mempool =
+        MempoolStore::new(PriorityOpId(0), 100).with_ordering(MempoolOrdering::FeePriority);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let account2 = Address::random();
+    let account3 = Address::random();
+    let now = unix_timestamp_ms();
+    let transactions = vec![
+        gen_l2_tx_with_fee(account0, Nonce(0), now, 100),
+        gen_l2_tx_with_fee(account1, Nonce(0), now + 1, 300),
+        gen_l2_tx_with_fee(account2, Nonce(0), now + 2, 200),
+        // Same fee as `account2`, but received later
+        gen_l2_tx_with_fee(account3, Nonce(0), now + 3, 200),
+    ];
+    mempool.insert_without_constraints(transactions, HashMap::new());
+
+    for expected_account in [account1, account2, account3, account0] {
+        assert_eq!(
+            view(mempool.next_transaction(&L2TxFilter::default())),
+            (expected_account, 0)
+        );
+    }
+    assert_eq!(mempool.next_transaction(&L2TxFilter::default()), None);
+}
+
+#[test]
+fn fifo_ordering_ignores_fees() {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let now = unix_timestamp_ms();
+    let transactions = vec![
+        gen_l2_tx_with_fee(account0, Nonce(0), now, 100),
+        gen_l2_tx_with_fee(account1, Nonce(0), now + 1, 300),
+    ];
+    mempool.insert_without_constraints(transactions, HashMap::new());
+
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 0)
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account1, 0)
+    );
+}
+
+#[test]
+fn fee_priority_ordering_respects_nonces() {
+    let mut mempool =
+        MempoolStore::new(PriorityOpId(0), 100).with_ordering(MempoolOrdering::FeePriority);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let now = unix_timestamp_ms();
+    let transactions = vec![
+        gen_l2_tx_with_fee(account0, Nonce(0), now, 100),
+        // Has the highest fee, but cannot be executed before the previous transaction of the same account
+        gen_l2_tx_with_fee(account0, Nonce(1), now, 1_000),
+        gen_l2_tx_with_fee(account1, Nonce(0), now, 200),
+    ];
+    mempool.insert_without_constraints(transactions, HashMap::new());
+
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account1, 0)
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 0)
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 1)
+    );
+}
+
+#[test]
+fn fee_priority_ordering_with_rollback() {
+    let mut mempool =
+        MempoolStore::new(PriorityOpId(0), 100).with_ordering(MempoolOrdering::FeePriority);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let now = unix_timestamp_ms();
+    let transactions = vec![
+        gen_l2_tx_with_fee(account0, Nonce(0), now, 300),
+        gen_l2_tx_with_fee(account0, Nonce(1), now, 100),
+        gen_l2_tx_with_fee(account1, Nonce(0), now, 200),
+    ];
+    mempool.insert_without_constraints(transactions, HashMap::new());
+
+    let (tx, _) = mempool.next_transaction(&L2TxFilter::default()).unwrap();
+    assert_eq!(tx.initiator_account(), account0);
+    mempool.rollback(&tx);
+    // Transaction should be reinserted by the state keeper after a rollback
+    mempool.insert_without_constraints(vec![tx], HashMap::new());
+
+    for (expected_account, expected_nonce) in [(account0, 0), (account1, 0), (account0, 1)] {
+        assert_eq!(
+            view(mempool.next_transaction(&L2TxFilter::default())),
+            (expected_account, expected_nonce)
+        );
+    }
+    assert_eq!(mempool.stats().l2_priority_queue_size, 0);
+}
+
+#[test]
+fn fee_priority_ordering_with_filter() {
+    let filter = L2TxFilter {
+        fee_input: Default::default(),
+        fee_per_gas: 150,
+        gas_per_pubdata: 0,
+    };
+    let mut mempool =
+        MempoolStore::new(PriorityOpId(0), 100).with_ordering(MempoolOrdering::FeePriority);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let now = unix_timestamp_ms();
+    let transactions = vec![
+        gen_l2_tx_with_fee(account0, Nonce(0), now, 100),
+        gen_l2_tx_with_fee(account1, Nonce(0), now + 1, 200),
+    ];
+    mempool.insert_without_constraints(transactions, HashMap::new());
+
+    assert_eq!(view(mempool.next_transaction(&filter)), (account1, 0));
+    assert_eq!(mempool.next_transaction(&filter), None);
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 0)
+    );
+}
+
+#[test_casing(2, ORDERINGS)]
+#[test]
+fn replacing_transactions_by_initiator_and_nonce(ordering: MempoolOrdering) {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100)
+        .with_ordering(ordering)
+        .with_max_pending_txs_per_account(2);
+    let account = Address::random();
+    let now = unix_timestamp_ms();
+    mempool.insert_without_constraints(
+        vec![
+            gen_l2_tx_with_fee(account, Nonce(0), now, 100),
+            gen_l2_tx_with_fee(account, Nonce(1), now, 100),
+        ],
+        HashMap::new(),
+    );
+
+    // Replacements are accepted regardless of fees and the account limit, since the DB decides
+    // which transaction with the same initiator and nonce wins.
+    mempool.insert_without_constraints(
+        vec![
+            gen_l2_tx_with_fee(account, Nonce(0), now, 50),
+            gen_l2_tx_with_fee(account, Nonce(1), now, 200),
+        ],
+        HashMap::new(),
+    );
+    assert_eq!(mempool.stats().l2_transaction_count, 2);
+    assert_eq!(mempool.stats().l2_priority_queue_size, 1);
+
+    let (tx, _) = mempool.next_transaction(&L2TxFilter::default()).unwrap();
+    assert_eq!(tx.nonce(), Some(Nonce(0)));
+    assert_eq!(tx.max_fee_per_gas(), 50.into());
+    let (tx, _) = mempool.next_transaction(&L2TxFilter::default()).unwrap();
+    assert_eq!(tx.nonce(), Some(Nonce(1)));
+    assert_eq!(tx.max_fee_per_gas(), 200.into());
+    assert!(!mempool.has_next(&L2TxFilter::default()));
+}
+
+#[test_casing(2, ORDERINGS)]
+#[test]
+fn max_pending_txs_per_account(ordering: MempoolOrdering) {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100)
+        .with_ordering(ordering)
+        .with_max_pending_txs_per_account(2);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let transactions = vec![
+        gen_l2_tx(account0, Nonce(0)),
+        gen_l2_tx(account0, Nonce(1)),
+        gen_l2_tx(account0, Nonce(2)),
+        gen_l2_tx(account1, Nonce(1)),
+        gen_l2_tx(account1, Nonce(2)),
+        gen_l2_tx(account1, Nonce(3)),
+    ];
+    let expected_rejected = [transactions[2].hash(), transactions[5].hash()];
+    let rejected = mempool.insert_without_constraints(transactions, HashMap::new());
+    assert_eq!(mempool.stats().l2_transaction_count, 4);
+    let rejected_hashes: Vec<_> = rejected.iter().map(|tx| tx.hash).collect();
+    assert_eq!(rejected_hashes, expected_rejected);
+    assert!(rejected[0].reason.contains("limit of 2"), "{rejected:?}");
+
+    // Replacing transactions doesn't count towards the limit.
+    mempool.insert_without_constraints(vec![gen_l2_tx(account0, Nonce(1))], HashMap::new());
+    assert_eq!(mempool.stats().l2_transaction_count, 4);
+    // A transaction with the next expected nonce is accepted even if the limit is reached.
+    mempool.insert_without_constraints(vec![gen_l2_tx(account1, Nonce(0))], HashMap::new());
+    assert_eq!(mempool.stats().l2_transaction_count, 5);
+
+    let mut executed = HashSet::new();
+    while let Some(tx) = mempool.next_transaction(&L2TxFilter::default()) {
+        executed.insert(view(Some(tx)));
+    }
+    let expected = HashSet::from([
+        (account0, 0),
+        (account0, 1),
+        (account1, 0),
+        (account1, 1),
+        (account1, 2),
+    ]);
+    assert_eq!(executed, expected);
+
+    // Once transactions are executed, the account can submit new ones.
+    mempool.insert_without_constraints(vec![gen_l2_tx(account0, Nonce(2))], HashMap::new());
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 2)
+    );
+}
+
 fn gen_l2_tx(address: Address, nonce: Nonce) -> Transaction {
     gen_l2_tx_with_timestamp(address, nonce, unix_timestamp_ms())
 }
@@ -411,6 +646,20 @@ fn gen_l2_tx_with_timestamp(address: Address, nonce: Nonce, received_at_ms: u64)
     txn.into()
 }
 
+fn gen_l2_tx_with_fee(
+    address: Address,
+    nonce: Nonce,
+    received_at_ms: u64,
+    max_fee_per_gas: u64,
+) -> Transaction {
+    let mut tx = gen_l2_tx_with_timestamp(address, nonce, received_at_ms);
+    match &mut tx.common_data {
+        ExecuteTransactionCommon::L2(data) => data.fee.max_fee_per_gas = max_fee_per_gas.into(),
+        _ => unreachable!(),
+    }
+    tx
+}
+
 fn gen_l1_tx(priority_id: PriorityOpId) -> Transaction {
     let execute = Execute {
         contract_address: Some(Address::repeat_byte(0x11)),
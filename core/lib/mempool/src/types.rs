@@ -1,5 +1,6 @@
 use std::{cmp::Ordering, collections::HashMap};
 
+use zksync_config::configs::chain::MempoolOrdering;
 use zksync_types::{
     fee::Fee, fee_model::BatchFeeInput, l2::L2Tx, Address, Nonce, Transaction,
     TransactionTimeRangeConstraint, U256,
@@ -13,13 +14,16 @@ pub(crate) struct AccountTransactions {
     /// account nonce in mempool
     /// equals to committed nonce in db + number of transactions sent to state keeper
     nonce: Nonce,
+    /// ordering used to compute transaction scores
+    ordering: MempoolOrdering,
 }
 
 impl AccountTransactions {
-    pub fn new(nonce: Nonce) -> Self {
+    pub fn new(nonce: Nonce, ordering: MempoolOrdering) -> Self {
         Self {
             transactions: HashMap::new(),
             nonce,
+            ordering,
         }
     }
 
@@ -28,6 +32,7 @@ impl AccountTransactions {
         &mut self,
         transaction: L2Tx,
         constraint: TransactionTimeRangeConstraint,
+        limits: AccountLimits,
     ) -> InsertionMetadata {
         let mut metadata = InsertionMetadata::default();
         let nonce = transaction.common_data.nonce;
@@ -35,11 +40,20 @@ impl AccountTransactions {
         if nonce < self.nonce {
             return metadata;
         }
-        let new_score = Self::score_for_transaction(&transaction);
+        if let Some(reason) = self.check_limits(&transaction, limits) {
+            tracing::debug!(
+                "rejecting L2 transaction {:?} from {:?} with nonce {nonce}: {reason}",
+                transaction.hash(),
+                transaction.initiator_account()
+            );
+            metadata.rejection_reason = Some(reason);
+            return metadata;
+        }
+        let new_score = self.score_for_transaction(&transaction);
         let previous_score = self
             .transactions
             .insert(nonce, (transaction, constraint))
-            .map(|x| Self::score_for_transaction(&x.0));
+            .map(|x| self.score_for_transaction(&x.0));
         metadata.is_new = previous_score.is_none();
         if nonce == self.nonce {
             metadata.new_score = Some(new_score);
@@ -59,7 +73,7 @@ impl AccountTransactions {
         let score = self
             .transactions
             .get(&self.nonce)
-            .map(|(tx, _c)| Self::score_for_transaction(tx));
+            .map(|(tx, _c)| self.score_for_transaction(tx));
         (transaction.0, transaction.1, score)
     }
 
@@ -76,28 +90,58 @@ impl AccountTransactions {
         self.nonce = self.nonce.min(tx_nonce);
         self.transactions
             .get(&(tx_nonce + 1))
-            .map(|(tx, c)| (Self::score_for_transaction(tx), c.clone()))
+            .map(|(tx, c)| (self.score_for_transaction(tx), c.clone()))
     }
 
     pub fn len(&self) -> usize {
         self.transactions.len()
     }
 
-    fn score_for_transaction(transaction: &L2Tx) -> MempoolScore {
+    /// Returns the reason to reject the transaction if it violates account limits.
+    ///
+    /// A transaction with the same nonce as an existing one always replaces it. Replacement fee requirements
+    /// are enforced when the transaction is persisted to the DB, so the mempool follows the DB as the source of truth;
+    /// otherwise, the DB and the mempool could disagree on which transaction with the nonce will be executed.
+    fn check_limits(&self, transaction: &L2Tx, limits: AccountLimits) -> Option<String> {
+        let nonce = transaction.common_data.nonce;
+        if self.transactions.contains_key(&nonce) {
+            return None;
+        }
+        if let Some(max_transactions) = limits.max_transactions {
+            // The transaction with the next expected nonce is always accepted; otherwise, the account
+            // could get stuck with a full set of transactions that cannot be executed because of a nonce gap.
+            if nonce != self.nonce && self.transactions.len() >= max_transactions {
+                return Some(format!(
+                    "account has reached the limit of {max_transactions} pending transactions"
+                ));
+            }
+        }
+        None
+    }
+
+    fn score_for_transaction(&self, transaction: &L2Tx) -> MempoolScore {
+        let fee_data = transaction.common_data.fee.clone();
+        let priority = match self.ordering {
+            MempoolOrdering::Fifo => U256::zero(),
+            MempoolOrdering::FeePriority => fee_data.max_fee_per_gas,
+        };
         MempoolScore {
             account: transaction.initiator_account(),
             received_at_ms: transaction.received_timestamp_ms,
-            fee_data: transaction.common_data.fee.clone(),
+            priority,
+            fee_data,
         }
     }
 }
 
-/// Mempool score of transaction. Used to prioritize L2 transactions in mempool
-/// Currently trivial ordering is used based on received at timestamp
+/// Mempool score of transaction. Used to prioritize L2 transactions in mempool.
+/// Transactions are ordered by priority (higher goes first), then by received at timestamp (earlier goes first).
 #[derive(Eq, PartialEq, Clone, Debug, Hash)]
 pub struct MempoolScore {
     pub account: Address,
     pub received_at_ms: u64,
+    /// Priority of the transaction according to the [`MempoolOrdering`]. Always zero for FIFO ordering.
+    pub priority: U256,
     // Not used for actual scoring, but state keeper would request
     // transactions that have acceptable fee values (so transactions
     // with fee too low would be ignored until prices go down).
@@ -114,6 +158,10 @@ impl MempoolScore {
 
 impl Ord for MempoolScore {
     fn cmp(&self, other: &MempoolScore) -> Ordering {
+        match self.priority.cmp(&other.priority) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
         match self.received_at_ms.cmp(&other.received_at_ms).reverse() {
             Ordering::Equal => {}
             ordering => return ordering,
@@ -128,11 +176,20 @@ impl PartialOrd for MempoolScore {
     }
 }
 
+/// Limits applied to L2 transactions of a single account in the mempool.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct AccountLimits {
+    /// Maximum number of transactions per account.
+    pub max_transactions: Option<usize>,
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct InsertionMetadata {
     pub new_score: Option<MempoolScore>,
     pub previous_score: Option<MempoolScore>,
     pub is_new: bool,
+    /// Set if the transaction was rejected because of account limits.
+    pub rejection_reason: Option<String>,
 }
 
 /// Structure that can be used by state keeper to describe
@@ -169,6 +226,7 @@ mod tests {
         let score = MempoolScore {
             account: Address::random(),
             received_at_ms: Default::default(), // Not important
+            priority: Default::default(),       // Not important
             fee_data: Fee {
                 gas_limit: Default::default(), // Not important
                 max_fee_per_gas: U256::from(MAX_FEE_PER_GAS),
@@ -0,0 +1,391 @@
+//! Diff between two versions of the Merkle tree.
+
+use std::fmt;
+
+use crate::{
+    types::{ChildRef, Nibbles, Node, NodeKey, Root, TreeEntryDiff},
+    Database, HashTree, Key, MerkleTree, NoVersionError, TreeEntry,
+};
+
+/// Error that can occur when computing a [`TreeDiff`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum TreeDiffError {
+    /// One of the compared tree versions does not exist or was pruned.
+    #[error(transparent)]
+    NoVersion(#[from] NoVersionError),
+    /// A tree node is missing. This can happen if a compared tree version is pruned while the diff is being computed.
+    #[error(
+        "missing {node_str} at {key}; the tree version was probably pruned",
+        node_str = if *is_leaf { "leaf" } else { "internal node" }
+    )]
+    MissingNode {
+        /// Key of the missing node.
+        key: NodeKey,
+        /// Whether the missing node is a leaf.
+        is_leaf: bool,
+    },
+}
+
+/// Tree node in one of the compared tree versions that is not yet visited by [`TreeDiff`].
+#[derive(Debug)]
+enum PendingNode {
+    Empty,
+    Loaded(Node),
+    Ref(ChildRef),
+}
+
+#[derive(Debug)]
+struct PendingNodes {
+    nibbles: Nibbles,
+    old: PendingNode,
+    new: PendingNode,
+}
+
+/// Iterator over entries that differ between two versions of a [`MerkleTree`] returned by [`MerkleTree::diff()`].
+///
+/// Entries are yielded in the increasing key order. The iterator walks both trees simultaneously and skips
+/// subtrees with matching hashes, so its performance is proportional to the number of changed entries
+/// rather than to the tree size.
+pub struct TreeDiff<'a, DB: ?Sized> {
+    db: &'a DB,
+    old_version: u64,
+    new_version: u64,
+    start_key: Key,
+    stack: Vec<PendingNodes>,
+}
+
+impl<DB: ?Sized> fmt::Debug for TreeDiff<'_, DB> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("TreeDiff")
+            .field("old_version", &self.old_version)
+            .field("new_version", &self.new_version)
+            .field("start_key", &self.start_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, DB: Database + ?Sized> TreeDiff<'a, DB> {
+    fn new(db: &'a DB, old_version: u64, new_version: u64) -> Result<Self, NoVersionError> {
+        let old_root = Self::load_root(db, old_version)?;
+        let new_root = Self::load_root(db, new_version)?;
+        Ok(Self {
+            db,
+            old_version,
+            new_version,
+            start_key: Key::zero(),
+            stack: vec![PendingNodes {
+                nibbles: Nibbles::EMPTY,
+                old: old_root,
+                new: new_root,
+            }],
+        })
+    }
+
+    fn load_root(db: &DB, version: u64) -> Result<PendingNode, NoVersionError> {
+        let root = db.root(version).ok_or_else(|| {
+            let manifest = db.manifest().unwrap_or_default();
+            NoVersionError {
+                missing_version: version,
+                version_count: manifest.version_count,
+            }
+        })?;
+        Ok(match root {
+            Root::Empty => PendingNode::Empty,
+            Root::Filled { node, .. } => PendingNode::Loaded(node),
+        })
+    }
+
+    /// Skips all entries with keys less than `start_key`. This is useful to paginate diffs.
+    #[must_use]
+    pub fn starting_from(mut self, start_key: Key) -> Self {
+        self.start_key = start_key;
+        self
+    }
+
+    fn load_node(
+        &self,
+        nibbles: Nibbles,
+        node: PendingNode,
+    ) -> Result<Option<Node>, TreeDiffError> {
+        Ok(match node {
+            PendingNode::Empty => None,
+            PendingNode::Loaded(node) => Some(node),
+            PendingNode::Ref(child_ref) => {
+                let key = nibbles.with_version(child_ref.version);
+                let node = self.db.tree_node(&key, child_ref.is_leaf).ok_or(
+                    TreeDiffError::MissingNode {
+                        key,
+                        is_leaf: child_ref.is_leaf,
+                    },
+                )?;
+                Some(node)
+            }
+        })
+    }
+
+    /// Splits a node into 16 child nodes on the next tree level. A leaf is moved to the child
+    /// corresponding to its key.
+    fn split(node: Option<Node>, nibble_count: usize) -> [PendingNode; 16] {
+        let mut children = [(); 16].map(|()| PendingNode::Empty);
+        match node {
+            None => { /* all children are empty */ }
+            Some(Node::Leaf(leaf)) => {
+                let nibble = Nibbles::nibble(&leaf.full_key, nibble_count);
+                children[usize::from(nibble)] = PendingNode::Loaded(Node::Leaf(leaf));
+            }
+            Some(Node::Internal(node)) => {
+                for (nibble, child_ref) in node.children() {
+                    children[usize::from(nibble)] = PendingNode::Ref(*child_ref);
+                }
+            }
+        }
+        children
+    }
+
+    /// Checks whether the subtree with the specified `nibbles` contains only keys less than `start_key`.
+    fn is_before_start(&self, nibbles: &Nibbles) -> bool {
+        let start_nibbles = Nibbles::new(&self.start_key, nibbles.nibble_count());
+        nibbles.bytes() < start_nibbles.bytes()
+    }
+
+    fn next_diff(&mut self) -> Result<Option<TreeEntryDiff>, TreeDiffError> {
+        while let Some(PendingNodes { nibbles, old, new }) = self.stack.pop() {
+            let old = self.load_node(nibbles, old)?;
+            let new = self.load_node(nibbles, new)?;
+
+            // Leaves can be compared directly if they have the same key, or if one of them is missing.
+            // Otherwise, nodes are split into children until this condition holds.
+            let leaves = match (&old, &new) {
+                (None, None) => continue,
+                (Some(Node::Leaf(old)), None) => {
+                    Some((TreeEntry::from(*old), TreeEntry::empty(old.full_key)))
+                }
+                (None, Some(Node::Leaf(new))) => {
+                    Some((TreeEntry::empty(new.full_key), TreeEntry::from(*new)))
+                }
+                (Some(Node::Leaf(old)), Some(Node::Leaf(new))) if old.full_key == new.full_key => {
+                    Some((TreeEntry::from(*old), TreeEntry::from(*new)))
+                }
+                _ => None,
+            };
+            if let Some((old, new)) = leaves {
+                if old != new && old.key >= self.start_key {
+                    return Ok(Some(TreeEntryDiff { old, new }));
+                }
+                continue;
+            }
+
+            let nibble_count = nibbles.nibble_count();
+            let old_children = Self::split(old, nibble_count);
+            let new_children = Self::split(new, nibble_count);
+            // Push children in the reverse order, so that they are popped in the increasing key order.
+            let children = old_children.into_iter().zip(new_children).enumerate().rev();
+            for (nibble, (old_child, new_child)) in children {
+                let is_unchanged = match (&old_child, &new_child) {
+                    (PendingNode::Empty, PendingNode::Empty) => true,
+                    (PendingNode::Ref(old_ref), PendingNode::Ref(new_ref)) => {
+                        old_ref.hash == new_ref.hash
+                    }
+                    _ => false,
+                };
+                if is_unchanged {
+                    continue;
+                }
+
+                #[allow(clippy::cast_possible_truncation)] // nibble < 16
+                let child_nibbles = nibbles
+                    .push(nibble as u8)
+                    .expect("tree nodes at the terminal level cannot have children");
+                if self.is_before_start(&child_nibbles) {
+                    continue;
+                }
+                self.stack.push(PendingNodes {
+                    nibbles: child_nibbles,
+                    old: old_child,
+                    new: new_child,
+                });
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<DB: Database + ?Sized> Iterator for TreeDiff<'_, DB> {
+    type Item = Result<TreeEntryDiff, TreeDiffError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.next_diff();
+        if result.is_err() {
+            // Do not yield any items after an error.
+            self.stack.clear();
+        }
+        result.transpose()
+    }
+}
+
+impl<DB: Database, H: HashTree> MerkleTree<DB, H> {
+    /// Returns an iterator over entries that differ between `old_version` and `new_version` of the tree.
+    /// Entries are yielded in the increasing key order. Versions do not need to be ordered; if `old_version`
+    /// is greater than `new_version`, the diff will revert changes made in the intermediate versions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the compared tree versions is missing or was pruned.
+    pub fn diff(
+        &self,
+        old_version: u64,
+        new_version: u64,
+    ) -> Result<TreeDiff<'_, DB>, NoVersionError> {
+        TreeDiff::new(&self.db, old_version, new_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{MerkleTreePruner, PatchSet, ValueHash};
+
+    fn generate_entries(indices: impl Iterator<Item = u64>, value_offset: u64) -> Vec<TreeEntry> {
+        indices
+            .map(|i| {
+                let key = (Key::from(i) * Key::from(0x_dead_beef_u64)) << 32;
+                TreeEntry::new(key, i + 1, ValueHash::from_low_u64_be(i + value_offset))
+            })
+            .collect()
+    }
+
+    fn collect_diff(
+        tree: &MerkleTree<PatchSet>,
+        old_version: u64,
+        new_version: u64,
+    ) -> Vec<TreeEntryDiff> {
+        tree.diff(old_version, new_version)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    /// Computes the reference diff by reading all `keys` in both versions.
+    fn reference_diff(
+        tree: &MerkleTree<PatchSet>,
+        keys: &[Key],
+        old_version: u64,
+        new_version: u64,
+    ) -> Vec<TreeEntryDiff> {
+        let old_entries = tree.entries(old_version, keys).unwrap();
+        let new_entries = tree.entries(new_version, keys).unwrap();
+        let diff: BTreeMap<_, _> = old_entries
+            .into_iter()
+            .zip(new_entries)
+            .filter(|(old, new)| old != new)
+            .map(|(old, new)| (old.key, TreeEntryDiff { old, new }))
+            .collect();
+        diff.into_values().collect()
+    }
+
+    #[test]
+    fn diff_for_empty_tree() {
+        let mut tree = MerkleTree::new(PatchSet::default()).unwrap();
+        tree.extend(vec![]).unwrap();
+        let entries = generate_entries(0..10, 0);
+        tree.extend(entries.clone()).unwrap();
+
+        assert!(collect_diff(&tree, 0, 0).is_empty());
+        assert!(collect_diff(&tree, 1, 1).is_empty());
+
+        let diff = collect_diff(&tree, 0, 1);
+        let mut expected_keys: Vec<_> = entries.iter().map(|entry| entry.key).collect();
+        expected_keys.sort_unstable();
+        let keys: Vec<_> = diff.iter().map(|diff| diff.new.key).collect();
+        assert_eq!(keys, expected_keys);
+        for diff in &diff {
+            assert!(diff.old.is_empty());
+            assert!(entries.contains(&diff.new));
+        }
+
+        let reverse_diff = collect_diff(&tree, 1, 0);
+        assert_eq!(reverse_diff.len(), diff.len());
+        for (reverse, diff) in reverse_diff.iter().zip(&diff) {
+            assert_eq!(reverse.old, diff.new);
+            assert_eq!(reverse.new, diff.old);
+        }
+    }
+
+    #[test]
+    fn diff_with_inserts_and_updates() {
+        let mut tree = MerkleTree::new(PatchSet::default()).unwrap();
+        tree.extend(generate_entries(0..100, 0)).unwrap();
+        // Update some entries and insert new ones.
+        let mut updates = generate_entries((0..100).step_by(7), 1_000);
+        updates.extend(generate_entries(100..120, 0));
+        tree.extend(updates.clone()).unwrap();
+        // Update some entries without changing their values.
+        tree.extend(generate_entries(105..115, 0)).unwrap();
+
+        let all_keys: Vec<_> = generate_entries(0..120, 0)
+            .into_iter()
+            .map(|entry| entry.key)
+            .collect();
+        for (old_version, new_version) in [(0, 1), (1, 2), (0, 2), (2, 0)] {
+            let diff = collect_diff(&tree, old_version, new_version);
+            let expected_diff = reference_diff(&tree, &all_keys, old_version, new_version);
+            assert_eq!(diff, expected_diff, "{old_version} -> {new_version}");
+        }
+        assert!(collect_diff(&tree, 1, 2).is_empty());
+        assert_eq!(collect_diff(&tree, 0, 1).len(), updates.len());
+    }
+
+    #[test]
+    fn paginating_diff() {
+        let mut tree = MerkleTree::new(PatchSet::default()).unwrap();
+        tree.extend(generate_entries(0..50, 0)).unwrap();
+        tree.extend(generate_entries(0..100, 1)).unwrap();
+        let full_diff = collect_diff(&tree, 0, 1);
+        assert_eq!(full_diff.len(), 100);
+
+        let mut start_key = Key::zero();
+        let mut paginated_diff = vec![];
+        loop {
+            let page: Vec<_> = tree
+                .diff(0, 1)
+                .unwrap()
+                .starting_from(start_key)
+                .take(11)
+                .collect::<Result<_, _>>()
+                .unwrap();
+            if page.len() < 11 {
+                paginated_diff.extend(page);
+                break;
+            }
+            start_key = page[10].new.key;
+            paginated_diff.extend_from_slice(&page[..10]);
+        }
+        assert_eq!(paginated_diff, full_diff);
+    }
+
+    #[test]
+    fn diff_for_pruned_version() {
+        let mut db = PatchSet::default();
+        let mut tree = MerkleTree::new(&mut db).unwrap();
+        for i in 0..5 {
+            tree.extend(generate_entries(i * 10..(i + 1) * 10, 0))
+                .unwrap();
+        }
+        let (mut pruner, _handle) = MerkleTreePruner::new(&mut db);
+        pruner.prune_up_to(2).unwrap().expect("tree was not pruned");
+
+        let tree = MerkleTree::new(&mut db).unwrap();
+        let err = tree.diff(1, 4).unwrap_err();
+        assert_eq!(err.missing_version, 1);
+        assert!(err.to_string().contains("pruned"), "{err}");
+        let err = tree.diff(2, 5).unwrap_err();
+        assert_eq!(err.missing_version, 5);
+
+        let diff: Vec<_> = tree.diff(2, 4).unwrap().collect::<Result<_, _>>().unwrap();
+        assert_eq!(diff.len(), 20);
+    }
+}
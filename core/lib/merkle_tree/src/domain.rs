@@ -10,10 +10,10 @@ use crate::{
     storage::{PatchSet, Patched, RocksDBWrapper},
     types::{
         Key, NodeKey, RawNode, Root, TreeEntry, TreeEntryWithProof, TreeInstruction, TreeLogEntry,
-        ValueHash, TREE_DEPTH,
+        TreeMultiProof, ValueHash, TREE_DEPTH,
     },
     BlockOutput, HashTree, MerkleTree, MerkleTreePruner, MerkleTreePrunerHandle, NoVersionError,
-    PruneDatabase,
+    PruneDatabase, TreeDiff,
 };
 
 impl TreeInstruction<StorageKey> {
@@ -453,6 +453,38 @@ impl ZkSyncTreeReader {
         self.0.entries_with_proofs(version, keys)
     }
 
+    /// Reads entries with the specified keys from the tree together with a multi-proof for all of them.
+    /// Entries in the returned proof are ordered by key and deduplicated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tree `version` is missing.
+    pub fn entries_with_multi_proof(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        keys: &[Key],
+    ) -> Result<TreeMultiProof, NoVersionError> {
+        let version = u64::from(l1_batch_number.0);
+        self.0.entries_with_multi_proof(version, keys)
+    }
+
+    /// Returns an iterator over entries that differ between the tree states after the specified L1 batches.
+    /// Entries are ordered by key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the compared tree versions is missing or was pruned.
+    pub fn diff(
+        &self,
+        old_l1_batch_number: L1BatchNumber,
+        new_l1_batch_number: L1BatchNumber,
+    ) -> Result<TreeDiff<'_, RocksDBWrapper>, NoVersionError> {
+        self.0.diff(
+            u64::from(old_l1_batch_number.0),
+            u64::from(new_l1_batch_number.0),
+        )
+    }
+
     /// Returns raw nodes for the specified `keys`.
     pub fn raw_nodes(&self, keys: &[NodeKey]) -> Vec<Option<RawNode>> {
         let raw_nodes = self.0.db.raw_nodes(keys).into_iter();
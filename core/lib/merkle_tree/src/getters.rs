@@ -4,7 +4,7 @@ use crate::{
     hasher::HasherWithStats,
     recovery::MerkleTreeRecovery,
     storage::{LoadAncestorsResult, SortedKeys, WorkingPatchSet},
-    types::{Nibbles, Node, ProfiledTreeOperation, TreeEntry, TreeEntryWithProof},
+    types::{Nibbles, Node, ProfiledTreeOperation, TreeEntry, TreeEntryWithProof, TreeMultiProof},
     Database, HashTree, Key, MerkleTree, NoVersionError, PruneDatabase, ValueHash,
 };
 
@@ -59,6 +59,25 @@ impl<DB: Database, H: HashTree> MerkleTree<DB, H> {
             },
         )
     }
+
+    /// Reads entries with the specified keys from the tree together with a [multi-proof](TreeMultiProof)
+    /// for all of them. Unlike [`Self::entries_with_proofs()`], entries in the returned proof are ordered by key
+    /// and deduplicated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tree `version` is missing.
+    pub fn entries_with_multi_proof(
+        &self,
+        version: u64,
+        leaf_keys: &[Key],
+    ) -> Result<TreeMultiProof, NoVersionError> {
+        let mut leaf_keys = leaf_keys.to_vec();
+        leaf_keys.sort_unstable();
+        leaf_keys.dedup();
+        let proofs = self.entries_with_proofs(version, &leaf_keys)?;
+        Ok(TreeMultiProof::new(proofs))
+    }
 }
 
 fn load_and_transform_entries<T>(
@@ -156,5 +175,74 @@ mod tests {
         entries[0].verify(&tree.hasher, output.root_hash).unwrap();
         assert!(entries[1].base.is_empty());
         entries[1].verify(&tree.hasher, output.root_hash).unwrap();
+
+        let multi_proof = tree
+            .entries_with_multi_proof(0, &[missing_key, key, missing_key])
+            .unwrap();
+        assert_eq!(multi_proof.entries.len(), 2);
+        assert!(multi_proof.entries[0].is_empty());
+        assert_eq!(multi_proof.entries[1].leaf_index, 1);
+        multi_proof.verify(&tree.hasher, output.root_hash).unwrap();
+
+        let multi_proof = tree.entries_with_multi_proof(0, &[]).unwrap();
+        assert!(multi_proof.entries.is_empty());
+        assert!(multi_proof.hashes.is_empty());
+        tree.entries_with_multi_proof(1, &[]).unwrap_err();
+    }
+
+    #[test]
+    fn multi_proofs_deduplicate_sibling_hashes() {
+        let mut tree = MerkleTree::new(PatchSet::default()).unwrap();
+        let entries = (1_u64..=100).map(|i| {
+            let key = (Key::from(i) * Key::from(0x_dead_beef_u64)) << 192;
+            TreeEntry::new(key, i, ValueHash::from_low_u64_be(i))
+        });
+        let entries: Vec<_> = entries.collect();
+        let output = tree.extend(entries.clone()).unwrap();
+
+        let mut keys: Vec<_> = entries.iter().step_by(3).map(|entry| entry.key).collect();
+        // Add some missing keys.
+        keys.extend((0_u64..10).map(|i| Key::from(i) << 128));
+
+        let multi_proof = tree.entries_with_multi_proof(0, &keys).unwrap();
+        assert_eq!(multi_proof.entries.len(), keys.len());
+        multi_proof.verify(&tree.hasher, output.root_hash).unwrap();
+        for entry in &multi_proof.entries {
+            let expected_entry = entries.iter().find(|other| other.key == entry.key);
+            let expected_entry = expected_entry
+                .copied()
+                .unwrap_or(TreeEntry::empty(entry.key));
+            assert_eq!(*entry, expected_entry);
+        }
+
+        let proofs = tree.entries_with_proofs(0, &keys).unwrap();
+        let individual_hash_count: usize = proofs.iter().map(|proof| proof.merkle_path.len()).sum();
+        assert!(
+            multi_proof.hashes.len() < individual_hash_count / 2,
+            "{} vs {individual_hash_count}",
+            multi_proof.hashes.len()
+        );
+
+        // Check that the proof is invalidated by changes.
+        let mut invalid_proof = multi_proof.clone();
+        invalid_proof.entries[0].value = ValueHash::repeat_byte(0xff);
+        invalid_proof
+            .verify(&tree.hasher, output.root_hash)
+            .unwrap_err();
+        let mut invalid_proof = multi_proof.clone();
+        invalid_proof.hashes.pop();
+        invalid_proof
+            .verify(&tree.hasher, output.root_hash)
+            .unwrap_err();
+        let mut invalid_proof = multi_proof.clone();
+        invalid_proof.hashes.push(ValueHash::zero());
+        invalid_proof
+            .verify(&tree.hasher, output.root_hash)
+            .unwrap_err();
+        let mut invalid_proof = multi_proof;
+        invalid_proof.entries.swap(0, 1);
+        invalid_proof
+            .verify(&tree.hasher, output.root_hash)
+            .unwrap_err();
     }
 }
@@ -2,13 +2,13 @@
 
 use std::mem;
 
-use anyhow::ensure;
+use anyhow::{ensure, Context as _};
 
 use crate::{
     hasher::{HashTree, HasherWithStats},
     types::{
         BlockOutputWithProofs, Key, LeafNode, TreeEntry, TreeEntryWithProof, TreeInstruction,
-        TreeLogEntry, ValueHash, TREE_DEPTH,
+        TreeLogEntry, TreeMultiProof, ValueHash, TREE_DEPTH,
     },
     utils,
 };
@@ -104,6 +104,192 @@ impl TreeEntryWithProof {
     }
 }
 
+/// Node in a subtree spanned by the entries of a [`TreeMultiProof`].
+#[derive(Debug)]
+struct MultiProofNode {
+    /// Index of the node on its level; equals the key of any entry in the node's subtree
+    /// right-shifted by the node depth.
+    index: Key,
+    hash: ValueHash,
+    /// Minimum Merkle path length among entries in the node's subtree. Sibling hashes
+    /// below this length correspond to empty subtrees.
+    min_path_len: usize,
+    /// Maximum Merkle path length among entries in the node's subtree.
+    max_path_len: usize,
+    /// Index of the entry with the longest Merkle path in the node's subtree.
+    longest_path_entry: usize,
+}
+
+impl TreeMultiProof {
+    /// Creates a multi-proof from individual proofs. Proofs must be ordered by key without duplicates.
+    /// If there are no proofs, the returned multi-proof is empty (and thus cannot be verified).
+    pub(crate) fn new(proofs: Vec<TreeEntryWithProof>) -> Self {
+        if proofs.is_empty() {
+            return Self {
+                entries: vec![],
+                merkle_path_lengths: vec![],
+                hashes: vec![],
+            };
+        }
+
+        let mut hashes = vec![];
+        let leaves = proofs.iter().enumerate().map(|(i, proof)| MultiProofNode {
+            index: proof.base.key,
+            hash: ValueHash::zero(),
+            min_path_len: proof.merkle_path.len(),
+            max_path_len: proof.merkle_path.len(),
+            longest_path_entry: i,
+        });
+        // Hashes are not computed, so we use a no-op hasher.
+        let leaves = leaves.collect();
+        let no_op_hasher: &dyn HashTree = &();
+        no_op_hasher
+            .fold_multi_proof(leaves, |depth, node| {
+                let path = &proofs[node.longest_path_entry].merkle_path;
+                let hash = path[depth + path.len() - TREE_DEPTH];
+                hashes.push(hash);
+                Ok(hash)
+            })
+            .expect("sibling hashes are always available");
+
+        Self {
+            merkle_path_lengths: proofs.iter().map(|proof| proof.merkle_path.len()).collect(),
+            entries: proofs.into_iter().map(|proof| proof.base).collect(),
+            hashes,
+        }
+    }
+
+    /// Verifies this multi-proof.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error <=> proof is invalid.
+    pub fn verify(
+        &self,
+        hasher: &dyn HashTree,
+        trusted_root_hash: ValueHash,
+    ) -> anyhow::Result<()> {
+        ensure!(!self.entries.is_empty(), "Multi-proof contains no entries");
+        ensure!(
+            self.entries.len() == self.merkle_path_lengths.len(),
+            "Number of entries ({}) differs from the number of Merkle path lengths ({})",
+            self.entries.len(),
+            self.merkle_path_lengths.len()
+        );
+        for window in self.entries.windows(2) {
+            ensure!(
+                window[0].key < window[1].key,
+                "Entries are not ordered by key or contain duplicates"
+            );
+        }
+
+        let mut leaves = Vec::with_capacity(self.entries.len());
+        for (i, (entry, &path_len)) in self
+            .entries
+            .iter()
+            .zip(&self.merkle_path_lengths)
+            .enumerate()
+        {
+            ensure!(
+                path_len <= TREE_DEPTH,
+                "Merkle path length {path_len} for key {:0>64x} exceeds tree depth",
+                entry.key
+            );
+            if entry.leaf_index == 0 {
+                ensure!(
+                    entry.value.is_zero(),
+                    "Invalid missing value specification: leaf index is zero, but value is non-default"
+                );
+            }
+            leaves.push(MultiProofNode {
+                index: entry.key,
+                hash: hasher.hash_leaf(&entry.value, entry.leaf_index),
+                min_path_len: path_len,
+                max_path_len: path_len,
+                longest_path_entry: i,
+            });
+        }
+
+        let mut sibling_hashes = self.hashes.iter().copied();
+        let root_hash = hasher.fold_multi_proof(leaves, |_, _| {
+            sibling_hashes
+                .next()
+                .context("Multi-proof has insufficient sibling hashes")
+        })?;
+        ensure!(
+            sibling_hashes.next().is_none(),
+            "Multi-proof has redundant sibling hashes"
+        );
+        ensure!(
+            root_hash == trusted_root_hash,
+            "Root hash mismatch: got {root_hash}, want {trusted_root_hash}"
+        );
+        Ok(())
+    }
+}
+
+impl dyn HashTree + '_ {
+    /// Folds a multi-proof with the specified leaves (ordered by key without duplicates) up to the root hash.
+    /// `get_sibling` is called for each sibling hash that cannot be computed from leaves, in the order
+    /// described in [`TreeMultiProof::hashes`].
+    fn fold_multi_proof(
+        &self,
+        mut nodes: Vec<MultiProofNode>,
+        mut get_sibling: impl FnMut(usize, &MultiProofNode) -> anyhow::Result<ValueHash>,
+    ) -> anyhow::Result<ValueHash> {
+        for depth in 0..TREE_DEPTH {
+            let mut parents = Vec::with_capacity(nodes.len());
+            let mut nodes_iter = nodes.into_iter().peekable();
+            while let Some(node) = nodes_iter.next() {
+                let is_right = node.index.bit(0);
+                let parent_index = node.index >> 1;
+                let has_right_sibling = !is_right
+                    && nodes_iter
+                        .peek()
+                        .map_or(false, |next| next.index >> 1 == parent_index);
+
+                let parent = if has_right_sibling {
+                    let right = nodes_iter.next().unwrap();
+                    // ^ `unwrap()` is safe due to the check above
+                    let longest = if node.max_path_len >= right.max_path_len {
+                        &node
+                    } else {
+                        &right
+                    };
+                    MultiProofNode {
+                        index: parent_index,
+                        hash: self.hash_branch(&node.hash, &right.hash),
+                        min_path_len: node.min_path_len.min(right.min_path_len),
+                        max_path_len: longest.max_path_len,
+                        longest_path_entry: longest.longest_path_entry,
+                    }
+                } else {
+                    let sibling_hash = if depth + node.min_path_len < TREE_DEPTH {
+                        self.empty_subtree_hash(depth)
+                    } else {
+                        get_sibling(depth, &node)?
+                    };
+                    let hash = if is_right {
+                        self.hash_branch(&sibling_hash, &node.hash)
+                    } else {
+                        self.hash_branch(&node.hash, &sibling_hash)
+                    };
+                    MultiProofNode {
+                        index: parent_index,
+                        hash,
+                        ..node
+                    }
+                };
+                parents.push(parent);
+            }
+            nodes = parents;
+        }
+
+        ensure!(nodes.len() == 1, "Multi-proof contains no entries");
+        Ok(nodes[0].hash)
+    }
+}
+
 /// Range digest in a Merkle tree allowing to compute its root hash based on the provided entries.
 ///
 /// - The entries must be ordered by key. I.e., the first entry must have the numerically smallest key,
@@ -49,6 +49,7 @@
 use zksync_crypto_primitives::hasher::blake2::Blake2Hasher;
 
 pub use crate::{
+    diff::{TreeDiff, TreeDiffError},
     errors::NoVersionError,
     hasher::{HashTree, TreeRangeDigest},
     pruning::{MerkleTreePruner, MerkleTreePrunerHandle},
@@ -57,13 +58,14 @@ pub use crate::{
         RocksDBWrapper,
     },
     types::{
-        BlockOutput, BlockOutputWithProofs, Key, TreeEntry, TreeEntryWithProof, TreeInstruction,
-        TreeLogEntry, TreeLogEntryWithProof, ValueHash,
+        BlockOutput, BlockOutputWithProofs, Key, TreeEntry, TreeEntryDiff, TreeEntryWithProof,
+        TreeInstruction, TreeLogEntry, TreeLogEntryWithProof, TreeMultiProof, ValueHash,
     },
 };
 use crate::{storage::Storage, types::Root};
 
 mod consistency;
+mod diff;
 pub mod domain;
 mod errors;
 mod getters;
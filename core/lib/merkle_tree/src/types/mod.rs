@@ -116,6 +116,33 @@ pub struct TreeEntryWithProof {
     pub merkle_path: Vec<ValueHash>,
 }
 
+/// Merkle multi-proof for several entries in a Merkle tree.
+///
+/// Unlike a set of [`TreeEntryWithProof`]s, a multi-proof contains each sibling hash at most once.
+/// Hashes that can be computed from the proven entries (e.g., for the subtree containing another proven
+/// entry) are omitted as well. A multi-proof can be verified using [`Self::verify()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeMultiProof {
+    /// Proven entries ordered by key without duplicates. Entries may be [empty](TreeEntry::is_empty()),
+    /// proving the absence of the corresponding keys.
+    pub entries: Vec<TreeEntry>,
+    /// Lengths of the Merkle paths for `entries` (i.e., [`TreeEntryWithProof::merkle_path`] lengths).
+    /// Sibling hashes below the path are treated as hashes of empty subtrees.
+    pub merkle_path_lengths: Vec<usize>,
+    /// Sibling hashes not derivable from `entries`. Hashes are ordered by tree level starting from the bottom
+    /// level, and by key within a level.
+    pub hashes: Vec<ValueHash>,
+}
+
+/// Change of a tree entry between two versions of a Merkle tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TreeEntryDiff {
+    /// Entry in the old tree version. [Empty](TreeEntry::is_empty()) if the key is missing in this version.
+    pub old: TreeEntry,
+    /// Entry in the new tree version. Empty if the key is missing in this version.
+    pub new: TreeEntry,
+}
+
 /// Output of inserting a block of entries into a Merkle tree.
 #[derive(Debug, PartialEq, Eq)]
 pub struct BlockOutput {
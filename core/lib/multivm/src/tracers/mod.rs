@@ -1,6 +1,9 @@
 pub use self::{
-    call_tracer::CallTracer, multivm_dispatcher::TracerDispatcher, prestate_tracer::PrestateTracer,
-    storage_invocation::StorageInvocations, validator::ValidationTracer,
+    call_tracer::CallTracer,
+    multivm_dispatcher::TracerDispatcher,
+    prestate_tracer::{Account as PrestateTracerAccount, PrestateTracer},
+    storage_invocation::StorageInvocations,
+    validator::ValidationTracer,
 };
 
 mod call_tracer;
@@ -2,14 +2,19 @@ use std::{collections::HashMap, fmt, sync::Arc};
 
 use once_cell::sync::OnceCell;
 use zksync_types::{
-    address_to_h256, get_code_key, get_nonce_key, h256_to_u256, web3::keccak256, AccountTreeId,
-    Address, StorageKey, StorageValue, H160, H256, L2_BASE_TOKEN_ADDRESS, U256,
+    address_to_h256, get_code_key, get_nonce_key, h256_to_address, h256_to_u256, u256_to_h256,
+    web3::keccak256, AccountTreeId, Address, StorageKey, StorageValue,
+    ACCOUNT_CODE_STORAGE_ADDRESS, H256, L2_BASE_TOKEN_ADDRESS, U256,
 };
 
-use crate::interface::storage::{StoragePtr, WriteStorage};
+use crate::{
+    glue::tracers::IntoOldVmTracer,
+    interface::storage::{StoragePtr, WriteStorage},
+};
 
 pub mod vm_1_4_1;
 pub mod vm_1_4_2;
+pub mod vm_boojum_integration;
 pub mod vm_latest;
 pub mod vm_refunds_enhancement;
 pub mod vm_virtual_blocks;
@@ -66,47 +71,82 @@ impl PrestateTracer {
     }
 }
 
+impl IntoOldVmTracer for PrestateTracer {}
+
 #[derive(Debug, Clone)]
 pub struct PrestateTracerConfig {
     diff_mode: bool,
 }
 
-pub fn process_modified_storage_keys<S>(
-    prestate: State,
+/// Collects the initial state of accounts owning the specified storage keys, together with the values
+/// of these keys. Initial values are the values read from the underlying storage, i.e., as of the start
+/// of the batch. Since `WriteStorage::set_value()` reads the original value, this works for written keys as well.
+fn collect_initial_state<'a, S: WriteStorage>(
     storage: &StoragePtr<S>,
-) -> HashMap<H160, Account>
-where
-    S: WriteStorage,
-{
-    let cloned_storage = &storage.clone();
-    let mut initial_storage_ref = cloned_storage.as_ref().borrow_mut();
-
-    initial_storage_ref
-        .modified_storage_keys()
-        .clone()
+    keys: impl IntoIterator<Item = &'a StorageKey>,
+) -> State {
+    let mut storage = storage.borrow_mut();
+    let mut state = State::new();
+    for key in keys {
+        let account = state
+            .entry(*key.address())
+            .or_insert_with(|| read_initial_account(&mut *storage, key.account()));
+        let value = u256_to_h256(read_initial_value(&mut *storage, key));
+        account
+            .storage
+            .get_or_insert_with(HashMap::new)
+            .insert(*key.key(), value);
+    }
+    state
+}
+
+/// Collects the initial state of all accounts and slots accessed during execution, including the ones that were only read.
+/// Besides owners of the accessed slots, this includes accounts with a read code hash (e.g., targets of calls, including EOAs)
+/// since account code hashes are stored in the `AccountCodeStorage` system contract.
+fn collect_accessed_state<S: WriteStorage>(storage: &StoragePtr<S>) -> State {
+    let accessed_keys: Vec<_> = storage
+        .borrow()
+        .read_storage_keys()
+        .keys()
+        .copied()
+        .collect();
+    let mut state = collect_initial_state(storage, &accessed_keys);
+
+    let mut storage = storage.borrow_mut();
+    let accounts_with_read_code = accessed_keys
         .iter()
-        .filter(|k| !prestate.contains_key(k.0.account().address()))
-        .map(|k| {
-            (
-                *(k.0.account().address()),
-                Account {
-                    balance: Some(h256_to_u256(
-                        initial_storage_ref.read_value(&get_balance_key(k.0.account())),
-                    )),
-                    code: Some(h256_to_u256(
-                        initial_storage_ref.read_value(&get_code_key(k.0.account().address())),
-                    )),
-                    nonce: Some(h256_to_u256(
-                        initial_storage_ref.read_value(&get_nonce_key(k.0.account().address())),
-                    )),
-                    storage: Some(get_storage_if_present(
-                        k.0.account(),
-                        initial_storage_ref.modified_storage_keys(),
-                    )),
-                },
-            )
-        })
-        .collect::<State>()
+        .filter(|key| *key.address() == ACCOUNT_CODE_STORAGE_ADDRESS)
+        .map(|key| AccountTreeId::new(h256_to_address(key.key())));
+    for account in accounts_with_read_code {
+        state
+            .entry(*account.address())
+            .or_insert_with(|| read_initial_account(&mut *storage, &account));
+    }
+    state
+}
+
+fn read_initial_account<S: WriteStorage>(storage: &mut S, account: &AccountTreeId) -> Account {
+    Account {
+        balance: Some(read_initial_value(storage, &get_balance_key(account))),
+        code: Some(read_initial_value(
+            storage,
+            &get_code_key(account.address()),
+        )),
+        nonce: Some(read_initial_value(
+            storage,
+            &get_nonce_key(account.address()),
+        )),
+        storage: Some(HashMap::new()),
+    }
+}
+
+fn read_initial_value<S: WriteStorage>(storage: &mut S, key: &StorageKey) -> U256 {
+    let value = match storage.read_storage_keys().get(key) {
+        Some(&value) => value,
+        // The key wasn't accessed, so its current value is equal to the initial one.
+        None => storage.read_value(key),
+    };
+    h256_to_u256(value)
 }
 
 fn get_balance_key(account: &AccountTreeId) -> StorageKey {
@@ -128,7 +168,17 @@ fn get_storage_if_present(
         .collect()
 }
 
-fn process_result(result: &Arc<OnceCell<(State, State)>>, mut pre: State, post: State) {
+/// Stores the tracer output. In the diff mode, accounts unchanged by execution are removed from the pre-state.
+fn process_result(
+    result: &Arc<OnceCell<(State, State)>>,
+    config: &PrestateTracerConfig,
+    mut pre: State,
+    post: State,
+) {
+    if !config.diff_mode {
+        result.set((pre, post)).unwrap();
+        return;
+    }
     pre.retain(|k, v| {
         if let Some(post_v) = post.get(k) {
             if v != post_v {
@@ -1,30 +1,16 @@
-use zk_evm_1_4_1::tracing::{BeforeExecutionData, VmLocalStateData};
 use zksync_types::{StorageKey, U256};
 
 use super::{
-    get_account_data, process_modified_storage_keys, process_result, PrestateTracer, State,
-    StorageAccess,
+    collect_accessed_state, collect_initial_state, get_account_data, process_result,
+    PrestateTracer, State, StorageAccess,
 };
 use crate::{
-    interface::storage::{StoragePtr, WriteStorage},
+    interface::storage::WriteStorage,
     tracers::dynamic::vm_1_4_1::DynTracer,
     vm_1_4_2::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
 };
 
-impl<S: WriteStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for PrestateTracer {
-    fn before_execution(
-        &mut self,
-        _state: VmLocalStateData<'_>,
-        _data: BeforeExecutionData,
-        _memory: &SimpleMemory<H>,
-        storage: StoragePtr<S>,
-    ) {
-        if self.config.diff_mode {
-            self.pre
-                .extend(process_modified_storage_keys(self.pre.clone(), &storage));
-        }
-    }
-}
+impl<S: WriteStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for PrestateTracer {}
 
 impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for PrestateTracer {
     fn after_vm_execution(
@@ -33,22 +19,23 @@ impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for PrestateTracer {
         _bootloader_state: &BootloaderState,
         _stop_reason: crate::interface::tracer::VmExecutionStopReason,
     ) {
+        let storage = state.storage.storage.inner().get_ptr();
         let modified_storage_keys = state.storage.storage.inner().get_modified_storage_keys();
         if self.config.diff_mode {
+            self.pre = collect_initial_state(&storage, modified_storage_keys.keys());
             self.post = modified_storage_keys
                 .iter()
                 .map(|k| get_account_data(k.0, state, &modified_storage_keys))
                 .collect::<State>();
         } else {
-            let read_keys = &state.storage.read_keys;
-            let map = read_keys.inner().clone();
-            let res = map
-                .iter()
-                .map(|k| get_account_data(k.0, state, &modified_storage_keys))
-                .collect::<State>();
-            self.post = res;
+            self.pre = collect_accessed_state(&storage);
         }
-        process_result(&self.result, self.pre.clone(), self.post.clone());
+        process_result(
+            &self.result,
+            &self.config,
+            self.pre.clone(),
+            self.post.clone(),
+        );
     }
 }
 
@@ -0,0 +1,46 @@
+use zksync_types::{StorageKey, U256};
+
+use super::{
+    collect_accessed_state, collect_initial_state, get_account_data, process_result,
+    PrestateTracer, State, StorageAccess,
+};
+use crate::{
+    interface::storage::WriteStorage,
+    tracers::dynamic::vm_1_4_0::DynTracer,
+    vm_boojum_integration::{BootloaderState, HistoryMode, SimpleMemory, VmTracer, ZkSyncVmState},
+};
+
+impl<S: WriteStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H>> for PrestateTracer {}
+
+impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H> for PrestateTracer {
+    fn after_vm_execution(
+        &mut self,
+        state: &mut ZkSyncVmState<S, H>,
+        _bootloader_state: &BootloaderState,
+        _stop_reason: crate::interface::tracer::VmExecutionStopReason,
+    ) {
+        let storage = state.storage.storage.inner().get_ptr();
+        let modified_storage_keys = state.storage.storage.inner().get_modified_storage_keys();
+        if self.config.diff_mode {
+            self.pre = collect_initial_state(&storage, modified_storage_keys.keys());
+            self.post = modified_storage_keys
+                .iter()
+                .map(|k| get_account_data(k.0, state, &modified_storage_keys))
+                .collect::<State>();
+        } else {
+            self.pre = collect_accessed_state(&storage);
+        }
+        process_result(
+            &self.result,
+            &self.config,
+            self.pre.clone(),
+            self.post.clone(),
+        );
+    }
+}
+
+impl<S: WriteStorage, H: HistoryMode> StorageAccess for ZkSyncVmState<S, H> {
+    fn read_from_storage(&self, key: &StorageKey) -> U256 {
+        self.storage.storage.read_from_storage(key)
+    }
+}
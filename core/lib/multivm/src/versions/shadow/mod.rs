@@ -1,7 +1,10 @@
 //! Shadow VM tests. Since there are no real VM implementations in the `vm_interface` crate where `ShadowVm` is defined,
 //! these tests are placed here.
 
+use std::sync::Arc;
+
 use assert_matches::assert_matches;
+use once_cell::sync::OnceCell;
 use zksync_test_contracts::{Account, LoadnextContractExecutionParams, TestContract, TxType};
 use zksync_types::{
     block::L2BlockHasher, fee::Fee, AccountTreeId, Address, Execute, L1BatchNumber, L2BlockNumber,
@@ -12,14 +15,16 @@ use crate::{
     interface::{
         storage::{InMemoryStorage, ReadStorage, StorageView},
         utils::{ShadowVm, VmDump},
-        ExecutionResult, L1BatchEnv, L2BlockEnv, VmFactory, VmInterface, VmInterfaceExt,
+        ExecutionResult, InspectExecutionMode, L1BatchEnv, L2BlockEnv, VmFactory, VmInterface,
+        VmInterfaceExt,
     },
+    tracers::CallTracer,
     utils::get_max_gas_per_pubdata_byte,
     versions::testonly::{
         default_l1_batch, default_system_env, make_address_rich, ContractToDeploy,
     },
     vm_fast, vm_latest,
-    vm_latest::HistoryEnabled,
+    vm_latest::{HistoryEnabled, ToTracerPointer},
 };
 
 mod tests;
@@ -267,3 +272,113 @@ fn shadow_vm_basics() {
     let new_dump = vm.dump_state();
     pretty_assertions::assert_eq!(new_dump, dump);
 }
+
+#[test]
+fn shadow_vm_compares_call_traces() {
+    let system_env = default_system_env();
+    let l1_batch_env = default_l1_batch(L1BatchNumber(1));
+    let mut storage = InMemoryStorage::with_system_contracts();
+    let mut harness = Harness::new(&l1_batch_env);
+    harness.setup_storage(&mut storage);
+
+    let storage = StorageView::new(storage).to_rc_ptr();
+    let mut vm = crate::vm_instance::ShadowedFastVm::<_, vm_fast::CallTracer>::new(
+        l1_batch_env,
+        system_env,
+        storage,
+    );
+
+    let write_fn = harness
+        .storage_contract_abi
+        .function("simpleWrite")
+        .unwrap();
+    let simple_write_tx = harness.alice.get_l2_tx_for_execute(
+        Execute {
+            contract_address: Some(Harness::STORAGE_CONTRACT_ADDRESS),
+            calldata: write_fn.encode_input(&[]).unwrap(),
+            value: 0.into(),
+            factory_deps: vec![],
+        },
+        None,
+    );
+    vm.push_transaction(simple_write_tx);
+
+    let legacy_calls = Arc::new(OnceCell::new());
+    let legacy_tracer = CallTracer::new(legacy_calls.clone()).into_tracer_pointer();
+    let mut tracer = (legacy_tracer.into(), vm_fast::CallTracer::default());
+    let exec_result = vm.inspect(&mut tracer, InspectExecutionMode::OneTx);
+    assert!(!exec_result.result.is_failed(), "{exec_result:#?}");
+
+    let legacy_calls = legacy_calls.get().unwrap();
+    let fast_calls = tracer.1.into_result();
+    assert!(!fast_calls.is_empty());
+    // The default divergence handler panics, so this will fail the test on a divergence.
+    vm.check_divergence(
+        "call_traces",
+        legacy_calls.as_slice(),
+        fast_calls.as_slice(),
+    );
+}
+
+#[test]
+fn fast_vm_call_traces_match_legacy_vm() {
+    let system_env = default_system_env();
+    let l1_batch_env = default_l1_batch(L1BatchNumber(1));
+    let mut storage = InMemoryStorage::with_system_contracts();
+    let harness = Harness::new(&l1_batch_env);
+    harness.setup_storage(&mut storage);
+
+    let mut legacy_vm = ReferenceVm::new(
+        l1_batch_env.clone(),
+        system_env.clone(),
+        StorageView::new(storage.clone()).to_rc_ptr(),
+    );
+    let mut fast_vm = vm_fast::Vm::<_, vm_fast::CallTracer>::new(
+        l1_batch_env,
+        system_env,
+        StorageView::new(storage).to_rc_ptr(),
+    );
+
+    let transfer_exec = Execute {
+        contract_address: Some(harness.bob.address()),
+        calldata: vec![],
+        value: 1_000_000_000.into(),
+        factory_deps: vec![],
+    };
+    let transfer_to_bob = harness
+        .alice
+        .get_l2_tx_for_execute(transfer_exec.clone(), None);
+    // Reverts because of running out of gas, which exercises calls with no gas left.
+    let out_of_gas_transfer = harness
+        .bob
+        .get_l2_tx_for_execute(transfer_exec, Some(tx_fee(200_000)));
+    let write_fn = harness
+        .storage_contract_abi
+        .function("simpleWrite")
+        .unwrap();
+    let simple_write_tx = harness.alice.get_l2_tx_for_execute(
+        Execute {
+            contract_address: Some(Harness::STORAGE_CONTRACT_ADDRESS),
+            calldata: write_fn.encode_input(&[]).unwrap(),
+            value: 0.into(),
+            factory_deps: vec![],
+        },
+        None,
+    );
+
+    for tx in [transfer_to_bob, out_of_gas_transfer, simple_write_tx] {
+        let legacy_calls = Arc::new(OnceCell::new());
+        let legacy_tracer = CallTracer::new(legacy_calls.clone()).into_tracer_pointer();
+        legacy_vm.push_transaction(tx.clone());
+        legacy_vm.inspect(&mut legacy_tracer.into(), InspectExecutionMode::OneTx);
+
+        let mut fast_tracer = vm_fast::CallTracer::default();
+        fast_vm.push_transaction(tx);
+        fast_vm.inspect(&mut fast_tracer, InspectExecutionMode::OneTx);
+
+        let legacy_calls = legacy_calls.get().unwrap();
+        let fast_calls = fast_tracer.into_result();
+        assert!(!fast_calls.is_empty());
+        pretty_assertions::assert_eq!(&fast_calls, legacy_calls);
+    }
+}
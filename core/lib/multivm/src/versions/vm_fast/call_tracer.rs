@@ -0,0 +1,163 @@
+//! Call tracer for the fast VM.
+
+use zksync_system_constants::CONTRACT_DEPLOYER_ADDRESS;
+use zksync_types::{zk_evm_types::FarCallOpcode, U256};
+use zksync_vm2::{
+    interface::{
+        CallframeInterface, CallingMode, GlobalStateInterface, Opcode, OpcodeType, ReturnType,
+        ShouldStop, StateInterface, Tracer,
+    },
+    FatPointer,
+};
+
+use super::utils::read_raw_fat_pointer;
+use crate::interface::{Call, CallType, VmRevertReason};
+
+/// Tracer collecting the call tree of the executed transaction(s).
+///
+/// Produces the same [`Call`]s as the legacy [`CallTracer`](crate::tracers::CallTracer) for `vm_latest`.
+/// Unlike the legacy tracer, the result is not stored in a shared cell; use [`Self::result()`] or [`Self::into_result()`]
+/// after VM execution.
+#[derive(Debug, Clone, Default)]
+pub struct CallTracer {
+    stack: Vec<FarcallAndNearCallCount>,
+}
+
+#[derive(Debug, Clone)]
+struct FarcallAndNearCallCount {
+    farcall: Call,
+    near_calls_after: usize,
+}
+
+impl CallTracer {
+    /// Returns top-level calls traced so far. Calls that haven't finished yet are included as well.
+    pub fn result(&self) -> Vec<Call> {
+        self.stack.iter().map(|call| call.farcall.clone()).collect()
+    }
+
+    /// Same as [`Self::result()`], but consumes the tracer.
+    pub fn into_result(self) -> Vec<Call> {
+        self.stack.into_iter().map(|call| call.farcall).collect()
+    }
+
+    fn handle_far_call<S: GlobalStateInterface>(&mut self, state: &mut S, mode: CallingMode) {
+        let has_parent = state.number_of_callframes() > 1;
+        let current_gas = u64::from(state.current_frame().gas());
+        let current_address = state.current_frame().address();
+        let (parent_gas, parent_address) = if has_parent {
+            let parent = state.callframe(1);
+            (u64::from(parent.gas()) + current_gas, parent.address())
+        } else {
+            (current_gas, current_address)
+        };
+
+        let far_call = match mode {
+            CallingMode::Normal => FarCallOpcode::Normal,
+            CallingMode::Delegate => FarCallOpcode::Delegate,
+            CallingMode::Mimic => FarCallOpcode::Mimic,
+        };
+        // All calls from the actual users are mimic calls, so we need to check that the previous call was to the deployer.
+        // Actually it's a call of the constructor. At this stage, caller is user and callee is deployed contract.
+        let r#type =
+            if far_call == FarCallOpcode::Mimic && parent_address == CONTRACT_DEPLOYER_ADDRESS {
+                CallType::Create
+            } else {
+                CallType::Call(far_call)
+            };
+
+        // Mirrors the legacy tracer, which doesn't read calldata if the callee frame has no code page or no gas.
+        // The fast VM has no code pages; a failed far call (e.g., if the callee bytecode cannot be decommitted)
+        // is recognized by the calldata register not holding a pointer.
+        let (calldata_ptr, is_pointer) = state.read_register(1);
+        let input = if current_gas == 0 || !is_pointer {
+            vec![]
+        } else {
+            read_raw_fat_pointer(state, FatPointer::from(calldata_ptr))
+        };
+
+        let from = state.current_frame().caller();
+        let value = U256::from(state.current_frame().context_u128());
+        let call = Call {
+            r#type,
+            from,
+            to: current_address,
+            parent_gas,
+            gas: current_gas,
+            value,
+            input,
+            ..Call::default()
+        };
+
+        self.stack.push(FarcallAndNearCallCount {
+            farcall: call,
+            near_calls_after: 0,
+        });
+    }
+
+    fn handle_ret<S: GlobalStateInterface>(&mut self, state: &mut S, return_type: ReturnType) {
+        let Some(mut current_call) = self.stack.pop() else {
+            return;
+        };
+
+        if current_call.near_calls_after > 0 {
+            current_call.near_calls_after -= 1;
+            self.stack.push(current_call);
+            return;
+        }
+
+        let call = &mut current_call.farcall;
+        let remaining_gas = u64::from(state.current_frame().gas());
+        call.gas_used = call.parent_gas.saturating_sub(remaining_gas);
+
+        let (output_ptr, is_pointer) = state.read_register(1);
+        let output = if is_pointer {
+            let output_ptr = FatPointer::from(output_ptr);
+            let is_trivial = output_ptr.offset == 0 && output_ptr.length == 0;
+            (!is_trivial).then(|| read_raw_fat_pointer(state, output_ptr))
+        } else {
+            None
+        };
+
+        match return_type {
+            ReturnType::Normal => {
+                call.output = output.unwrap_or_default();
+            }
+            ReturnType::Revert => {
+                call.revert_reason = Some(output.map_or_else(
+                    || "Unknown revert reason".to_owned(),
+                    |output| VmRevertReason::from(output.as_slice()).to_string(),
+                ));
+            }
+            ReturnType::Panic => {
+                call.error = Some("Panic".to_owned());
+            }
+        }
+
+        // If there is a parent call, push the current call to it. Otherwise, push the current call to the stack,
+        // because it's the top-level call.
+        if let Some(parent_call) = self.stack.last_mut() {
+            parent_call.farcall.calls.push(current_call.farcall);
+        } else {
+            self.stack.push(current_call);
+        }
+    }
+}
+
+impl Tracer for CallTracer {
+    fn after_instruction<OP: OpcodeType, S: GlobalStateInterface>(
+        &mut self,
+        state: &mut S,
+    ) -> ShouldStop {
+        match OP::VALUE {
+            Opcode::FarCall(mode) => self.handle_far_call(state, mode),
+            Opcode::NearCall => {
+                if let Some(last) = self.stack.last_mut() {
+                    last.near_calls_after += 1;
+                }
+            }
+            Opcode::Ret(return_type) => self.handle_ret(state, return_type),
+            _ => {}
+        }
+        ShouldStop::Continue
+    }
+}
@@ -1,10 +1,11 @@
 pub use zksync_vm2::interface;
 
 pub(crate) use self::version::FastVmVersion;
-pub use self::vm::Vm;
+pub use self::{call_tracer::CallTracer, vm::Vm};
 
 mod bootloader_state;
 mod bytecode;
+mod call_tracer;
 mod circuits_tracer;
 mod events;
 mod evm_deploy_tracer;
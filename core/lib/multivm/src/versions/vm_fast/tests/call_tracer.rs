@@ -0,0 +1,48 @@
+use zksync_test_contracts::TestContract;
+use zksync_types::{Address, Execute};
+
+use crate::{
+    interface::{InspectExecutionMode, TxExecutionMode, VmInterface},
+    versions::testonly::{ContractToDeploy, VmTesterBuilder},
+    vm_fast::{CallTracer, Vm},
+    vm_latest::constants::BATCH_COMPUTATIONAL_GAS_LIMIT,
+};
+
+#[test]
+fn test_basic_behavior() {
+    let contract = TestContract::counter().bytecode.to_vec();
+    let address = Address::repeat_byte(1);
+    let mut vm = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_rich_accounts(1)
+        .with_bootloader_gas_limit(BATCH_COMPUTATIONAL_GAS_LIMIT)
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_custom_contracts(vec![ContractToDeploy::account(contract, address)])
+        .build::<Vm<_, CallTracer>>();
+
+    let increment_by_6_calldata =
+        "7cf5dab00000000000000000000000000000000000000000000000000000000000000006";
+
+    let account = &mut vm.rich_accounts[0];
+    let tx = account.get_l2_tx_for_execute(
+        Execute {
+            contract_address: Some(address),
+            calldata: hex::decode(increment_by_6_calldata).unwrap(),
+            value: Default::default(),
+            factory_deps: vec![],
+        },
+        None,
+    );
+
+    let mut call_tracer = CallTracer::default();
+    vm.vm.push_transaction(tx);
+    let res = vm.vm.inspect(&mut call_tracer, InspectExecutionMode::OneTx);
+
+    let call_tracer_result = call_tracer.into_result();
+
+    assert_eq!(call_tracer_result.len(), 1);
+    // Expect that there are a plenty of subcalls underneath.
+    let subcall = &call_tracer_result[0].calls;
+    assert!(subcall.len() > 10);
+    assert!(!res.result.is_failed());
+}
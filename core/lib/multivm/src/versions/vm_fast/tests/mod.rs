@@ -3,7 +3,7 @@ use std::{any::Any, collections::HashSet, fmt, rc::Rc};
 use zksync_types::{
     h256_to_u256, writes::StateDiffRecord, StorageKey, Transaction, H160, H256, U256,
 };
-use zksync_vm2::interface::{Event, HeapId, StateInterface};
+use zksync_vm2::interface::{Event, HeapId, StateInterface, Tracer};
 use zksync_vm_interface::{
     pubdata::PubdataBuilder, storage::ReadStorage, CurrentExecutionState, L2BlockEnv,
     VmExecutionMode, VmExecutionResultAndLogs, VmInterface,
@@ -19,6 +19,7 @@ use crate::{
 mod block_tip;
 mod bootloader;
 mod bytecode_publishing;
+mod call_tracer;
 mod circuits;
 mod code_oracle;
 mod default_aa;
@@ -78,7 +79,7 @@ impl PartialEq for VmStateDump {
     }
 }
 
-impl TestedVm for Vm<ImmutableStorageView<InMemoryStorage>> {
+impl<Tr: Tracer + Default + 'static> TestedVm for Vm<ImmutableStorageView<InMemoryStorage>, Tr> {
     type StateDump = VmStateDump;
 
     fn dump_state(&self) -> Self::StateDump {
@@ -125,7 +126,7 @@ impl TestedVm for Vm<ImmutableStorageView<InMemoryStorage>> {
 
     fn manually_decommit(&mut self, code_hash: H256) -> bool {
         let mut tracer = (
-            ((), CircuitsTracer::default()),
+            (Tr::default(), CircuitsTracer::default()),
             EvmDeployTracer::new(DynamicBytecodes::default()),
         );
         let (_, is_fresh) = self.inner.world_diff_mut().decommit_opcode(
@@ -11,3 +11,10 @@ pub(super) fn read_fat_pointer<S: StateInterface>(state: &S, raw: U256) -> Vec<u
     }
     result
 }
+
+/// Reads data referenced by a fat pointer in the same way as legacy VM tracers do, i.e., ignoring the pointer offset.
+pub(super) fn read_raw_fat_pointer<S: StateInterface>(state: &S, pointer: FatPointer) -> Vec<u8> {
+    (pointer.start..pointer.start + pointer.length)
+        .map(|addr| state.read_heap_byte(pointer.memory_page, addr))
+        .collect()
+}
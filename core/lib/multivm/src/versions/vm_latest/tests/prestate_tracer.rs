@@ -23,6 +23,7 @@ fn test_prestate_tracer() {
 
     vm.deploy_test_contract();
     let account = &mut vm.rich_accounts[0];
+    let sender = account.address;
 
     let tx1 = account.get_test_contract_transaction(
         vm.test_contract.unwrap(),
@@ -45,7 +46,11 @@ fn test_prestate_tracer() {
         .take()
         .unwrap_or_default();
 
-    assert!(prestate_result.1.contains_key(&contract_address));
+    // In the default mode, only the pre-state is populated.
+    assert!(prestate_result.0.contains_key(&contract_address));
+    assert!(prestate_result.1.is_empty());
+    // The sender doesn't own any accessed slots, but its code hash is read when calling it.
+    assert!(prestate_result.0.contains_key(&sender));
 }
 
 #[test]
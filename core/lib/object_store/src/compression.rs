@@ -0,0 +1,369 @@
+//! Object store wrapper compressing objects and checking their integrity.
+
+use std::{collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    metrics::OBJECT_STORE_METRICS,
+    raw::{Bucket, ListedKeys, ObjectStore, ObjectStoreError},
+};
+
+/// Magic bytes at the start of objects written by [`StoreWithCompression`]. Chosen so that they cannot be confused
+/// with the start of legacy objects (gzip-compressed protobuf or bincode-serialized data).
+const MAGIC: [u8; 8] = *b"\x89ZKOBJ\r\n";
+/// Length of the object header: magic bytes, encoding and the SHA-256 digest of the object contents.
+const HEADER_LEN: usize = MAGIC.len() + 1 + 32;
+
+/// Encoding of the object payload following the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum Encoding {
+    Identity = 0,
+    Zstd = 1,
+}
+
+impl Encoding {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::Identity),
+            1 => Some(Self::Zstd),
+            _ => None,
+        }
+    }
+}
+
+/// [`ObjectStore`] wrapper that compresses objects in the configured buckets using zstd and prepends a header
+/// with a SHA-256 checksum of the object contents to stored objects. The checksum is verified when
+/// the object is retrieved; on mismatch, [`ObjectStoreError::Corrupted`] is returned.
+///
+/// Objects in [public](Bucket::is_public()) buckets are stored as-is (i.e., without a header) unless these buckets
+/// are explicitly configured to be compressed, so that they remain readable without this wrapper.
+/// Objects without a header (e.g., ones written before the wrapper was enabled) are returned as-is.
+#[derive(Debug)]
+pub(crate) struct StoreWithCompression {
+    inner: Arc<dyn ObjectStore>,
+    level: i32,
+    /// If `None`, objects in all buckets are compressed.
+    compressed_buckets: Option<HashSet<Bucket>>,
+}
+
+impl StoreWithCompression {
+    /// Creates a new wrapper. `compressed_buckets` set to `None` means that objects in all buckets are compressed.
+    pub fn new(
+        inner: Arc<dyn ObjectStore>,
+        level: i32,
+        compressed_buckets: Option<HashSet<Bucket>>,
+    ) -> Result<Self, ObjectStoreError> {
+        if !zstd::compression_level_range().contains(&level) {
+            let err = format!(
+                "zstd compression level {level} is out of range {:?}",
+                zstd::compression_level_range()
+            );
+            return Err(ObjectStoreError::Initialization {
+                source: err.into(),
+                is_retriable: false,
+            });
+        }
+        Ok(Self {
+            inner,
+            level,
+            compressed_buckets,
+        })
+    }
+
+    /// Returns `None` if objects in the bucket should be stored as-is, without a header.
+    fn encoding(&self, bucket: Bucket) -> Option<Encoding> {
+        let is_explicitly_compressed = self
+            .compressed_buckets
+            .as_ref()
+            .is_some_and(|buckets| buckets.contains(&bucket));
+        if is_explicitly_compressed {
+            Some(Encoding::Zstd)
+        } else if bucket.is_public() {
+            None
+        } else if self.compressed_buckets.is_none() {
+            Some(Encoding::Zstd)
+        } else {
+            Some(Encoding::Identity)
+        }
+    }
+
+    fn encode(value: &[u8], encoding: Encoding, level: i32) -> Result<Vec<u8>, ObjectStoreError> {
+        let digest = Sha256::digest(value);
+        let mut encoded = Vec::with_capacity(HEADER_LEN + value.len());
+        encoded.extend_from_slice(&MAGIC);
+        encoded.push(encoding as u8);
+        encoded.extend_from_slice(&digest);
+        match encoding {
+            Encoding::Identity => encoded.extend_from_slice(value),
+            Encoding::Zstd => {
+                zstd::stream::copy_encode(value, &mut encoded, level)
+                    .map_err(|err| ObjectStoreError::Serialization(err.into()))?;
+            }
+        }
+        Ok(encoded)
+    }
+
+    /// Returns `Ok(None)` if the object has no header.
+    fn decode(raw: &[u8]) -> Result<Option<Vec<u8>>, String> {
+        if !raw.starts_with(&MAGIC) {
+            return Ok(None);
+        }
+        if raw.len() < HEADER_LEN {
+            return Err(format!("object header is truncated ({} bytes)", raw.len()));
+        }
+
+        let encoding_byte = raw[MAGIC.len()];
+        let encoding = Encoding::from_byte(encoding_byte)
+            .ok_or_else(|| format!("unknown object encoding: {encoding_byte}"))?;
+        let expected_digest = &raw[MAGIC.len() + 1..HEADER_LEN];
+        let payload = &raw[HEADER_LEN..];
+        let value = match encoding {
+            Encoding::Identity => payload.to_vec(),
+            Encoding::Zstd => zstd::stream::decode_all(payload)
+                .map_err(|err| format!("failed decompressing object: {err}"))?,
+        };
+
+        let actual_digest = Sha256::digest(&value);
+        if actual_digest.as_slice() != expected_digest {
+            return Err(format!(
+                "checksum mismatch: expected {}, got {}",
+                hex::encode(expected_digest),
+                hex::encode(actual_digest)
+            ));
+        }
+        Ok(Some(value))
+    }
+}
+
+#[async_trait]
+impl ObjectStore for StoreWithCompression {
+    async fn get_raw(&self, bucket: Bucket, key: &str) -> Result<Vec<u8>, ObjectStoreError> {
+        let raw = self.inner.get_raw(bucket, key).await?;
+        let decoded = tokio::task::spawn_blocking(move || {
+            let decoded = StoreWithCompression::decode(&raw);
+            decoded.map(|value| value.unwrap_or(raw))
+        })
+        .await
+        .map_err(|err| ObjectStoreError::Other {
+            source: err.into(),
+            is_retriable: false,
+        })?;
+
+        decoded.map_err(|err| {
+            OBJECT_STORE_METRICS.observe_corrupted_object(bucket);
+            tracing::warn!("Object `{key}` in bucket `{bucket}` is corrupted: {err}");
+            ObjectStoreError::Corrupted(
+                format!("object `{key}` in bucket `{bucket}`: {err}").into(),
+            )
+        })
+    }
+
+    async fn put_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), ObjectStoreError> {
+        let Some(encoding) = self.encoding(bucket) else {
+            return self.inner.put_raw(bucket, key, value).await;
+        };
+        let level = self.level;
+        let encoded = tokio::task::spawn_blocking(move || Self::encode(&value, encoding, level))
+            .await
+            .map_err(|err| ObjectStoreError::Other {
+                source: err.into(),
+                is_retriable: false,
+            })??;
+        self.inner.put_raw(bucket, key, encoded).await
+    }
+
+    async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError> {
+        self.inner.remove_raw(bucket, key).await
+    }
+
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+        page_token: Option<String>,
+    ) -> Result<ListedKeys, ObjectStoreError> {
+        self.inner.list_raw(bucket, prefix, page_token).await
+    }
+
+    async fn exists_raw(&self, bucket: Bucket, key: &str) -> Result<bool, ObjectStoreError> {
+        self.inner.exists_raw(bucket, key).await
+    }
+
+    async fn remove_many_raw(
+        &self,
+        bucket: Bucket,
+        keys: &[String],
+    ) -> Result<(), ObjectStoreError> {
+        self.inner.remove_many_raw(bucket, keys).await
+    }
+
+    fn storage_prefix_raw(&self, bucket: Bucket) -> String {
+        self.inner.storage_prefix_raw(bucket)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::MockObjectStore;
+
+    fn test_value() -> Vec<u8> {
+        (0..10_000_u32)
+            .flat_map(|i| (i % 100).to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn buckets_can_be_parsed() {
+        for bucket in Bucket::ALL {
+            assert_eq!(bucket.as_str().parse::<Bucket>().unwrap(), bucket);
+        }
+        "unknown".parse::<Bucket>().unwrap_err();
+    }
+
+    #[test]
+    fn invalid_compression_level_is_rejected() {
+        let err = StoreWithCompression::new(MockObjectStore::arc(), 1_000, None).unwrap_err();
+        assert_matches!(err, ObjectStoreError::Initialization { .. });
+    }
+
+    #[tokio::test]
+    async fn compressing_objects() {
+        let inner = MockObjectStore::arc();
+        let compressed_buckets = HashSet::from([Bucket::WitnessInput]);
+        let store = StoreWithCompression::new(inner.clone(), 3, Some(compressed_buckets)).unwrap();
+        let value = test_value();
+
+        for bucket in [Bucket::WitnessInput, Bucket::ProofsFri] {
+            store.put_raw(bucket, "test", value.clone()).await.unwrap();
+            assert_eq!(store.get_raw(bucket, "test").await.unwrap(), value);
+        }
+
+        let compressed = inner.get_raw(Bucket::WitnessInput, "test").await.unwrap();
+        assert!(compressed.starts_with(&MAGIC));
+        assert_eq!(compressed[MAGIC.len()], Encoding::Zstd as u8);
+        assert!(compressed.len() < value.len() / 10, "{}", compressed.len());
+
+        let uncompressed = inner.get_raw(Bucket::ProofsFri, "test").await.unwrap();
+        assert_eq!(uncompressed[MAGIC.len()], Encoding::Identity as u8);
+        assert_eq!(uncompressed[HEADER_LEN..], value);
+    }
+
+    #[tokio::test]
+    async fn public_buckets_are_not_encoded_by_default() {
+        let inner = MockObjectStore::arc();
+        let store = StoreWithCompression::new(inner.clone(), 3, None).unwrap();
+        let value = test_value();
+        store
+            .put_raw(Bucket::StorageSnapshot, "test", value.clone())
+            .await
+            .unwrap();
+        let raw = inner
+            .get_raw(Bucket::StorageSnapshot, "test")
+            .await
+            .unwrap();
+        assert_eq!(raw, value);
+        let retrieved = store
+            .get_raw(Bucket::StorageSnapshot, "test")
+            .await
+            .unwrap();
+        assert_eq!(retrieved, value);
+
+        // Explicitly configured public buckets should be compressed.
+        let compressed_buckets = HashSet::from([Bucket::StorageSnapshot]);
+        let store = StoreWithCompression::new(inner.clone(), 3, Some(compressed_buckets)).unwrap();
+        store
+            .put_raw(Bucket::StorageSnapshot, "test", value.clone())
+            .await
+            .unwrap();
+        let raw = inner
+            .get_raw(Bucket::StorageSnapshot, "test")
+            .await
+            .unwrap();
+        assert!(raw.starts_with(&MAGIC));
+        assert_eq!(raw[MAGIC.len()], Encoding::Zstd as u8);
+        let retrieved = store
+            .get_raw(Bucket::StorageSnapshot, "test")
+            .await
+            .unwrap();
+        assert_eq!(retrieved, value);
+    }
+
+    #[tokio::test]
+    async fn reading_legacy_objects() {
+        let inner = MockObjectStore::arc();
+        let store = StoreWithCompression::new(inner.clone(), 3, None).unwrap();
+        let value = test_value();
+        inner
+            .put_raw(Bucket::StorageSnapshot, "legacy", value.clone())
+            .await
+            .unwrap();
+
+        let retrieved = store
+            .get_raw(Bucket::StorageSnapshot, "legacy")
+            .await
+            .unwrap();
+        assert_eq!(retrieved, value);
+        // Empty objects should be handled as well.
+        inner
+            .put_raw(Bucket::StorageSnapshot, "empty", vec![])
+            .await
+            .unwrap();
+        let retrieved = store
+            .get_raw(Bucket::StorageSnapshot, "empty")
+            .await
+            .unwrap();
+        assert!(retrieved.is_empty());
+    }
+
+    #[tokio::test]
+    async fn detecting_corrupted_objects() {
+        let inner = MockObjectStore::arc();
+        let store = StoreWithCompression::new(inner.clone(), 3, None).unwrap();
+        store
+            .put_raw(Bucket::VmDumps, "test", test_value())
+            .await
+            .unwrap();
+        let mut raw = inner.get_raw(Bucket::VmDumps, "test").await.unwrap();
+
+        // Truncated header
+        inner
+            .put_raw(Bucket::VmDumps, "truncated", raw[..HEADER_LEN - 1].to_vec())
+            .await
+            .unwrap();
+        let err = store
+            .get_raw(Bucket::VmDumps, "truncated")
+            .await
+            .unwrap_err();
+        assert_matches!(err, ObjectStoreError::Corrupted(_));
+
+        // Checksum mismatch
+        raw[MAGIC.len() + 1] ^= 1;
+        inner
+            .put_raw(Bucket::VmDumps, "test", raw.clone())
+            .await
+            .unwrap();
+        let err = store.get_raw(Bucket::VmDumps, "test").await.unwrap_err();
+        assert_matches!(err, ObjectStoreError::Corrupted(_));
+        assert!(err.to_string().contains("checksum mismatch"), "{err}");
+        assert!(!err.is_retriable());
+
+        // Corrupted compressed payload
+        raw[MAGIC.len() + 1] ^= 1;
+        let last_idx = raw.len() - 1;
+        raw[last_idx] ^= 0xff;
+        inner.put_raw(Bucket::VmDumps, "test", raw).await.unwrap();
+        let err = store.get_raw(Bucket::VmDumps, "test").await.unwrap_err();
+        assert_matches!(err, ObjectStoreError::Corrupted(_));
+    }
+}
@@ -5,11 +5,13 @@ use tokio::sync::OnceCell;
 use zksync_config::configs::object_store::{ObjectStoreConfig, ObjectStoreMode};
 
 use crate::{
+    compression::StoreWithCompression,
     file::FileBackedObjectStore,
     gcs::{GoogleCloudStore, GoogleCloudStoreAuthMode},
     mirror::MirroringObjectStore,
-    raw::{ObjectStore, ObjectStoreError},
+    raw::{Bucket, ObjectStore, ObjectStoreError},
     retries::StoreWithRetries,
+    s3::{S3Store, S3StoreAuthMode},
 };
 
 /// Factory of [`ObjectStore`]s that caches the store instance once it's created. Used mainly for legacy reasons.
@@ -61,6 +63,13 @@ impl ObjectStoreFactory {
         config: &ObjectStoreConfig,
     ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
         tracing::trace!("Initializing object store with configuration {config:?}");
+        let store = Self::create_base_store(config).await?;
+        Self::wrap_compression(store, config)
+    }
+
+    async fn create_base_store(
+        config: &ObjectStoreConfig,
+    ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
         match &config.mode {
             ObjectStoreMode::GCS { bucket_base_url } => {
                 let store = StoreWithRetries::try_new(config.max_retries, || {
@@ -97,6 +106,36 @@ impl ObjectStoreFactory {
                 .await?;
                 Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
             }
+            ObjectStoreMode::S3 {
+                bucket_base_url,
+                s3_region,
+                s3_endpoint,
+            } => {
+                let auth_mode = S3StoreAuthMode::Authenticated;
+                Self::create_s3_store(config, auth_mode, bucket_base_url, s3_region, s3_endpoint)
+                    .await
+            }
+            ObjectStoreMode::S3WithCredentialFile {
+                bucket_base_url,
+                s3_region,
+                s3_endpoint,
+                s3_credential_file_path,
+            } => {
+                let auth_mode = S3StoreAuthMode::AuthenticatedWithCredentialFile(
+                    s3_credential_file_path.clone(),
+                );
+                Self::create_s3_store(config, auth_mode, bucket_base_url, s3_region, s3_endpoint)
+                    .await
+            }
+            ObjectStoreMode::S3AnonymousReadOnly {
+                bucket_base_url,
+                s3_region,
+                s3_endpoint,
+            } => {
+                let auth_mode = S3StoreAuthMode::Anonymous;
+                Self::create_s3_store(config, auth_mode, bucket_base_url, s3_region, s3_endpoint)
+                    .await
+            }
 
             ObjectStoreMode::FileBacked {
                 file_backed_base_path,
@@ -114,6 +153,53 @@ impl ObjectStoreFactory {
         }
     }
 
+    async fn create_s3_store(
+        config: &ObjectStoreConfig,
+        auth_mode: S3StoreAuthMode,
+        bucket_name: &str,
+        region: &str,
+        endpoint: &Option<String>,
+    ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
+        let store = StoreWithRetries::try_new(config.max_retries, || {
+            S3Store::new(
+                auth_mode.clone(),
+                bucket_name.to_owned(),
+                region.to_owned(),
+                endpoint.clone(),
+            )
+        })
+        .await?;
+        Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
+    }
+
+    fn wrap_compression(
+        store: Arc<dyn ObjectStore>,
+        config: &ObjectStoreConfig,
+    ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
+        let Some(level) = config.compression_level else {
+            return Ok(store);
+        };
+        let compressed_buckets = if config.compressed_buckets.is_empty() {
+            None
+        } else {
+            let buckets = config
+                .compressed_buckets
+                .iter()
+                .map(|name| name.parse::<Bucket>())
+                .collect::<Result<_, _>>()
+                .map_err(|err| ObjectStoreError::Initialization {
+                    source: err.into(),
+                    is_retriable: false,
+                })?;
+            Some(buckets)
+        };
+        Ok(Arc::new(StoreWithCompression::new(
+            store,
+            level,
+            compressed_buckets,
+        )?))
+    }
+
     async fn wrap_mirroring(
         store: impl ObjectStore,
         mirror_path: Option<&String>,
@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use async_trait::async_trait;
 use tokio::{fs, io};
 
-use crate::raw::{Bucket, ObjectStore, ObjectStoreError};
+use crate::raw::{Bucket, ListedKeys, ObjectStore, ObjectStoreError};
 
 impl From<io::Error> for ObjectStoreError {
     fn from(err: io::Error) -> Self {
@@ -43,6 +43,7 @@ impl FileBackedObjectStore {
             Bucket::ProofsFri,
             Bucket::StorageSnapshot,
             Bucket::VmDumps,
+            Bucket::MerkleTreeCheckpoints,
         ] {
             let bucket_path = format!("{base_dir}/{bucket}");
             fs::create_dir_all(&bucket_path).await?;
@@ -77,6 +78,43 @@ impl ObjectStore for FileBackedObjectStore {
         fs::remove_file(filename).await.map_err(From::from)
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+        page_token: Option<String>,
+    ) -> Result<ListedKeys, ObjectStoreError> {
+        let bucket_dir = format!("{}/{bucket}", self.base_dir);
+        let mut entries = match fs::read_dir(bucket_dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(ListedKeys::default()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut keys = vec![];
+        while let Some(entry) = entries.next_entry().await? {
+            if !entry.file_type().await?.is_file() {
+                continue;
+            }
+            // Non-UTF-8 file names cannot be produced by `put_raw()`, so they are skipped.
+            if let Ok(key) = entry.file_name().into_string() {
+                if key.starts_with(prefix) {
+                    keys.push(key);
+                }
+            }
+        }
+        keys.sort_unstable();
+        Ok(ListedKeys::paginate(
+            keys.into_iter(),
+            page_token.as_deref(),
+        ))
+    }
+
+    async fn exists_raw(&self, bucket: Bucket, key: &str) -> Result<bool, ObjectStoreError> {
+        let filename = self.filename(bucket, key);
+        fs::try_exists(filename).await.map_err(From::from)
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         format!("{}/{}", self.base_dir, bucket)
     }
@@ -117,6 +155,55 @@ mod test {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_list_and_exists() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.into_path().into_os_string().into_string().unwrap();
+        let object_store = FileBackedObjectStore::new(path).await.unwrap();
+        for key in ["b-2.bin", "a-1.bin", "b-1.bin"] {
+            object_store
+                .put_raw(Bucket::ProverJobs, key, vec![0, 1])
+                .await
+                .unwrap();
+        }
+
+        let listed = object_store
+            .list_raw(Bucket::ProverJobs, "b-", None)
+            .await
+            .unwrap();
+        assert_eq!(listed.keys, ["b-1.bin", "b-2.bin"]);
+        assert_eq!(listed.next_page_token, None);
+        let listed = object_store
+            .list_raw(Bucket::ProofsTee, "", None)
+            .await
+            .unwrap();
+        assert_eq!(listed, ListedKeys::default());
+
+        assert!(object_store
+            .exists_raw(Bucket::ProverJobs, "a-1.bin")
+            .await
+            .unwrap());
+        assert!(!object_store
+            .exists_raw(Bucket::ProverJobs, "a-2.bin")
+            .await
+            .unwrap());
+
+        let keys = [
+            "a-1.bin".to_owned(),
+            "b-2.bin".to_owned(),
+            "c.bin".to_owned(),
+        ];
+        object_store
+            .remove_many_raw(Bucket::ProverJobs, &keys)
+            .await
+            .unwrap();
+        let listed = object_store
+            .list_raw(Bucket::ProverJobs, "", None)
+            .await
+            .unwrap();
+        assert_eq!(listed.keys, ["b-1.bin"]);
+    }
+
     #[tokio::test]
     async fn test_remove() {
         let dir = TempDir::new().unwrap();
@@ -1,8 +1,9 @@
 //! This crate provides the [object storage abstraction](ObjectStore) that allows to get,
-//! put and remove binary blobs. The following implementations are available:
+//! put, remove and list binary blobs. The following implementations are available:
 //!
 //! - [File-backed store](FileBackedObjectStore) saving blobs as separate files in the local filesystem
 //! - [GCS-based store](GoogleCloudStore)
+//! - [S3-based store](S3Store), which also works with S3-compatible services (e.g., MinIO)
 //! - [Mock in-memory store](MockObjectStore)
 //!
 //! Normally, these implementations are not used directly. Instead, a store trait object (`Arc<dyn ObjectStore>`)
@@ -23,6 +24,7 @@
     clippy::doc_markdown
 )]
 
+mod compression;
 mod factory;
 mod file;
 mod gcs;
@@ -32,6 +34,7 @@ mod mock;
 mod objects;
 mod raw;
 mod retries;
+mod s3;
 
 // Re-export `bincode` crate so that client binaries can conveniently use it.
 pub use bincode;
@@ -47,5 +50,6 @@ pub use self::{
     gcs::{GoogleCloudStore, GoogleCloudStoreAuthMode},
     mock::MockObjectStore,
     objects::StoredObject,
-    raw::{Bucket, ObjectStore, ObjectStoreError},
+    raw::{Bucket, ListedKeys, ObjectStore, ObjectStoreError},
+    s3::{S3Credentials, S3Store, S3StoreAuthMode},
 };
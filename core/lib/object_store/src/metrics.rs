@@ -2,7 +2,7 @@
 
 use std::time::Duration;
 
-use vise::{Buckets, Histogram, LabeledFamily, LatencyObserver, Metrics};
+use vise::{Buckets, Counter, Histogram, LabeledFamily, LatencyObserver, Metrics};
 
 use crate::Bucket;
 
@@ -15,6 +15,9 @@ pub(crate) struct ObjectStoreMetrics {
     /// Latency to store an object in the store (accounting for retries).
     #[metrics(buckets = Buckets::LATENCIES, labels = ["bucket"])]
     storing_time: LabeledFamily<&'static str, Histogram<Duration>>,
+    /// Number of fetched objects that have failed integrity checks.
+    #[metrics(labels = ["bucket"])]
+    corrupted_objects: LabeledFamily<&'static str, Counter>,
 }
 
 impl ObjectStoreMetrics {
@@ -25,6 +28,10 @@ impl ObjectStoreMetrics {
     pub fn start_store(&self, bucket: Bucket) -> LatencyObserver<'_> {
         self.storing_time[&bucket.as_str()].start()
     }
+
+    pub fn observe_corrupted_object(&self, bucket: Bucket) {
+        self.corrupted_objects[&bucket.as_str()].inc();
+    }
 }
 
 #[vise::register]
@@ -2,7 +2,11 @@
 
 use async_trait::async_trait;
 
-use crate::{file::FileBackedObjectStore, raw::ObjectStore, Bucket, ObjectStoreError};
+use crate::{
+    file::FileBackedObjectStore,
+    raw::{ListedKeys, ObjectStore},
+    Bucket, ObjectStoreError,
+};
 
 #[derive(Debug)]
 pub(crate) struct MirroringObjectStore<S> {
@@ -85,6 +89,57 @@ impl<S: ObjectStore> ObjectStore for MirroringObjectStore<S> {
         Ok(())
     }
 
+    /// Keys are always listed using the underlying store since the mirror may be incomplete.
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+        page_token: Option<String>,
+    ) -> Result<ListedKeys, ObjectStoreError> {
+        self.inner.list_raw(bucket, prefix, page_token).await
+    }
+
+    #[tracing::instrument(name = "MirroringObjectStore::exists_raw", skip(self))]
+    async fn exists_raw(&self, bucket: Bucket, key: &str) -> Result<bool, ObjectStoreError> {
+        match self.mirror_store.exists_raw(bucket, key).await {
+            Ok(true) => {
+                tracing::trace!("object exists in mirror");
+                return Ok(true);
+            }
+            Ok(false) => { /* fall through to the underlying store */ }
+            Err(err) => {
+                tracing::warn!(
+                    "unexpected error calling local mirror store: {:#}",
+                    anyhow::Error::from(err)
+                );
+            }
+        }
+        self.inner.exists_raw(bucket, key).await
+    }
+
+    #[tracing::instrument(
+        name = "MirroringObjectStore::remove_many_raw",
+        skip(self, keys),
+        fields(keys.len = keys.len())
+    )]
+    async fn remove_many_raw(
+        &self,
+        bucket: Bucket,
+        keys: &[String],
+    ) -> Result<(), ObjectStoreError> {
+        self.inner.remove_many_raw(bucket, keys).await?;
+        // Only remove the values from the mirror once they have been removed in the underlying store
+        if let Err(err) = self.mirror_store.remove_many_raw(bucket, keys).await {
+            tracing::warn!(
+                "failed removing objects from mirror: {:#}",
+                anyhow::Error::from(err)
+            );
+        } else {
+            tracing::trace!("removed objects from mirror");
+        }
+        Ok(())
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
@@ -151,4 +206,52 @@ mod tests {
             .unwrap();
         assert_eq!(object, [3, 2, 1]);
     }
+
+    #[tokio::test]
+    async fn mirroring_listing_and_removal() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.into_path().into_os_string().into_string().unwrap();
+
+        let mock_store = MockObjectStore::default();
+        mock_store
+            .put_raw(Bucket::StorageSnapshot, "test", vec![1, 2, 3])
+            .await
+            .unwrap();
+        let mirroring_store = MirroringObjectStore::new(mock_store, path).await.unwrap();
+        mirroring_store
+            .put_raw(Bucket::StorageSnapshot, "other", vec![3, 2, 1])
+            .await
+            .unwrap();
+
+        // `test` is not mirrored, but should be listed nevertheless.
+        let listed = mirroring_store
+            .list_raw(Bucket::StorageSnapshot, "", None)
+            .await
+            .unwrap();
+        assert_eq!(listed.keys, ["other", "test"]);
+        assert!(mirroring_store
+            .exists_raw(Bucket::StorageSnapshot, "test")
+            .await
+            .unwrap());
+        assert!(!mirroring_store
+            .exists_raw(Bucket::StorageSnapshot, "missing")
+            .await
+            .unwrap());
+
+        let keys = ["other".to_owned(), "test".to_owned()];
+        mirroring_store
+            .remove_many_raw(Bucket::StorageSnapshot, &keys)
+            .await
+            .unwrap();
+        assert!(!mirroring_store
+            .mirror_store
+            .exists_raw(Bucket::StorageSnapshot, "other")
+            .await
+            .unwrap());
+        let listed = mirroring_store
+            .list_raw(Bucket::StorageSnapshot, "", None)
+            .await
+            .unwrap();
+        assert!(listed.keys.is_empty());
+    }
 }
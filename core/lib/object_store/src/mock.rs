@@ -5,7 +5,7 @@ use std::{collections::HashMap, sync::Arc};
 use async_trait::async_trait;
 use tokio::sync::Mutex;
 
-use crate::raw::{Bucket, ObjectStore, ObjectStoreError};
+use crate::raw::{Bucket, ListedKeys, ObjectStore, ObjectStoreError};
 
 type BucketMap = HashMap<String, Vec<u8>>;
 
@@ -54,6 +54,34 @@ impl ObjectStore for MockObjectStore {
         Ok(())
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+        page_token: Option<String>,
+    ) -> Result<ListedKeys, ObjectStoreError> {
+        let lock = self.inner.lock().await;
+        let mut keys: Vec<_> = lock
+            .get(&bucket)
+            .into_iter()
+            .flat_map(HashMap::keys)
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect();
+        keys.sort_unstable();
+        Ok(ListedKeys::paginate(
+            keys.into_iter(),
+            page_token.as_deref(),
+        ))
+    }
+
+    async fn exists_raw(&self, bucket: Bucket, key: &str) -> Result<bool, ObjectStoreError> {
+        let lock = self.inner.lock().await;
+        Ok(lock
+            .get(&bucket)
+            .is_some_and(|bucket_map| bucket_map.contains_key(key)))
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         bucket.to_string()
     }
@@ -200,6 +200,83 @@ impl dyn ObjectStore + '_ {
         self.remove_raw(V::BUCKET, &key).await
     }
 
+    /// Checks whether a value associated with the key exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns I/O errors specific to the storage.
+    #[tracing::instrument(
+        name = "ObjectStore::exists",
+        skip_all,
+        fields(key) // Will be recorded within the function.
+    )]
+    pub async fn exists<V: StoredObject>(&self, key: V::Key<'_>) -> Result<bool, ObjectStoreError> {
+        let key = V::encode_key(key);
+        // Record the key for tracing.
+        tracing::Span::current().record("key", key.as_str());
+        self.exists_raw(V::BUCKET, &key).await
+    }
+
+    /// Removes values associated with the provided keys. Missing keys are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns I/O errors specific to the storage.
+    #[tracing::instrument(
+        name = "ObjectStore::remove_many",
+        skip_all,
+        fields(keys.len) // Will be recorded within the function.
+    )]
+    pub async fn remove_many<'a, V: StoredObject>(
+        &self,
+        keys: impl IntoIterator<Item = V::Key<'a>>,
+    ) -> Result<(), ObjectStoreError> {
+        let keys: Vec<_> = keys.into_iter().map(V::encode_key).collect();
+        tracing::Span::current().record("keys.len", keys.len());
+        self.remove_many_raw(V::BUCKET, &keys).await
+    }
+
+    /// Lists all keys in the given bucket starting with `prefix`, walking through all pages
+    /// returned by [`ObjectStore::list_raw()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns I/O errors specific to the storage.
+    pub async fn list_all_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<String>, ObjectStoreError> {
+        let mut keys = vec![];
+        let mut page_token = None;
+        loop {
+            let page = self.list_raw(bucket, prefix, page_token).await?;
+            keys.extend(page.keys);
+            page_token = page.next_page_token;
+            if page_token.is_none() {
+                break Ok(keys);
+            }
+        }
+    }
+
+    /// Lists encoded keys of all stored objects of type `V` starting with `prefix`. The returned keys can be used
+    /// with [`Self::get_by_encoded_key()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns I/O errors specific to the storage.
+    #[tracing::instrument(
+        name = "ObjectStore::list_encoded_keys",
+        skip(self),
+        fields(bucket = %V::BUCKET)
+    )]
+    pub async fn list_encoded_keys<V: StoredObject>(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<String>, ObjectStoreError> {
+        self.list_all_raw(V::BUCKET, prefix).await
+    }
+
     pub fn get_storage_prefix<V: StoredObject>(&self) -> String {
         self.storage_prefix_raw(V::BUCKET)
     }
@@ -214,7 +291,7 @@ mod tests {
     };
 
     use super::*;
-    use crate::MockObjectStore;
+    use crate::{raw::ListedKeys, MockObjectStore};
 
     #[test]
     fn test_storage_logs_filesnames_generate_corretly() {
@@ -290,4 +367,70 @@ mod tests {
         let reconstructed_factory_deps = store.get(key).await.unwrap();
         assert_eq!(factory_deps, reconstructed_factory_deps);
     }
+
+    #[tokio::test]
+    async fn listing_and_removing_typed_objects() {
+        let store = MockObjectStore::arc();
+        let chunk_count = ListedKeys::PAGE_SIZE as u64 + 10;
+        let keys: Vec<_> = (0..chunk_count)
+            .map(|chunk_id| SnapshotStorageLogsStorageKey {
+                l1_batch_number: L1BatchNumber(42),
+                chunk_id,
+            })
+            .collect();
+        let chunk: SnapshotStorageLogsChunk = SnapshotStorageLogsChunk {
+            storage_logs: vec![],
+        };
+        for &key in &keys {
+            store.put(key, &chunk).await.unwrap();
+        }
+        let other_key = SnapshotStorageLogsStorageKey {
+            l1_batch_number: L1BatchNumber(4),
+            chunk_id: 0,
+        };
+        store.put(other_key, &chunk).await.unwrap();
+        let factory_deps = SnapshotFactoryDependencies {
+            factory_deps: vec![],
+        };
+        store.put(L1BatchNumber(42), &factory_deps).await.unwrap();
+
+        let listed_keys = store
+            .list_encoded_keys::<SnapshotStorageLogsChunk>("snapshot_l1_batch_42_storage_logs_")
+            .await
+            .unwrap();
+        let expected_keys: Vec<_> = keys
+            .iter()
+            .map(|&key| <SnapshotStorageLogsChunk>::encode_key(key))
+            .collect();
+        assert_eq!(listed_keys, expected_keys);
+
+        assert!(store
+            .exists::<SnapshotStorageLogsChunk>(keys[5])
+            .await
+            .unwrap());
+        let missing_key = SnapshotStorageLogsStorageKey {
+            l1_batch_number: L1BatchNumber(42),
+            chunk_id: chunk_count,
+        };
+        assert!(!store
+            .exists::<SnapshotStorageLogsChunk>(missing_key)
+            .await
+            .unwrap());
+
+        store
+            .remove_many::<SnapshotStorageLogsChunk>(keys.iter().copied().chain([missing_key]))
+            .await
+            .unwrap();
+        let listed_keys = store
+            .list_all_raw(Bucket::StorageSnapshot, "snapshot_l1_batch_")
+            .await
+            .unwrap();
+        assert_eq!(
+            listed_keys,
+            [
+                SnapshotFactoryDependencies::encode_key(L1BatchNumber(42)),
+                <SnapshotStorageLogsChunk>::encode_key(other_key),
+            ]
+        );
+    }
 }
@@ -1,6 +1,7 @@
-use std::{error, fmt};
+use std::{error, fmt, str::FromStr};
 
 use async_trait::async_trait;
+use futures::{stream::FuturesUnordered, StreamExt};
 
 /// Bucket for [`ObjectStore`] in which objects can be placed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -20,9 +21,29 @@ pub enum Bucket {
     StorageSnapshot,
     DataAvailability,
     VmDumps,
+    MerkleTreeCheckpoints,
 }
 
 impl Bucket {
+    /// All buckets.
+    pub const ALL: [Self; 15] = [
+        Self::ProverJobs,
+        Self::WitnessInput,
+        Self::LeafAggregationWitnessJobs,
+        Self::NodeAggregationWitnessJobs,
+        Self::SchedulerWitnessJobs,
+        Self::ProverJobsFri,
+        Self::LeafAggregationWitnessJobsFri,
+        Self::NodeAggregationWitnessJobsFri,
+        Self::SchedulerWitnessJobsFri,
+        Self::ProofsFri,
+        Self::ProofsTee,
+        Self::StorageSnapshot,
+        Self::DataAvailability,
+        Self::VmDumps,
+        Self::MerkleTreeCheckpoints,
+    ];
+
     pub(crate) fn as_str(self) -> &'static str {
         match self {
             Self::ProverJobs => "prover_jobs",
@@ -39,8 +60,15 @@ impl Bucket {
             Self::StorageSnapshot => "storage_logs_snapshots",
             Self::DataAvailability => "data_availability",
             Self::VmDumps => "vm_dumps",
+            Self::MerkleTreeCheckpoints => "merkle_tree_checkpoints",
         }
     }
+
+    /// Checks whether objects in this bucket are intended to be read by third parties (e.g., external nodes
+    /// recovering from a snapshot), potentially without using this crate.
+    pub(crate) fn is_public(self) -> bool {
+        matches!(self, Self::StorageSnapshot | Self::MerkleTreeCheckpoints)
+    }
 }
 
 impl fmt::Display for Bucket {
@@ -49,6 +77,17 @@ impl fmt::Display for Bucket {
     }
 }
 
+impl FromStr for Bucket {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|bucket| bucket.as_str() == s)
+            .ok_or_else(|| format!("unknown bucket: `{s}`"))
+    }
+}
+
 /// Thread-safe boxed error.
 pub type BoxedError = Box<dyn error::Error + Send + Sync>;
 
@@ -65,6 +104,8 @@ pub enum ObjectStoreError {
     KeyNotFound(BoxedError),
     /// Object (de)serialization failed.
     Serialization(BoxedError),
+    /// Stored object is corrupted, e.g. its integrity checksum doesn't match its contents.
+    Corrupted(BoxedError),
     /// Other error has occurred when accessing the store (e.g., a network error).
     Other {
         source: BoxedError,
@@ -79,7 +120,7 @@ impl ObjectStoreError {
             Self::Initialization { is_retriable, .. } | Self::Other { is_retriable, .. } => {
                 *is_retriable
             }
-            Self::KeyNotFound(_) | Self::Serialization(_) => false,
+            Self::KeyNotFound(_) | Self::Serialization(_) | Self::Corrupted(_) => false,
         }
     }
 }
@@ -99,6 +140,7 @@ impl fmt::Display for ObjectStoreError {
             }
             Self::KeyNotFound(err) => write!(formatter, "key not found: {err}"),
             Self::Serialization(err) => write!(formatter, "serialization error: {err}"),
+            Self::Corrupted(err) => write!(formatter, "corrupted object: {err}"),
             Self::Other {
                 source,
                 is_retriable,
@@ -116,7 +158,9 @@ impl error::Error for ObjectStoreError {
             Self::Initialization { source, .. } | Self::Other { source, .. } => {
                 Some(source.as_ref())
             }
-            Self::KeyNotFound(err) | Self::Serialization(err) => Some(err.as_ref()),
+            Self::KeyNotFound(err) | Self::Serialization(err) | Self::Corrupted(err) => {
+                Some(err.as_ref())
+            }
         }
     }
 }
@@ -157,5 +201,108 @@ pub trait ObjectStore: 'static + fmt::Debug + Send + Sync {
     /// Returns an error if removal fails.
     async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError>;
 
+    /// Lists a page of keys in the given bucket that start with `prefix`. Keys are returned in the lexicographic order.
+    /// To get the first page, `page_token` should be set to `None`; subsequent pages are retrieved by passing
+    /// [`ListedKeys::next_page_token`] from the previous page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if listing fails.
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+        page_token: Option<String>,
+    ) -> Result<ListedKeys, ObjectStoreError>;
+
+    /// Checks whether an object with the specified key exists in the given bucket.
+    ///
+    /// The default implementation fetches the object using [`Self::get_raw()`]; implementations
+    /// should override it with a more efficient approach if possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object cannot be accessed.
+    async fn exists_raw(&self, bucket: Bucket, key: &str) -> Result<bool, ObjectStoreError> {
+        match self.get_raw(bucket, key).await {
+            Ok(_) => Ok(true),
+            Err(ObjectStoreError::KeyNotFound(_)) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Removes values associated with the specified keys from the given bucket. Unlike [`Self::remove_raw()`],
+    /// missing keys are not treated as an error.
+    ///
+    /// The default implementation calls [`Self::remove_raw()`] for each key with bounded concurrency.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if removal of any key fails. Some keys may be removed in this case.
+    async fn remove_many_raw(
+        &self,
+        bucket: Bucket,
+        keys: &[String],
+    ) -> Result<(), ObjectStoreError> {
+        const MAX_CONCURRENCY: usize = 16;
+
+        fn check_result(result: Result<(), ObjectStoreError>) -> Result<(), ObjectStoreError> {
+            match result {
+                Ok(()) | Err(ObjectStoreError::KeyNotFound(_)) => Ok(()),
+                Err(err) => Err(err),
+            }
+        }
+
+        let mut removals = FuturesUnordered::new();
+        for key in keys {
+            if removals.len() >= MAX_CONCURRENCY {
+                if let Some(result) = removals.next().await {
+                    check_result(result)?;
+                }
+            }
+            removals.push(self.remove_raw(bucket, key));
+        }
+        while let Some(result) = removals.next().await {
+            check_result(result)?;
+        }
+        Ok(())
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String;
 }
+
+/// Page of keys returned by [`ObjectStore::list_raw()`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListedKeys {
+    /// Keys in the lexicographic order.
+    pub keys: Vec<String>,
+    /// Opaque token to retrieve the next page of keys. `None` if this page is the last one.
+    pub next_page_token: Option<String>,
+}
+
+impl ListedKeys {
+    /// Default maximum number of keys in a page for stores that paginate keys themselves.
+    pub(crate) const PAGE_SIZE: usize = 1_000;
+
+    /// Paginates sorted `keys` returned by a store that doesn't support pagination natively.
+    /// The page token is the last key returned in the previous page.
+    pub(crate) fn paginate(
+        sorted_keys: impl Iterator<Item = String>,
+        page_token: Option<&str>,
+    ) -> Self {
+        let mut keys: Vec<_> = sorted_keys
+            .skip_while(|key| page_token.is_some_and(|token| key.as_str() <= token))
+            .take(Self::PAGE_SIZE + 1)
+            .collect();
+        let next_page_token = if keys.len() > Self::PAGE_SIZE {
+            keys.truncate(Self::PAGE_SIZE);
+            keys.last().cloned()
+        } else {
+            None
+        };
+        Self {
+            keys,
+            next_page_token,
+        }
+    }
+}
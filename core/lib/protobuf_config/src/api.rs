@@ -56,6 +56,23 @@ impl ProtoRepr for proto::Web3JsonRpc {
             })
             .collect::<anyhow::Result<_>>()
             .context("max_response_body_size_overrides")?;
+        let rate_limit_method_costs = self
+            .rate_limit_method_costs
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let cost = *required(&entry.cost).with_context(|| format!("[{i}].cost"))?;
+                let cost = NonZeroU32::new(cost).with_context(|| format!("[{i}].cost is zero"))?;
+                Ok((
+                    entry
+                        .method
+                        .clone()
+                        .with_context(|| format!("[{i}].method"))?,
+                    cost,
+                ))
+            })
+            .collect::<anyhow::Result<_>>()
+            .context("rate_limit_method_costs")?;
         let api_namespaces = if self.api_namespaces.is_empty() {
             None
         } else {
@@ -119,6 +136,17 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .transpose()
                 .context("latest_values_max_block_lag")?,
             fee_history_limit: self.fee_history_limit,
+            simulate_blocks_limit: self
+                .simulate_blocks_limit
+                .map(|x| x.try_into())
+                .transpose()
+                .context("simulate_blocks_limit")?,
+            simulate_calls_limit: self
+                .simulate_calls_limit
+                .map(|x| x.try_into())
+                .transpose()
+                .context("simulate_calls_limit")?,
+            simulate_gas_limit: self.simulate_gas_limit,
             max_batch_request_size: self
                 .max_batch_request_size
                 .map(|x| x.try_into())
@@ -135,6 +163,13 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .map(|x| x.try_into())
                 .transpose()
                 .context("websocket_requests_per_minute_limit")?,
+            client_requests_per_minute_limit: self
+                .client_requests_per_minute_limit
+                .map(|x| x.try_into())
+                .transpose()
+                .context("client_requests_per_minute_limit")?,
+            trust_forwarded_for_header: self.trust_forwarded_for_header.unwrap_or(false),
+            rate_limit_method_costs,
             tree_api_url: self.tree_api_url.clone(),
             mempool_cache_update_interval: self.mempool_cache_update_interval,
             mempool_cache_size: self
@@ -190,6 +225,9 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .map(|x| x.try_into().unwrap()),
             latest_values_max_block_lag: this.latest_values_max_block_lag.map(NonZeroU32::get),
             fee_history_limit: this.fee_history_limit,
+            simulate_blocks_limit: this.simulate_blocks_limit.map(|x| x.try_into().unwrap()),
+            simulate_calls_limit: this.simulate_calls_limit.map(|x| x.try_into().unwrap()),
+            simulate_gas_limit: this.simulate_gas_limit,
             max_batch_request_size: this.max_batch_request_size.map(|x| x.try_into().unwrap()),
             max_response_body_size_mb: this
                 .max_response_body_size_mb
@@ -209,6 +247,18 @@ impl ProtoRepr for proto::Web3JsonRpc {
             websocket_requests_per_minute_limit: this
                 .websocket_requests_per_minute_limit
                 .map(|x| x.into()),
+            client_requests_per_minute_limit: this
+                .client_requests_per_minute_limit
+                .map(NonZeroU32::get),
+            trust_forwarded_for_header: Some(this.trust_forwarded_for_header),
+            rate_limit_method_costs: this
+                .rate_limit_method_costs
+                .iter()
+                .map(|(method, cost)| proto::RateLimitMethodCost {
+                    method: Some(method.to_owned()),
+                    cost: Some(cost.get()),
+                })
+                .collect(),
             tree_api_url: this.tree_api_url.clone(),
             whitelisted_tokens_for_aa: this
                 .whitelisted_tokens_for_aa
@@ -22,6 +22,24 @@ impl proto::FeeModelVersion {
     }
 }
 
+impl proto::MempoolOrdering {
+    fn new(n: &configs::chain::MempoolOrdering) -> Self {
+        use configs::chain::MempoolOrdering as From;
+        match n {
+            From::Fifo => Self::Fifo,
+            From::FeePriority => Self::FeePriority,
+        }
+    }
+
+    fn parse(&self) -> configs::chain::MempoolOrdering {
+        use configs::chain::MempoolOrdering as To;
+        match self {
+            Self::Fifo => To::Fifo,
+            Self::FeePriority => To::FeePriority,
+        }
+    }
+}
+
 impl ProtoRepr for proto::StateKeeper {
     type Type = configs::chain::StateKeeperConfig;
     fn read(&self) -> anyhow::Result<Self::Type> {
@@ -151,6 +169,14 @@ impl ProtoRepr for proto::Mempool {
             stuck_tx_timeout: *required(&self.stuck_tx_timeout).context("stuck_tx_timeout")?,
             remove_stuck_txs: *required(&self.remove_stuck_txs).context("remove_stuck_txs")?,
             delay_interval: *required(&self.delay_interval).context("delay_interval")?,
+            ordering: self
+                .ordering
+                .map(proto::MempoolOrdering::try_from)
+                .transpose()
+                .context("ordering")?
+                .map_or_else(Default::default, |ordering| ordering.parse()),
+            max_pending_txs_per_account: self.max_pending_txs_per_account,
+            replacement_fee_bump_percent: self.replacement_fee_bump_percent,
         })
     }
 
@@ -162,6 +188,9 @@ impl ProtoRepr for proto::Mempool {
             stuck_tx_timeout: Some(this.stuck_tx_timeout),
             remove_stuck_txs: Some(this.remove_stuck_txs),
             delay_interval: Some(this.delay_interval),
+            ordering: Some(proto::MempoolOrdering::new(&this.ordering).into()),
+            max_pending_txs_per_account: this.max_pending_txs_per_account,
+            replacement_fee_bump_percent: this.replacement_fee_bump_percent,
         }
     }
 }
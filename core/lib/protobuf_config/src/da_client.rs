@@ -1,11 +1,16 @@
+use std::str::FromStr;
+
 use anyhow::Context;
+use zksync_basic_types::url::SensitiveUrl;
 use zksync_config::configs::{
     self,
     da_client::{
         avail::{AvailClientConfig, AvailConfig, AvailDefaultConfig, AvailGasRelayConfig},
         celestia::CelestiaConfig,
+        composite::CompositeConfig,
         eigen::EigenConfig,
-        DAClientConfig::{Avail, Celestia, Eigen, ObjectStore},
+        sidecar::SidecarConfig,
+        DAClientConfig::{Avail, Celestia, Composite, Eigen, ObjectStore, Sidecar},
     },
 };
 use zksync_protobuf::{required, ProtoRepr};
@@ -62,6 +67,24 @@ impl ProtoRepr for proto::DataAvailabilityClient {
             proto::data_availability_client::Config::ObjectStore(conf) => {
                 ObjectStore(object_store_proto::ObjectStore::read(conf)?)
             }
+            proto::data_availability_client::Config::Sidecar(conf) => Sidecar(SidecarConfig {
+                api_url: SensitiveUrl::from_str(required(&conf.api_url).context("api_url")?)
+                    .context("api_url")?,
+                timeout_ms: *required(&conf.timeout_ms).context("timeout_ms")?,
+            }),
+            proto::data_availability_client::Config::Composite(conf) => {
+                Composite(CompositeConfig {
+                    clients: conf
+                        .clients
+                        .iter()
+                        .enumerate()
+                        .map(|(i, client)| client.read().with_context(|| format!("clients[{i}]")))
+                        .collect::<anyhow::Result<_>>()?,
+                    quorum: required(&conf.quorum)
+                        .and_then(|&quorum| Ok(quorum.try_into()?))
+                        .context("quorum")?,
+                })
+            }
         };
 
         Ok(client)
@@ -102,6 +125,18 @@ impl ProtoRepr for proto::DataAvailabilityClient {
             ObjectStore(config) => proto::data_availability_client::Config::ObjectStore(
                 object_store_proto::ObjectStore::build(config),
             ),
+            Sidecar(config) => {
+                proto::data_availability_client::Config::Sidecar(proto::SidecarConfig {
+                    api_url: Some(config.api_url.expose_str().to_owned()),
+                    timeout_ms: Some(config.timeout_ms),
+                })
+            }
+            Composite(config) => {
+                proto::data_availability_client::Config::Composite(proto::CompositeConfig {
+                    clients: config.clients.iter().map(ProtoRepr::build).collect(),
+                    quorum: Some(config.quorum as u64),
+                })
+            }
         };
 
         Self {
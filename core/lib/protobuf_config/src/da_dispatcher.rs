@@ -12,6 +12,9 @@ impl ProtoRepr for proto::DataAvailabilityDispatcher {
             max_rows_to_dispatch: self.max_rows_to_dispatch,
             max_retries: self.max_retries.map(|x| x as u16),
             use_dummy_inclusion_data: self.use_dummy_inclusion_data,
+            inclusion_timeout_ms: self.inclusion_timeout_ms,
+            max_concurrent_inclusion_requests: self.max_concurrent_inclusion_requests,
+            split_oversized_blobs: self.split_oversized_blobs,
         })
     }
 
@@ -21,6 +24,9 @@ impl ProtoRepr for proto::DataAvailabilityDispatcher {
             max_rows_to_dispatch: this.max_rows_to_dispatch,
             max_retries: this.max_retries.map(Into::into),
             use_dummy_inclusion_data: this.use_dummy_inclusion_data,
+            inclusion_timeout_ms: this.inclusion_timeout_ms,
+            max_concurrent_inclusion_requests: this.max_concurrent_inclusion_requests,
+            split_oversized_blobs: this.split_oversized_blobs,
         }
     }
 }
@@ -34,6 +34,15 @@ impl ProtoRepr for proto::ExternalNode {
             main_node_rate_limit_rps: self
                 .main_node_rate_limit_rps
                 .and_then(|a| NonZeroUsize::new(a as usize)),
+            main_node_fallback_urls: self
+                .main_node_fallback_urls
+                .iter()
+                .enumerate()
+                .map(|(i, url)| {
+                    SensitiveUrl::from_str(url)
+                        .with_context(|| format!("main_node_fallback_urls[{i}]"))
+                })
+                .collect::<anyhow::Result<_>>()?,
             gateway_url: self
                 .gateway_url
                 .as_ref()
@@ -42,6 +51,9 @@ impl ProtoRepr for proto::ExternalNode {
             bridge_addresses_refresh_interval_sec: self
                 .bridge_addresses_refresh_interval_sec
                 .and_then(NonZeroU64::new),
+            auto_rollback_on_reorg: self.auto_rollback_on_reorg,
+            max_auto_rollback_depth: self.max_auto_rollback_depth,
+            merkle_tree_restore_from_checkpoint: self.merkle_tree_restore_from_checkpoint,
         })
     }
 
@@ -57,6 +69,11 @@ impl ProtoRepr for proto::ExternalNode {
                 .into(),
             ),
             main_node_rate_limit_rps: this.main_node_rate_limit_rps.map(|a| a.get() as u64),
+            main_node_fallback_urls: this
+                .main_node_fallback_urls
+                .iter()
+                .map(|url| url.expose_str().to_string())
+                .collect(),
             gateway_url: this
                 .gateway_url
                 .as_ref()
@@ -64,6 +81,9 @@ impl ProtoRepr for proto::ExternalNode {
             bridge_addresses_refresh_interval_sec: this
                 .bridge_addresses_refresh_interval_sec
                 .map(|a| a.get()),
+            auto_rollback_on_reorg: this.auto_rollback_on_reorg,
+            max_auto_rollback_depth: this.max_auto_rollback_depth,
+            merkle_tree_restore_from_checkpoint: this.merkle_tree_restore_from_checkpoint,
         }
     }
 }
@@ -1,9 +1,12 @@
+use std::str::FromStr;
+
 use anyhow::Context as _;
+use zksync_basic_types::url::SensitiveUrl;
 use zksync_config::configs::{self};
 use zksync_protobuf::{required, ProtoRepr};
 use zksync_types::pubdata_da::PubdataSendingMode;
 
-use crate::{proto::eth as proto, read_optional_repr};
+use crate::{parse_h160, proto::eth as proto, read_optional_repr};
 
 impl proto::ProofSendingMode {
     fn new(x: &configs::eth_sender::ProofSendingMode) -> Self {
@@ -25,6 +28,28 @@ impl proto::ProofSendingMode {
     }
 }
 
+impl proto::FeeBumpingMode {
+    fn new(x: &configs::eth_sender::FeeBumpingMode) -> Self {
+        use configs::eth_sender::FeeBumpingMode as From;
+        match x {
+            From::Legacy => Self::Legacy,
+            From::Linear => Self::Linear,
+            From::Exponential => Self::Exponential,
+            From::Percentile => Self::Percentile,
+        }
+    }
+
+    fn parse(&self) -> configs::eth_sender::FeeBumpingMode {
+        use configs::eth_sender::FeeBumpingMode as To;
+        match self {
+            Self::Legacy => To::Legacy,
+            Self::Linear => To::Linear,
+            Self::Exponential => To::Exponential,
+            Self::Percentile => To::Percentile,
+        }
+    }
+}
+
 impl proto::PubdataSendingMode {
     fn new(x: &PubdataSendingMode) -> Self {
         match x {
@@ -53,6 +78,7 @@ impl ProtoRepr for proto::Eth {
             sender: read_optional_repr(&self.sender),
             gas_adjuster: read_optional_repr(&self.gas_adjuster),
             watcher: read_optional_repr(&self.watcher),
+            remote_signer: read_optional_repr(&self.remote_signer),
         })
     }
 
@@ -61,6 +87,7 @@ impl ProtoRepr for proto::Eth {
             sender: this.sender.as_ref().map(ProtoRepr::build),
             gas_adjuster: this.gas_adjuster.as_ref().map(ProtoRepr::build),
             watcher: this.watcher.as_ref().map(ProtoRepr::build),
+            remote_signer: this.remote_signer.as_ref().map(ProtoRepr::build),
         }
     }
 }
@@ -117,6 +144,24 @@ impl ProtoRepr for proto::Sender {
             time_in_mempool_in_l1_blocks_cap: self
                 .time_in_mempool_in_l1_blocks_cap
                 .unwrap_or(Self::Type::default_time_in_mempool_in_l1_blocks_cap()),
+            max_commit_txs_in_flight: self.max_commit_txs_in_flight,
+            max_prove_txs_in_flight: self.max_prove_txs_in_flight,
+            max_execute_txs_in_flight: self.max_execute_txs_in_flight,
+            fee_bumping_mode: self
+                .fee_bumping_mode
+                .map(proto::FeeBumpingMode::try_from)
+                .transpose()
+                .context("fee_bumping_mode")?
+                .map_or_else(Default::default, |mode| mode.parse()),
+            fee_bump_percent: self
+                .fee_bump_percent
+                .unwrap_or(Self::Type::default_fee_bump_percent()),
+            max_fee_bump_multiplier: self
+                .max_fee_bump_multiplier
+                .unwrap_or(Self::Type::default_max_fee_bump_multiplier()),
+            fee_bump_percentile: self
+                .fee_bump_percentile
+                .unwrap_or(Self::Type::default_fee_bump_percentile()),
         })
     }
 
@@ -150,6 +195,13 @@ impl ProtoRepr for proto::Sender {
             tx_aggregation_only_prove_and_execute: Some(this.tx_aggregation_only_prove_and_execute),
             tx_aggregation_paused: Some(this.tx_aggregation_paused),
             time_in_mempool_in_l1_blocks_cap: Some(this.time_in_mempool_in_l1_blocks_cap),
+            max_commit_txs_in_flight: this.max_commit_txs_in_flight,
+            max_prove_txs_in_flight: this.max_prove_txs_in_flight,
+            max_execute_txs_in_flight: this.max_execute_txs_in_flight,
+            fee_bumping_mode: Some(proto::FeeBumpingMode::new(&this.fee_bumping_mode).into()),
+            fee_bump_percent: Some(this.fee_bump_percent),
+            max_fee_bump_multiplier: Some(this.max_fee_bump_multiplier),
+            fee_bump_percentile: Some(this.fee_bump_percentile),
         }
     }
 }
@@ -228,3 +280,50 @@ impl ProtoRepr for proto::EthWatch {
         }
     }
 }
+
+impl ProtoRepr for proto::RemoteSigner {
+    type Type = configs::eth_sender::RemoteSignerConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            url: SensitiveUrl::from_str(required(&self.url).context("url")?).context("url")?,
+            operator_address: required(&self.operator_address)
+                .and_then(|addr| parse_h160(addr))
+                .context("operator_address")?,
+            blob_operator_address: self
+                .blob_operator_address
+                .as_ref()
+                .map(|addr| parse_h160(addr))
+                .transpose()
+                .context("blob_operator_address")?,
+            prove_operator_address: self
+                .prove_operator_address
+                .as_ref()
+                .map(|addr| parse_h160(addr))
+                .transpose()
+                .context("prove_operator_address")?,
+            execute_operator_address: self
+                .execute_operator_address
+                .as_ref()
+                .map(|addr| parse_h160(addr))
+                .transpose()
+                .context("execute_operator_address")?,
+            request_timeout_ms: self
+                .request_timeout_ms
+                .unwrap_or(Self::Type::default_request_timeout_ms()),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            url: Some(this.url.expose_str().to_owned()),
+            operator_address: Some(format!("{:?}", this.operator_address)),
+            blob_operator_address: this.blob_operator_address.map(|addr| format!("{addr:?}")),
+            prove_operator_address: this.prove_operator_address.map(|addr| format!("{addr:?}")),
+            execute_operator_address: this
+                .execute_operator_address
+                .map(|addr| format!("{addr:?}")),
+            request_timeout_ms: Some(this.request_timeout_ms),
+        }
+    }
+}
@@ -37,6 +37,34 @@ impl ProtoRepr for proto::ObjectStore {
                     .context("file_backed_base_path")?
                     .clone(),
             },
+            proto::object_store::Mode::S3(mode) => ObjectStoreMode::S3 {
+                bucket_base_url: required(&mode.bucket_base_url)
+                    .context("bucket_base_url")?
+                    .clone(),
+                s3_region: required(&mode.region).context("region")?.clone(),
+                s3_endpoint: mode.endpoint.clone(),
+            },
+            proto::object_store::Mode::S3WithCredentialFile(mode) => {
+                ObjectStoreMode::S3WithCredentialFile {
+                    bucket_base_url: required(&mode.bucket_base_url)
+                        .context("bucket_base_url")?
+                        .clone(),
+                    s3_region: required(&mode.region).context("region")?.clone(),
+                    s3_endpoint: mode.endpoint.clone(),
+                    s3_credential_file_path: required(&mode.credential_file_path)
+                        .context("credential_file_path")?
+                        .clone(),
+                }
+            }
+            proto::object_store::Mode::S3AnonymousReadOnly(mode) => {
+                ObjectStoreMode::S3AnonymousReadOnly {
+                    bucket_base_url: required(&mode.bucket_base_url)
+                        .context("bucket_base_url")?
+                        .clone(),
+                    s3_region: required(&mode.region).context("region")?.clone(),
+                    s3_endpoint: mode.endpoint.clone(),
+                }
+            }
         };
 
         Ok(Self::Type {
@@ -45,6 +73,8 @@ impl ProtoRepr for proto::ObjectStore {
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("max_retries")?,
             local_mirror_path: self.local_mirror_path.clone(),
+            compression_level: self.compression_level,
+            compressed_buckets: self.compressed_buckets.clone(),
         })
     }
 
@@ -76,12 +106,47 @@ impl ProtoRepr for proto::ObjectStore {
             } => proto::object_store::Mode::FileBacked(proto::object_store::FileBacked {
                 file_backed_base_path: Some(file_backed_base_path.clone()),
             }),
+            ObjectStoreMode::S3 {
+                bucket_base_url,
+                s3_region,
+                s3_endpoint,
+            } => proto::object_store::Mode::S3(proto::object_store::S3 {
+                bucket_base_url: Some(bucket_base_url.clone()),
+                region: Some(s3_region.clone()),
+                endpoint: s3_endpoint.clone(),
+            }),
+            ObjectStoreMode::S3WithCredentialFile {
+                bucket_base_url,
+                s3_region,
+                s3_endpoint,
+                s3_credential_file_path,
+            } => proto::object_store::Mode::S3WithCredentialFile(
+                proto::object_store::S3WithCredentialFile {
+                    bucket_base_url: Some(bucket_base_url.clone()),
+                    region: Some(s3_region.clone()),
+                    endpoint: s3_endpoint.clone(),
+                    credential_file_path: Some(s3_credential_file_path.clone()),
+                },
+            ),
+            ObjectStoreMode::S3AnonymousReadOnly {
+                bucket_base_url,
+                s3_region,
+                s3_endpoint,
+            } => proto::object_store::Mode::S3AnonymousReadOnly(
+                proto::object_store::S3AnonymousReadOnly {
+                    bucket_base_url: Some(bucket_base_url.clone()),
+                    region: Some(s3_region.clone()),
+                    endpoint: s3_endpoint.clone(),
+                },
+            ),
         };
 
         Self {
             mode: Some(mode),
             max_retries: Some(this.max_retries.into()),
             local_mirror_path: this.local_mirror_path.clone(),
+            compression_level: this.compression_level,
+            compressed_buckets: this.compressed_buckets.clone(),
         }
     }
 }
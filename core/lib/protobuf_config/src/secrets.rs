@@ -8,7 +8,10 @@ use zksync_basic_types::{
 };
 use zksync_config::configs::{
     consensus::{AttesterSecretKey, ConsensusSecrets, NodeSecretKey, ValidatorSecretKey},
-    da_client::{avail::AvailSecrets, celestia::CelestiaSecrets, eigen::EigenSecrets},
+    da_client::{
+        avail::AvailSecrets, celestia::CelestiaSecrets, composite::CompositeSecrets,
+        eigen::EigenSecrets,
+    },
     secrets::{DataAvailabilitySecrets, Secrets},
     DatabaseSecrets, L1Secrets,
 };
@@ -86,12 +89,28 @@ impl ProtoRepr for proto::L1Secrets {
     fn read(&self) -> anyhow::Result<Self::Type> {
         Ok(Self::Type {
             l1_rpc_url: SensitiveUrl::from_str(required(&self.l1_rpc_url).context("l1_rpc_url")?)?,
+            fallback_l1_rpc_urls: self
+                .fallback_l1_rpc_urls
+                .iter()
+                .enumerate()
+                .map(|(i, url)| {
+                    SensitiveUrl::from_str(url)
+                        .with_context(|| format!("fallback_l1_rpc_urls[{i}]"))
+                })
+                .collect::<anyhow::Result<_>>()?,
+            cross_check_l1_block_hashes: self.cross_check_l1_block_hashes.unwrap_or(false),
         })
     }
 
     fn build(this: &Self::Type) -> Self {
         Self {
             l1_rpc_url: Some(this.l1_rpc_url.expose_str().to_string()),
+            fallback_l1_rpc_urls: this
+                .fallback_l1_rpc_urls
+                .iter()
+                .map(|url| url.expose_str().to_string())
+                .collect(),
+            cross_check_l1_block_hashes: Some(this.cross_check_l1_block_hashes),
         }
     }
 }
@@ -138,6 +157,24 @@ impl ProtoRepr for proto::DataAvailabilitySecrets {
                     required(&eigen.private_key).context("private_key")?,
                 )?,
             }),
+            DaSecrets::Composite(composite) => {
+                DataAvailabilitySecrets::Composite(CompositeSecrets {
+                    secrets: composite
+                        .backends
+                        .iter()
+                        .enumerate()
+                        .map(|(i, secrets)| {
+                            // Backends without secrets are represented by empty messages.
+                            secrets
+                                .da_secrets
+                                .is_some()
+                                .then(|| secrets.read())
+                                .transpose()
+                                .with_context(|| format!("backends[{i}]"))
+                        })
+                        .collect::<anyhow::Result<_>>()?,
+                })
+            }
         };
 
         Ok(client)
@@ -187,6 +224,15 @@ impl ProtoRepr for proto::DataAvailabilitySecrets {
             DataAvailabilitySecrets::Eigen(config) => Some(DaSecrets::Eigen(proto::EigenSecret {
                 private_key: Some(config.private_key.0.expose_secret().to_string()),
             })),
+            DataAvailabilitySecrets::Composite(config) => {
+                Some(DaSecrets::Composite(proto::CompositeSecret {
+                    backends: config
+                        .secrets
+                        .iter()
+                        .map(|secrets| secrets.as_ref().map(ProtoRepr::build).unwrap_or_default())
+                        .collect(),
+                }))
+            }
         };
 
         Self {
@@ -24,6 +24,9 @@ impl ProtoRepr for proto::SnapshotsCreator {
                 .context("storage_logs_chunk_size")?,
             concurrent_queries_count: *required(&self.concurrent_queries_count)
                 .context("concurrent_queries_count")?,
+            max_incremental_chain_length: self
+                .max_incremental_chain_length
+                .unwrap_or_else(Self::Type::default_max_incremental_chain_length),
             object_store,
         })
     }
@@ -34,6 +37,7 @@ impl ProtoRepr for proto::SnapshotsCreator {
             l1_batch_number: this.l1_batch_number.map(|num| num.0),
             storage_logs_chunk_size: Some(this.storage_logs_chunk_size),
             concurrent_queries_count: Some(this.concurrent_queries_count),
+            max_incremental_chain_length: Some(this.max_incremental_chain_length),
             object_store: this.object_store.as_ref().map(ProtoRepr::build),
         }
     }
@@ -8,21 +8,26 @@ use zksync_types::{Address, K256PrivateKey};
 
 use crate::{parse_h160, parse_h256, proto::wallets as proto};
 
+fn read_optional_pk_wallet(
+    wallet: Option<&proto::PrivateKeyWallet>,
+    name: &str,
+) -> anyhow::Result<Option<Wallet>> {
+    wallet
+        .map(|wallet| {
+            Wallet::from_private_key_bytes(
+                parse_h256(required(&wallet.private_key).context(name.to_owned())?)?,
+                wallet.address.as_ref().and_then(|a| parse_h160(a).ok()),
+            )
+        })
+        .transpose()
+}
+
 impl ProtoRepr for proto::Wallets {
     type Type = configs::wallets::Wallets;
     fn read(&self) -> anyhow::Result<Self::Type> {
         let eth_sender = if self.operator.is_some() && self.blob_operator.is_some() {
-            let blob_operator = if let Some(blob_operator) = &self.blob_operator {
-                Some(Wallet::from_private_key_bytes(
-                    parse_h256(required(&blob_operator.private_key).context("blob operator")?)?,
-                    blob_operator
-                        .address
-                        .as_ref()
-                        .and_then(|a| parse_h160(a).ok()),
-                )?)
-            } else {
-                None
-            };
+            let blob_operator =
+                read_optional_pk_wallet(self.blob_operator.as_ref(), "blob operator")?;
 
             let operator_wallet = &self.operator.clone().context("Operator private key")?;
 
@@ -37,6 +42,14 @@ impl ProtoRepr for proto::Wallets {
             Some(EthSender {
                 operator,
                 blob_operator,
+                prove_operator: read_optional_pk_wallet(
+                    self.prove_operator.as_ref(),
+                    "prove operator",
+                )?,
+                execute_operator: read_optional_pk_wallet(
+                    self.execute_operator.as_ref(),
+                    "execute operator",
+                )?,
             })
         } else {
             None
@@ -86,22 +99,25 @@ impl ProtoRepr for proto::Wallets {
             }
         };
 
-        let (operator, blob_operator) = if let Some(eth_sender) = &this.eth_sender {
-            let blob = eth_sender
-                .blob_operator
-                .as_ref()
-                .map(|blob| create_pk_wallet(blob.address(), blob.private_key()));
-            (
-                Some(create_pk_wallet(
-                    eth_sender.operator.address(),
-                    eth_sender.operator.private_key(),
-                )),
-                blob,
-            )
-        } else {
-            (None, None)
+        let create_optional_pk_wallet = |wallet: Option<&Wallet>| {
+            wallet.map(|wallet| create_pk_wallet(wallet.address(), wallet.private_key()))
         };
 
+        let (operator, blob_operator, prove_operator, execute_operator) =
+            if let Some(eth_sender) = &this.eth_sender {
+                (
+                    Some(create_pk_wallet(
+                        eth_sender.operator.address(),
+                        eth_sender.operator.private_key(),
+                    )),
+                    create_optional_pk_wallet(eth_sender.blob_operator.as_ref()),
+                    create_optional_pk_wallet(eth_sender.prove_operator.as_ref()),
+                    create_optional_pk_wallet(eth_sender.execute_operator.as_ref()),
+                )
+            } else {
+                (None, None, None, None)
+            };
+
         let fee_account = this
             .state_keeper
             .as_ref()
@@ -122,6 +138,8 @@ impl ProtoRepr for proto::Wallets {
         Self {
             blob_operator,
             operator,
+            prove_operator,
+            execute_operator,
             fee_account,
             token_multiplier_setter,
         }
@@ -407,13 +407,20 @@ impl SnapshotsApplierTask {
     }
 }
 
-/// Strategy determining how snapshot recovery should proceed.
+/// Snapshot applied during recovery.
 #[derive(Debug, Clone, Copy)]
+struct SnapshotLayer {
+    l1_batch_number: L1BatchNumber,
+    version: SnapshotVersion,
+}
+
+/// Strategy determining how snapshot recovery should proceed.
+#[derive(Debug, Clone)]
 enum SnapshotRecoveryStrategy {
-    /// Snapshot recovery should proceed from scratch with the specified params.
-    New(SnapshotVersion),
-    /// Snapshot recovery should continue with the specified params.
-    Resumed(SnapshotVersion),
+    /// Snapshot recovery should proceed from scratch with the specified snapshot layers.
+    New(Vec<SnapshotLayer>),
+    /// Snapshot recovery should continue with the specified snapshot layers.
+    Resumed(Vec<SnapshotLayer>),
     /// Snapshot recovery has already been completed.
     Completed,
 }
@@ -446,11 +453,12 @@ impl SnapshotRecoveryStrategy {
                 })?;
             // Old snapshots can theoretically be removed by the node, but in this case the snapshot data may be removed as well,
             // so returning an error looks appropriate here.
-            let snapshot_version = Self::check_snapshot_version(snapshot_header.version)?;
+            let snapshot_layers =
+                Self::fetch_snapshot_layers(main_node_client, snapshot_header).await?;
 
             let latency = latency.observe();
             tracing::info!("Re-initialized snapshots applier after reset/failure in {latency:?}");
-            Ok((Self::Resumed(snapshot_version), applied_snapshot_status))
+            Ok((Self::Resumed(snapshot_layers), applied_snapshot_status))
         } else {
             let is_genesis_needed = storage.blocks_dal().is_genesis_needed().await?;
             if !is_genesis_needed {
@@ -460,7 +468,7 @@ impl SnapshotRecoveryStrategy {
                 return Err(SnapshotsApplierError::Fatal(err));
             }
 
-            let (recovery_status, snapshot_version) =
+            let (recovery_status, snapshot_layers) =
                 Self::create_fresh_recovery_status(main_node_client, snapshot_l1_batch).await?;
 
             let storage_logs_count = storage
@@ -478,14 +486,14 @@ impl SnapshotRecoveryStrategy {
 
             let latency = latency.observe();
             tracing::info!("Initialized fresh snapshots applier in {latency:?}");
-            Ok((Self::New(snapshot_version), recovery_status))
+            Ok((Self::New(snapshot_layers), recovery_status))
         }
     }
 
     async fn create_fresh_recovery_status(
         main_node_client: &dyn SnapshotsApplierMainNodeClient,
         snapshot_l1_batch: Option<L1BatchNumber>,
-    ) -> Result<(SnapshotRecoveryStatus, SnapshotVersion), SnapshotsApplierError> {
+    ) -> Result<(SnapshotRecoveryStatus, Vec<SnapshotLayer>), SnapshotsApplierError> {
         let l1_batch_number = match snapshot_l1_batch {
             Some(num) => num,
             None => main_node_client
@@ -505,7 +513,8 @@ impl SnapshotRecoveryStrategy {
             version = snapshot.version,
             chunk_count = snapshot.storage_logs_chunks.len()
         );
-        let snapshot_version = Self::check_snapshot_version(snapshot.version)?;
+        let storage_logs_chunk_count = snapshot.storage_logs_chunks.len();
+        let snapshot_layers = Self::fetch_snapshot_layers(main_node_client, snapshot).await?;
 
         let l1_batch = main_node_client
             .fetch_l1_batch_details(l1_batch_number)
@@ -537,13 +546,79 @@ impl SnapshotRecoveryStrategy {
             l1_batch_number,
             l1_batch_timestamp: l1_batch.base.timestamp,
             l1_batch_root_hash,
-            l2_block_number: snapshot.l2_block_number,
+            l2_block_number,
             l2_block_timestamp: l2_block.base.timestamp,
             l2_block_hash,
             protocol_version,
-            storage_logs_chunks_processed: vec![false; snapshot.storage_logs_chunks.len()],
+            storage_logs_chunks_processed: vec![false; storage_logs_chunk_count],
         };
-        Ok((status, snapshot_version))
+        Ok((status, snapshot_layers))
+    }
+
+    /// Resolves the chain of base snapshots for the provided snapshot. Returned layers are ordered from the full snapshot
+    /// to the provided one; for full snapshots, the provided snapshot is the only layer.
+    async fn fetch_snapshot_layers(
+        main_node_client: &dyn SnapshotsApplierMainNodeClient,
+        snapshot: SnapshotHeader,
+    ) -> Result<Vec<SnapshotLayer>, SnapshotsApplierError> {
+        let chunk_count = snapshot.storage_logs_chunks.len();
+        let mut layers = vec![SnapshotLayer {
+            l1_batch_number: snapshot.l1_batch_number,
+            version: Self::check_snapshot_version(snapshot.version)?,
+        }];
+        let mut base_l1_batch_number = snapshot.base_l1_batch_number;
+
+        while let Some(l1_batch_number) = base_l1_batch_number {
+            let child = layers.last().unwrap(); // `unwrap()` is safe: `layers` is non-empty
+            if child.version != SnapshotVersion::Version2
+                || l1_batch_number >= child.l1_batch_number
+            {
+                let err = anyhow::anyhow!(
+                    "snapshot for L1 batch #{} with version {:?} cannot have base snapshot for L1 batch #{l1_batch_number}",
+                    child.l1_batch_number,
+                    child.version
+                );
+                return Err(err.into());
+            }
+
+            let base_snapshot = main_node_client
+                .fetch_snapshot(l1_batch_number)
+                .await?
+                .with_context(|| {
+                    format!(
+                        "base snapshot for L1 batch #{l1_batch_number} is not present on main node"
+                    )
+                })?;
+            let version = Self::check_snapshot_version(base_snapshot.version)?;
+            if !version.can_be_base() {
+                let err = anyhow::anyhow!(
+                    "base snapshot for L1 batch #{l1_batch_number} has version {version:?} that doesn't support incremental snapshots"
+                );
+                return Err(err.into());
+            }
+            if base_snapshot.storage_logs_chunks.len() != chunk_count {
+                let err = anyhow::anyhow!(
+                    "base snapshot for L1 batch #{l1_batch_number} has {} storage logs chunks, while the recovered snapshot \
+                     has {chunk_count}",
+                    base_snapshot.storage_logs_chunks.len()
+                );
+                return Err(err.into());
+            }
+
+            tracing::info!(
+                "Snapshot for L1 batch #{} is incremental on top of snapshot for L1 batch #{l1_batch_number}, \
+                 version {version:?}",
+                child.l1_batch_number
+            );
+            layers.push(SnapshotLayer {
+                l1_batch_number,
+                version,
+            });
+            base_l1_batch_number = base_snapshot.base_l1_batch_number;
+        }
+
+        layers.reverse();
+        Ok(layers)
     }
 
     fn check_snapshot_version(raw_version: u16) -> anyhow::Result<SnapshotVersion> {
@@ -553,9 +628,16 @@ impl SnapshotRecoveryStrategy {
             )
         })?;
         anyhow::ensure!(
-            matches!(version, SnapshotVersion::Version0 | SnapshotVersion::Version1),
+            matches!(
+                version,
+                SnapshotVersion::Version0 | SnapshotVersion::Version1 | SnapshotVersion::Version2
+            ),
             "Cannot recover from a snapshot with version {version:?}; the only supported versions are {:?}",
-            [SnapshotVersion::Version0, SnapshotVersion::Version1]
+            [
+                SnapshotVersion::Version0,
+                SnapshotVersion::Version1,
+                SnapshotVersion::Version2
+            ]
         );
         Ok(version)
     }
@@ -579,7 +661,7 @@ impl StorageLogs {
                 let logs: SnapshotStorageLogsChunk<StorageKey> = blob_store.get(key).await?;
                 Ok(Self::V0(logs.storage_logs))
             }
-            SnapshotVersion::Version1 => {
+            SnapshotVersion::Version1 | SnapshotVersion::Version2 => {
                 let logs: SnapshotStorageLogsChunk = blob_store.get(key).await?;
                 Ok(Self::V1(logs.storage_logs))
             }
@@ -618,6 +700,33 @@ impl StorageLogs {
         Ok(())
     }
 
+    /// Applies logs from a chunk of an incremental snapshot on top of this chunk. Both chunks must use hashed keys.
+    fn apply_incremental(self, delta: Self) -> anyhow::Result<Self> {
+        let (Self::V1(base_logs), Self::V1(delta_logs)) = (self, delta) else {
+            anyhow::bail!(
+                "incremental snapshots are only supported for storage logs with hashed keys"
+            );
+        };
+
+        let mut logs_by_key: HashMap<_, _> =
+            base_logs.into_iter().map(|log| (log.key, log)).collect();
+        for log in delta_logs {
+            if let Some(prev_log) = logs_by_key.get(&log.key) {
+                anyhow::ensure!(
+                    prev_log.enumeration_index == log.enumeration_index
+                        && prev_log.l1_batch_number_of_initial_write
+                            == log.l1_batch_number_of_initial_write,
+                    "storage log in incremental snapshot {log:?} is inconsistent with the base snapshot log {prev_log:?}"
+                );
+            }
+            logs_by_key.insert(log.key, log);
+        }
+
+        let mut logs: Vec<_> = logs_by_key.into_values().collect();
+        logs.sort_unstable_by_key(|log| log.enumeration_index);
+        Ok(Self::V1(logs))
+    }
+
     fn drop_key_preimages(&mut self) {
         match self {
             Self::V0(logs) => {
@@ -651,7 +760,8 @@ struct SnapshotsApplier<'a> {
     blob_store: &'a dyn ObjectStore,
     applied_snapshot_status: SnapshotRecoveryStatus,
     health_updater: &'a HealthUpdater,
-    snapshot_version: SnapshotVersion,
+    /// Snapshots applied during recovery, starting from a full snapshot.
+    snapshot_layers: Vec<SnapshotLayer>,
     max_concurrency: usize,
     drop_storage_key_preimages: bool,
     factory_deps_recovered: bool,
@@ -684,10 +794,10 @@ impl<'a> SnapshotsApplier<'a> {
         )
         .await?;
         tracing::info!("Chosen snapshot recovery strategy: {strategy:?} with status: {applied_snapshot_status:?}");
-        let (created_from_scratch, snapshot_version) = match strategy {
+        let (created_from_scratch, snapshot_layers) = match &strategy {
             SnapshotRecoveryStrategy::Completed => return Ok((strategy, applied_snapshot_status)),
-            SnapshotRecoveryStrategy::New(version) => (true, version),
-            SnapshotRecoveryStrategy::Resumed(version) => (false, version),
+            SnapshotRecoveryStrategy::New(layers) => (true, layers.clone()),
+            SnapshotRecoveryStrategy::Resumed(layers) => (false, layers.clone()),
         };
 
         let mut this = Self {
@@ -696,7 +806,7 @@ impl<'a> SnapshotsApplier<'a> {
             blob_store: task.blob_store.as_ref(),
             applied_snapshot_status,
             health_updater,
-            snapshot_version,
+            snapshot_layers,
             max_concurrency: task.config.max_concurrency.get(),
             drop_storage_key_preimages: task.drop_storage_key_preimages,
             factory_deps_recovered: !created_from_scratch,
@@ -782,41 +892,47 @@ impl<'a> SnapshotsApplier<'a> {
     ) -> Result<(), SnapshotsApplierError> {
         let latency = METRICS.initial_stage_duration[&InitialStage::ApplyFactoryDeps].start();
 
-        tracing::debug!("Fetching factory dependencies from object store");
-        let l1_batch_number = self.applied_snapshot_status.l1_batch_number;
-        let factory_deps: SnapshotFactoryDependencies =
-            self.blob_store.get(l1_batch_number).await.map_err(|err| {
-                let context = format!(
-                    "cannot fetch factory deps for L1 batch #{l1_batch_number} from object store"
-                );
-                SnapshotsApplierError::object_store(err, context)
-            })?;
-        tracing::debug!(
-            "Fetched {} factory dependencies from object store",
-            factory_deps.factory_deps.len()
-        );
+        // Incremental snapshots only contain factory deps added after their base snapshot, so deps from all layers are recovered.
+        for layer in &self.snapshot_layers {
+            tracing::debug!(
+                "Fetching factory dependencies for L1 batch #{} from object store",
+                layer.l1_batch_number
+            );
+            let l1_batch_number = layer.l1_batch_number;
+            let factory_deps: SnapshotFactoryDependencies =
+                self.blob_store.get(l1_batch_number).await.map_err(|err| {
+                    let context = format!(
+                        "cannot fetch factory deps for L1 batch #{l1_batch_number} from object store"
+                    );
+                    SnapshotsApplierError::object_store(err, context)
+                })?;
+            tracing::debug!(
+                "Fetched {} factory dependencies from object store",
+                factory_deps.factory_deps.len()
+            );
 
-        // we cannot insert all factory deps because of field size limit triggered by UNNEST
-        // in underlying query, see `https://www.postgresql.org/docs/current/limits.html`
-        // there were around 100 thousand contracts on mainnet, where this issue first manifested
-        for chunk in factory_deps.factory_deps.chunks(1000) {
-            // TODO: bytecode hashing is ambiguous with EVM bytecodes
-            let chunk_deps_hashmap: HashMap<H256, Vec<u8>> = chunk
-                .iter()
-                .map(|dep| {
-                    (
-                        BytecodeHash::for_bytecode(&dep.bytecode.0).value(),
-                        dep.bytecode.0.clone(),
+            // we cannot insert all factory deps because of field size limit triggered by UNNEST
+            // in underlying query, see `https://www.postgresql.org/docs/current/limits.html`
+            // there were around 100 thousand contracts on mainnet, where this issue first manifested
+            for chunk in factory_deps.factory_deps.chunks(1000) {
+                // TODO: bytecode hashing is ambiguous with EVM bytecodes
+                let chunk_deps_hashmap: HashMap<H256, Vec<u8>> = chunk
+                    .iter()
+                    .map(|dep| {
+                        (
+                            BytecodeHash::for_bytecode(&dep.bytecode.0).value(),
+                            dep.bytecode.0.clone(),
+                        )
+                    })
+                    .collect();
+                storage
+                    .factory_deps_dal()
+                    .insert_factory_deps(
+                        self.applied_snapshot_status.l2_block_number,
+                        &chunk_deps_hashmap,
                     )
-                })
-                .collect();
-            storage
-                .factory_deps_dal()
-                .insert_factory_deps(
-                    self.applied_snapshot_status.l2_block_number,
-                    &chunk_deps_hashmap,
-                )
-                .await?;
+                    .await?;
+            }
         }
 
         let latency = latency.observe();
@@ -879,20 +995,30 @@ impl<'a> SnapshotsApplier<'a> {
         let latency =
             METRICS.storage_logs_chunks_duration[&StorageLogsChunksStage::LoadFromGcs].start();
 
-        let storage_key = SnapshotStorageLogsStorageKey {
-            chunk_id,
-            l1_batch_number: self.applied_snapshot_status.l1_batch_number,
-        };
-        let mut storage_logs =
-            StorageLogs::load(self.blob_store, storage_key, self.snapshot_version)
+        // Chunks with the same ID cover the same range of hashed keys for all snapshot layers,
+        // so the chunk can be recovered by applying the corresponding layer chunks on top of each other.
+        let mut storage_logs: Option<StorageLogs> = None;
+        for layer in &self.snapshot_layers {
+            let storage_key = SnapshotStorageLogsStorageKey {
+                chunk_id,
+                l1_batch_number: layer.l1_batch_number,
+            };
+            let layer_logs = StorageLogs::load(self.blob_store, storage_key, layer.version)
                 .await
                 .map_err(|err| {
                     let context =
                         format!("cannot fetch storage logs {storage_key:?} from object store");
                     SnapshotsApplierError::object_store(err, context)
                 })?;
+            layer_logs.validate(&self.applied_snapshot_status)?;
 
-        storage_logs.validate(&self.applied_snapshot_status)?;
+            storage_logs = Some(match storage_logs {
+                None => layer_logs,
+                Some(logs) => logs.apply_incremental(layer_logs)?,
+            });
+        }
+        // `unwrap()` is safe: there's always at least one snapshot layer
+        let mut storage_logs = storage_logs.unwrap();
         if self.drop_storage_key_preimages {
             storage_logs.drop_key_preimages();
         }
@@ -13,7 +13,9 @@ use zksync_object_store::MockObjectStore;
 use zksync_types::{
     api::{BlockDetails, L1BatchDetails},
     block::L1BatchHeader,
-    get_code_key, L1BatchNumber, ProtocolVersion, ProtocolVersionId,
+    get_code_key,
+    snapshots::{uniform_hashed_keys_chunk, SnapshotFactoryDependency},
+    L1BatchNumber, ProtocolVersion, ProtocolVersionId,
 };
 
 use self::utils::{
@@ -243,6 +245,180 @@ async fn applier_recovers_explicitly_specified_snapshot() {
     assert_eq!(all_storage_logs.len(), storage_logs.len());
 }
 
+async fn put_incremental_snapshot_layer(
+    object_store: &dyn ObjectStore,
+    l1_batch_number: L1BatchNumber,
+    chunk_count: u64,
+    logs: &[SnapshotStorageLog],
+    bytecode: Vec<u8>,
+) {
+    for chunk_id in 0..chunk_count {
+        let hashed_keys_range = uniform_hashed_keys_chunk(chunk_id, chunk_count);
+        let chunk = SnapshotStorageLogsChunk {
+            storage_logs: logs
+                .iter()
+                .filter(|log| hashed_keys_range.contains(&log.key))
+                .cloned()
+                .collect(),
+        };
+        let key = SnapshotStorageLogsStorageKey {
+            l1_batch_number,
+            chunk_id,
+        };
+        object_store.put(key, &chunk).await.unwrap();
+    }
+
+    let factory_deps = SnapshotFactoryDependencies {
+        factory_deps: vec![SnapshotFactoryDependency {
+            bytecode: bytecode.into(),
+        }],
+    };
+    object_store
+        .put(l1_batch_number, &factory_deps)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn applier_recovers_incremental_snapshot() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let expected_status = mock_recovery_status();
+    let chunk_count = expected_status.storage_logs_chunks_processed.len() as u64;
+    let base_l1_batch_number = expected_status.l1_batch_number - 10;
+    let base_logs = random_storage_logs::<H256>(base_l1_batch_number, 200);
+    let mut delta_logs: Vec<_> = base_logs
+        .iter()
+        .step_by(3)
+        .map(|log| SnapshotStorageLog {
+            value: H256::random(),
+            ..log.clone()
+        })
+        .collect();
+    delta_logs.extend(
+        random_storage_logs::<H256>(expected_status.l1_batch_number, 50)
+            .into_iter()
+            .map(|log| SnapshotStorageLog {
+                enumeration_index: log.enumeration_index + base_logs.len() as u64,
+                ..log
+            }),
+    );
+
+    let (object_store, mut client) = prepare_clients(&expected_status, &delta_logs).await;
+    put_incremental_snapshot_layer(
+        object_store.as_ref(),
+        base_l1_batch_number,
+        chunk_count,
+        &base_logs,
+        vec![1; 32],
+    )
+    .await;
+    put_incremental_snapshot_layer(
+        object_store.as_ref(),
+        expected_status.l1_batch_number,
+        chunk_count,
+        &delta_logs,
+        vec![2; 32],
+    )
+    .await;
+
+    let base_status = SnapshotRecoveryStatus {
+        l1_batch_number: base_l1_batch_number,
+        ..expected_status.clone()
+    };
+    let base_header = mock_snapshot_header(SnapshotVersion::Version1.into(), &base_status);
+    client
+        .fetch_snapshot_responses
+        .insert(base_l1_batch_number, base_header);
+    let header = client.fetch_newest_snapshot_response.as_mut().unwrap();
+    header.version = SnapshotVersion::Version2.into();
+    header.base_l1_batch_number = Some(base_l1_batch_number);
+
+    let task = SnapshotsApplierTask::new(
+        SnapshotsApplierConfig::for_tests(),
+        pool.clone(),
+        Box::new(client),
+        object_store,
+    );
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    let stats = task.run(stop_receiver).await.unwrap();
+    assert!(stats.done_work);
+
+    let mut expected_logs: HashMap<_, _> =
+        base_logs.into_iter().map(|log| (log.key, log)).collect();
+    expected_logs.extend(delta_logs.into_iter().map(|log| (log.key, log)));
+
+    let mut storage = pool.connection().await.unwrap();
+    let all_storage_logs = storage
+        .storage_logs_dal()
+        .dump_all_storage_logs_for_tests()
+        .await;
+    assert_eq!(all_storage_logs.len(), expected_logs.len());
+    for db_log in all_storage_logs {
+        let expected_log = &expected_logs[&db_log.hashed_key];
+        assert_eq!(db_log.value, expected_log.value);
+        assert_eq!(db_log.l2_block_number, expected_status.l2_block_number);
+    }
+
+    let all_initial_writes = storage
+        .storage_logs_dedup_dal()
+        .dump_all_initial_writes_for_tests()
+        .await;
+    assert_eq!(all_initial_writes.len(), expected_logs.len());
+    for initial_write in all_initial_writes {
+        let expected_log = &expected_logs[&initial_write.hashed_key];
+        assert_eq!(
+            initial_write.l1_batch_number,
+            expected_log.l1_batch_number_of_initial_write
+        );
+        assert_eq!(initial_write.index, expected_log.enumeration_index);
+    }
+
+    for bytecode in [vec![1; 32], vec![2; 32]] {
+        let hash = BytecodeHash::for_bytecode(&bytecode).value();
+        let stored_bytecode = storage
+            .factory_deps_dal()
+            .get_sealed_factory_dep(hash)
+            .await
+            .unwrap();
+        assert_eq!(stored_bytecode, Some(bytecode));
+    }
+}
+
+#[tokio::test]
+async fn applier_errors_with_mismatched_incremental_snapshot_chunks() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let expected_status = mock_recovery_status();
+    let base_l1_batch_number = expected_status.l1_batch_number - 10;
+    let storage_logs = random_storage_logs::<H256>(expected_status.l1_batch_number, 100);
+    let (object_store, mut client) = prepare_clients(&expected_status, &storage_logs).await;
+
+    let base_status = SnapshotRecoveryStatus {
+        l1_batch_number: base_l1_batch_number,
+        storage_logs_chunks_processed: vec![true; 3],
+        ..expected_status.clone()
+    };
+    let base_header = mock_snapshot_header(SnapshotVersion::Version1.into(), &base_status);
+    client
+        .fetch_snapshot_responses
+        .insert(base_l1_batch_number, base_header);
+    let header = client.fetch_newest_snapshot_response.as_mut().unwrap();
+    header.version = SnapshotVersion::Version2.into();
+    header.base_l1_batch_number = Some(base_l1_batch_number);
+
+    let task = SnapshotsApplierTask::new(
+        SnapshotsApplierConfig::for_tests(),
+        pool,
+        Box::new(client),
+        object_store,
+    );
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    let err = task.run(stop_receiver).await.unwrap_err();
+    assert!(
+        format!("{err:#}").contains("storage logs chunks"),
+        "{err:#}"
+    );
+}
+
 #[tokio::test]
 async fn applier_error_for_missing_explicitly_specified_snapshot() {
     let pool = ConnectionPool::<Core>::test_pool().await;
@@ -8,7 +8,9 @@ use std::{
 
 use async_trait::async_trait;
 use tokio::sync::watch;
-use zksync_object_store::{Bucket, MockObjectStore, ObjectStore, ObjectStoreError, StoredObject};
+use zksync_object_store::{
+    Bucket, ListedKeys, MockObjectStore, ObjectStore, ObjectStoreError, StoredObject,
+};
 use zksync_types::{
     api,
     block::L2BlockHeader,
@@ -53,6 +55,8 @@ pub(super) struct MockMainNodeClient {
     pub fetch_l1_batch_responses: HashMap<L1BatchNumber, api::L1BatchDetails>,
     pub fetch_l2_block_responses: HashMap<L2BlockNumber, api::BlockDetails>,
     pub fetch_newest_snapshot_response: Option<SnapshotHeader>,
+    /// Snapshots other than the newest one (e.g., base snapshots for incremental snapshots).
+    pub fetch_snapshot_responses: HashMap<L1BatchNumber, SnapshotHeader>,
     pub tokens_response: Vec<TokenInfo>,
     pub tokens_response_error: Arc<RwLock<Option<EnrichedClientError>>>,
 }
@@ -98,10 +102,14 @@ impl SnapshotsApplierMainNodeClient for MockMainNodeClient {
         &self,
         l1_batch_number: L1BatchNumber,
     ) -> EnrichedClientResult<Option<SnapshotHeader>> {
-        Ok(self
+        let newest_snapshot = self
             .fetch_newest_snapshot_response
             .clone()
-            .filter(|response| response.l1_batch_number == l1_batch_number))
+            .filter(|response| response.l1_batch_number == l1_batch_number);
+        Ok(
+            newest_snapshot
+                .or_else(|| self.fetch_snapshot_responses.get(&l1_batch_number).cloned()),
+        )
     }
 
     async fn fetch_tokens(
@@ -161,6 +169,15 @@ impl ObjectStore for ObjectStoreWithErrors {
         unreachable!("Should not be used in snapshot applier")
     }
 
+    async fn list_raw(
+        &self,
+        _bucket: Bucket,
+        _prefix: &str,
+        _page_token: Option<String>,
+    ) -> Result<ListedKeys, ObjectStoreError> {
+        unreachable!("Should not be used in snapshot applier")
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
@@ -285,6 +302,7 @@ pub(super) fn mock_snapshot_header(
         version,
         l1_batch_number: status.l1_batch_number,
         l2_block_number: status.l2_block_number,
+        base_l1_batch_number: None,
         storage_logs_chunks: (0..status.storage_logs_chunks_processed.len() as u64)
             .map(|chunk_id| SnapshotStorageLogsChunkMetadata {
                 chunk_id,
@@ -407,6 +425,15 @@ impl ObjectStore for HangingObjectStore {
         unreachable!("Should not be used in snapshot applier")
     }
 
+    async fn list_raw(
+        &self,
+        _bucket: Bucket,
+        _prefix: &str,
+        _page_token: Option<String>,
+    ) -> Result<ListedKeys, ObjectStoreError> {
+        unreachable!("Should not be used in snapshot applier")
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
@@ -133,6 +133,9 @@ pub struct RocksdbStorageOptions {
     /// Number of open files that can be simultaneously opened by RocksDB. Default is `None`, for no limit.
     /// Can be used to restrict memory usage of RocksDB.
     pub max_open_files: Option<NonZeroU32>,
+    /// Opens the storage in the read-only mode, e.g. to inspect it while it's used by another process.
+    /// Updating a read-only storage fails.
+    pub read_only: bool,
 }
 
 impl Default for RocksdbStorageOptions {
@@ -140,6 +143,7 @@ impl Default for RocksdbStorageOptions {
         Self {
             block_cache_capacity: 128 << 20,
             max_open_files: None,
+            read_only: false,
         }
     }
 }
@@ -149,6 +153,7 @@ impl RocksdbStorageOptions {
         RocksDBOptions {
             block_cache_capacity: Some(self.block_cache_capacity),
             max_open_files: self.max_open_files,
+            read_only: self.read_only,
             ..RocksDBOptions::default()
         }
     }
@@ -16,8 +16,9 @@ use std::{
 };
 
 use rocksdb::{
-    perf, properties, BlockBasedOptions, Cache, ColumnFamily, ColumnFamilyDescriptor,
-    DBPinnableSlice, Direction, IteratorMode, Options, PrefixRange, ReadOptions, WriteOptions, DB,
+    checkpoint::Checkpoint, perf, properties, BlockBasedOptions, Cache, ColumnFamily,
+    ColumnFamilyDescriptor, DBPinnableSlice, Direction, IteratorMode, Options, PrefixRange,
+    ReadOptions, WriteOptions, DB,
 };
 use thread_local::ThreadLocal;
 
@@ -298,6 +299,9 @@ pub struct RocksDBOptions {
     pub stalled_writes_retries: StalledWritesRetries,
     /// Number of open files that can be used by the DB. Default is None, for no limit.
     pub max_open_files: Option<NonZeroU32>,
+    /// Opens the DB in the read-only mode. The DB must exist and contain all column families. Writes to a read-only DB
+    /// fail, and it doesn't see changes made by other instances after it was opened.
+    pub read_only: bool,
 }
 
 impl Default for RocksDBOptions {
@@ -308,6 +312,7 @@ impl Default for RocksDBOptions {
             large_memtable_capacity: None,
             stalled_writes_retries: StalledWritesRetries::new(Duration::from_secs(10)),
             max_open_files: None,
+            read_only: false,
         }
     }
 }
@@ -402,7 +407,11 @@ impl<CF: NamedColumnFamily> RocksDB<CF> {
             ColumnFamilyDescriptor::new(cf_name, cf_options)
         });
 
-        let db = DB::open_cf_descriptors(&db_options, path, cfs)?;
+        let db = if options.read_only {
+            DB::open_cf_descriptors_read_only(&db_options, path, cfs, false)?
+        } else {
+            DB::open_cf_descriptors(&db_options, path, cfs)?
+        };
         let inner = Arc::new(RocksDBInner {
             db,
             db_name: CF::DB_NAME,
@@ -418,7 +427,9 @@ impl<CF: NamedColumnFamily> RocksDB<CF> {
             path.display()
         );
 
-        inner.wait_for_writes_to_resume(&options.stalled_writes_retries);
+        if !options.read_only {
+            inner.wait_for_writes_to_resume(&options.stalled_writes_retries);
+        }
         Ok(Self {
             inner,
             sync_writes: false,
@@ -608,6 +619,18 @@ impl<CF: NamedColumnFamily> RocksDB<CF> {
         // ^ unwrap() is safe for the same reasons as in `prefix_iterator_cf()`.
     }
 
+    /// Creates a consistent point-in-time checkpoint of this database in the specified directory.
+    /// Memtables are flushed before creating the checkpoint, so the checkpoint includes all writes
+    /// completed before this call. SST files are hard-linked if `path` is on the same filesystem
+    /// as the database, and copied otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Propagates RocksDB I/O errors. In particular, returns an error if `path` already exists.
+    pub fn create_checkpoint(&self, path: &Path) -> Result<(), rocksdb::Error> {
+        Checkpoint::new(&self.inner.db)?.create_checkpoint(path)
+    }
+
     /// Creates a new profiled operation.
     pub fn new_profiled_operation(&self, name: &'static str) -> ProfiledOperation {
         ProfiledOperation {
@@ -979,6 +1002,36 @@ mod tests {
         assert_eq!(value.unwrap(), b"value");
     }
 
+    #[test]
+    fn creating_checkpoint() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDB::<OldColumnFamilies>::new(temp_dir.path()).unwrap();
+        let mut batch = db.new_write_batch();
+        batch.put_cf(OldColumnFamilies::Default, b"test", b"value");
+        batch.put_cf(OldColumnFamilies::Junk, b"other", b"junk");
+        db.write(batch).unwrap();
+
+        let checkpoint_dir = TempDir::new().unwrap();
+        let checkpoint_path = checkpoint_dir.path().join("checkpoint");
+        db.create_checkpoint(&checkpoint_path).unwrap();
+        // Writes after the checkpoint is created must not be visible in it.
+        let mut batch = db.new_write_batch();
+        batch.put_cf(OldColumnFamilies::Default, b"test", b"new_value");
+        db.write(batch).unwrap();
+        // Creating a checkpoint in an existing directory must fail.
+        db.create_checkpoint(&checkpoint_path).unwrap_err();
+
+        let checkpoint = RocksDB::<OldColumnFamilies>::new(&checkpoint_path).unwrap();
+        let value = checkpoint
+            .get_cf(OldColumnFamilies::Default, b"test")
+            .unwrap();
+        assert_eq!(value.unwrap(), b"value");
+        let value = checkpoint
+            .get_cf(OldColumnFamilies::Junk, b"other")
+            .unwrap();
+        assert_eq!(value.unwrap(), b"junk");
+    }
+
     #[derive(Debug, Clone, Copy)]
     struct JunkColumnFamily;
 
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
@@ -20,6 +22,7 @@ use crate::{
 };
 
 pub mod en;
+pub mod simulate;
 pub mod state_override;
 
 /// Block Number
@@ -726,12 +729,21 @@ impl ProtocolVersion {
 pub enum SupportedTracers {
     CallTracer,
     FlatCallTracer,
+    /// `prestateTracer` with the output shape of the geth tracer. In the default mode, all accounts and slots read or written
+    /// by the transaction are returned. Unlike geth, accounts are discovered via storage accesses, so the output also contains
+    /// system contracts storing balances, nonces and code hashes, and accounts whose balance or nonce was only read
+    /// without calling them may be missing.
+    PrestateTracer,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, Copy)]
 #[serde(rename_all = "camelCase")]
 pub struct CallTracerConfig {
+    #[serde(default)]
     pub only_top_call: bool,
+    /// Whether `prestateTracer` should return both pre- and post-transaction state. Ignored by other tracers.
+    #[serde(default)]
+    pub diff_mode: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy)]
@@ -748,6 +760,7 @@ impl Default for TracerConfig {
             tracer: SupportedTracers::CallTracer,
             tracer_config: CallTracerConfig {
                 only_top_call: false,
+                diff_mode: false,
             },
         }
     }
@@ -760,6 +773,50 @@ pub enum BlockStatus {
     Verified,
 }
 
+/// Account state returned by `prestateTracer`. In the diff mode, fields that were not modified by the transaction are omitted from the post-state.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrestateAccount {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub storage: BTreeMap<H256, H256>,
+}
+
+impl PrestateAccount {
+    pub fn is_empty(&self) -> bool {
+        self.balance.is_none()
+            && self.nonce.is_none()
+            && self.code.is_none()
+            && self.storage.is_empty()
+    }
+}
+
+/// Output of `prestateTracer` for a single transaction, with the same shape as the output of the geth tracer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PrestateTrace {
+    /// Output in the `diffMode`.
+    Diff {
+        pre: BTreeMap<Address, PrestateAccount>,
+        post: BTreeMap<Address, PrestateAccount>,
+    },
+    /// Output in the default mode.
+    Prestate(BTreeMap<Address, PrestateAccount>),
+}
+
+/// `prestateTracer` output for a transaction in a block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultPrestateTrace {
+    pub tx_hash: H256,
+    pub result: PrestateTrace,
+}
+
 /// Result tracers need to have a nested result field for compatibility. So we have two different
 /// structs 1 for blocks tracing and one for txs and call tracing
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -767,20 +824,28 @@ pub enum BlockStatus {
 pub enum CallTracerBlockResult {
     CallTrace(Vec<ResultDebugCall>),
     FlatCallTrace(Vec<ResultDebugCallFlat>),
+    PrestateTrace(Vec<ResultPrestateTrace>),
 }
 
 impl CallTracerBlockResult {
     pub fn unwrap_flat(self) -> Vec<ResultDebugCallFlat> {
         match self {
-            Self::CallTrace(_) => panic!("Result is a FlatCallTrace"),
             Self::FlatCallTrace(trace) => trace,
+            _ => panic!("Result is not a FlatCallTrace"),
         }
     }
 
     pub fn unwrap_default(self) -> Vec<ResultDebugCall> {
         match self {
             Self::CallTrace(trace) => trace,
-            Self::FlatCallTrace(_) => panic!("Result is a CallTrace"),
+            _ => panic!("Result is not a CallTrace"),
+        }
+    }
+
+    pub fn unwrap_prestate(self) -> Vec<ResultPrestateTrace> {
+        match self {
+            Self::PrestateTrace(trace) => trace,
+            _ => panic!("Result is not a PrestateTrace"),
         }
     }
 }
@@ -790,20 +855,28 @@ impl CallTracerBlockResult {
 pub enum CallTracerResult {
     CallTrace(DebugCall),
     FlatCallTrace(Vec<DebugCallFlat>),
+    PrestateTrace(PrestateTrace),
 }
 
 impl CallTracerResult {
     pub fn unwrap_flat(self) -> Vec<DebugCallFlat> {
         match self {
-            Self::CallTrace(_) => panic!("Result is a FlatCallTrace"),
             Self::FlatCallTrace(trace) => trace,
+            _ => panic!("Result is not a FlatCallTrace"),
         }
     }
 
     pub fn unwrap_default(self) -> DebugCall {
         match self {
             Self::CallTrace(trace) => trace,
-            Self::FlatCallTrace(_) => panic!("Result is a CallTrace"),
+            _ => panic!("Result is not a CallTrace"),
+        }
+    }
+
+    pub fn unwrap_prestate(self) -> PrestateTrace {
+        match self {
+            Self::PrestateTrace(trace) => trace,
+            _ => panic!("Result is not a PrestateTrace"),
         }
     }
 }
@@ -849,6 +922,28 @@ pub struct L1BatchDetails {
     pub base: BlockDetailsBase,
 }
 
+/// Data availability state of an L1 batch returned by `zks_getL1BatchDataAvailabilityDetails`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchDataAvailabilityDetails {
+    pub number: L1BatchNumber,
+    /// ID of the current DA blob. `None` if the batch is awaiting (re-)dispatch.
+    pub blob_id: Option<String>,
+    pub sent_at: Option<DateTime<Utc>>,
+    /// Inclusion data for the current DA blob. `None` if the blob is not included yet.
+    pub inclusion_data: Option<Bytes>,
+    /// Previously dispatched DA blobs that were abandoned because they weren't included within the inclusion timeout.
+    pub abandoned_blobs: Vec<AbandonedDataAvailabilityBlob>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AbandonedDataAvailabilityBlob {
+    pub blob_id: String,
+    pub sent_at: DateTime<Utc>,
+    pub abandoned_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StorageProof {
@@ -858,11 +953,36 @@ pub struct StorageProof {
     pub index: u64,
 }
 
+/// Format of Merkle proofs returned by `zks_getProof`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProofFormat {
+    /// Independent root-to-leaf Merkle path for each requested key.
+    #[default]
+    Individual,
+    /// Single [`StorageMultiProof`] with sibling hashes shared among all requested keys.
+    Compact,
+}
+
+/// Sibling hashes shared among all storage proofs in a [`Proof`]. Entries in [`Proof::storage_proof`]
+/// are ordered by the hashed key and deduplicated if this proof is present; their `proof` fields are empty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageMultiProof {
+    /// Merkle path length for each entry in [`Proof::storage_proof`]. Sibling hashes below this length
+    /// are implied to be empty subtree hashes.
+    pub merkle_path_lengths: Vec<usize>,
+    /// Sibling hashes ordered by tree level starting from the leaf level, and by the hashed key within a level.
+    pub hashes: Vec<H256>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Proof {
     pub address: Address,
     pub storage_proof: Vec<StorageProof>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multi_proof: Option<StorageMultiProof>,
 }
 
 #[serde_as]
@@ -917,6 +1037,20 @@ pub struct FeeHistory {
     pub l2_pubdata_price: Vec<U256>,
 }
 
+/// Result of the `eth_createAccessList` call.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessListResult {
+    /// Storage slots accessed by the transaction, grouped by the contract address.
+    pub access_list: AccessList,
+    /// Gas used by the traced execution of the transaction. This is not a gas estimate; e.g., it doesn't include
+    /// the padding applied by `eth_estimateGas`.
+    pub gas_used: U256,
+    /// Revert reason or halt reason if the transaction has failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -951,4 +1085,77 @@ mod tests {
         serde_json::from_str::<OldProtocolVersion>(&serde_json::to_string(&new_version).unwrap())
             .unwrap();
     }
+
+    #[test]
+    fn deserializing_prestate_tracer_config() {
+        let config: TracerConfig = serde_json::from_value(serde_json::json!({
+            "tracer": "prestateTracer",
+            "tracerConfig": { "diffMode": true },
+        }))
+        .unwrap();
+        assert_matches::assert_matches!(config.tracer, SupportedTracers::PrestateTracer);
+        assert!(config.tracer_config.diff_mode);
+        assert!(!config.tracer_config.only_top_call);
+
+        let config: TracerConfig =
+            serde_json::from_value(serde_json::json!({ "tracer": "prestateTracer" })).unwrap();
+        assert!(!config.tracer_config.diff_mode);
+    }
+
+    #[test]
+    fn serializing_prestate_trace() {
+        let address = Address::repeat_byte(1);
+        let account = PrestateAccount {
+            balance: Some(U256::from(1_000)),
+            nonce: Some(3),
+            code: None,
+            storage: BTreeMap::from([(H256::zero(), H256::repeat_byte(0xff))]),
+        };
+        let trace = PrestateTrace::Prestate(BTreeMap::from([(address, account.clone())]));
+        let json = serde_json::to_value(&trace).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "0x0101010101010101010101010101010101010101": {
+                    "balance": "0x3e8",
+                    "nonce": 3,
+                    "storage": {
+                        "0x0000000000000000000000000000000000000000000000000000000000000000":
+                            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                    },
+                },
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<PrestateTrace>(json).unwrap(),
+            trace
+        );
+
+        let post_account = PrestateAccount {
+            nonce: Some(4),
+            ..PrestateAccount::default()
+        };
+        let trace = PrestateTrace::Diff {
+            pre: BTreeMap::from([(address, account)]),
+            post: BTreeMap::from([(address, post_account)]),
+        };
+        let json = serde_json::to_value(&trace).unwrap();
+        assert_eq!(
+            json["post"],
+            serde_json::json!({
+                "0x0101010101010101010101010101010101010101": { "nonce": 4 },
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<PrestateTrace>(json).unwrap(),
+            trace
+        );
+
+        let empty_trace = PrestateTrace::Prestate(BTreeMap::new());
+        let json = serde_json::to_value(&empty_trace).unwrap();
+        assert_eq!(
+            serde_json::from_value::<PrestateTrace>(json).unwrap(),
+            empty_trace
+        );
+    }
 }
@@ -0,0 +1,159 @@
+//! Types used by the `eth_simulateV1` method.
+
+use serde::{Deserialize, Serialize};
+use zksync_basic_types::{web3::Bytes, H256, U256, U64};
+
+use super::{state_override::StateOverride, Log};
+use crate::transaction_request::CallRequest;
+
+/// JSON-RPC error code returned for reverted simulated calls. Matches the code used for reverts in `eth_call`.
+pub const SIMULATED_CALL_REVERTED_CODE: i32 = 3;
+/// JSON-RPC error code returned for simulated calls halted by the VM (e.g., because of running out of gas).
+pub const SIMULATED_CALL_HALTED_CODE: i32 = -32015;
+
+/// Payload of the `eth_simulateV1` method.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatePayload {
+    /// Blocks to simulate. Blocks are executed in order, each on top of the state produced by the previous ones.
+    pub block_state_calls: Vec<SimulateBlock>,
+    /// If set, base token transfers are included into the returned logs.
+    ///
+    /// Unlike Geth, which emits synthetic `Transfer` logs from the `0xEeeeeEeeeEeEeeEeEeEeeEEEeeeeEeeeeeeeEEeE`
+    /// pseudo-address, ZKsync returns the actual `Transfer` events emitted by the L2 base token contract
+    /// (`0x000000000000000000000000000000000000800a`), since base token transfers are regular contract calls.
+    /// Without this flag, these events are filtered out.
+    #[serde(default)]
+    pub trace_transfers: bool,
+    /// If set, calls are checked for having a correct nonce, a sufficient fee per gas and a sufficient balance
+    /// before execution.
+    #[serde(default)]
+    pub validation: bool,
+}
+
+/// Single simulated block in [`SimulatePayload`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulateBlock {
+    /// Overrides for the block environment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_overrides: Option<BlockOverrides>,
+    /// State overrides applied before executing calls in the block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_overrides: Option<StateOverride>,
+    /// Calls to execute in the block.
+    #[serde(default)]
+    pub calls: Vec<CallRequest>,
+}
+
+/// Overrides for the environment of a simulated block.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockOverrides {
+    /// L2 block number. Must be greater than the number of the previous simulated block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<U64>,
+    /// L2 block timestamp in seconds. Must be greater than the timestamp of the previous simulated block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<U64>,
+    /// Base fee per gas used for all calls in the block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_fee_per_gas: Option<U256>,
+}
+
+/// Result of simulating a single block in `eth_simulateV1`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedBlock {
+    pub number: U64,
+    /// Hash of the simulated block. Since simulated blocks are not a part of the chain, this hash is only
+    /// meaningful within the simulation.
+    pub hash: H256,
+    pub timestamp: U64,
+    /// Total gas used by all calls in the block.
+    pub gas_used: U256,
+    pub base_fee_per_gas: U256,
+    /// Results of the executed calls in the order of their appearance in the request.
+    pub calls: Vec<SimulatedCallResult>,
+}
+
+/// Result of a single simulated call in `eth_simulateV1`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedCallResult {
+    /// Data returned by the call. Empty if the call has failed.
+    pub return_data: Bytes,
+    /// Events emitted by the call.
+    pub logs: Vec<Log>,
+    pub gas_used: U256,
+    /// 1 if the call has succeeded, 0 otherwise.
+    pub status: U64,
+    /// Error details if the call has failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SimulatedCallError>,
+}
+
+/// Error of a failed simulated call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulatedCallError {
+    /// JSON-RPC error code, either [`SIMULATED_CALL_REVERTED_CODE`] or [`SIMULATED_CALL_HALTED_CODE`].
+    pub code: i32,
+    pub message: String,
+    /// Revert data, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Bytes>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializing_simulate_payload() {
+        let json = serde_json::json!({
+            "blockStateCalls": [
+                {
+                    "blockOverrides": {
+                        "number": "0x10",
+                        "time": "0x64",
+                        "baseFeePerGas": "0x5f5e100",
+                    },
+                    "stateOverrides": {
+                        "0x0123456789abcdef0123456789abcdef01234567": {
+                            "balance": "0x123",
+                        },
+                    },
+                    "calls": [{
+                        "from": "0x0123456789abcdef0123456789abcdef01234567",
+                        "to": "0x1123456789abcdef0123456789abcdef01234567",
+                        "data": "0x01",
+                    }],
+                },
+                {},
+            ],
+            "traceTransfers": true,
+        });
+        let payload: SimulatePayload = serde_json::from_value(json).unwrap();
+
+        assert!(payload.trace_transfers);
+        assert!(!payload.validation);
+        assert_eq!(payload.block_state_calls.len(), 2);
+        let block = &payload.block_state_calls[0];
+        assert_eq!(
+            block.block_overrides,
+            Some(BlockOverrides {
+                number: Some(16.into()),
+                time: Some(100.into()),
+                base_fee_per_gas: Some(100_000_000.into()),
+            })
+        );
+        assert!(block.state_overrides.is_some());
+        assert_eq!(block.calls.len(), 1);
+        assert_eq!(block.calls[0].data, Some(Bytes(vec![1])));
+
+        let block = &payload.block_state_calls[1];
+        assert_eq!(block.block_overrides, None);
+        assert!(block.state_overrides.is_none());
+        assert!(block.calls.is_empty());
+    }
+}
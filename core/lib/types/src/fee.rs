@@ -26,6 +26,19 @@ impl Fee {
         // For now, we charge only for base fee.
         block_base_fee_per_gas
     }
+
+    /// Checks whether a transaction with this fee can replace a transaction with the `previous` fee,
+    /// i.e., whether both `max_fee_per_gas` and `max_priority_fee_per_gas` are bumped by at least `min_bump_percent`.
+    pub fn is_sufficient_replacement_for(&self, previous: &Self, min_bump_percent: u32) -> bool {
+        let is_bumped = |new: U256, old: U256| {
+            new.full_mul(100.into()) >= old.full_mul((100 + u64::from(min_bump_percent)).into())
+        };
+        is_bumped(self.max_fee_per_gas, previous.max_fee_per_gas)
+            && is_bumped(
+                self.max_priority_fee_per_gas,
+                previous.max_priority_fee_per_gas,
+            )
+    }
 }
 
 /// Returns how many slots would ABI-encoding of the transaction with such parameters take
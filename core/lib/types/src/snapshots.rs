@@ -27,6 +27,20 @@ pub enum SnapshotVersion {
     /// Snapshot version made compatible with L1 recovery. Differs from `Version0` by including
     /// hashed keys in storage logs instead of `(address, key)` pairs.
     Version1 = 1,
+    /// Incremental snapshot version. Uses the same data format as `Version1`, but a snapshot may reference
+    /// a base snapshot (see [`SnapshotMetadata::base_l1_batch_number`]). In this case, the snapshot only contains
+    /// storage logs and factory deps changed after the base snapshot L1 batch, and has the same number of storage log chunks
+    /// as the base snapshot. The full state is restored by applying the base snapshot and then all snapshots
+    /// in the chain on top of it, chunk by chunk.
+    Version2 = 2,
+}
+
+impl SnapshotVersion {
+    /// Checks whether snapshots of this version can be used as a base for incremental snapshots.
+    /// This requires storage logs to use hashed keys.
+    pub fn can_be_base(self) -> bool {
+        !matches!(self, Self::Version0)
+    }
 }
 
 /// Storage snapshot metadata. Used in DAL to fetch certain snapshot data.
@@ -35,6 +49,9 @@ pub struct SnapshotMetadata {
     pub version: SnapshotVersion,
     /// L1 batch for the snapshot. The data in the snapshot captures node storage at the end of this batch.
     pub l1_batch_number: L1BatchNumber,
+    /// L1 batch of the base snapshot for incremental snapshots. If set, the snapshot only contains data changed
+    /// after this L1 batch.
+    pub base_l1_batch_number: Option<L1BatchNumber>,
     /// Path to the factory dependencies blob.
     pub factory_deps_filepath: String,
     /// Paths to the storage log blobs. Ordered by the chunk ID. If a certain chunk is not produced yet,
@@ -60,6 +77,9 @@ pub struct SnapshotHeader {
     pub l1_batch_number: L1BatchNumber,
     #[serde(rename = "miniblockNumber")] // legacy naming
     pub l2_block_number: L2BlockNumber,
+    /// L1 batch of the base snapshot for incremental snapshots. The base snapshot must be applied before this one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_l1_batch_number: Option<L1BatchNumber>,
     /// Ordered by chunk IDs.
     pub storage_logs_chunks: Vec<SnapshotStorageLogsChunkMetadata>,
     pub factory_deps_filepath: String,
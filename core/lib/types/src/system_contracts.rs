@@ -176,6 +176,13 @@ static SYSTEM_CONTRACT_LIST: [(&str, &str, Address, ContractLanguage); 26] = [
     ),
 ];
 
+/// Checks whether the specified address belongs to a system contract (including precompiles).
+pub fn is_system_contract(address: Address) -> bool {
+    SYSTEM_CONTRACT_LIST
+        .iter()
+        .any(|(_, _, contract_address, _)| *contract_address == address)
+}
+
 /// Gets default set of system contracts, based on Cargo workspace location.
 pub fn get_system_smart_contracts(use_evm_emulator: bool) -> Vec<DeployedContract> {
     SYSTEM_CONTRACT_LIST
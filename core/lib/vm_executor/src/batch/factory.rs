@@ -9,7 +9,7 @@ use zksync_multivm::{
         pubdata::PubdataBuilder,
         storage::{ReadStorage, StoragePtr, StorageView, StorageViewStats},
         utils::DivergenceHandler,
-        BatchTransactionExecutionResult, BytecodeCompressionError, CompressedBytecodeInfo,
+        BatchTransactionExecutionResult, BytecodeCompressionError, Call, CompressedBytecodeInfo,
         ExecutionResult, FinishedL1Batch, Halt, L1BatchEnv, L2BlockEnv, SystemEnv, VmFactory,
         VmInterface, VmInterfaceHistoryEnabled,
     },
@@ -38,6 +38,10 @@ pub trait BatchTracer: fmt::Debug + 'static + Send + Sealed {
     /// Tracer for the fast VM.
     #[doc(hidden)]
     type Fast: vm_fast::interface::Tracer + Default + 'static;
+
+    /// Extracts call traces collected by the fast VM tracer.
+    #[doc(hidden)]
+    fn fast_call_traces(tracer: Self::Fast) -> Vec<Call>;
 }
 
 impl Sealed for () {}
@@ -46,6 +50,10 @@ impl Sealed for () {}
 impl BatchTracer for () {
     const TRACE_CALLS: bool = false;
     type Fast = ();
+
+    fn fast_call_traces(_tracer: Self::Fast) -> Vec<Call> {
+        vec![]
+    }
 }
 
 /// [`BatchTracer`] implementation tracing calls (returned in [`BatchTransactionExecutionResult`]s).
@@ -56,7 +64,11 @@ impl Sealed for TraceCalls {}
 
 impl BatchTracer for TraceCalls {
     const TRACE_CALLS: bool = true;
-    type Fast = (); // TODO: change once call tracing is implemented in fast VM
+    type Fast = vm_fast::CallTracer;
+
+    fn fast_call_traces(tracer: Self::Fast) -> Vec<Call> {
+        tracer.into_result()
+    }
 }
 
 /// The default implementation of [`BatchExecutorFactory`].
@@ -221,23 +233,55 @@ impl<S: ReadStorage, Tr: BatchTracer> BatchVm<S, Tr> {
         };
         let mut legacy_tracer = legacy_tracer.into();
 
-        let (compression_result, tx_result) = match self {
-            Self::Legacy(vm) => vm.inspect_transaction_with_bytecode_compression(
-                &mut legacy_tracer,
-                tx,
-                with_compression,
-            ),
+        let (compressed_bytecodes, tx_result, fast_call_traces) = match self {
+            Self::Legacy(vm) => {
+                let (compression_result, tx_result) = vm
+                    .inspect_transaction_with_bytecode_compression(
+                        &mut legacy_tracer,
+                        tx,
+                        with_compression,
+                    );
+                (compression_result.map(Cow::into_owned), tx_result, None)
+            }
             Self::Fast(vm) => {
                 let mut tracer = (legacy_tracer.into(), <Tr::Fast>::default());
-                vm.inspect_transaction_with_bytecode_compression(&mut tracer, tx, with_compression)
+                let (compression_result, tx_result) = vm
+                    .inspect_transaction_with_bytecode_compression(
+                        &mut tracer,
+                        tx,
+                        with_compression,
+                    );
+                let compressed_bytecodes = compression_result.map(Cow::into_owned);
+
+                let fast_call_traces = if Tr::TRACE_CALLS {
+                    let fast_call_traces = Tr::fast_call_traces(tracer.1);
+                    match vm {
+                        FastVmInstance::Fast(_) => Some(fast_call_traces),
+                        FastVmInstance::Shadowed(vm) => {
+                            // Call traces from the main (legacy) VM are returned; the fast VM ones are only compared with them.
+                            let legacy_call_traces =
+                                call_tracer_result.get().map_or(&[][..], Vec::as_slice);
+                            vm.check_divergence(
+                                "call_traces",
+                                legacy_call_traces,
+                                fast_call_traces.as_slice(),
+                            );
+                            None
+                        }
+                    }
+                } else {
+                    None
+                };
+                (compressed_bytecodes, tx_result, fast_call_traces)
             }
         };
 
-        let compressed_bytecodes = compression_result.map(Cow::into_owned);
-        let call_traces = Arc::try_unwrap(call_tracer_result)
-            .expect("failed extracting call traces")
-            .take()
-            .unwrap_or_default();
+        let call_traces = fast_call_traces.unwrap_or_else(|| {
+            Arc::try_unwrap(call_tracer_result)
+                .expect("failed extracting call traces")
+                .take()
+                .unwrap_or_default()
+        });
         BatchTransactionExecutionResult {
             tx_result: Box::new(tx_result),
             compressed_bytecodes,
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{collections::BTreeSet, fmt};
 
 use async_trait::async_trait;
 use zksync_multivm::interface::{
@@ -6,7 +6,8 @@ use zksync_multivm::interface::{
     storage::ReadStorage,
     tracer::{ValidationError, ValidationParams, ValidationTraces},
     ExecutionResult, OneshotEnv, OneshotTracingParams, OneshotTransactionExecutionResult,
-    TxExecutionArgs, TxExecutionMode, VmExecutionResultAndLogs,
+    PrestateTracerResult, PrestateTracingMode, TxExecutionArgs, TxExecutionMode,
+    VmExecutionResultAndLogs,
 };
 use zksync_types::{l2::L2Tx, Transaction};
 
@@ -79,6 +80,14 @@ impl MockOneshotExecutor {
         )
     }
 
+    /// Same as [`Self::set_call_responses()`], but allows to customize returned VM logs etc.
+    pub fn set_full_call_responses<F>(&mut self, responses: F)
+    where
+        F: Fn(&Transaction, &OneshotEnv) -> VmExecutionResultAndLogs + 'static + Send + Sync,
+    {
+        self.call_responses = Box::new(responses);
+    }
+
     /// Same as [`Self::set_tx_responses()`], but allows to customize returned VM logs etc.
     pub fn set_full_tx_responses<F>(&mut self, responses: F)
     where
@@ -107,12 +116,30 @@ where
         _storage: S,
         env: OneshotEnv,
         args: TxExecutionArgs,
-        _params: OneshotTracingParams,
+        params: OneshotTracingParams,
     ) -> anyhow::Result<OneshotTransactionExecutionResult> {
+        let tx_result = self.mock_inspect(&env, args);
+        // Approximate accessed storage slots with ones mentioned in the returned storage logs.
+        let accessed_storage_keys = if params.trace_storage_access {
+            let keys: BTreeSet<_> = tx_result
+                .logs
+                .storage_logs
+                .iter()
+                .map(|log| log.log.key)
+                .collect();
+            keys.into_iter().collect()
+        } else {
+            vec![]
+        };
+        let prestate = params
+            .trace_prestate
+            .map(|mode| mock_prestate(&tx_result, mode));
         Ok(OneshotTransactionExecutionResult {
-            tx_result: Box::new(self.mock_inspect(&env, args)),
+            tx_result: Box::new(tx_result),
             compression_result: Ok(()),
             call_traces: vec![],
+            accessed_storage_keys,
+            prestate,
         })
     }
 }
@@ -142,3 +169,30 @@ where
         )
     }
 }
+
+/// Approximates prestate tracer output using storage logs in the returned result. Only storage of the accessed
+/// accounts is populated.
+fn mock_prestate(
+    tx_result: &VmExecutionResultAndLogs,
+    mode: PrestateTracingMode,
+) -> PrestateTracerResult {
+    let mut result = PrestateTracerResult::default();
+    for log in &tx_result.logs.storage_logs {
+        let address = *log.log.key.address();
+        let key = *log.log.key.key();
+        match mode {
+            PrestateTracingMode::Prestate => {
+                let account = result.pre.entry(address).or_default();
+                account.storage.entry(key).or_insert(log.previous_value);
+            }
+            PrestateTracingMode::Diff if log.log.is_write() => {
+                let account = result.pre.entry(address).or_default();
+                account.storage.entry(key).or_insert(log.previous_value);
+                let account = result.post.entry(address).or_default();
+                account.storage.insert(key, log.log.value);
+            }
+            PrestateTracingMode::Diff => { /* read-only access */ }
+        }
+    }
+    result
+}
@@ -7,6 +7,7 @@
 //! which can be used to prepare environment for `MainOneshotExecutor` (i.e., a [`OneshotEnv`] instance).
 
 use std::{
+    collections::{BTreeSet, HashMap},
     sync::Arc,
     time::{Duration, Instant},
 };
@@ -17,16 +18,24 @@ use once_cell::sync::OnceCell;
 use zksync_multivm::{
     interface::{
         executor::{OneshotExecutor, TransactionValidator},
-        storage::{ReadStorage, StorageView, StorageWithOverrides},
+        storage::{ReadStorage, StoragePtr, StorageView, StorageWithOverrides, WriteStorage},
         tracer::{ValidationError, ValidationParams, ValidationTraces},
         utils::{DivergenceHandler, ShadowVm},
         Call, ExecutionResult, InspectExecutionMode, OneshotEnv, OneshotTracingParams,
-        OneshotTransactionExecutionResult, StoredL2BlockEnv, TxExecutionArgs, TxExecutionMode,
-        VmFactory, VmInterface,
+        OneshotTransactionExecutionResult, PrestateAccountState, PrestateTracerResult,
+        PrestateTracingMode, StoredL2BlockEnv, TxExecutionArgs, TxExecutionMode, VmFactory,
+        VmInterface,
     },
     is_supported_by_fast_vm,
-    tracers::{CallTracer, StorageInvocations, TracerDispatcher, ValidationTracer},
+    tracers::{
+        CallTracer, PrestateTracer, PrestateTracerAccount, StorageInvocations, TracerDispatcher,
+        ValidationTracer,
+    },
     utils::adjust_pubdata_price_for_tx,
+    vm_fast::{
+        self,
+        interface::{GlobalStateInterface, OpcodeType, ShouldStop, Tracer},
+    },
     vm_latest::{HistoryDisabled, HistoryEnabled},
     zk_evm_latest::ethereum_types::U256,
     FastVmInstance, HistoryMode, LegacyVmInstance, MultiVmTracer,
@@ -38,7 +47,7 @@ use zksync_types::{
     u256_to_h256,
     utils::{decompose_full_nonce, nonces_to_full_nonce, storage_key_for_eth_balance},
     vm::FastVmMode,
-    AccountTreeId, Nonce, StorageKey, Transaction, SYSTEM_CONTEXT_ADDRESS,
+    AccountTreeId, Address, Nonce, StorageKey, Transaction, SYSTEM_CONTEXT_ADDRESS,
     SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION, SYSTEM_CONTEXT_CURRENT_TX_ROLLING_HASH_POSITION,
 };
 
@@ -109,8 +118,10 @@ impl MainOneshotExecutor {
         env: &OneshotEnv,
         tracing_params: &OneshotTracingParams,
     ) -> FastVmMode {
-        if tracing_params.trace_calls || !is_supported_by_fast_vm(env.system.version) {
-            FastVmMode::Old // the fast VM doesn't support call tracing or old protocol versions
+        if !is_supported_by_fast_vm(env.system.version) {
+            FastVmMode::Old // the fast VM doesn't support old protocol versions
+        } else if tracing_params.trace_prestate.is_some() {
+            FastVmMode::Old // the prestate tracer is only implemented for the legacy VM
         } else {
             self.fast_vm_mode
         }
@@ -146,13 +157,18 @@ where
         };
 
         tokio::task::spawn_blocking(move || {
-            sandbox.execute_in_vm(|vm, transaction| {
-                vm.inspect_transaction_with_bytecode_compression(
+            sandbox.execute_in_vm(|vm, transaction, storage_view| {
+                let trace_storage_access = tracing_params.trace_storage_access;
+                let mut result = vm.inspect_transaction_with_bytecode_compression(
                     missed_storage_invocation_limit,
                     tracing_params,
                     transaction,
                     true,
-                )
+                );
+                if trace_storage_access {
+                    result.accessed_storage_keys = accessed_storage_keys(&storage_view.borrow());
+                }
+                result
             })
         })
         .await
@@ -198,7 +214,7 @@ where
             let validation_traces = validation_tracer.get_traces();
             let tracers = vec![validation_tracer.into_tracer_pointer()];
 
-            let exec_result = sandbox.execute_in_vm(|vm, transaction| {
+            let exec_result = sandbox.execute_in_vm(|vm, transaction, _| {
                 let Vm::Legacy(vm) = vm else {
                     unreachable!("Fast VM is never used for validation yet");
                 };
@@ -223,7 +239,24 @@ where
 #[derive(Debug)]
 enum Vm<S: ReadStorage> {
     Legacy(LegacyVmInstance<S, HistoryDisabled>),
-    Fast(FastVmInstance<S, ()>),
+    Fast(FastVmInstance<S, OptionalCallTracer>),
+}
+
+/// Fast VM tracer that traces calls only if requested in [`OneshotTracingParams`].
+#[derive(Debug, Default)]
+struct OptionalCallTracer(Option<vm_fast::CallTracer>);
+
+impl Tracer for OptionalCallTracer {
+    #[inline(always)]
+    fn after_instruction<OP: OpcodeType, S: GlobalStateInterface>(
+        &mut self,
+        state: &mut S,
+    ) -> ShouldStop {
+        match &mut self.0 {
+            Some(tracer) => tracer.after_instruction::<OP, S>(state),
+            None => ShouldStop::Continue,
+        }
+    }
 }
 
 impl<S: ReadStorage> Vm<S> {
@@ -235,53 +268,119 @@ impl<S: ReadStorage> Vm<S> {
         with_compression: bool,
     ) -> OneshotTransactionExecutionResult {
         let mut calls_result = Arc::<OnceCell<_>>::default();
+        let prestate_tracer = params
+            .trace_prestate
+            .map(|mode| PrestateTracer::new(mode == PrestateTracingMode::Diff, Arc::default()));
+        let prestate_result = prestate_tracer.as_ref().map(|tracer| tracer.result.clone());
         let (compression_result, tx_result) = match self {
             Self::Legacy(vm) => {
                 let mut tracers = Self::create_legacy_tracers(
                     missed_storage_invocation_limit,
                     params.trace_calls.then(|| calls_result.clone()),
+                    prestate_tracer,
                 );
-                vm.inspect_transaction_with_bytecode_compression(&mut tracers, tx, with_compression)
+                let (compression_result, tx_result) = vm
+                    .inspect_transaction_with_bytecode_compression(
+                        &mut tracers,
+                        tx,
+                        with_compression,
+                    );
+                (compression_result.map(drop), tx_result)
             }
             Self::Fast(vm) => {
-                assert!(
-                    !params.trace_calls,
-                    "Call tracing is not supported by fast VM yet"
-                );
                 let legacy_tracers = Self::create_legacy_tracers::<HistoryEnabled>(
                     missed_storage_invocation_limit,
-                    None,
+                    params.trace_calls.then(|| calls_result.clone()),
+                    prestate_tracer,
                 );
-                let mut full_tracer = (legacy_tracers.into(), ());
-                vm.inspect_transaction_with_bytecode_compression(
-                    &mut full_tracer,
-                    tx,
-                    with_compression,
-                )
+                let fast_tracer =
+                    OptionalCallTracer(params.trace_calls.then(vm_fast::CallTracer::default));
+                let mut full_tracer = (legacy_tracers.into(), fast_tracer);
+                let (compression_result, tx_result) = vm
+                    .inspect_transaction_with_bytecode_compression(
+                        &mut full_tracer,
+                        tx,
+                        with_compression,
+                    );
+                let compression_result = compression_result.map(drop);
+
+                let OptionalCallTracer(fast_tracer) = full_tracer.1;
+                if let Some(fast_tracer) = fast_tracer {
+                    let fast_calls = fast_tracer.into_result();
+                    match vm {
+                        FastVmInstance::Fast(_) => {
+                            calls_result = Arc::new(OnceCell::with_value(fast_calls));
+                        }
+                        FastVmInstance::Shadowed(vm) => {
+                            // The legacy VM is the main one, so its traces are returned.
+                            let legacy_calls = calls_result.get().map_or(&[][..], Vec::as_slice);
+                            vm.check_divergence("call_traces", legacy_calls, &fast_calls);
+                        }
+                    }
+                }
+                (compression_result, tx_result)
             }
         };
 
         OneshotTransactionExecutionResult {
             tx_result: Box::new(tx_result),
-            compression_result: compression_result.map(drop),
+            compression_result,
             call_traces: Arc::make_mut(&mut calls_result).take().unwrap_or_default(),
+            accessed_storage_keys: vec![],
+            prestate: prestate_result.map(|result| {
+                let (pre, post) = result.get().cloned().unwrap_or_default();
+                PrestateTracerResult {
+                    pre: convert_prestate(pre),
+                    post: convert_prestate(post),
+                }
+            }),
         }
     }
 
     fn create_legacy_tracers<H: HistoryMode>(
         missed_storage_invocation_limit: usize,
         calls_result: Option<Arc<OnceCell<Vec<Call>>>>,
+        prestate_tracer: Option<PrestateTracer>,
     ) -> TracerDispatcher<StorageView<S>, H> {
         let mut tracers = vec![];
         if let Some(calls_result) = calls_result {
             tracers.push(CallTracer::new(calls_result).into_tracer_pointer());
         }
+        if let Some(prestate_tracer) = prestate_tracer {
+            tracers.push(prestate_tracer.into_tracer_pointer());
+        }
         tracers
             .push(StorageInvocations::new(missed_storage_invocation_limit).into_tracer_pointer());
         tracers.into()
     }
 }
 
+fn convert_prestate(
+    state: HashMap<Address, PrestateTracerAccount>,
+) -> HashMap<Address, PrestateAccountState> {
+    state
+        .into_iter()
+        .map(|(address, account)| {
+            let account = PrestateAccountState {
+                balance: account.balance,
+                code_hash: account.code.map(u256_to_h256),
+                nonce: account.nonce,
+                storage: account.storage.unwrap_or_default(),
+            };
+            (address, account)
+        })
+        .collect()
+}
+
+/// Collects storage slots accessed by the VM. Since the storage is set up outside the storage view,
+/// the view only contains slots read or written during VM execution.
+fn accessed_storage_keys<S: ReadStorage>(storage_view: &StorageView<S>) -> Vec<StorageKey> {
+    let read_keys = storage_view.read_storage_keys().keys();
+    let written_keys = storage_view.modified_storage_keys().keys();
+    let keys: BTreeSet<_> = read_keys.chain(written_keys).copied().collect();
+    keys.into_iter().collect()
+}
+
 /// Full parameters necessary to instantiate a VM for oneshot execution.
 #[derive(Debug)]
 struct VmSandbox<S> {
@@ -345,7 +444,11 @@ impl<S: ReadStorage> VmSandbox<S> {
     /// This method is blocking.
     fn execute_in_vm<T>(
         mut self,
-        action: impl FnOnce(&mut Vm<StorageWithOverrides<S>>, Transaction) -> T,
+        action: impl FnOnce(
+            &mut Vm<StorageWithOverrides<S>>,
+            Transaction,
+            &StoragePtr<StorageView<StorageWithOverrides<S>>>,
+        ) -> T,
     ) -> T {
         Self::setup_storage(
             &mut self.storage,
@@ -399,7 +502,7 @@ impl<S: ReadStorage> VmSandbox<S> {
         };
 
         let started_at = Instant::now();
-        let result = action(&mut vm, transaction);
+        let result = action(&mut vm, transaction, &storage_view);
         let vm_execution_took = started_at.elapsed();
 
         if let Some(histogram) = self.execution_latency_histogram {
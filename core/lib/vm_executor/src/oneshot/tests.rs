@@ -3,7 +3,7 @@
 use assert_matches::assert_matches;
 use test_casing::{test_casing, Product};
 use zksync_multivm::interface::storage::InMemoryStorage;
-use zksync_types::{ProtocolVersionId, H256};
+use zksync_types::{ProtocolVersionId, H256, L2_BASE_TOKEN_ADDRESS};
 
 use super::*;
 use crate::testonly::{
@@ -30,15 +30,19 @@ fn selecting_vm_for_execution() {
         let mode = executor.select_fast_vm_mode(&env, &OneshotTracingParams::default());
         assert_matches!(mode, FastVmMode::New);
 
-        // Tracing calls is not supported by the new VM.
-        let mode = executor.select_fast_vm_mode(&env, &OneshotTracingParams { trace_calls: true });
-        assert_matches!(mode, FastVmMode::Old);
-
-        // Old protocol versions are not supported either.
+        // Old protocol versions are not supported by the new VM.
         let mut old_env = env.clone();
         old_env.system.version = ProtocolVersionId::Version22;
         let mode = executor.select_fast_vm_mode(&old_env, &OneshotTracingParams::default());
         assert_matches!(mode, FastVmMode::Old);
+
+        // The prestate tracer is not supported by the new VM.
+        let tracing_params = OneshotTracingParams {
+            trace_prestate: Some(PrestateTracingMode::Prestate),
+            ..OneshotTracingParams::default()
+        };
+        let mode = executor.select_fast_vm_mode(&env, &tracing_params);
+        assert_matches!(mode, FastVmMode::Old);
     }
 }
 
@@ -104,3 +108,97 @@ async fn inspecting_transfer(exec_mode: TxExecutionMode, fast_vm_mode: FastVmMod
     let exec_result = result.tx_result.result;
     assert!(!exec_result.is_failed(), "{exec_result:?}");
 }
+
+#[test_casing(3, FAST_VM_MODES)]
+#[tokio::test]
+async fn collecting_accessed_storage_keys(fast_vm_mode: FastVmMode) {
+    let tx = create_l2_transaction(1_000_000_000.into(), Nonce(0));
+    let initiator = tx.initiator_account();
+    let mut storage = InMemoryStorage::with_system_contracts();
+    storage.set_value(
+        storage_key_for_eth_balance(&initiator),
+        u256_to_h256(u64::MAX.into()),
+    );
+    let storage = StorageWithOverrides::new(storage);
+
+    let l1_batch = default_l1_batch_env(1);
+    let env = OneshotEnv {
+        system: default_system_env(TxExecutionMode::EthCall),
+        current_block: Some(StoredL2BlockEnv {
+            number: l1_batch.first_l2_block.number - 1,
+            timestamp: l1_batch.first_l2_block.timestamp - 1,
+            txs_rolling_hash: H256::zero(),
+        }),
+        l1_batch,
+    };
+    let args = TxExecutionArgs::for_eth_call(tx);
+    let tracing = OneshotTracingParams {
+        trace_storage_access: true,
+        ..OneshotTracingParams::default()
+    };
+
+    let mut executor = MainOneshotExecutor::new(usize::MAX);
+    executor.set_fast_vm_mode(fast_vm_mode);
+    let result = executor
+        .inspect_transaction_with_bytecode_compression(storage, env, args, tracing)
+        .await
+        .unwrap();
+    let exec_result = result.tx_result.result;
+    assert!(!exec_result.is_failed(), "{exec_result:?}");
+
+    let accessed_keys = &result.accessed_storage_keys;
+    assert!(accessed_keys.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(accessed_keys.contains(&storage_key_for_eth_balance(&initiator)));
+    assert!(accessed_keys.contains(&get_nonce_key(&initiator)));
+}
+
+#[test_casing(3, FAST_VM_MODES)]
+#[tokio::test]
+async fn tracing_prestate(fast_vm_mode: FastVmMode) {
+    let tx = create_l2_transaction(1_000_000_000.into(), Nonce(0));
+    let initiator = tx.initiator_account();
+    let mut storage = InMemoryStorage::with_system_contracts();
+    storage.set_value(
+        storage_key_for_eth_balance(&initiator),
+        u256_to_h256(u64::MAX.into()),
+    );
+    let storage = StorageWithOverrides::new(storage);
+
+    let l1_batch = default_l1_batch_env(1);
+    let env = OneshotEnv {
+        system: default_system_env(TxExecutionMode::EthCall),
+        current_block: Some(StoredL2BlockEnv {
+            number: l1_batch.first_l2_block.number - 1,
+            timestamp: l1_batch.first_l2_block.timestamp - 1,
+            txs_rolling_hash: H256::zero(),
+        }),
+        l1_batch,
+    };
+    let args = TxExecutionArgs::for_eth_call(tx);
+    let tracing = OneshotTracingParams {
+        trace_prestate: Some(PrestateTracingMode::Prestate),
+        ..OneshotTracingParams::default()
+    };
+
+    let mut executor = MainOneshotExecutor::new(usize::MAX);
+    executor.set_fast_vm_mode(fast_vm_mode);
+    let result = executor
+        .inspect_transaction_with_bytecode_compression(storage, env, args, tracing)
+        .await
+        .unwrap();
+    let exec_result = result.tx_result.result;
+    assert!(!exec_result.is_failed(), "{exec_result:?}");
+
+    let prestate = result.prestate.expect("no prestate");
+    assert!(prestate.post.is_empty(), "{prestate:?}");
+    // The base token contract is accessed during execution when paying fees.
+    let base_token_state = &prestate.pre[&L2_BASE_TOKEN_ADDRESS];
+    assert!(base_token_state.balance.is_some());
+    assert!(base_token_state.code_hash.is_some());
+    // The initiator balance is reported as of before the execution.
+    let balance_key = storage_key_for_eth_balance(&initiator);
+    assert_eq!(
+        base_token_state.storage[balance_key.key()],
+        u256_to_h256(u64::MAX.into())
+    );
+}
@@ -69,6 +69,16 @@ impl TxExecutionArgs {
         }
     }
 
+    /// Arguments to re-execute a transaction that was included into a block, i.e., without any overrides.
+    pub fn for_replay(transaction: Transaction) -> Self {
+        Self {
+            enforced_nonce: None,
+            added_balance: U256::zero(),
+            adjust_pubdata_price: false,
+            transaction,
+        }
+    }
+
     pub fn for_gas_estimate(transaction: Transaction) -> Self {
         // For L2 transactions we need to explicitly put enough balance into the account of the users
         // while for L1->L2 transactions the `to_mint` field plays this role
@@ -92,4 +102,17 @@ impl TxExecutionArgs {
 pub struct OneshotTracingParams {
     /// Whether to trace contract calls.
     pub trace_calls: bool,
+    /// Whether to collect storage slots accessed during execution.
+    pub trace_storage_access: bool,
+    /// Whether to run the prestate tracer, and in which mode.
+    pub trace_prestate: Option<PrestateTracingMode>,
+}
+
+/// Mode of the prestate tracer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrestateTracingMode {
+    /// Collect the state of all accounts accessed during execution.
+    Prestate,
+    /// Collect the state of accounts modified during execution before and after execution.
+    Diff,
 }
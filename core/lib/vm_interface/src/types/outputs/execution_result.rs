@@ -9,7 +9,7 @@ use zksync_types::{
     ethabi,
     l2_to_l1_log::{SystemL2ToL1Log, UserL2ToL1Log},
     zk_evm_types::FarCallOpcode,
-    Address, L1BatchNumber, StorageLogWithPreviousValue, Transaction, H256, U256,
+    Address, L1BatchNumber, StorageKey, StorageLogWithPreviousValue, Transaction, H256, U256,
 };
 
 use crate::{
@@ -360,6 +360,30 @@ pub struct OneshotTransactionExecutionResult {
     pub compression_result: Result<(), BytecodeCompressionError>,
     /// Call traces (if requested; otherwise, empty).
     pub call_traces: Vec<Call>,
+    /// Storage slots read or written during execution, sorted by key (if requested; otherwise, empty).
+    pub accessed_storage_keys: Vec<StorageKey>,
+    /// Output of the prestate tracer (if requested; otherwise, `None`).
+    pub prestate: Option<PrestateTracerResult>,
+}
+
+/// Account state collected by the prestate tracer.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PrestateAccountState {
+    pub balance: Option<U256>,
+    /// Versioned hash of the account bytecode.
+    pub code_hash: Option<H256>,
+    /// Full account nonce, i.e. including the deployment nonce.
+    pub nonce: Option<U256>,
+    pub storage: HashMap<H256, H256>,
+}
+
+/// Output of the prestate tracer. In the default mode, only `pre` is populated, with the state of all accounts
+/// and slots accessed (read or written) during execution as of before the execution. In the diff mode, `pre` and `post` contain
+/// the state of modified accounts before and after execution, respectively.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PrestateTracerResult {
+    pub pre: HashMap<Address, PrestateAccountState>,
+    pub post: HashMap<Address, PrestateAccountState>,
 }
 
 /// High-level transaction execution result used by the API server sandbox etc.
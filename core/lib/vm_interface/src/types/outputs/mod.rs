@@ -4,8 +4,9 @@ pub use self::{
     bytecode::CompressedBytecodeInfo,
     execution_result::{
         BatchTransactionExecutionResult, Call, CallType, ExecutionResult,
-        OneshotTransactionExecutionResult, Refunds, TransactionExecutionResult, TxExecutionStatus,
-        VmEvent, VmExecutionLogs, VmExecutionResultAndLogs,
+        OneshotTransactionExecutionResult, PrestateAccountState, PrestateTracerResult, Refunds,
+        TransactionExecutionResult, TxExecutionStatus, VmEvent, VmExecutionLogs,
+        VmExecutionResultAndLogs,
     },
     execution_state::{BootloaderMemory, CurrentExecutionState},
     finished_l1batch::FinishedL1Batch,
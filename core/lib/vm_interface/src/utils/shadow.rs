@@ -13,7 +13,7 @@ use super::dump::{DumpingVm, VmDump};
 use crate::{
     pubdata::PubdataBuilder,
     storage::{ReadStorage, StoragePtr, StorageView},
-    BytecodeCompressionResult, CurrentExecutionState, FinishedL1Batch, InspectExecutionMode,
+    BytecodeCompressionResult, Call, CurrentExecutionState, FinishedL1Batch, InspectExecutionMode,
     L1BatchEnv, L2BlockEnv, PushTransactionResult, SystemEnv, VmExecutionResultAndLogs, VmFactory,
     VmInterface, VmInterfaceHistoryEnabled, VmTrackingContracts,
 };
@@ -224,6 +224,16 @@ impl CheckDivergence for FinishedL1Batch {
     }
 }
 
+/// Compares call traces recursively, so that a divergence is reported for the innermost diverging call
+/// instead of the entire trace.
+impl CheckDivergence for [Call] {
+    fn check_divergence(&self, other: &Self) -> DivergenceErrors {
+        let mut errors = DivergenceErrors::new();
+        errors.check_call_traces("call_traces", self, other);
+        errors
+    }
+}
+
 /// Shadowed VM that executes 2 VMs for each operation and compares their outputs.
 ///
 /// If a divergence is detected, the VM state is dumped using [a pluggable handler](Self::set_dump_handler()),
@@ -292,6 +302,22 @@ where
         main_output
     }
 
+    /// Checks whether values obtained from the main and shadow VM outside of this wrapper (e.g., outputs of tracers)
+    /// match. Does nothing if the shadow VM has already diverged.
+    pub fn check_divergence<R: CheckDivergence + ?Sized>(
+        &mut self,
+        name: &str,
+        main: &R,
+        shadow: &R,
+    ) {
+        if self.shadow.get_mut().is_none() {
+            return;
+        }
+        if let Err(err) = main.check_divergence(shadow).into_result() {
+            self.report(err.context(format!("check_divergence({name})")));
+        }
+    }
+
     /// Gets the specified value from both the main and shadow VM, potentially changing their state
     /// and checking whether the returned value matches.
     pub fn get_mut<R>(
@@ -529,6 +555,27 @@ impl DivergenceErrors {
         }
     }
 
+    fn check_call_traces(&mut self, context: &str, main: &[Call], shadow: &[Call]) {
+        self.check_match(&format!("{context}.len()"), &main.len(), &shadow.len());
+        for (i, (main_call, shadow_call)) in main.iter().zip(shadow).enumerate() {
+            let context = format!("{context}[{i}]");
+            let main_shallow = Call {
+                calls: vec![],
+                ..main_call.clone()
+            };
+            let shadow_shallow = Call {
+                calls: vec![],
+                ..shadow_call.clone()
+            };
+            self.check_match(&context, &main_shallow, &shadow_shallow);
+            self.check_call_traces(
+                &format!("{context}.calls"),
+                &main_call.calls,
+                &shadow_call.calls,
+            );
+        }
+    }
+
     fn gather_logs(logs: &[StorageLog]) -> BTreeMap<StorageKey, &StorageLog> {
         logs.iter()
             .filter(|log| log.is_write())
@@ -0,0 +1,672 @@
+//! JSON-RPC client failing over between several endpoints.
+
+use std::{
+    any, fmt,
+    future::Future,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use jsonrpsee::{
+    core::{
+        client::{BatchResponse, ClientT, Error},
+        params::BatchRequestBuilder,
+        traits::ToRpcParams,
+    },
+    http_client::HttpClient,
+};
+use serde::de::DeserializeOwned;
+use tokio::time::Instant;
+use zksync_types::url::SensitiveUrl;
+
+use super::{
+    boxed::RawParams,
+    metrics::{EndpointLabels, FailoverClientMetrics, FAILOVER_METRICS},
+    CallOrigin, Client, ClientBase, ClientBuilder, ForWeb3Network, Network, TaggedClient,
+};
+use crate::error::is_retriable;
+
+/// RPC error codes used by providers to signal rate limiting: `-32005` is the "limit exceeded" code
+/// from EIP-1474, and some providers mirror the HTTP 429 status as the error code.
+const RATE_LIMIT_ERROR_CODES: [i32; 2] = [-32005, 429];
+/// Cooldown is doubled after each consecutive failure of an endpoint, up to `2^MAX_COOLDOWN_EXPONENT` times.
+const MAX_COOLDOWN_EXPONENT: u32 = 5;
+/// Method whose responses are cross-checked if block hash cross-checks are enabled.
+const CROSS_CHECKED_METHOD: &str = "eth_getBlockByNumber";
+
+/// Whether a call failed because of the endpoint rather than the call itself, i.e., whether the call
+/// may succeed if sent to another endpoint.
+fn should_fail_over(err: &Error) -> bool {
+    match err {
+        Error::RestartNeeded(_) => true,
+        Error::Call(err) if RATE_LIMIT_ERROR_CODES.contains(&err.code()) => true,
+        _ => is_retriable(err),
+    }
+}
+
+#[derive(Debug, Default)]
+struct EndpointHealth {
+    consecutive_failures: u32,
+    unhealthy_until: Option<Instant>,
+}
+
+/// JSON-RPC client sending calls to several endpoints (e.g., several L1 providers) in the order of preference.
+///
+/// A call is sent to the next endpoint if the previous one fails with a transport error (including HTTP 5xx
+/// and 429 responses), a timeout, or a rate-limiting RPC error. RPC errors not caused by the endpoint
+/// (e.g., reverted calls) are returned as is. A failed endpoint is considered unhealthy for a cooldown
+/// that grows with consecutive failures; unhealthy endpoints are only tried after all healthy ones.
+/// Endpoint health is shared among all clones of the client.
+///
+/// Optionally, blocks returned by `eth_getBlockByNumber` are cross-checked with another endpoint, and the call
+/// fails if the endpoints return different hashes for the same block number.
+#[derive(Clone)]
+pub struct FailoverClient<Net, C = HttpClient> {
+    endpoints: Vec<Client<Net, C>>,
+    health: Arc<[Mutex<EndpointHealth>]>,
+    cooldown: Duration,
+    cross_check_block_hashes: bool,
+    component_name: &'static str,
+    metrics: &'static FailoverClientMetrics,
+    network: Net,
+}
+
+impl<Net: fmt::Debug, C: 'static> fmt::Debug for FailoverClient<Net, C> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("FailoverClient")
+            .field("endpoints", &self.endpoints)
+            .field("cooldown", &self.cooldown)
+            .field("cross_check_block_hashes", &self.cross_check_block_hashes)
+            .field("component_name", &self.component_name)
+            .field("network", &self.network)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Net: Network> FailoverClient<Net> {
+    /// Creates a client with HTTP-backed endpoints. URLs must be specified in the order of preference.
+    pub fn http(urls: Vec<SensitiveUrl>) -> anyhow::Result<FailoverClientBuilder<Net>> {
+        anyhow::ensure!(!urls.is_empty(), "no JSON-RPC endpoints specified");
+        let endpoints = urls
+            .into_iter()
+            .map(Client::http)
+            .collect::<anyhow::Result<_>>()?;
+        Ok(FailoverClientBuilder::new(endpoints))
+    }
+}
+
+impl<Net: Network, C: ClientBase> FailoverClient<Net, C> {
+    fn endpoint_labels(&self, endpoint: usize) -> EndpointLabels {
+        EndpointLabels {
+            network: self.network.metric_label(),
+            endpoint,
+        }
+    }
+
+    /// Returns endpoint indices in the order in which they should be tried: healthy endpoints in the order
+    /// of preference, then unhealthy ones in the order of their cooldown expiration.
+    fn endpoint_order(&self) -> Vec<usize> {
+        let now = Instant::now();
+        let mut healthy = Vec::with_capacity(self.endpoints.len());
+        let mut unhealthy = vec![];
+        for (i, health) in self.health.iter().enumerate() {
+            match health.lock().unwrap().unhealthy_until {
+                Some(until) if until > now => unhealthy.push((until, i)),
+                _ => healthy.push(i),
+            }
+        }
+        unhealthy.sort_unstable();
+        healthy.extend(unhealthy.into_iter().map(|(_, i)| i));
+        healthy
+    }
+
+    fn mark_success(&self, endpoint: usize, latency: Duration) {
+        *self.health[endpoint].lock().unwrap() = EndpointHealth::default();
+        let labels = self.endpoint_labels(endpoint);
+        self.metrics.endpoint_latency[&labels].observe(latency);
+        self.metrics.endpoint_healthy[&labels].set(1);
+    }
+
+    fn mark_failure(&self, endpoint: usize, origin: CallOrigin<'_>, err: &Error) {
+        let cooldown = {
+            let mut health = self.health[endpoint].lock().unwrap();
+            let exponent = health.consecutive_failures.min(MAX_COOLDOWN_EXPONENT);
+            health.consecutive_failures += 1;
+            let cooldown = self.cooldown * 2_u32.pow(exponent);
+            health.unhealthy_until = Some(Instant::now() + cooldown);
+            cooldown
+        };
+
+        let labels = self.endpoint_labels(endpoint);
+        self.metrics.failovers[&labels].inc();
+        self.metrics.endpoint_healthy[&labels].set(0);
+        tracing::warn!(
+            network = labels.network,
+            component = self.component_name,
+            %origin,
+            "Call to {origin} by component `{}` failed on JSON-RPC endpoint #{endpoint} ({:?}): {err}; \
+             marking the endpoint as unhealthy for {cooldown:?}",
+            self.component_name,
+            self.endpoints[endpoint].url
+        );
+    }
+
+    /// Performs a call on endpoints in the order returned by [`Self::endpoint_order()`] until an endpoint responds.
+    /// Returns the index of the responding endpoint together with the call result.
+    async fn call_with_failover<T, F, Fut>(
+        &self,
+        origin: CallOrigin<'_>,
+        call: F,
+    ) -> Result<(usize, T), Error>
+    where
+        F: Fn(usize) -> Fut + Send,
+        Fut: Future<Output = Result<T, Error>> + Send,
+    {
+        let mut last_err = None;
+        for endpoint in self.endpoint_order() {
+            let started_at = Instant::now();
+            match call(endpoint).await {
+                Err(err) if should_fail_over(&err) => {
+                    self.mark_failure(endpoint, origin, &err);
+                    last_err = Some(err);
+                }
+                result => {
+                    self.mark_success(endpoint, started_at.elapsed());
+                    return result.map(|output| (endpoint, output));
+                }
+            }
+        }
+        Err(last_err.expect("client has no endpoints"))
+    }
+
+    /// Cross-checks a block returned by `endpoint` with the next endpoint in the order of preference.
+    /// Errors and `null` responses from the other endpoint are logged and ignored, since cross-checks are best-effort.
+    async fn cross_check_block(
+        &self,
+        endpoint: usize,
+        params: RawParams,
+        block: &serde_json::Value,
+    ) -> Result<(), Error> {
+        let Some((number, hash)) = block_number_and_hash(block) else {
+            return Ok(()); // The block is not available
+        };
+        let Some(other_endpoint) = self.endpoint_order().into_iter().find(|&i| i != endpoint)
+        else {
+            return Ok(());
+        };
+
+        let other_block: serde_json::Value = match self.endpoints[other_endpoint]
+            .request(CROSS_CHECKED_METHOD, params)
+            .await
+        {
+            Ok(block) => block,
+            Err(err) => {
+                tracing::info!(
+                    "Cannot cross-check block {number} with JSON-RPC endpoint #{other_endpoint}: {err}"
+                );
+                return Ok(());
+            }
+        };
+        let Some((other_number, other_hash)) = block_number_and_hash(&other_block) else {
+            return Ok(());
+        };
+
+        if number == other_number && hash != other_hash {
+            let network_label = self.network.metric_label();
+            self.metrics.block_hash_mismatches[&network_label].inc();
+            // Reported as a transport error, so that callers treat it as transient: the mismatch can be caused
+            // by a reorg racing with the requests.
+            let message = format!(
+                "block {number} has hash {hash} on JSON-RPC endpoint #{endpoint}, \
+                 but {other_hash} on endpoint #{other_endpoint}"
+            );
+            tracing::warn!(network = network_label, "{message}");
+            return Err(Error::Transport(anyhow::Error::msg(message)));
+        }
+        Ok(())
+    }
+}
+
+fn block_number_and_hash(block: &serde_json::Value) -> Option<(&str, &str)> {
+    Some((block.get("number")?.as_str()?, block.get("hash")?.as_str()?))
+}
+
+impl<Net: Network, C: ClientBase> ForWeb3Network for FailoverClient<Net, C> {
+    type Net = Net;
+
+    fn network(&self) -> Self::Net {
+        self.network
+    }
+
+    fn component(&self) -> &'static str {
+        self.component_name
+    }
+}
+
+impl<Net: Network, C: ClientBase> TaggedClient for FailoverClient<Net, C> {
+    fn set_component(&mut self, component_name: &'static str) {
+        self.component_name = component_name;
+        for endpoint in &mut self.endpoints {
+            endpoint.set_component(component_name);
+        }
+    }
+}
+
+#[async_trait]
+impl<Net: Network, C: ClientBase> ClientT for FailoverClient<Net, C> {
+    async fn notification<Params>(&self, method: &str, params: Params) -> Result<(), Error>
+    where
+        Params: ToRpcParams + Send,
+    {
+        let params = RawParams::new(params)?;
+        let origin = CallOrigin::Notification(method);
+        self.call_with_failover(origin, |i| {
+            self.endpoints[i].notification(method, params.clone())
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn request<R, Params>(&self, method: &str, params: Params) -> Result<R, Error>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        let params = RawParams::new(params)?;
+        let origin = CallOrigin::Request(method);
+        let (endpoint, response) = self
+            .call_with_failover(origin, |i| {
+                self.endpoints[i].request::<serde_json::Value, _>(method, params.clone())
+            })
+            .await?;
+        if self.cross_check_block_hashes && method == CROSS_CHECKED_METHOD {
+            self.cross_check_block(endpoint, params, &response).await?;
+        }
+        serde_json::from_value(response).map_err(Error::ParseError)
+    }
+
+    async fn batch_request<'a, R>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, Error>
+    where
+        R: DeserializeOwned + fmt::Debug + 'a,
+    {
+        let origin = CallOrigin::BatchRequest(&batch);
+        let (_, response) = self
+            .call_with_failover(origin, |i| self.endpoints[i].batch_request(batch.clone()))
+            .await?;
+        Ok(response)
+    }
+}
+
+/// Builder for the [`FailoverClient`].
+pub struct FailoverClientBuilder<Net, C = HttpClient> {
+    endpoints: Vec<ClientBuilder<Net, C>>,
+    cooldown: Duration,
+    cross_check_block_hashes: bool,
+}
+
+impl<Net: fmt::Debug, C: 'static> fmt::Debug for FailoverClientBuilder<Net, C> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("FailoverClientBuilder")
+            .field("client", &any::type_name::<C>())
+            .field("endpoints", &self.endpoints)
+            .field("cooldown", &self.cooldown)
+            .field("cross_check_block_hashes", &self.cross_check_block_hashes)
+            .finish()
+    }
+}
+
+impl<Net: Network, C: ClientBase> FailoverClientBuilder<Net, C> {
+    const DEFAULT_COOLDOWN: Duration = Duration::from_secs(5);
+
+    /// Wraps the provided endpoints.
+    fn new(endpoints: Vec<ClientBuilder<Net, C>>) -> Self {
+        assert!(!endpoints.is_empty(), "no JSON-RPC endpoints specified");
+        Self {
+            endpoints,
+            cooldown: Self::DEFAULT_COOLDOWN,
+            cross_check_block_hashes: false,
+        }
+    }
+
+    /// Specifies the network to be used by the client. The network is logged and is used as a metrics label.
+    pub fn for_network(mut self, network: Net) -> Self {
+        self.endpoints = self
+            .endpoints
+            .into_iter()
+            .map(|endpoint| endpoint.for_network(network))
+            .collect();
+        self
+    }
+
+    /// Sets the rate limit for each of the endpoints. As with [`Client`], the rate limit is applied across
+    /// all client instances, including cloned ones.
+    pub fn with_allowed_requests_per_second(mut self, rps: NonZeroUsize) -> Self {
+        self.endpoints = self
+            .endpoints
+            .into_iter()
+            .map(|endpoint| endpoint.with_allowed_requests_per_second(rps))
+            .collect();
+        self
+    }
+
+    /// Sets the base cooldown for failed endpoints. The cooldown is doubled after each consecutive failure
+    /// of an endpoint, up to 32 times. The default value is 5 seconds.
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Enables or disables cross-checking block hashes returned by `eth_getBlockByNumber` calls with another endpoint.
+    /// Disabled by default.
+    pub fn with_block_hash_cross_check(mut self, enabled: bool) -> Self {
+        self.cross_check_block_hashes = enabled;
+        self
+    }
+
+    /// Builds the client.
+    pub fn build(self) -> FailoverClient<Net, C> {
+        let network = self.endpoints[0].network;
+        tracing::info!(
+            "Creating failover JSON-RPC client for network {network:?} with {} endpoints, cooldown {:?} \
+             and block hash cross-checks {}",
+            self.endpoints.len(),
+            self.cooldown,
+            if self.cross_check_block_hashes { "enabled" } else { "disabled" }
+        );
+
+        let endpoints: Vec<_> = self
+            .endpoints
+            .into_iter()
+            .map(ClientBuilder::build)
+            .collect();
+        let health = endpoints.iter().map(|_| Mutex::default()).collect();
+        FailoverClient {
+            endpoints,
+            health,
+            cooldown: self.cooldown,
+            cross_check_block_hashes: self.cross_check_block_hashes,
+            component_name: "",
+            metrics: &FAILOVER_METRICS,
+            network,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use assert_matches::assert_matches;
+    use jsonrpsee::{
+        http_client::transport,
+        types::{error::ErrorCode, ErrorObject},
+    };
+    use zksync_types::{L2ChainId, H256, U64};
+
+    use super::*;
+    use crate::{
+        client::{MockClient, L2},
+        namespaces::EthNamespaceClient,
+    };
+
+    const COOLDOWN: Duration = Duration::from_secs(10);
+
+    #[derive(Debug, Default)]
+    struct CallCounters(Vec<AtomicUsize>);
+
+    impl CallCounters {
+        fn new(len: usize) -> Arc<Self> {
+            Arc::new(Self((0..len).map(|_| AtomicUsize::new(0)).collect()))
+        }
+
+        fn get(&self) -> Vec<usize> {
+            self.0
+                .iter()
+                .map(|count| count.load(Ordering::SeqCst))
+                .collect()
+        }
+    }
+
+    fn http_error(status_code: u16) -> Error {
+        Error::Transport(transport::Error::Rejected { status_code }.into())
+    }
+
+    /// Creates a client whose endpoints respond to `eth_blockNumber` according to `responses`.
+    fn mock_client(
+        responses: Vec<fn() -> Result<U64, Error>>,
+        counters: &Arc<CallCounters>,
+    ) -> FailoverClient<L2, MockClient<L2>> {
+        let endpoints = responses
+            .into_iter()
+            .enumerate()
+            .map(|(i, response)| {
+                let counters = counters.clone();
+                let client = MockClient::builder(L2::default())
+                    .method("eth_blockNumber", move || {
+                        counters.0[i].fetch_add(1, Ordering::SeqCst);
+                        response()
+                    })
+                    .build();
+                let url = format!("http://endpoint-{i}/").parse().unwrap();
+                ClientBuilder::new(client, url).report_config(false)
+            })
+            .collect();
+
+        let mut client = FailoverClientBuilder::new(endpoints)
+            .for_network(L2ChainId::default().into())
+            .with_cooldown(COOLDOWN)
+            .build();
+        client.metrics = Box::leak(Box::default());
+        client
+    }
+
+    #[tokio::test]
+    async fn failing_over_on_transport_errors() {
+        tokio::time::pause();
+        let counters = CallCounters::new(2);
+        let client = mock_client(
+            vec![|| Err(http_error(503)), || Ok(U64::from(42))],
+            &counters,
+        );
+
+        let block_number = client.get_block_number().await.unwrap();
+        assert_eq!(block_number, 42.into());
+        assert_eq!(counters.get(), [1, 1]);
+        let labels = client.endpoint_labels(0);
+        assert_eq!(client.metrics.failovers[&labels].get(), 1);
+        assert_eq!(client.metrics.endpoint_healthy[&labels].get(), 0);
+
+        // The failed endpoint should not be called during the cooldown.
+        client.get_block_number().await.unwrap();
+        assert_eq!(counters.get(), [1, 2]);
+
+        tokio::time::advance(COOLDOWN).await;
+        client.get_block_number().await.unwrap();
+        assert_eq!(counters.get(), [2, 3]);
+        // The cooldown should be doubled after the second failure.
+        tokio::time::advance(COOLDOWN).await;
+        client.get_block_number().await.unwrap();
+        assert_eq!(counters.get(), [2, 4]);
+        tokio::time::advance(COOLDOWN).await;
+        client.get_block_number().await.unwrap();
+        assert_eq!(counters.get(), [3, 5]);
+    }
+
+    #[tokio::test]
+    async fn failing_over_on_rate_limiting() {
+        let counters = CallCounters::new(3);
+        let client = mock_client(
+            vec![
+                || Err(http_error(429)),
+                || {
+                    let err = ErrorObject::owned(-32005, "limit exceeded", None::<()>);
+                    Err(Error::Call(err))
+                },
+                || Ok(U64::from(42)),
+            ],
+            &counters,
+        );
+
+        let block_number = client.get_block_number().await.unwrap();
+        assert_eq!(block_number, 42.into());
+        assert_eq!(counters.get(), [1, 1, 1]);
+        let mut cloned_client = client.clone();
+        cloned_client.set_component("test");
+        cloned_client.get_block_number().await.unwrap();
+        assert_eq!(counters.get(), [1, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn recovered_endpoint_is_preferred() {
+        static PRIMARY_FAILS: AtomicBool = AtomicBool::new(true);
+
+        tokio::time::pause();
+        let counters = CallCounters::new(2);
+        let client = mock_client(
+            vec![
+                || {
+                    if PRIMARY_FAILS.load(Ordering::SeqCst) {
+                        Err(Error::RequestTimeout)
+                    } else {
+                        Ok(U64::from(1))
+                    }
+                },
+                || Ok(U64::from(2)),
+            ],
+            &counters,
+        );
+
+        assert_eq!(client.get_block_number().await.unwrap(), 2.into());
+        PRIMARY_FAILS.store(false, Ordering::SeqCst);
+        tokio::time::advance(COOLDOWN).await;
+        assert_eq!(client.get_block_number().await.unwrap(), 1.into());
+        assert_eq!(client.get_block_number().await.unwrap(), 1.into());
+        assert_eq!(counters.get(), [3, 1]);
+        let labels = client.endpoint_labels(0);
+        assert_eq!(client.metrics.endpoint_healthy[&labels].get(), 1);
+    }
+
+    #[tokio::test]
+    async fn not_failing_over_on_rpc_errors() {
+        let counters = CallCounters::new(2);
+        let client = mock_client(
+            vec![
+                || {
+                    let err = ErrorObject::owned(3, "execution reverted", None::<()>);
+                    Err(Error::Call(err))
+                },
+                || Ok(U64::from(42)),
+            ],
+            &counters,
+        );
+
+        let err = client.get_block_number().await.unwrap_err();
+        assert_matches!(err, Error::Call(err) if err.code() == 3);
+        assert_eq!(counters.get(), [1, 0]);
+    }
+
+    #[tokio::test]
+    async fn all_endpoints_failing() {
+        let counters = CallCounters::new(2);
+        let client = mock_client(
+            vec![|| Err(http_error(502)), || Err(http_error(503))],
+            &counters,
+        );
+
+        let err = client.get_block_number().await.unwrap_err();
+        assert_matches!(err, Error::Transport(_));
+        assert_eq!(counters.get(), [1, 1]);
+        // Unhealthy endpoints should still be tried.
+        client.get_block_number().await.unwrap_err();
+        assert_eq!(counters.get(), [2, 2]);
+    }
+
+    fn mock_block(number: u64, hash: H256) -> serde_json::Value {
+        serde_json::json!({
+            "number": U64::from(number),
+            "hash": hash,
+        })
+    }
+
+    fn cross_checking_client(
+        blocks: [Option<serde_json::Value>; 2],
+    ) -> FailoverClient<L2, MockClient<L2>> {
+        let endpoints = blocks
+            .into_iter()
+            .enumerate()
+            .map(|(i, block)| {
+                let client = MockClient::builder(L2::default())
+                    .method(
+                        "eth_getBlockByNumber",
+                        move |_number: serde_json::Value, _full: bool| Ok(block.clone()),
+                    )
+                    .build();
+                let url = format!("http://endpoint-{i}/").parse().unwrap();
+                ClientBuilder::new(client, url).report_config(false)
+            })
+            .collect();
+
+        let mut client = FailoverClientBuilder::new(endpoints)
+            .for_network(L2ChainId::default().into())
+            .with_block_hash_cross_check(true)
+            .build();
+        client.metrics = Box::leak(Box::default());
+        client
+    }
+
+    async fn get_block(
+        client: &FailoverClient<L2, MockClient<L2>>,
+    ) -> Result<Option<serde_json::Value>, Error> {
+        client
+            .request(
+                CROSS_CHECKED_METHOD,
+                jsonrpsee::rpc_params!["latest", false],
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn cross_checking_block_hashes() {
+        let block = mock_block(1, H256::repeat_byte(1));
+        let client = cross_checking_client([Some(block.clone()), Some(block.clone())]);
+        assert_eq!(get_block(&client).await.unwrap(), Some(block.clone()));
+
+        // Different block numbers are not considered a mismatch.
+        let client =
+            cross_checking_client([Some(block.clone()), Some(mock_block(2, H256::zero()))]);
+        assert_eq!(get_block(&client).await.unwrap(), Some(block.clone()));
+        let client = cross_checking_client([Some(block.clone()), None]);
+        assert_eq!(get_block(&client).await.unwrap(), Some(block.clone()));
+        let client = cross_checking_client([None, Some(block.clone())]);
+        assert_eq!(get_block(&client).await.unwrap(), None);
+
+        let client =
+            cross_checking_client([Some(block.clone()), Some(mock_block(1, H256::zero()))]);
+        let err = get_block(&client).await.unwrap_err();
+        assert_matches!(&err, Error::Transport(_));
+        assert!(err.to_string().contains("block 0x1"), "{err}");
+        let network_label = client.network.metric_label();
+        assert_eq!(
+            client.metrics.block_hash_mismatches[&network_label].get(),
+            1
+        );
+    }
+
+    #[test]
+    fn classifying_errors() {
+        assert!(should_fail_over(&http_error(500)));
+        assert!(should_fail_over(&Error::RequestTimeout));
+        let internal_err = ErrorObject::owned(ErrorCode::InternalError.code(), "", None::<()>);
+        assert!(should_fail_over(&Error::Call(internal_err)));
+        let not_found = ErrorObject::owned(ErrorCode::MethodNotFound.code(), "", None::<()>);
+        assert!(!should_fail_over(&Error::Call(not_found)));
+        assert!(!should_fail_over(&Error::Custom("test".to_owned())));
+    }
+}
@@ -4,8 +4,8 @@ use std::time::Duration;
 
 use jsonrpsee::{core::client, http_client::transport};
 use vise::{
-    Buckets, Counter, DurationAsSecs, EncodeLabelSet, EncodeLabelValue, Family, Histogram, Info,
-    LabeledFamily, Metrics, Unit,
+    Buckets, Counter, DurationAsSecs, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram,
+    Info, LabeledFamily, Metrics, Unit,
 };
 
 use super::{AcquireStats, CallOrigin, SharedRateLimit};
@@ -211,3 +211,29 @@ impl L2ClientMetrics {
 
 #[vise::register]
 pub(super) static METRICS: vise::Global<L2ClientMetrics> = vise::Global::new();
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+pub(super) struct EndpointLabels {
+    pub network: String,
+    /// Zero-based index of the endpoint in the order of preference.
+    pub endpoint: usize,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "failover_client")]
+pub(super) struct FailoverClientMetrics {
+    /// Number of calls to an endpoint that failed with a transient error, so that the call was failed over
+    /// to the next endpoint.
+    pub failovers: Family<EndpointLabels, Counter>,
+    /// Latency of calls to an endpoint that have received a response.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub endpoint_latency: Family<EndpointLabels, Histogram<Duration>>,
+    /// Whether an endpoint is considered healthy (1) or is cooling down after failures (0).
+    pub endpoint_healthy: Family<EndpointLabels, Gauge<u64>>,
+    /// Number of block hash mismatches between endpoints detected during cross-checks.
+    #[metrics(labels = ["network"])]
+    pub block_hash_mismatches: LabeledFamily<String, Counter>,
+}
+
+#[vise::register]
+pub(super) static FAILOVER_METRICS: vise::Global<FailoverClientMetrics> = vise::Global::new();
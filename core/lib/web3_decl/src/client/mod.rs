@@ -5,6 +5,8 @@
 //!
 //! - [`Client`] is the main client implementation. It's parameterized by the transport (e.g., HTTP or WS),
 //!   with HTTP being the default option.
+//! - [`FailoverClient`] is a client sending calls to several endpoints (e.g., several L1 providers) and failing over
+//!   between them if an endpoint is unavailable.
 //! - [`MockClient`] is a mock client useful for testing. Bear in mind that because of the client being generic,
 //!   mock tooling is fairly low-level. Prefer defining a domain-specific wrapper trait for the client functionality and mock it
 //!   where it's possible.
@@ -37,12 +39,14 @@ use zksync_types::url::SensitiveUrl;
 use self::metrics::{L2ClientMetrics, METRICS};
 pub use self::{
     boxed::{DynClient, ObjectSafeClient},
+    failover::{FailoverClient, FailoverClientBuilder},
     mock::{MockClient, MockClientBuilder},
     network::{ForWeb3Network, Network, TaggedClient, L1, L2},
     shared::Shared,
 };
 
 mod boxed;
+mod failover;
 mod metrics;
 mod mock;
 mod network;
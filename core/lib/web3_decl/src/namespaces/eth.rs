@@ -3,8 +3,10 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use zksync_types::{
     api::{
-        state_override::StateOverride, BlockId, BlockIdVariant, BlockNumber, FeeHistory,
-        Transaction, TransactionVariant,
+        simulate::{SimulatePayload, SimulatedBlock},
+        state_override::StateOverride,
+        AccessListResult, BlockId, BlockIdVariant, BlockNumber, FeeHistory, Transaction,
+        TransactionVariant,
     },
     transaction_request::CallRequest,
     Address, H256,
@@ -49,6 +51,26 @@ pub trait EthNamespace {
         state_override: Option<StateOverride>,
     ) -> RpcResult<U256>;
 
+    /// Generates an access list for the call by executing it and recording accessed storage slots. Unlike Geth,
+    /// the returned `gasUsed` is the gas used by this execution and is not re-estimated with the access list applied,
+    /// since access lists don't influence gas costs on ZKsync. Use `eth_estimateGas` to get a gas limit for the transaction.
+    #[method(name = "createAccessList")]
+    async fn create_access_list(
+        &self,
+        req: CallRequest,
+        block: Option<BlockIdVariant>,
+        state_override: Option<StateOverride>,
+    ) -> RpcResult<AccessListResult>;
+
+    /// Simulates a sequence of blocks with calls on top of the specified block. See [`SimulatePayload::trace_transfers`]
+    /// for differences in base token transfer logs compared to Geth.
+    #[method(name = "simulateV1")]
+    async fn simulate_v1(
+        &self,
+        payload: SimulatePayload,
+        block: Option<BlockIdVariant>,
+    ) -> RpcResult<Vec<SimulatedBlock>>;
+
     #[method(name = "gasPrice")]
     async fn gas_price(&self) -> RpcResult<U256>;
 
@@ -136,9 +136,17 @@ impl TempConfigStore {
             let blob_operator = sender
                 .private_key_blobs()
                 .and_then(|operator| Wallet::from_private_key_bytes(operator, None).ok());
+            let prove_operator = sender
+                .private_key_prove()
+                .and_then(|operator| Wallet::from_private_key_bytes(operator, None).ok());
+            let execute_operator = sender
+                .private_key_execute()
+                .and_then(|operator| Wallet::from_private_key_bytes(operator, None).ok());
             Some(EthSender {
                 operator,
                 blob_operator,
+                prove_operator,
+                execute_operator,
             })
         });
         let state_keeper = self
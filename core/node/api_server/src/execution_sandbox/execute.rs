@@ -11,11 +11,15 @@ use zksync_multivm::interface::{
     storage::{ReadStorage, StorageWithOverrides},
     tracer::{TimestampAsserterParams, ValidationError, ValidationParams, ValidationTraces},
     Call, OneshotEnv, OneshotTracingParams, OneshotTransactionExecutionResult,
-    TransactionExecutionMetrics, TxExecutionArgs, VmExecutionResultAndLogs,
+    PrestateTracerResult, PrestateTracingMode, StoredL2BlockEnv, TransactionExecutionMetrics,
+    TxExecutionArgs, TxExecutionMode, VmExecutionResultAndLogs,
 };
 use zksync_state::{PostgresStorage, PostgresStorageCaches};
 use zksync_types::{
-    api::state_override::StateOverride, fee_model::BatchFeeInput, l2::L2Tx, Transaction,
+    api::state_override::StateOverride, block::L2BlockHasher, fee_model::BatchFeeInput,
+    h256_to_u256, l2::L2Tx, u256_to_h256, AccountTreeId, L2BlockNumber, StorageKey, Transaction,
+    H256, SYSTEM_CONTEXT_ADDRESS, SYSTEM_CONTEXT_CURRENT_L2_BLOCK_HASHES_POSITION,
+    SYSTEM_CONTEXT_STORED_L2_BLOCK_HASHES, U256,
 };
 use zksync_vm_executor::oneshot::{MainOneshotExecutor, MockOneshotExecutor};
 
@@ -23,7 +27,10 @@ use super::{
     vm_metrics::{self, SandboxStage},
     BlockArgs, VmPermit, SANDBOX_METRICS,
 };
-use crate::{execution_sandbox::storage::apply_state_override, tx_sender::SandboxExecutorOptions};
+use crate::{
+    execution_sandbox::storage::{apply_state_override, SharedPostgresStorage, StorageOverlay},
+    tx_sender::SandboxExecutorOptions,
+};
 
 /// Action that can be executed by [`SandboxExecutor`].
 #[derive(Debug)]
@@ -81,12 +88,79 @@ pub(crate) struct SandboxExecutionOutput {
     pub vm: VmExecutionResultAndLogs,
     /// Traced calls if requested.
     pub call_traces: Vec<Call>,
+    /// Storage slots accessed during execution if requested.
+    pub accessed_storage_keys: Vec<StorageKey>,
+    /// Output of the prestate tracer if requested.
+    pub prestate: Option<PrestateTracerResult>,
     /// Execution metrics.
     pub metrics: TransactionExecutionMetrics,
     /// Were published bytecodes OK?
     pub are_published_bytecodes_ok: bool,
 }
 
+/// Environment of a block simulated in `eth_simulateV1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SimulatedBlockEnv {
+    pub number: L2BlockNumber,
+    pub timestamp: u64,
+    /// Base fee enforced for all calls in the block.
+    pub base_fee: Option<u64>,
+}
+
+impl SimulatedBlockEnv {
+    /// Returns the environment of the first L2 block executed by the VM for `env`.
+    pub fn new(env: &OneshotEnv) -> Self {
+        let first_block = &env.l1_batch.first_l2_block;
+        Self {
+            number: L2BlockNumber(first_block.number),
+            timestamp: first_block.timestamp,
+            base_fee: env.l1_batch.enforced_base_fee,
+        }
+    }
+
+    /// Adjusts `env` to execute a transaction in this block. Returns a storage slot that should be overridden
+    /// to keep the system context consistent.
+    fn apply(&self, env: &mut OneshotEnv) -> Option<(StorageKey, H256)> {
+        if let Some(base_fee) = self.base_fee {
+            env.l1_batch.enforced_base_fee = Some(base_fee);
+        }
+        env.l1_batch.timestamp = self.timestamp;
+        let first_block = &mut env.l1_batch.first_l2_block;
+        first_block.timestamp = self.timestamp;
+        if first_block.number == self.number.0 {
+            // The block is executed on top of the actual state; the system context is already consistent.
+            return None;
+        }
+
+        // Otherwise, pretend that the simulated block is preceded by a block with an empty rolling hash,
+        // and with a synthetic hash stored for its parent. This is enough to pass system context checks.
+        let prev_block_number = self.number - 1;
+        let prev_block_timestamp = self.timestamp - 1;
+        let stored_hash_number = L2BlockNumber(prev_block_number.0.saturating_sub(1));
+        let stored_hash = L2BlockHasher::legacy_hash(stored_hash_number);
+        first_block.number = self.number.0;
+        first_block.prev_block_hash =
+            L2BlockHasher::new(prev_block_number, prev_block_timestamp, stored_hash)
+                .finalize(env.system.version);
+        env.current_block = Some(StoredL2BlockEnv {
+            number: prev_block_number.0,
+            timestamp: prev_block_timestamp,
+            txs_rolling_hash: H256::zero(),
+        });
+        Some((l2_block_hash_key(stored_hash_number), stored_hash))
+    }
+}
+
+/// Returns the system context slot storing the hash of the specified L2 block.
+fn l2_block_hash_key(block_number: L2BlockNumber) -> StorageKey {
+    let position = h256_to_u256(SYSTEM_CONTEXT_CURRENT_L2_BLOCK_HASHES_POSITION)
+        + U256::from(block_number.0 % SYSTEM_CONTEXT_STORED_L2_BLOCK_HASHES);
+    StorageKey::new(
+        AccountTreeId::new(SYSTEM_CONTEXT_ADDRESS),
+        u256_to_h256(position),
+    )
+}
+
 #[derive(Debug)]
 enum SandboxExecutorEngine {
     Real(MainOneshotExecutor),
@@ -174,6 +248,8 @@ impl SandboxExecutor {
         Ok(SandboxExecutionOutput {
             vm: *result.tx_result,
             call_traces: result.call_traces,
+            accessed_storage_keys: result.accessed_storage_keys,
+            prestate: result.prestate,
             metrics,
             are_published_bytecodes_ok: result.compression_result.is_ok(),
         })
@@ -228,6 +304,17 @@ impl SandboxExecutor {
             }
         };
 
+        let storage = self.prepare_storage(connection, block_args).await?;
+        initialization_stage.observe();
+        Ok((env, storage))
+    }
+
+    async fn prepare_storage(
+        &self,
+        connection: Connection<'static, Core>,
+        block_args: &BlockArgs,
+    ) -> anyhow::Result<PostgresStorage<'static>> {
+        let resolved_block_info = &block_args.resolved;
         if block_args.resolves_to_latest_sealed_l2_block() {
             if let Some(caches) = &self.storage_caches {
                 caches.schedule_values_update(resolved_block_info.state_l2_block_number());
@@ -246,8 +333,151 @@ impl SandboxExecutor {
         if let Some(caches) = &self.storage_caches {
             storage = storage.with_caches(caches.clone());
         }
-        initialization_stage.observe();
-        Ok((env, storage))
+        Ok(storage)
+    }
+
+    /// Prepares the base environment for calls simulated in `eth_simulateV1`. The environment is adjusted
+    /// for each simulated block using [`SimulatedBlockEnv`].
+    pub async fn simulation_env(
+        &self,
+        connection: &mut Connection<'static, Core>,
+        block_args: &BlockArgs,
+        fee_input: BatchFeeInput,
+    ) -> anyhow::Result<OneshotEnv> {
+        self.options
+            .eth_call
+            .to_call_env(connection, &block_args.resolved, fee_input, None)
+            .await
+    }
+
+    /// Prepares storage shared among all calls simulated in a single `eth_simulateV1` request.
+    pub async fn simulation_storage(
+        &self,
+        connection: Connection<'static, Core>,
+        block_args: &BlockArgs,
+    ) -> anyhow::Result<SharedPostgresStorage> {
+        let storage = self.prepare_storage(connection, block_args).await?;
+        Ok(SharedPostgresStorage::new(storage))
+    }
+
+    /// Executes a call simulated in `eth_simulateV1` on top of the provided storage overlay.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn execute_simulated_call(
+        &self,
+        vm_permit: VmPermit,
+        storage: SharedPostgresStorage,
+        mut env: OneshotEnv,
+        block_env: &SimulatedBlockEnv,
+        call: L2Tx,
+        overlay: &StorageOverlay,
+    ) -> anyhow::Result<SandboxExecutionOutput> {
+        let total_factory_deps = call.execute.factory_deps.len() as u16;
+        let mut storage = overlay.apply_to(storage);
+        if let Some((key, value)) = block_env.apply(&mut env) {
+            storage.set_value(key, value);
+        }
+
+        let result = self
+            .inspect_transaction_with_bytecode_compression(
+                storage,
+                env,
+                TxExecutionArgs::for_eth_call(call),
+                OneshotTracingParams::default(),
+            )
+            .await?;
+        drop(vm_permit);
+
+        let metrics =
+            vm_metrics::collect_tx_execution_metrics(total_factory_deps, &result.tx_result);
+        Ok(SandboxExecutionOutput {
+            vm: *result.tx_result,
+            call_traces: result.call_traces,
+            accessed_storage_keys: result.accessed_storage_keys,
+            prestate: result.prestate,
+            metrics,
+            are_published_bytecodes_ok: result.compression_result.is_ok(),
+        })
+    }
+
+    /// Re-executes transactions of a sealed L2 block on top of the state before the block, so that each transaction
+    /// observes changes produced by the preceding ones. `block_args` must point to the replayed block.
+    ///
+    /// If `traced_tx` is specified, transactions are executed up to and including it, and only this transaction
+    /// is traced; otherwise, all transactions are traced. Returns outputs for the traced transactions.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn replay_l2_block(
+        &self,
+        vm_permit: VmPermit,
+        mut connection: Connection<'static, Core>,
+        block_args: &BlockArgs,
+        transactions: Vec<Transaction>,
+        traced_tx: Option<H256>,
+        prestate_mode: PrestateTracingMode,
+    ) -> anyhow::Result<Vec<(H256, SandboxExecutionOutput)>> {
+        let block_number = block_args.resolved_block_number();
+        anyhow::ensure!(
+            block_number > L2BlockNumber(0),
+            "genesis block cannot be replayed"
+        );
+        let fee_input = block_args.historical_fee_input(&mut connection).await?;
+        let mut env = self
+            .options
+            .eth_call
+            .to_call_env(&mut connection, &block_args.resolved, fee_input, None)
+            .await?;
+        // Transactions are executed in the same way as by the state keeper, i.e., with validation and fee payment.
+        env.system.execution_mode = TxExecutionMode::VerifyExecute;
+
+        let mut storage =
+            PostgresStorage::new_async(Handle::current(), connection, block_number - 1, false)
+                .await
+                .context("cannot create `PostgresStorage`")?;
+        if let Some(caches) = &self.storage_caches {
+            storage = storage.with_caches(caches.clone());
+        }
+        let storage = SharedPostgresStorage::new(storage);
+
+        let mut overlay = StorageOverlay::default();
+        let mut outputs = vec![];
+        for tx in transactions {
+            let tx_hash = tx.hash();
+            let is_traced = traced_tx.map_or(true, |hash| hash == tx_hash);
+            let tracing_params = OneshotTracingParams {
+                trace_prestate: is_traced.then_some(prestate_mode),
+                ..OneshotTracingParams::default()
+            };
+            let factory_deps = tx.execute.factory_deps.clone();
+            let result = self
+                .inspect_transaction_with_bytecode_compression(
+                    overlay.apply_to(storage.clone()),
+                    env.clone(),
+                    TxExecutionArgs::for_replay(tx),
+                    tracing_params,
+                )
+                .await?;
+            overlay.record_execution(&factory_deps, &result.tx_result);
+
+            if is_traced {
+                let metrics = vm_metrics::collect_tx_execution_metrics(
+                    factory_deps.len() as u16,
+                    &result.tx_result,
+                );
+                let output = SandboxExecutionOutput {
+                    vm: *result.tx_result,
+                    call_traces: result.call_traces,
+                    accessed_storage_keys: result.accessed_storage_keys,
+                    prestate: result.prestate,
+                    metrics,
+                    are_published_bytecodes_ok: result.compression_result.is_ok(),
+                };
+                outputs.push((tx_hash, output));
+            }
+            if traced_tx == Some(tx_hash) {
+                break;
+            }
+        }
+        drop(vm_permit);
+        Ok(outputs)
     }
 }
 
@@ -1,60 +1,212 @@
 //! VM storage functionality specifically used in the VM sandbox.
 
-use zksync_multivm::interface::storage::{ReadStorage, StorageWithOverrides};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Context as _;
+use zksync_multivm::interface::{
+    storage::{ReadStorage, StorageWithOverrides},
+    VmExecutionResultAndLogs,
+};
+use zksync_state::PostgresStorage;
 use zksync_types::{
     api::state_override::{OverrideState, StateOverride},
+    bytecode::BytecodeHash,
     get_code_key, get_known_code_key, get_nonce_key, h256_to_u256, u256_to_h256,
     utils::{decompose_full_nonce, nonces_to_full_nonce, storage_key_for_eth_balance},
-    AccountTreeId, StorageKey, H256,
+    AccountTreeId, StorageKey, StorageValue, H256, SYSTEM_CONTEXT_ADDRESS,
 };
 
 /// This method is blocking.
 pub(super) fn apply_state_override<S: ReadStorage>(
-    storage: S,
+    mut storage: S,
     state_override: &StateOverride,
 ) -> StorageWithOverrides<S> {
-    let mut storage = StorageWithOverrides::new(storage);
-    for (account, overrides) in state_override.iter() {
-        if let Some(balance) = overrides.balance {
-            let balance_key = storage_key_for_eth_balance(account);
-            storage.set_value(balance_key, u256_to_h256(balance));
-        }
+    let mut overlay = StorageOverlay::default();
+    overlay.apply_state_override_with(state_override, |key| storage.read_value(key));
+    overlay.apply_to(storage)
+}
+
+/// Postgres storage shared among several sequentially executed transactions / calls, so that they can reuse
+/// a single DB connection.
+#[derive(Debug, Clone)]
+pub(crate) struct SharedPostgresStorage(Arc<Mutex<PostgresStorage<'static>>>);
 
-        if let Some(nonce) = overrides.nonce {
-            let nonce_key = get_nonce_key(account);
-            let full_nonce = storage.read_value(&nonce_key);
-            let (_, deployment_nonce) = decompose_full_nonce(h256_to_u256(full_nonce));
-            let new_full_nonce = u256_to_h256(nonces_to_full_nonce(nonce, deployment_nonce));
-            storage.set_value(nonce_key, new_full_nonce);
+impl SharedPostgresStorage {
+    pub fn new(storage: PostgresStorage<'static>) -> Self {
+        Self(Arc::new(Mutex::new(storage)))
+    }
+
+    fn with_storage<T>(&self, action: impl FnOnce(&mut PostgresStorage<'static>) -> T) -> T {
+        action(&mut self.0.lock().expect("Postgres storage is poisoned"))
+    }
+}
+
+impl ReadStorage for SharedPostgresStorage {
+    fn read_value(&mut self, key: &StorageKey) -> StorageValue {
+        self.with_storage(|storage| storage.read_value(key))
+    }
+
+    fn is_write_initial(&mut self, key: &StorageKey) -> bool {
+        self.with_storage(|storage| storage.is_write_initial(key))
+    }
+
+    fn load_factory_dep(&mut self, hash: H256) -> Option<Vec<u8>> {
+        self.with_storage(|storage| storage.load_factory_dep(hash))
+    }
+
+    fn get_enumeration_index(&mut self, key: &StorageKey) -> Option<u64> {
+        self.with_storage(|storage| storage.get_enumeration_index(key))
+    }
+}
+
+/// In-memory storage changes accumulated on top of the Postgres state, e.g., by calls sequentially executed
+/// in `eth_simulateV1`.
+#[derive(Debug, Default)]
+pub(crate) struct StorageOverlay {
+    slots: HashMap<StorageKey, H256>,
+    factory_deps: HashMap<H256, Vec<u8>>,
+    erased_accounts: HashSet<AccountTreeId>,
+}
+
+impl StorageOverlay {
+    /// Reads a storage value taking the overlay into account. Values not present in the overlay are read
+    /// from the underlying `storage`.
+    pub async fn read_value(
+        &self,
+        storage: &SharedPostgresStorage,
+        key: &StorageKey,
+    ) -> anyhow::Result<H256> {
+        if let Some(value) = self.slots.get(key) {
+            return Ok(*value);
+        }
+        if self.erased_accounts.contains(key.account()) {
+            return Ok(H256::zero());
         }
+        let mut storage = storage.clone();
+        let key = *key;
+        // `PostgresStorage` blocks on DB queries, so it must not be accessed from an async context.
+        let value = tokio::task::spawn_blocking(move || storage.read_value(&key))
+            .await
+            .context("panicked reading storage value")?;
+        Ok(value)
+    }
+
+    pub fn set_value(&mut self, key: StorageKey, value: H256) {
+        self.slots.insert(key, value);
+    }
 
-        if let Some(code) = &overrides.code {
-            let code_key = get_code_key(account);
-            let code_hash = code.hash();
-            storage.set_value(code_key, code_hash);
-            let known_code_key = get_known_code_key(&code_hash);
-            storage.set_value(known_code_key, H256::from_low_u64_be(1));
-            storage.store_factory_dep(code_hash, code.clone().into_bytes());
+    /// Applies the provided state override on top of the overlay.
+    pub async fn apply_state_override(
+        &mut self,
+        storage: &SharedPostgresStorage,
+        state_override: &StateOverride,
+    ) -> anyhow::Result<()> {
+        // Nonce overrides need to retain the current deployment nonce, so we load full nonces beforehand.
+        let mut full_nonces = HashMap::new();
+        for (account, overrides) in state_override.iter() {
+            if overrides.nonce.is_some() {
+                let nonce_key = get_nonce_key(account);
+                let full_nonce = self.read_value(storage, &nonce_key).await?;
+                full_nonces.insert(nonce_key, full_nonce);
+            }
         }
+        self.apply_state_override_with(state_override, |key| full_nonces[key]);
+        Ok(())
+    }
 
-        match &overrides.state {
-            Some(OverrideState::State(state)) => {
-                let account = AccountTreeId::new(*account);
-                for (&key, &value) in state {
-                    storage.set_value(StorageKey::new(account, key), value);
-                }
-                storage.insert_erased_account(account);
+    fn apply_state_override_with(
+        &mut self,
+        state_override: &StateOverride,
+        mut read_full_nonce: impl FnMut(&StorageKey) -> H256,
+    ) {
+        for (account, overrides) in state_override.iter() {
+            if let Some(balance) = overrides.balance {
+                let balance_key = storage_key_for_eth_balance(account);
+                self.slots.insert(balance_key, u256_to_h256(balance));
             }
-            Some(OverrideState::StateDiff(state_diff)) => {
-                let account = AccountTreeId::new(*account);
-                for (&key, &value) in state_diff {
-                    storage.set_value(StorageKey::new(account, key), value);
+
+            if let Some(nonce) = overrides.nonce {
+                let nonce_key = get_nonce_key(account);
+                let full_nonce = read_full_nonce(&nonce_key);
+                let (_, deployment_nonce) = decompose_full_nonce(h256_to_u256(full_nonce));
+                let new_full_nonce = u256_to_h256(nonces_to_full_nonce(nonce, deployment_nonce));
+                self.slots.insert(nonce_key, new_full_nonce);
+            }
+
+            if let Some(code) = &overrides.code {
+                let code_key = get_code_key(account);
+                let code_hash = code.hash();
+                self.slots.insert(code_key, code_hash);
+                let known_code_key = get_known_code_key(&code_hash);
+                self.slots.insert(known_code_key, H256::from_low_u64_be(1));
+                self.factory_deps
+                    .insert(code_hash, code.clone().into_bytes());
+            }
+
+            match &overrides.state {
+                Some(OverrideState::State(state)) => {
+                    let account = AccountTreeId::new(*account);
+                    // Slots set by the previous changes must be erased as well.
+                    self.slots.retain(|key, _| *key.account() != account);
+                    for (&key, &value) in state {
+                        self.slots.insert(StorageKey::new(account, key), value);
+                    }
+                    self.erased_accounts.insert(account);
+                }
+                Some(OverrideState::StateDiff(state_diff)) => {
+                    let account = AccountTreeId::new(*account);
+                    for (&key, &value) in state_diff {
+                        self.slots.insert(StorageKey::new(account, key), value);
+                    }
                 }
+                None => { /* do nothing */ }
             }
-            None => { /* do nothing */ }
         }
     }
-    storage
+
+    /// Records storage writes and new bytecodes produced by executing a transaction.
+    ///
+    /// Writes to the system context are skipped; the block and batch info stored there is set up
+    /// separately for each execution.
+    pub fn record_execution(
+        &mut self,
+        factory_deps: &[Vec<u8>],
+        output: &VmExecutionResultAndLogs,
+    ) {
+        for dep in factory_deps {
+            let hash = BytecodeHash::for_bytecode(dep).value();
+            self.factory_deps.insert(hash, dep.clone());
+        }
+        for (&hash, dep) in &output.dynamic_factory_deps {
+            self.factory_deps.insert(hash, dep.clone());
+        }
+
+        let writes =
+            output.logs.storage_logs.iter().filter(|log| {
+                log.log.is_write() && *log.log.key.address() != SYSTEM_CONTEXT_ADDRESS
+            });
+        for log in writes {
+            self.slots.insert(log.log.key, log.log.value);
+        }
+    }
+
+    /// Wraps the provided storage so that it reflects changes in this overlay.
+    pub fn apply_to<S: ReadStorage>(&self, storage: S) -> StorageWithOverrides<S> {
+        let mut storage = StorageWithOverrides::new(storage);
+        for (&key, &value) in &self.slots {
+            storage.set_value(key, value);
+        }
+        for (&hash, dep) in &self.factory_deps {
+            storage.store_factory_dep(hash, dep.clone());
+        }
+        for &account in &self.erased_accounts {
+            storage.insert_erased_account(account);
+        }
+        storage
+    }
 }
 
 #[cfg(test)]
@@ -14,6 +14,7 @@ use crate::web3::metrics::API_METRICS;
 pub struct MasterPoolSink {
     master_pool: ConnectionPool<Core>,
     inflight_requests: Mutex<HashMap<(Address, Nonce), H256>>,
+    replacement_fee_bump_percent: Option<u32>,
 }
 
 impl MasterPoolSink {
@@ -21,8 +22,18 @@ impl MasterPoolSink {
         Self {
             master_pool,
             inflight_requests: Mutex::new(HashMap::new()),
+            replacement_fee_bump_percent: None,
         }
     }
+
+    /// Requires transactions replacing a pending transaction with the same nonce to bump both `max_fee_per_gas`
+    /// and `max_priority_fee_per_gas` by at least `percent`. The check is performed atomically with the DB insertion,
+    /// so it cannot be bypassed by concurrent replacements.
+    #[must_use]
+    pub fn with_replacement_fee_bump_percent(mut self, percent: u32) -> Self {
+        self.replacement_fee_bump_percent = Some(percent);
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -56,7 +67,12 @@ impl TxSink for MasterPoolSink {
         let result = match self.master_pool.connection_tagged("api").await {
             Ok(mut connection) => connection
                 .transactions_dal()
-                .insert_transaction_l2(tx, execution_metrics, validation_traces)
+                .insert_transaction_l2_with_min_fee_bump(
+                    tx,
+                    execution_metrics,
+                    validation_traces,
+                    self.replacement_fee_bump_percent,
+                )
                 .await
                 .inspect(|submission_res_handle| {
                     APP_METRICS.processed_txs[&TxStage::Mempool(*submission_res_handle)].inc();
@@ -4,7 +4,10 @@ use std::{sync::Arc, time::Duration};
 
 use anyhow::Context as _;
 use tokio::sync::RwLock;
-use zksync_config::configs::{api::Web3JsonRpcConfig, chain::StateKeeperConfig};
+use zksync_config::configs::{
+    api::Web3JsonRpcConfig,
+    chain::{MempoolConfig, StateKeeperConfig},
+};
 use zksync_dal::{
     transactions_dal::L2TxSubmissionResult, Connection, ConnectionPool, Core, CoreDal,
 };
@@ -36,17 +39,22 @@ use zksync_vm_executor::oneshot::{
     CallOrExecute, EstimateGas, MultiVmBaseSystemContracts, OneshotEnvParameters,
 };
 
-pub(super) use self::{gas_estimation::BinarySearchKind, result::SubmitTxError};
+pub(super) use self::{
+    gas_estimation::BinarySearchKind,
+    result::SubmitTxError,
+    simulate::{SimulatedBlockOutput, SimulationBlock, SimulationCall},
+};
 use self::{master_pool_sink::MasterPoolSink, result::ApiCallResult, tx_sink::TxSink};
 use crate::execution_sandbox::{
-    BlockArgs, SandboxAction, SandboxExecutor, SubmitTxStage, VmConcurrencyBarrier,
-    VmConcurrencyLimiter, SANDBOX_METRICS,
+    BlockArgs, SandboxAction, SandboxExecutionOutput, SandboxExecutor, SubmitTxStage,
+    VmConcurrencyBarrier, VmConcurrencyLimiter, SANDBOX_METRICS,
 };
 
 mod gas_estimation;
 pub mod master_pool_sink;
 pub mod proxy;
 mod result;
+mod simulate;
 #[cfg(test)]
 pub(crate) mod tests;
 pub mod tx_sink;
@@ -61,7 +69,10 @@ pub async fn build_tx_sender(
     storage_caches: PostgresStorageCaches,
 ) -> anyhow::Result<(TxSender, VmConcurrencyBarrier)> {
     let sequencer_sealer = SequencerSealer::new(state_keeper_config.clone());
-    let master_pool_sink = MasterPoolSink::new(master_pool);
+    let mut master_pool_sink = MasterPoolSink::new(master_pool);
+    if let Some(percent) = tx_sender_config.replacement_fee_bump_percent {
+        master_pool_sink = master_pool_sink.with_replacement_fee_bump_percent(percent);
+    }
     let tx_sender_builder = TxSenderBuilder::new(
         tx_sender_config.clone(),
         replica_pool.clone(),
@@ -243,6 +254,12 @@ pub struct TxSenderConfig {
     pub chain_id: L2ChainId,
     pub whitelisted_tokens_for_aa: Vec<Address>,
     pub timestamp_asserter_params: Option<TimestampAsserterParams>,
+    /// Maximum number of pending transactions per account. If not set, the number of pending transactions
+    /// is only limited by `max_nonce_ahead`.
+    pub max_pending_txs_per_account: Option<u32>,
+    /// Minimum fee bump (in percent) required to replace a pending transaction with the same nonce.
+    /// If not set, replacements are accepted regardless of fees.
+    pub replacement_fee_bump_percent: Option<u32>,
 }
 
 #[derive(Debug, Clone)]
@@ -270,8 +287,18 @@ impl TxSenderConfig {
             chain_id,
             whitelisted_tokens_for_aa: web3_json_config.whitelisted_tokens_for_aa.clone(),
             timestamp_asserter_params,
+            max_pending_txs_per_account: None,
+            replacement_fee_bump_percent: None,
         }
     }
+
+    /// Enforces the same per-account limits as the mempool with the specified config.
+    #[must_use]
+    pub fn with_mempool_limits(mut self, mempool_config: &MempoolConfig) -> Self {
+        self.max_pending_txs_per_account = mempool_config.max_pending_txs_per_account;
+        self.replacement_fee_bump_percent = mempool_config.replacement_fee_bump_percent;
+        self
+    }
 }
 
 pub struct TxSenderInner {
@@ -408,6 +435,14 @@ impl TxSender {
                 Err(SubmitTxError::IncorrectTx(TxDuplication(tx.hash())))
             }
             L2TxSubmissionResult::InsertionInProgress => Err(SubmitTxError::InsertionInProgress),
+            L2TxSubmissionResult::ReplacementUnderpriced => {
+                let min_bump_percent = self
+                    .0
+                    .sender_config
+                    .replacement_fee_bump_percent
+                    .unwrap_or_default();
+                Err(SubmitTxError::ReplacementUnderpriced(min_bump_percent))
+            }
             L2TxSubmissionResult::Proxied => {
                 stage_latency.set_stage(SubmitTxStage::TxProxy);
                 stage_latency.observe();
@@ -495,6 +530,7 @@ impl TxSender {
         // We still double-check the nonce manually
         // to make sure that only the correct nonce is submitted and the transaction's hashes never repeat
         self.validate_account_nonce(tx).await?;
+        self.validate_pending_transactions(tx).await?;
         // Even though without enough balance the tx will not pass anyway
         // we check the user for enough balance explicitly here for better DevEx.
         self.validate_enough_balance(tx).await?;
@@ -532,6 +568,56 @@ impl TxSender {
         }
     }
 
+    /// Checks the transaction against pending transactions of the same account: a replacement must bump fees
+    /// sufficiently, and a new transaction must not exceed the limit on pending transactions per account.
+    ///
+    /// This check reads from a replica, so it's best-effort: concurrent submissions may race past it.
+    /// The limits are enforced authoritatively by the mempool, which marks violating transactions as rejected.
+    async fn validate_pending_transactions(&self, tx: &L2Tx) -> Result<(), SubmitTxError> {
+        let config = &self.0.sender_config;
+        if config.max_pending_txs_per_account.is_none()
+            && config.replacement_fee_bump_percent.is_none()
+        {
+            return Ok(());
+        }
+
+        let initiator_account = tx.initiator_account();
+        let Nonce(committed_nonce) = self
+            .get_expected_nonce(initiator_account)
+            .await
+            .with_context(|| format!("failed getting expected nonce for {initiator_account:?}"))?;
+        let pending_txs = self
+            .acquire_replica_connection()
+            .await?
+            .transactions_web3_dal()
+            .pending_transactions_by_initiator_account(initiator_account, committed_nonce.into())
+            .await
+            .with_context(|| {
+                format!("failed getting pending transactions for {initiator_account:?}")
+            })?;
+
+        let nonce = tx.nonce();
+        if let Some((_, previous_fee)) = pending_txs.iter().find(|(tx_nonce, _)| *tx_nonce == nonce)
+        {
+            if let Some(min_bump_percent) = config.replacement_fee_bump_percent {
+                if !tx
+                    .common_data
+                    .fee
+                    .is_sufficient_replacement_for(previous_fee, min_bump_percent)
+                {
+                    return Err(SubmitTxError::ReplacementUnderpriced(min_bump_percent));
+                }
+            }
+        } else if let Some(max_pending_txs) = config.max_pending_txs_per_account {
+            // Consistently with the mempool, a transaction with the next expected nonce is always accepted
+            // so that the account cannot get stuck because of a nonce gap.
+            if nonce.0 != committed_nonce && pending_txs.len() >= max_pending_txs as usize {
+                return Err(SubmitTxError::TooManyPendingTransactions(max_pending_txs));
+            }
+        }
+        Ok(())
+    }
+
     async fn get_expected_nonce(&self, initiator_account: Address) -> anyhow::Result<Nonce> {
         let mut storage = self.acquire_replica_connection().await?;
         let latest_block_number = storage
@@ -605,9 +691,51 @@ impl TxSender {
         call: L2Tx,
         state_override: Option<StateOverride>,
     ) -> Result<Vec<u8>, SubmitTxError> {
+        let tracing_params = OneshotTracingParams::default();
+        let result = self
+            .eth_call_with_tracing(
+                block_args,
+                call_overrides,
+                call,
+                state_override,
+                tracing_params,
+            )
+            .await?;
+        result.vm.into_api_call_result()
+    }
+
+    /// Same as [`Self::eth_call()`], but returns the full sandbox output, with the specified tracing params.
+    pub(crate) async fn eth_call_with_tracing(
+        &self,
+        block_args: BlockArgs,
+        call_overrides: CallOverrides,
+        call: L2Tx,
+        state_override: Option<StateOverride>,
+        tracing_params: OneshotTracingParams,
+    ) -> Result<SandboxExecutionOutput, SubmitTxError> {
         let vm_permit = self.0.vm_concurrency_limiter.acquire().await;
         let vm_permit = vm_permit.ok_or(SubmitTxError::ServerShuttingDown)?;
+        let (fee_input, connection) = self.call_fee_input_and_connection(&block_args).await?;
 
+        let action = SandboxAction::Call {
+            call,
+            fee_input,
+            enforced_base_fee: call_overrides.enforced_base_fee,
+            tracing_params,
+        };
+        let output = self
+            .0
+            .executor
+            .execute_in_sandbox(vm_permit, connection, action, &block_args, state_override)
+            .await?;
+        Ok(output)
+    }
+
+    /// Returns fee input for calls executed on top of the specified block, together with a connection to use for execution.
+    async fn call_fee_input_and_connection(
+        &self,
+        block_args: &BlockArgs,
+    ) -> anyhow::Result<(BatchFeeInput, Connection<'static, Core>)> {
         let mut connection;
         let fee_input = if block_args.resolves_to_latest_sealed_l2_block() {
             let fee_input = self
@@ -622,19 +750,7 @@ impl TxSender {
             connection = self.acquire_replica_connection().await?;
             block_args.historical_fee_input(&mut connection).await?
         };
-
-        let action = SandboxAction::Call {
-            call,
-            fee_input,
-            enforced_base_fee: call_overrides.enforced_base_fee,
-            tracing_params: OneshotTracingParams::default(),
-        };
-        let result = self
-            .0
-            .executor
-            .execute_in_sandbox(vm_permit, connection, action, &block_args, state_override)
-            .await?;
-        result.vm.into_api_call_result()
+        Ok((fee_input, connection))
     }
 
     pub async fn gas_price(&self) -> anyhow::Result<u64> {
@@ -14,6 +14,10 @@ pub enum SubmitTxError {
     NonceIsTooLow(u32, u32, u32),
     #[error("insertion of another transaction with the same nonce is in progress")]
     InsertionInProgress,
+    #[error("replacement transaction underpriced. max fee per gas and max priority fee per gas must be bumped by at least {0}%")]
+    ReplacementUnderpriced(u32),
+    #[error("too many pending transactions for the account. at most {0} pending transactions are allowed")]
+    TooManyPendingTransactions(u32),
     #[error("{0}")]
     IncorrectTx(#[from] TxCheckError),
     #[error("insufficient funds for gas + value. balance: {0}, fee: {1}, value: {2}")]
@@ -69,6 +73,10 @@ pub enum SubmitTxError {
     Internal(#[from] anyhow::Error),
     #[error("transaction failed block.timestamp assertion")]
     FailedBlockTimestampAssertion,
+    #[error("invalid block overrides: {0}")]
+    InvalidBlockOverrides(String),
+    #[error("simulation limit exceeded: {0}")]
+    SimulationLimitExceeded(String),
 }
 
 impl SubmitTxError {
@@ -77,6 +85,8 @@ impl SubmitTxError {
             Self::NonceIsTooHigh(_, _, _) => "nonce-is-too-high",
             Self::NonceIsTooLow(_, _, _) => "nonce-is-too-low",
             Self::InsertionInProgress => "insertion-in-progress",
+            Self::ReplacementUnderpriced(_) => "replacement-underpriced",
+            Self::TooManyPendingTransactions(_) => "too-many-pending-transactions",
             Self::IncorrectTx(_) => "incorrect-tx",
             Self::NotEnoughBalanceForFeeValue(_, _, _) => "not-enough-balance-for-fee",
             Self::ExecutionReverted(_, _) => "execution-reverted",
@@ -99,6 +109,8 @@ impl SubmitTxError {
             Self::ProxyError(_) => "proxy-error",
             Self::Internal(_) => "internal",
             Self::FailedBlockTimestampAssertion => "failed-block-timestamp-assertion",
+            Self::InvalidBlockOverrides(_) => "invalid-block-overrides",
+            Self::SimulationLimitExceeded(_) => "simulation-limit-exceeded",
         }
     }
 
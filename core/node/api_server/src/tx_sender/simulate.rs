@@ -0,0 +1,366 @@
+//! Multi-call simulation used by `eth_simulateV1`.
+
+use zksync_multivm::{
+    interface::VmExecutionResultAndLogs, utils::derive_base_fee_and_gas_per_pubdata,
+};
+use zksync_types::{
+    api::{simulate::BlockOverrides, state_override::StateOverride},
+    block::L2BlockHasher,
+    get_nonce_key, h256_to_u256,
+    l2::L2Tx,
+    transaction_request::CallOverrides,
+    u256_to_h256,
+    utils::{decompose_full_nonce, nonces_to_full_nonce, storage_key_for_eth_balance},
+    L2BlockNumber, Nonce, H256, U256,
+};
+
+use super::{SubmitTxError, TxSender};
+use crate::execution_sandbox::{
+    BlockArgs, SharedPostgresStorage, SimulatedBlockEnv, StorageOverlay,
+};
+
+/// Call to simulate.
+#[derive(Debug)]
+pub(crate) struct SimulationCall {
+    pub tx: L2Tx,
+    pub overrides: CallOverrides,
+    /// Whether the nonce was specified in the call request. If not, the current account nonce is used.
+    pub has_nonce: bool,
+}
+
+/// Block of calls to simulate.
+#[derive(Debug, Default)]
+pub(crate) struct SimulationBlock {
+    pub block_overrides: BlockOverrides,
+    pub state_override: Option<StateOverride>,
+    pub calls: Vec<SimulationCall>,
+}
+
+/// Output of a simulated block.
+#[derive(Debug)]
+pub(crate) struct SimulatedBlockOutput {
+    pub number: L2BlockNumber,
+    pub timestamp: u64,
+    pub base_fee: u64,
+    /// Synthetic hash of the block.
+    pub hash: H256,
+    /// Executed calls together with their outputs.
+    pub calls: Vec<(L2Tx, VmExecutionResultAndLogs)>,
+}
+
+impl TxSender {
+    /// Sequentially executes calls in the provided blocks, so that each call observes state changes produced
+    /// by the previous calls. All calls share a single DB connection.
+    ///
+    /// Request limits (the number of blocks, calls and the total gas) are expected to be checked by the caller.
+    pub(crate) async fn simulate_blocks(
+        &self,
+        block_args: BlockArgs,
+        blocks: Vec<SimulationBlock>,
+        validation: bool,
+    ) -> Result<Vec<SimulatedBlockOutput>, SubmitTxError> {
+        let vm_permit = self.0.vm_concurrency_limiter.acquire().await;
+        let vm_permit = vm_permit.ok_or(SubmitTxError::ServerShuttingDown)?;
+        let (fee_input, mut connection) = self.call_fee_input_and_connection(&block_args).await?;
+        let base_env = self
+            .0
+            .executor
+            .simulation_env(&mut connection, &block_args, fee_input)
+            .await?;
+        let protocol_version = block_args.protocol_version();
+        let (default_base_fee, _) =
+            derive_base_fee_and_gas_per_pubdata(fee_input, protocol_version.into());
+
+        let block_envs = resolve_block_envs(SimulatedBlockEnv::new(&base_env), &blocks)?;
+        let storage = self
+            .0
+            .executor
+            .simulation_storage(connection, &block_args)
+            .await?;
+        let mut prev_block_hash = base_env.l1_batch.first_l2_block.prev_block_hash;
+        let mut overlay = StorageOverlay::default();
+        let mut outputs = Vec::with_capacity(blocks.len());
+        for (block, block_env) in blocks.into_iter().zip(block_envs) {
+            if let Some(state_override) = &block.state_override {
+                overlay
+                    .apply_state_override(&storage, state_override)
+                    .await?;
+            }
+
+            let base_fee = block_env.base_fee.unwrap_or(default_base_fee);
+            let mut hasher =
+                L2BlockHasher::new(block_env.number, block_env.timestamp, prev_block_hash);
+            let mut calls = Vec::with_capacity(block.calls.len());
+            for mut call in block.calls {
+                let nonce_key = get_nonce_key(&call.tx.initiator_account());
+                let full_nonce = overlay.read_value(&storage, &nonce_key).await?;
+                let (account_nonce, deployment_nonce) =
+                    decompose_full_nonce(h256_to_u256(full_nonce));
+                let account_nonce = account_nonce.as_u32();
+                if !call.has_nonce {
+                    call.tx.common_data.nonce = Nonce(account_nonce);
+                }
+                if validation {
+                    Self::validate_simulated_call(
+                        &storage,
+                        &overlay,
+                        &call,
+                        account_nonce,
+                        base_fee,
+                    )
+                    .await?;
+                }
+
+                let call_block_env = SimulatedBlockEnv {
+                    // In the validation mode, the fee per gas provided in the call is only checked against the base fee,
+                    // but doesn't influence it.
+                    base_fee: block_env.base_fee.or(if validation {
+                        None
+                    } else {
+                        call.overrides.enforced_base_fee
+                    }),
+                    ..block_env
+                };
+                let tx = call.tx;
+                let output = self
+                    .0
+                    .executor
+                    .execute_simulated_call(
+                        vm_permit.clone(),
+                        storage.clone(),
+                        base_env.clone(),
+                        &call_block_env,
+                        tx.clone(),
+                        &overlay,
+                    )
+                    .await?;
+
+                overlay.record_execution(&tx.execute.factory_deps, &output.vm);
+                // Calls are executed in the `eth_call` mode, in which the VM doesn't increment the nonce,
+                // so we need to bump it manually for subsequent calls to observe the change. The nonce is never
+                // decreased if it's overridden in the call.
+                let next_nonce = U256::from(tx.nonce().0.max(account_nonce)) + 1;
+                let full_nonce = nonces_to_full_nonce(next_nonce, deployment_nonce);
+                overlay.set_value(nonce_key, u256_to_h256(full_nonce));
+                hasher.push_tx_hash(tx.hash());
+                calls.push((tx, output.vm));
+            }
+
+            let hash = hasher.finalize(protocol_version);
+            prev_block_hash = hash;
+            outputs.push(SimulatedBlockOutput {
+                number: block_env.number,
+                timestamp: block_env.timestamp,
+                base_fee,
+                hash,
+                calls,
+            });
+        }
+        Ok(outputs)
+    }
+
+    /// Performs checks that would be performed by the VM for a real transaction (nonce, fee per gas and balance).
+    async fn validate_simulated_call(
+        storage: &SharedPostgresStorage,
+        overlay: &StorageOverlay,
+        call: &SimulationCall,
+        account_nonce: u32,
+        base_fee: u64,
+    ) -> Result<(), SubmitTxError> {
+        let nonce = call.tx.nonce().0;
+        if nonce < account_nonce {
+            return Err(SubmitTxError::NonceIsTooLow(
+                account_nonce,
+                account_nonce,
+                nonce,
+            ));
+        } else if nonce > account_nonce {
+            return Err(SubmitTxError::NonceIsTooHigh(
+                account_nonce,
+                account_nonce,
+                nonce,
+            ));
+        }
+
+        let fee = &call.tx.common_data.fee;
+        if fee.max_fee_per_gas < base_fee.into() {
+            return Err(SubmitTxError::MaxFeePerGasTooLow);
+        }
+        if fee.max_priority_fee_per_gas > fee.max_fee_per_gas {
+            return Err(SubmitTxError::MaxPriorityFeeGreaterThanMaxFee);
+        }
+
+        let balance_key = storage_key_for_eth_balance(&call.tx.payer());
+        let balance = overlay.read_value(storage, &balance_key).await?;
+        let balance = h256_to_u256(balance);
+        let max_fee = fee.gas_limit.saturating_mul(fee.max_fee_per_gas);
+        let value = call.tx.execute.value;
+        if balance < max_fee.saturating_add(value) {
+            return Err(SubmitTxError::NotEnoughBalanceForFeeValue(
+                balance, max_fee, value,
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Resolves environments for all simulated blocks, checking that block numbers and timestamps are increasing.
+fn resolve_block_envs(
+    base: SimulatedBlockEnv,
+    blocks: &[SimulationBlock],
+) -> Result<Vec<SimulatedBlockEnv>, SubmitTxError> {
+    let mut prev_block: Option<SimulatedBlockEnv> = None;
+    let mut envs = Vec::with_capacity(blocks.len());
+    for block in blocks {
+        let overrides = &block.block_overrides;
+        let (default_number, default_timestamp) = match &prev_block {
+            Some(prev) => {
+                let number = prev.number.0.checked_add(1).ok_or_else(|| {
+                    SubmitTxError::InvalidBlockOverrides(format!(
+                        "block number after {} overflows",
+                        prev.number
+                    ))
+                })?;
+                let timestamp = prev.timestamp.checked_add(1).ok_or_else(|| {
+                    SubmitTxError::InvalidBlockOverrides(format!(
+                        "block timestamp after {} overflows",
+                        prev.timestamp
+                    ))
+                })?;
+                (L2BlockNumber(number), timestamp)
+            }
+            None => (base.number, base.timestamp),
+        };
+
+        let number = match overrides.number {
+            Some(number) => {
+                let number = u32::try_from(number.as_u64()).map_err(|_| {
+                    SubmitTxError::InvalidBlockOverrides(format!(
+                        "block number {number} is too large"
+                    ))
+                })?;
+                L2BlockNumber(number)
+            }
+            None => default_number,
+        };
+        if number < default_number {
+            return Err(SubmitTxError::InvalidBlockOverrides(format!(
+                "block number {number} is lower than the minimum allowed {default_number}"
+            )));
+        }
+
+        let timestamp = overrides
+            .time
+            .map_or(default_timestamp, |time| time.as_u64());
+        if timestamp < default_timestamp {
+            return Err(SubmitTxError::InvalidBlockOverrides(format!(
+                "block timestamp {timestamp} is lower than the minimum allowed {default_timestamp}"
+            )));
+        }
+
+        let base_fee = overrides
+            .base_fee_per_gas
+            .map(|fee| {
+                u64::try_from(fee).map_err(|_| {
+                    SubmitTxError::InvalidBlockOverrides(format!("base fee {fee} is too large"))
+                })
+            })
+            .transpose()?;
+
+        let env = SimulatedBlockEnv {
+            number,
+            timestamp,
+            base_fee,
+        };
+        envs.push(env);
+        prev_block = Some(env);
+    }
+    Ok(envs)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    fn block_with_overrides(number: Option<u64>, time: Option<u64>) -> SimulationBlock {
+        SimulationBlock {
+            block_overrides: BlockOverrides {
+                number: number.map(Into::into),
+                time: time.map(Into::into),
+                base_fee_per_gas: None,
+            },
+            ..SimulationBlock::default()
+        }
+    }
+
+    #[test]
+    fn resolving_block_envs() {
+        let base = SimulatedBlockEnv {
+            number: L2BlockNumber(10),
+            timestamp: 1_000,
+            base_fee: None,
+        };
+        let blocks = [
+            block_with_overrides(None, None),
+            block_with_overrides(None, Some(2_000)),
+            block_with_overrides(Some(20), None),
+            block_with_overrides(None, None),
+        ];
+        let envs = resolve_block_envs(base, &blocks).unwrap();
+        let numbers_and_timestamps: Vec<_> = envs
+            .iter()
+            .map(|env| (env.number.0, env.timestamp))
+            .collect();
+        assert_eq!(
+            numbers_and_timestamps,
+            [(10, 1_000), (11, 2_000), (20, 2_001), (21, 2_002)]
+        );
+    }
+
+    #[test]
+    fn resolving_block_envs_with_decreasing_values() {
+        let base = SimulatedBlockEnv {
+            number: L2BlockNumber(10),
+            timestamp: 1_000,
+            base_fee: None,
+        };
+
+        let blocks = [block_with_overrides(Some(9), None)];
+        let err = resolve_block_envs(base, &blocks).unwrap_err();
+        assert!(err.to_string().contains("block number 9"), "{err}");
+
+        let blocks = [
+            block_with_overrides(None, Some(2_000)),
+            block_with_overrides(None, Some(2_000)),
+        ];
+        let err = resolve_block_envs(base, &blocks).unwrap_err();
+        assert!(err.to_string().contains("block timestamp 2000"), "{err}");
+    }
+
+    #[test]
+    fn resolving_block_envs_with_overflowing_values() {
+        let base = SimulatedBlockEnv {
+            number: L2BlockNumber(10),
+            timestamp: 1_000,
+            base_fee: None,
+        };
+
+        let blocks = [
+            block_with_overrides(Some(u32::MAX.into()), None),
+            block_with_overrides(None, None),
+        ];
+        let err = resolve_block_envs(base, &blocks).unwrap_err();
+        assert_matches!(err, SubmitTxError::InvalidBlockOverrides(_));
+        assert!(err.to_string().contains("block number"), "{err}");
+
+        let blocks = [
+            block_with_overrides(None, Some(u64::MAX)),
+            block_with_overrides(None, None),
+        ];
+        let err = resolve_block_envs(base, &blocks).unwrap_err();
+        assert_matches!(err, SubmitTxError::InvalidBlockOverrides(_));
+        assert!(err.to_string().contains("block timestamp"), "{err}");
+    }
+}
@@ -119,6 +119,70 @@ async fn nonce_validation_errors() {
     );
 }
 
+#[tokio::test]
+async fn pending_transactions_validation_errors() {
+    let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+
+    let initiator = Address::repeat_byte(1);
+    let mut pending_txs = vec![];
+    for nonce in [0, 1] {
+        let mut tx = create_l2_transaction(55, 555);
+        // Changing transaction fields invalidates its signature, but it's OK for test purposes
+        tx.common_data.initiator_address = initiator;
+        tx.common_data.nonce = Nonce(nonce);
+        tx.common_data.fee.max_priority_fee_per_gas = 10.into();
+        storage
+            .transactions_dal()
+            .insert_transaction_l2(
+                &tx,
+                TransactionExecutionMetrics::default(),
+                ValidationTraces::default(),
+            )
+            .await
+            .unwrap();
+        pending_txs.push(tx);
+    }
+    drop(storage);
+
+    let l2_chain_id = L2ChainId::default();
+    let tx_executor = SandboxExecutor::mock(MockOneshotExecutor::default()).await;
+    let (mut tx_sender, _) = create_test_tx_sender(pool.clone(), l2_chain_id, tx_executor).await;
+    // Without limits, any transaction is accepted.
+    let mut tx = pending_txs[0].clone();
+    tx_sender.validate_pending_transactions(&tx).await.unwrap();
+
+    let sender_config = &mut Arc::get_mut(&mut tx_sender.0).unwrap().sender_config;
+    sender_config.max_pending_txs_per_account = Some(2);
+    sender_config.replacement_fee_bump_percent = Some(10);
+
+    let err = tx_sender
+        .validate_pending_transactions(&tx)
+        .await
+        .unwrap_err();
+    assert_matches!(err, SubmitTxError::ReplacementUnderpriced(10));
+
+    tx.common_data.fee.max_fee_per_gas = tx.common_data.fee.max_fee_per_gas * 11 / 10;
+    let err = tx_sender
+        .validate_pending_transactions(&tx)
+        .await
+        .unwrap_err();
+    assert_matches!(err, SubmitTxError::ReplacementUnderpriced(10));
+
+    tx.common_data.fee.max_priority_fee_per_gas = 11.into();
+    tx_sender.validate_pending_transactions(&tx).await.unwrap();
+
+    tx.common_data.nonce = Nonce(2);
+    let err = tx_sender
+        .validate_pending_transactions(&tx)
+        .await
+        .unwrap_err();
+    assert_matches!(err, SubmitTxError::TooManyPendingTransactions(2));
+}
+
 #[tokio::test]
 async fn fee_validation_errors() {
     let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
@@ -2,9 +2,10 @@ use std::{
     cell::RefCell,
     collections::HashSet,
     future::Future,
+    net::{IpAddr, SocketAddr},
     num::NonZeroU32,
     pin::Pin,
-    sync::Arc,
+    sync::{Arc, Weak},
     task::{Context, Poll},
     time::{Duration, Instant},
 };
@@ -12,7 +13,7 @@ use std::{
 use governor::{
     clock::DefaultClock,
     middleware::NoOpMiddleware,
-    state::{InMemoryState, NotKeyed},
+    state::{keyed::DefaultKeyedStateStore, InMemoryState, NotKeyed},
     Quota, RateLimiter,
 };
 use once_cell::sync::OnceCell;
@@ -21,12 +22,16 @@ use rand::{rngs::SmallRng, RngCore, SeedableRng};
 use tokio::sync::watch;
 use tracing::instrument::{Instrument, Instrumented};
 use vise::{
-    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, GaugeGuard, Histogram, Metrics,
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, GaugeGuard, Histogram,
+    Metrics,
 };
+use zksync_config::configs::api::RateLimitMethodCosts;
+#[cfg(test)]
+use zksync_web3_decl::jsonrpsee::ConnectionId;
 use zksync_web3_decl::jsonrpsee::{
     server::middleware::rpc::{layer::ResponseFuture, RpcServiceT},
-    types::{error::ErrorCode, ErrorObject, Request},
-    MethodResponse,
+    types::{error::ErrorCode, ErrorObject, Id, Request},
+    Extensions, MethodResponse,
 };
 
 use super::metadata::{MethodCall, MethodTracer};
@@ -35,9 +40,23 @@ use crate::web3::metrics::{ObservedRpcParams, API_METRICS};
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
 #[metrics(label = "transport", rename_all = "snake_case")]
 pub(crate) enum Transport {
+    Http,
     Ws,
 }
 
+/// Class of the key used to identify a client in [`ClientRateLimiter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "key_class", rename_all = "snake_case")]
+enum ClientKeyClass {
+    /// Client IP address from a trusted `X-Forwarded-For` header.
+    ForwardedIp,
+    /// IP address of the peer connected to the server.
+    PeerIp,
+    /// Connection to the server; only used in tests, in which the peer address may be unknown.
+    #[cfg(test)]
+    Connection,
+}
+
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "api_jsonrpc_backend_batch")]
 struct LimitMiddlewareMetrics {
@@ -48,29 +67,242 @@ struct LimitMiddlewareMetrics {
     size: Family<Transport, Histogram<usize>>,
     /// Number of requests rejected by the limiter.
     rejected: Family<Transport, Counter>,
+    /// Number of requests rejected by the per-client rate limiter.
+    client_rate_limited: Family<ClientKeyClass, Counter>,
+    /// Total cost of requests checked by the per-client rate limiter.
+    client_requests_cost: Family<ClientKeyClass, Counter>,
+    /// Number of clients currently tracked by the per-client rate limiter.
+    tracked_clients: Gauge<usize>,
 }
 
 #[vise::register]
 static METRICS: vise::Global<LimitMiddlewareMetrics> = vise::Global::new();
 
+fn too_many_requests_response(id: Id<'_>) -> MethodResponse {
+    MethodResponse::error(
+        id,
+        ErrorObject::borrowed(
+            ErrorCode::ServerError(http::StatusCode::TOO_MANY_REQUESTS.as_u16().into()).code(),
+            "Too many requests",
+            None,
+        ),
+    )
+}
+
+/// Client IP address extracted from a trusted `X-Forwarded-For` header by [`ForwardedForLayer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ClientIp(IpAddr);
+
+/// HTTP middleware that extracts the client IP address from the `X-Forwarded-For` header and puts it into request extensions,
+/// from which it's picked by [`LimitMiddleware`].
+///
+/// The rightmost address in the header is used since it's the one appended by the closest proxy; other addresses
+/// can be spoofed by the client. Hence, this middleware must only be used if the server is behind a trusted reverse proxy.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ForwardedForLayer;
+
+impl<S> tower::Layer<S> for ForwardedForLayer {
+    type Service = ForwardedFor<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ForwardedFor { inner }
+    }
+}
+
+/// Service produced by [`ForwardedForLayer`].
+#[derive(Debug, Clone)]
+pub(crate) struct ForwardedFor<S> {
+    inner: S,
+}
+
+impl<S, B> tower::Service<http::Request<B>> for ForwardedFor<S>
+where
+    S: tower::Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        if let Some(ip) = forwarded_client_ip(request.headers()) {
+            request.extensions_mut().insert(ClientIp(ip));
+        }
+        self.inner.call(request)
+    }
+}
+
+/// Peer IP address of the connection to the server, inserted by [`WithPeerIp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct PeerIp(IpAddr);
+
+/// HTTP service wrapper that puts the peer IP address of the connection into request extensions, from which
+/// it's picked by [`LimitMiddleware`]. Must be instantiated for each accepted connection.
+#[derive(Debug, Clone)]
+pub(crate) struct WithPeerIp<S> {
+    inner: S,
+    peer_ip: IpAddr,
+}
+
+impl<S> WithPeerIp<S> {
+    pub fn new(inner: S, peer_ip: IpAddr) -> Self {
+        Self { inner, peer_ip }
+    }
+}
+
+impl<S, B> tower::Service<http::Request<B>> for WithPeerIp<S>
+where
+    S: tower::Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        request.extensions_mut().insert(PeerIp(self.peer_ip));
+        self.inner.call(request)
+    }
+}
+
+fn forwarded_client_ip(headers: &http::HeaderMap) -> Option<IpAddr> {
+    // If the header is repeated, the proxy appends to the last occurrence.
+    let header = headers.get_all("x-forwarded-for").iter().last()?;
+    let entry = header.to_str().ok()?.rsplit(',').next()?.trim();
+    // Some proxies include the client port.
+    entry
+        .parse::<IpAddr>()
+        .or_else(|_| entry.parse::<SocketAddr>().map(|addr| addr.ip()))
+        .ok()
+}
+
+/// Key identifying a client in [`ClientRateLimiter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ClientKey {
+    ForwardedIp(IpAddr),
+    PeerIp(IpAddr),
+    #[cfg(test)]
+    Connection(ConnectionId),
+}
+
+impl ClientKey {
+    fn new(extensions: &Extensions) -> Option<Self> {
+        if let Some(&ClientIp(ip)) = extensions.get::<ClientIp>() {
+            return Some(Self::ForwardedIp(ip));
+        }
+        if let Some(&PeerIp(ip)) = extensions.get::<PeerIp>() {
+            return Some(Self::PeerIp(ip));
+        }
+        #[cfg(test)]
+        if let Some(&id) = extensions.get::<ConnectionId>() {
+            return Some(Self::Connection(id));
+        }
+        None
+    }
+
+    fn class(&self) -> ClientKeyClass {
+        match self {
+            Self::ForwardedIp(_) => ClientKeyClass::ForwardedIp,
+            Self::PeerIp(_) => ClientKeyClass::PeerIp,
+            #[cfg(test)]
+            Self::Connection(_) => ClientKeyClass::Connection,
+        }
+    }
+}
+
+/// Rate limiter shared among all connections to the server that limits requests per client, taking into account
+/// method-specific request costs.
+#[derive(Debug)]
+pub(crate) struct ClientRateLimiter {
+    limit: NonZeroU32,
+    method_costs: RateLimitMethodCosts,
+    inner: RateLimiter<ClientKey, DefaultKeyedStateStore<ClientKey>, DefaultClock>,
+}
+
+impl ClientRateLimiter {
+    /// Interval between evicting stale clients from the limiter state.
+    const CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub fn new(requests_per_minute_limit: NonZeroU32, method_costs: RateLimitMethodCosts) -> Self {
+        Self {
+            limit: requests_per_minute_limit,
+            method_costs,
+            inner: RateLimiter::keyed(Quota::per_minute(requests_per_minute_limit)),
+        }
+    }
+
+    /// Checks whether a request to the specified method from the specified client fits into the limit.
+    fn check(&self, key: &ClientKey, method_name: &str) -> bool {
+        // Costs exceeding the limit would make the method uncallable, so we cap them.
+        let cost = self.method_costs.get(method_name).min(self.limit);
+        let key_class = key.class();
+        METRICS.client_requests_cost[&key_class].inc_by(cost.get().into());
+        let is_allowed = self.inner.check_key_n(key, cost).is_ok();
+        if !is_allowed {
+            METRICS.client_rate_limited[&key_class].inc();
+        }
+        is_allowed
+    }
+
+    /// Periodically evicts clients that are indistinguishable from new ones from the limiter state. Terminates
+    /// once the limiter is dropped.
+    pub async fn run_cleanup(this: Weak<Self>) {
+        let mut interval = tokio::time::interval(Self::CLEANUP_INTERVAL);
+        loop {
+            interval.tick().await;
+            let Some(this) = this.upgrade() else {
+                return;
+            };
+            this.inner.retain_recent();
+            this.inner.shrink_to_fit();
+            METRICS.tracked_clients.set(this.inner.len());
+        }
+    }
+}
+
 /// A rate-limiting middleware.
 ///
-/// `jsonrpsee` will allocate the instance of this struct once per session.
+/// `jsonrpsee` will allocate the instance of this struct once per session for WebSocket connections,
+/// and once per request for HTTP.
 pub(crate) struct LimitMiddleware<S> {
     inner: S,
     rate_limiter: Option<RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>>,
+    client_rate_limiter: Option<Arc<ClientRateLimiter>>,
     transport: Transport,
-    _guard: GaugeGuard,
+    _guard: Option<GaugeGuard>,
 }
 
 impl<S> LimitMiddleware<S> {
-    pub(crate) fn new(inner: S, requests_per_minute_limit: Option<NonZeroU32>) -> Self {
+    /// Creates a middleware for a WebSocket session. `requests_per_minute_limit` is applied to the session.
+    pub(crate) fn ws(
+        inner: S,
+        requests_per_minute_limit: Option<NonZeroU32>,
+        client_rate_limiter: Option<Arc<ClientRateLimiter>>,
+    ) -> Self {
         Self {
             inner,
             rate_limiter: requests_per_minute_limit
                 .map(|limit| RateLimiter::direct(Quota::per_minute(limit))),
+            client_rate_limiter,
             transport: Transport::Ws,
-            _guard: API_METRICS.ws_open_sessions.inc_guard(1),
+            _guard: Some(API_METRICS.ws_open_sessions.inc_guard(1)),
+        }
+    }
+
+    /// Creates a middleware for an HTTP request.
+    pub(crate) fn http(inner: S, client_rate_limiter: Option<Arc<ClientRateLimiter>>) -> Self {
+        Self {
+            inner,
+            rate_limiter: None,
+            client_rate_limiter,
+            transport: Transport::Http,
+            _guard: None,
         }
     }
 }
@@ -88,17 +320,17 @@ where
             // Note: if required, we can extract data on rate limiting from the error.
             if rate_limiter.check_n(num_requests).is_err() {
                 METRICS.rate_limited[&self.transport].inc();
+                return ResponseFuture::ready(too_many_requests_response(request.id));
+            }
+        }
 
-                let rp = MethodResponse::error(
-                    request.id,
-                    ErrorObject::borrowed(
-                        ErrorCode::ServerError(http::StatusCode::TOO_MANY_REQUESTS.as_u16().into())
-                            .code(),
-                        "Too many requests",
-                        None,
-                    ),
-                );
-                return ResponseFuture::ready(rp);
+        if let Some(client_rate_limiter) = &self.client_rate_limiter {
+            let key = ClientKey::new(request.extensions());
+            if let Some(key) = key {
+                if !client_rate_limiter.check(&key, request.method_name()) {
+                    METRICS.rate_limited[&self.transport].inc();
+                    return ResponseFuture::ready(too_many_requests_response(request.id));
+                }
             }
         }
         ResponseFuture::future(self.inner.call(request))
@@ -332,15 +564,31 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::time::Duration;
+    use std::{borrow::Cow, time::Duration};
 
+    use futures::future;
     use rand::{thread_rng, Rng};
     use test_casing::{test_casing, Product};
     use zksync_types::api;
-    use zksync_web3_decl::jsonrpsee::{types::Id, ResponsePayload};
+    use zksync_web3_decl::jsonrpsee::ResponsePayload;
 
     use super::*;
 
+    #[derive(Debug)]
+    struct MockService;
+
+    impl<'a> RpcServiceT<'a> for MockService {
+        type Future = future::Ready<MethodResponse>;
+
+        fn call(&self, request: Request<'a>) -> Self::Future {
+            future::ready(MethodResponse::response(
+                request.id,
+                ResponsePayload::success("{}".to_string()),
+                usize::MAX,
+            ))
+        }
+    }
+
     #[test_casing(4, Product(([false, true], [false, true])))]
     #[tokio::test(flavor = "multi_thread")]
     async fn metadata_middleware_basics(spawn_tasks: bool, sleep: bool) {
@@ -412,4 +660,101 @@ mod tests {
         let elapsed = now.elapsed();
         assert!(elapsed >= Duration::from_millis(15), "{elapsed:?}");
     }
+
+    #[test]
+    fn extracting_forwarded_client_ip() {
+        let mut headers = http::HeaderMap::new();
+        assert_eq!(forwarded_client_ip(&headers), None);
+
+        headers.insert("x-forwarded-for", "10.0.0.1".parse().unwrap());
+        assert_eq!(
+            forwarded_client_ip(&headers),
+            Some(IpAddr::from([10, 0, 0, 1]))
+        );
+        headers.insert("x-forwarded-for", "1.2.3.4, 10.0.0.2:8080".parse().unwrap());
+        assert_eq!(
+            forwarded_client_ip(&headers),
+            Some(IpAddr::from([10, 0, 0, 2]))
+        );
+        headers.append("x-forwarded-for", "::1".parse().unwrap());
+        assert_eq!(forwarded_client_ip(&headers), Some("::1".parse().unwrap()));
+        headers.insert("x-forwarded-for", "10.0.0.1, garbage".parse().unwrap());
+        assert_eq!(forwarded_client_ip(&headers), None);
+    }
+
+    fn test_request(
+        method: &'static str,
+        connection: usize,
+        ip: Option<IpAddr>,
+        peer_ip: Option<IpAddr>,
+    ) -> Request<'static> {
+        let mut request = Request::new(Cow::Borrowed(method), None, Id::Number(1));
+        request
+            .extensions_mut()
+            .insert(ConnectionId::from(connection));
+        if let Some(ip) = ip {
+            request.extensions_mut().insert(ClientIp(ip));
+        }
+        if let Some(peer_ip) = peer_ip {
+            request.extensions_mut().insert(PeerIp(peer_ip));
+        }
+        request
+    }
+
+    async fn is_rate_limited(
+        limiter: &Arc<ClientRateLimiter>,
+        method: &'static str,
+        connection: usize,
+        ip: Option<IpAddr>,
+        peer_ip: Option<IpAddr>,
+    ) -> bool {
+        let middleware = LimitMiddleware::http(MockService, Some(limiter.clone()));
+        let request = test_request(method, connection, ip, peer_ip);
+        let response = middleware.call(request).await;
+        if response.is_success() {
+            false
+        } else {
+            let expected_code = i32::from(http::StatusCode::TOO_MANY_REQUESTS.as_u16());
+            assert_eq!(response.as_error_code(), Some(expected_code));
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn client_rate_limiting() {
+        let method_costs = [
+            ("eth_getLogs", NonZeroU32::new(5).unwrap()),
+            ("debug_*", NonZeroU32::new(100).unwrap()),
+        ];
+        let limiter = Arc::new(ClientRateLimiter::new(
+            NonZeroU32::new(10).unwrap(),
+            method_costs.into_iter().collect(),
+        ));
+        let first_ip = Some(IpAddr::from([10, 0, 0, 1]));
+        let second_ip = Some(IpAddr::from([10, 0, 0, 2]));
+
+        // Clients identified by IP addresses are limited regardless of the connection.
+        assert!(!is_rate_limited(&limiter, "eth_getLogs", 0, first_ip, None).await);
+        assert!(!is_rate_limited(&limiter, "eth_getLogs", 1, first_ip, None).await);
+        assert!(is_rate_limited(&limiter, "eth_chainId", 2, first_ip, None).await);
+        // Costs exceeding the limit are capped.
+        assert!(!is_rate_limited(&limiter, "debug_traceCall", 0, second_ip, None).await);
+        assert!(is_rate_limited(&limiter, "eth_chainId", 0, second_ip, None).await);
+
+        // Clients without a forwarded IP address are identified by the peer IP address.
+        let peer_ip = Some(IpAddr::from([10, 0, 0, 3]));
+        assert!(!is_rate_limited(&limiter, "eth_getLogs", 0, None, peer_ip).await);
+        assert!(!is_rate_limited(&limiter, "eth_getLogs", 1, None, peer_ip).await);
+        assert!(is_rate_limited(&limiter, "eth_chainId", 2, None, peer_ip).await);
+        // The forwarded IP address takes precedence over the peer one.
+        let forwarded_ip = Some(IpAddr::from([10, 0, 0, 4]));
+        assert!(!is_rate_limited(&limiter, "eth_chainId", 0, forwarded_ip, peer_ip).await);
+
+        // Clients without a known IP address are identified by the connection.
+        for _ in 0..10 {
+            assert!(!is_rate_limited(&limiter, "eth_chainId", 0, None, None).await);
+        }
+        assert!(is_rate_limited(&limiter, "eth_chainId", 0, None, None).await);
+        assert!(!is_rate_limited(&limiter, "eth_chainId", 1, None, None).await);
+    }
 }
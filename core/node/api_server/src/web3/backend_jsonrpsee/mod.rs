@@ -10,7 +10,8 @@ use zksync_web3_decl::{
 pub(crate) use self::{
     metadata::{MethodMetadata, MethodTracer},
     middleware::{
-        CorrelationMiddleware, LimitMiddleware, MetadataLayer, ShutdownMiddleware, TrafficTracker,
+        ClientRateLimiter, CorrelationMiddleware, ForwardedForLayer, LimitMiddleware,
+        MetadataLayer, ShutdownMiddleware, TrafficTracker, WithPeerIp,
     },
 };
 use crate::tx_sender::SubmitTxError;
@@ -39,6 +40,7 @@ impl MethodTracer {
             | Web3Error::TooManyTopics
             | Web3Error::FilterNotFound
             | Web3Error::InvalidFilterBlockHash
+            | Web3Error::InvalidParams(_)
             | Web3Error::LogsLimitExceeded(_, _, _) => ErrorCode::InvalidParams.code(),
             Web3Error::SubmitTransactionError(_, _)
             | Web3Error::SerializationError(_)
@@ -62,6 +64,7 @@ impl From<SubmitTxError> for Web3Error {
         match err {
             SubmitTxError::Internal(err) => Self::InternalError(err),
             SubmitTxError::ProxyError(err) => Self::ProxyError(err),
+            SubmitTxError::InvalidBlockOverrides(_) => Self::InvalidParams(err.to_string()),
             _ => Self::SubmitTransactionError(err.to_string(), err.data()),
         }
     }
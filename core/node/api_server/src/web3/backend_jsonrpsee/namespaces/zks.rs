@@ -4,8 +4,8 @@ use zksync_multivm::interface::VmEvent;
 use zksync_types::{
     api::{
         state_override::StateOverride, ApiStorageLog, BlockDetails, BridgeAddresses,
-        L1BatchDetails, L2ToL1LogProof, Log, Proof, ProtocolVersion, TransactionDetailedResult,
-        TransactionDetails,
+        L1BatchDataAvailabilityDetails, L1BatchDetails, L2ToL1LogProof, Log, Proof, ProofFormat,
+        ProtocolVersion, TransactionDetailedResult, TransactionDetails,
     },
     fee::Fee,
     fee_model::{FeeParams, PubdataIndependentBatchFeeModelInput},
@@ -148,6 +148,15 @@ impl ZksNamespaceServer for ZksNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn get_l1_batch_data_availability_details(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> RpcResult<Option<L1BatchDataAvailabilityDetails>> {
+        self.get_l1_batch_data_availability_details_impl(batch_number)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_bytecode_by_hash(&self, hash: H256) -> RpcResult<Option<Vec<u8>>> {
         self.get_bytecode_by_hash_impl(hash)
             .await
@@ -186,8 +195,9 @@ impl ZksNamespaceServer for ZksNamespace {
         address: Address,
         keys: Vec<H256>,
         l1_batch_number: L1BatchNumber,
+        format: Option<ProofFormat>,
     ) -> RpcResult<Option<Proof>> {
-        self.get_proofs_impl(address, keys, l1_batch_number)
+        self.get_proofs_impl(address, keys, l1_batch_number, format.unwrap_or_default())
             .await
             .map_err(|err| self.current_method().map_err(err))
     }
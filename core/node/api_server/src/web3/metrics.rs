@@ -170,6 +170,7 @@ enum Web3ErrorKind {
     FilterNotFound,
     LogsLimitExceeded,
     InvalidFilterBlockHash,
+    InvalidParams,
     TreeApiUnavailable,
     Internal,
 }
@@ -186,6 +187,7 @@ impl Web3ErrorKind {
             Web3Error::FilterNotFound => Self::FilterNotFound,
             Web3Error::LogsLimitExceeded(..) => Self::LogsLimitExceeded,
             Web3Error::InvalidFilterBlockHash => Self::InvalidFilterBlockHash,
+            Web3Error::InvalidParams(_) => Self::InvalidParams,
             Web3Error::TreeApiUnavailable => Self::TreeApiUnavailable,
             Web3Error::InternalError(_) | Web3Error::MethodNotImplemented => Self::Internal,
         }
@@ -225,6 +227,7 @@ struct Web3ConfigLabels {
     #[metrics(unit = Unit::Bytes)]
     response_body_size_limit: Option<usize>,
     websocket_requests_per_minute_limit: Option<u32>,
+    client_requests_per_minute_limit: Option<u32>,
 }
 
 /// Roughly exponential buckets for the `web3_call_block_diff` metric. The distribution should be skewed towards lower values.
@@ -293,6 +296,10 @@ impl ApiMetrics {
             websocket_requests_per_minute_limit: optional
                 .websocket_requests_per_minute_limit
                 .map(Into::into),
+            client_requests_per_minute_limit: optional
+                .client_rate_limit
+                .as_ref()
+                .map(|(limit, _)| limit.get()),
         };
         tracing::info!("{transport:?} Web3 server is configured with options: {config_labels:?}");
         if self.web3_info[&transport].set(config_labels).is_err() {
@@ -1,15 +1,18 @@
-use std::{collections::HashSet, net::SocketAddr, num::NonZeroU32, sync::Arc, time::Duration};
+use std::{collections::HashSet, io, net::SocketAddr, num::NonZeroU32, sync::Arc, time::Duration};
 
 use anyhow::Context as _;
 use chrono::NaiveDateTime;
 use futures::future;
 use serde::Deserialize;
 use tokio::{
-    sync::{mpsc, oneshot, watch, Mutex},
+    net::TcpListener,
+    sync::{mpsc, oneshot, watch, Mutex, Semaphore},
     task::JoinHandle,
 };
 use tower_http::{cors::CorsLayer, metrics::InFlightRequestsLayer};
-use zksync_config::configs::api::{MaxResponseSize, MaxResponseSizeOverrides};
+use zksync_config::configs::api::{
+    MaxResponseSize, MaxResponseSizeOverrides, RateLimitMethodCosts,
+};
 use zksync_dal::{helpers::wait_for_l1_batch, ConnectionPool, Core};
 use zksync_health_check::{HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_metadata_calculator::api_server::TreeApiClient;
@@ -18,7 +21,8 @@ use zksync_types::L2BlockNumber;
 use zksync_web3_decl::{
     jsonrpsee::{
         server::{
-            middleware::rpc::either::Either, BatchRequestConfig, RpcServiceBuilder, ServerBuilder,
+            middleware::rpc::either::Either, serve_with_graceful_shutdown, stop_channel,
+            BatchRequestConfig, RpcServiceBuilder, ServerBuilder,
         },
         MethodCallback, Methods, RpcModule,
     },
@@ -32,8 +36,8 @@ use zksync_web3_decl::{
 
 use self::{
     backend_jsonrpsee::{
-        CorrelationMiddleware, LimitMiddleware, MetadataLayer, MethodTracer, ShutdownMiddleware,
-        TrafficTracker,
+        ClientRateLimiter, CorrelationMiddleware, ForwardedForLayer, LimitMiddleware,
+        MetadataLayer, MethodTracer, ShutdownMiddleware, TrafficTracker, WithPeerIp,
     },
     mempool_cache::MempoolCache,
     metrics::API_METRICS,
@@ -67,6 +71,9 @@ const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
 /// the server will cease processing any further traffic. If this interval is exceeded, the server will start
 /// shutting down anyway.
 const NO_REQUESTS_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+/// Delay before accepting connections again after a non-transient accept error (e.g., reaching the limit
+/// on open file descriptors). Without the delay, the accept loop would spin and flood the logs.
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_secs(1);
 /// Time interval with no requests sent to the API server to declare that traffic to the server is ceased,
 /// and start gracefully shutting down the server.
 const SHUTDOWN_INTERVAL_WITHOUT_REQUESTS: Duration = Duration::from_millis(500);
@@ -130,9 +137,12 @@ struct OptionalApiParams {
     sync_state: Option<SyncState>,
     filters_limit: Option<usize>,
     subscriptions_limit: Option<usize>,
+    max_connections: Option<usize>,
     batch_request_size_limit: Option<usize>,
     response_body_size_limit: Option<MaxResponseSize>,
     websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    client_rate_limit: Option<(NonZeroU32, RateLimitMethodCosts)>,
+    trust_forwarded_for_header: bool,
     tree_api: Option<Arc<dyn TreeApiClient>>,
     mempool_cache: Option<MempoolCache>,
     extended_tracing: bool,
@@ -225,6 +235,13 @@ impl ApiBuilder {
         self
     }
 
+    /// Sets the maximum number of concurrent connections to the server. If not set, the limit is equal
+    /// to the subscriptions limit for WS servers, and to 5,000 for HTTP servers.
+    pub fn with_max_connections(mut self, max_connections: usize) -> Self {
+        self.optional.max_connections = Some(max_connections);
+        self
+    }
+
     pub fn with_batch_request_size_limit(mut self, batch_request_size_limit: usize) -> Self {
         self.optional.batch_request_size_limit = Some(batch_request_size_limit);
         self
@@ -244,6 +261,24 @@ impl ApiBuilder {
         self
     }
 
+    /// Enables per-client rate limiting for both HTTP and WS servers. Requests to each method are weighted
+    /// according to `method_costs`.
+    pub fn with_client_rate_limit(
+        mut self,
+        requests_per_minute_limit: NonZeroU32,
+        method_costs: RateLimitMethodCosts,
+    ) -> Self {
+        self.optional.client_rate_limit = Some((requests_per_minute_limit, method_costs));
+        self
+    }
+
+    /// Makes the server identify clients by the `X-Forwarded-For` header for per-client rate limiting.
+    /// Should only be used if the server is behind a trusted reverse proxy.
+    pub fn with_trusted_forwarded_for_header(mut self) -> Self {
+        self.optional.trust_forwarded_for_header = true;
+        self
+    }
+
     pub fn with_sync_state(mut self, sync_state: SyncState) -> Self {
         self.optional.sync_state = Some(sync_state);
         self
@@ -626,7 +661,26 @@ impl ApiServer {
                 (u32::MAX, MaxResponseSizeOverrides::empty())
             };
         let websocket_requests_per_minute_limit = self.optional.websocket_requests_per_minute_limit;
+        let client_rate_limiter = self.optional.client_rate_limit.clone().map(
+            |(requests_per_minute_limit, method_costs)| {
+                Arc::new(ClientRateLimiter::new(
+                    requests_per_minute_limit,
+                    method_costs,
+                ))
+            },
+        );
+        if let Some(limiter) = &client_rate_limiter {
+            tokio::spawn(ClientRateLimiter::run_cleanup(Arc::downgrade(limiter)));
+        }
+        let trust_forwarded_for_header = self.optional.trust_forwarded_for_header;
+        if trust_forwarded_for_header {
+            tracing::info!(
+                "{transport_str} API server will identify clients by the `X-Forwarded-For` header"
+            );
+        }
+        let forwarded_for = trust_forwarded_for_header.then_some(ForwardedForLayer);
         let subscriptions_limit = self.optional.subscriptions_limit;
+        let max_connections = self.optional.max_connections;
         let vm_barrier = self.optional.vm_barrier.clone();
         let health_updater = self.health_updater.clone();
         let method_tracer = self.method_tracer.clone();
@@ -664,13 +718,16 @@ impl ApiServer {
         // Assemble server middleware.
         let middleware = tower::ServiceBuilder::new()
             .layer(in_flight_requests)
-            .option_layer(cors);
+            .option_layer(cors)
+            .option_layer(forwarded_for);
 
         // Settings shared by HTTP and WS servers.
-        let max_connections = !is_http
-            .then_some(subscriptions_limit)
-            .flatten()
-            .unwrap_or(5_000);
+        let max_connections = max_connections.unwrap_or_else(|| {
+            (!is_http)
+                .then_some(subscriptions_limit)
+                .flatten()
+                .unwrap_or(5_000)
+        });
 
         let metadata_layer = MetadataLayer::new(registered_method_names, method_tracer);
         let metadata_layer = if extended_tracing {
@@ -693,11 +750,17 @@ impl ApiServer {
             )
             .layer(metadata_layer)
             // We want to capture limit middleware errors with `metadata_layer`; hence, `LimitMiddleware` is placed after it.
-            .option_layer((!is_http).then(|| {
-                tower::layer::layer_fn(move |svc| {
-                    LimitMiddleware::new(svc, websocket_requests_per_minute_limit)
-                })
-            }));
+            .layer_fn(move |svc| {
+                if is_http {
+                    LimitMiddleware::http(svc, client_rate_limiter.clone())
+                } else {
+                    LimitMiddleware::ws(
+                        svc,
+                        websocket_requests_per_minute_limit,
+                        client_rate_limiter.clone(),
+                    )
+                }
+            });
 
         let server_builder = ServerBuilder::default()
             .max_connections(max_connections as u32)
@@ -706,26 +769,67 @@ impl ApiServer {
             .set_batch_request_config(batch_request_config)
             .set_rpc_middleware(rpc_middleware);
 
-        let (local_addr, server_handle) = if is_http {
+        let server_builder = if is_http {
             // HTTP-specific settings
-            let server = server_builder
-                .http_only()
-                .build(addr)
-                .await
-                .context("Failed building HTTP JSON-RPC server")?;
-            (server.local_addr(), server.start(rpc))
+            server_builder.http_only()
         } else {
             // WS-specific settings
-            let server = server_builder
-                .set_id_provider(EthSubscriptionIdProvider)
-                .build(addr)
-                .await
-                .context("Failed building WS JSON-RPC server")?;
-            (server.local_addr(), server.start(rpc))
+            server_builder.set_id_provider(EthSubscriptionIdProvider)
         };
-        let local_addr = local_addr.with_context(|| {
+        let service_builder = server_builder.to_service_builder();
+
+        // We run the accept loop manually rather than using `Server::start()` so that the peer address
+        // of each connection is available to the per-client rate limiter.
+        let listener = TcpListener::bind(addr)
+            .await
+            .with_context(|| format!("Failed binding {transport_str} JSON-RPC server"))?;
+        let local_addr = listener.local_addr().with_context(|| {
             format!("Failed getting local address for {transport_str} JSON-RPC server")
         })?;
+        let methods = Methods::from(rpc);
+        let connection_permits = Arc::new(Semaphore::new(max_connections));
+        let (stop_handle, server_handle) = stop_channel();
+        tokio::spawn(async move {
+            loop {
+                let (socket, peer_addr) = tokio::select! {
+                    res = listener.accept() => match res {
+                        Ok(connection) => connection,
+                        Err(err) => {
+                            tracing::warn!("Failed accepting connection to {transport_str} JSON-RPC server: {err}");
+                            if !is_connection_error(&err) {
+                                tokio::select! {
+                                    () = tokio::time::sleep(ACCEPT_ERROR_BACKOFF) => {}
+                                    () = stop_handle.clone().shutdown() => break,
+                                }
+                            }
+                            continue;
+                        }
+                    },
+                    () = stop_handle.clone().shutdown() => break,
+                };
+                let Ok(permit) = connection_permits.clone().try_acquire_owned() else {
+                    tracing::debug!(
+                        "Dropping connection from {peer_addr} since {transport_str} JSON-RPC server \
+                         has reached the connection limit"
+                    );
+                    continue;
+                };
+
+                let service = service_builder
+                    .clone()
+                    .build(methods.clone(), stop_handle.clone());
+                let service = WithPeerIp::new(service, peer_addr.ip());
+                let stopped = stop_handle.clone().shutdown();
+                tokio::spawn(async move {
+                    if let Err(err) = serve_with_graceful_shutdown(socket, service, stopped).await {
+                        tracing::debug!(
+                            "Failed serving connection from {peer_addr} to {transport_str} JSON-RPC server: {err}"
+                        );
+                    }
+                    drop(permit);
+                });
+            }
+        });
         tracing::info!("Initialized {transport_str} API on {local_addr:?}");
         local_addr_sender.send(local_addr).ok();
         health_updater.update(HealthStatus::Ready.into());
@@ -787,3 +891,14 @@ impl ApiServer {
         Ok(())
     }
 }
+
+/// Checks whether an error returned by [`TcpListener::accept()`] relates to a single connection, as opposed
+/// to the listener state (e.g., the process running out of file descriptors). Matches the logic used in `hyper`.
+fn is_connection_error(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::ConnectionRefused
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::ConnectionReset
+    )
+}
@@ -1,16 +1,24 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use anyhow::Context as _;
-use zksync_dal::{CoreDal, DalError};
-use zksync_multivm::interface::{Call, CallType, ExecutionResult, OneshotTracingParams};
+use zksync_dal::{Connection, Core, CoreDal, DalError};
+use zksync_multivm::interface::{
+    Call, CallType, ExecutionResult, OneshotTracingParams, PrestateAccountState,
+    PrestateTracerResult, PrestateTracingMode,
+};
 use zksync_system_constants::MAX_ENCODED_TX_SIZE;
 use zksync_types::{
     api::{
         BlockId, BlockNumber, CallTracerBlockResult, CallTracerResult, DebugCall, DebugCallType,
-        ResultDebugCall, SupportedTracers, TracerConfig,
+        PrestateAccount, PrestateTrace, ResultDebugCall, ResultPrestateTrace, SupportedTracers,
+        TracerConfig,
     },
+    bytecode::{trim_padded_evm_bytecode, validate_bytecode, BytecodeHash, BytecodeMarker},
     debug_flat_call::{Action, CallResult, CallTraceMeta, DebugCallFlat, ResultDebugCallFlat},
     l2::L2Tx,
     transaction_request::CallRequest,
-    web3, H256, U256,
+    utils::decompose_full_nonce,
+    web3, Address, L2BlockNumber, H256, U256,
 };
 use zksync_web3_decl::error::Web3Error;
 
@@ -29,12 +37,14 @@ impl DebugNamespace {
         Ok(Self { state })
     }
 
+    /// Maps a call trace to the output of the requested tracer. Returns `None` for tracers not based on call traces
+    /// (i.e., `prestateTracer`).
     pub(crate) fn map_call(
         call: Call,
         meta: CallTraceMeta,
         tracer_option: TracerConfig,
-    ) -> CallTracerResult {
-        match tracer_option.tracer {
+    ) -> Option<CallTracerResult> {
+        Some(match tracer_option.tracer {
             SupportedTracers::CallTracer => CallTracerResult::CallTrace(Self::map_default_call(
                 call,
                 tracer_option.tracer_config.only_top_call,
@@ -51,8 +61,10 @@ impl DebugNamespace {
                 );
                 CallTracerResult::FlatCallTrace(calls)
             }
-        }
+            SupportedTracers::PrestateTracer => return None,
+        })
     }
+
     pub(crate) fn map_default_call(call: Call, only_top_call: bool) -> DebugCall {
         let calls = if only_top_call {
             vec![]
@@ -137,6 +149,171 @@ impl DebugNamespace {
         }
     }
 
+    /// Converts the prestate tracer output to the `prestateTracer` format. Similar to geth, fields unchanged
+    /// by the transaction are omitted from the post-transaction state in the diff mode.
+    async fn map_prestate(
+        connection: &mut Connection<'_, Core>,
+        prestate: PrestateTracerResult,
+        factory_deps: &HashMap<H256, Vec<u8>>,
+        diff_mode: bool,
+    ) -> Result<PrestateTrace, Web3Error> {
+        let code_hashes: HashSet<_> = prestate
+            .pre
+            .values()
+            .chain(prestate.post.values())
+            .filter_map(|account| account.code_hash)
+            .filter(|hash| !hash.is_zero())
+            .collect();
+        let mut codes = HashMap::with_capacity(code_hashes.len());
+        for code_hash in code_hashes {
+            if let Some(code) = Self::load_code(connection, code_hash, factory_deps).await? {
+                codes.insert(code_hash, code);
+            }
+        }
+
+        let map_state = |state: HashMap<Address, PrestateAccountState>| -> BTreeMap<_, _> {
+            state
+                .into_iter()
+                .map(|(address, account)| {
+                    let (nonce, _) = account.nonce.map(decompose_full_nonce).unzip();
+                    let account = PrestateAccount {
+                        balance: account.balance,
+                        nonce: nonce.map(|nonce| nonce.as_u64()),
+                        code: account.code_hash.and_then(|hash| codes.get(&hash).cloned()),
+                        storage: account.storage.into_iter().collect(),
+                    };
+                    (address, account)
+                })
+                .collect()
+        };
+
+        Ok(if diff_mode {
+            let pre = map_state(prestate.pre);
+            let mut post = map_state(prestate.post);
+            for (address, post_account) in &mut post {
+                let Some(pre_account) = pre.get(address) else {
+                    continue;
+                };
+                if post_account.balance == pre_account.balance {
+                    post_account.balance = None;
+                }
+                if post_account.nonce == pre_account.nonce {
+                    post_account.nonce = None;
+                }
+                if post_account.code == pre_account.code {
+                    post_account.code = None;
+                }
+                post_account
+                    .storage
+                    .retain(|key, value| pre_account.storage.get(key) != Some(value));
+            }
+            post.retain(|_, account| !account.is_empty());
+            PrestateTrace::Diff { pre, post }
+        } else {
+            PrestateTrace::Prestate(map_state(prestate.pre))
+        })
+    }
+
+    /// Loads the deployed code with the specified hash. EVM bytecodes are returned without padding.
+    async fn load_code(
+        connection: &mut Connection<'_, Core>,
+        code_hash: H256,
+        factory_deps: &HashMap<H256, Vec<u8>>,
+    ) -> Result<Option<web3::Bytes>, Web3Error> {
+        let bytecode = if let Some(bytecode) = factory_deps.get(&code_hash) {
+            Some(bytecode.clone())
+        } else {
+            connection
+                .storage_web3_dal()
+                .get_factory_dep(code_hash)
+                .await
+                .map_err(DalError::generalize)?
+                .map(|(bytecode, _)| bytecode)
+        };
+        let Some(bytecode) = bytecode else {
+            return Ok(None);
+        };
+
+        let bytecode = if BytecodeMarker::new(code_hash) == Some(BytecodeMarker::Evm) {
+            trim_padded_evm_bytecode(&bytecode)
+                .with_context(|| format!("malformed EVM bytecode, hash = {code_hash:?}"))?
+                .to_vec()
+        } else {
+            bytecode
+        };
+        Ok(Some(web3::Bytes(bytecode)))
+    }
+
+    /// Traces transactions in a sealed L2 block with `prestateTracer` by re-executing the block on top
+    /// of the preceding state. If `tx_hash` is specified, only this transaction is traced.
+    async fn trace_block_prestate(
+        &self,
+        block_number: L2BlockNumber,
+        tx_hash: Option<H256>,
+        diff_mode: bool,
+    ) -> Result<Vec<ResultPrestateTrace>, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = self
+            .state
+            .resolve_block_args(&mut connection, BlockId::Number(block_number.0.into()))
+            .await?;
+        let transactions = connection
+            .transactions_web3_dal()
+            .get_raw_l2_block_transactions(block_number)
+            .await
+            .map_err(DalError::generalize)?;
+        if transactions.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let vm_permit = self
+            .state
+            .tx_sender
+            .vm_concurrency_limiter()
+            .acquire()
+            .await;
+        let vm_permit = vm_permit.context("cannot acquire VM permit")?;
+        let prestate_mode = if diff_mode {
+            PrestateTracingMode::Diff
+        } else {
+            PrestateTracingMode::Prestate
+        };
+        let executor = &self.state.tx_sender.0.executor;
+        let outputs = executor
+            .replay_l2_block(
+                vm_permit,
+                connection,
+                &block_args,
+                transactions,
+                tx_hash,
+                prestate_mode,
+            )
+            .await?;
+
+        let mut connection = self.state.acquire_connection().await?;
+        let mut traces = Vec::with_capacity(outputs.len());
+        for (tx_hash, output) in outputs {
+            let prestate = output
+                .prestate
+                .context("prestate tracer output is missing")?;
+            let result =
+                Self::map_prestate(&mut connection, prestate, &HashMap::new(), diff_mode).await?;
+            traces.push(ResultPrestateTrace { tx_hash, result });
+        }
+        Ok(traces)
+    }
+
+    async fn block_call_traces(
+        connection: &mut Connection<'_, Core>,
+        block_number: L2BlockNumber,
+    ) -> Result<Vec<(Call, CallTraceMeta)>, Web3Error> {
+        Ok(connection
+            .blocks_web3_dal()
+            .get_traces_for_l2_block(block_number)
+            .await
+            .map_err(DalError::generalize)?)
+    }
+
     pub(crate) fn current_method(&self) -> &MethodTracer {
         &self.state.current_method
     }
@@ -158,23 +335,31 @@ impl DebugNamespace {
         self.current_method()
             .set_block_diff(self.state.last_sealed_l2_block.diff(block_number));
 
-        let call_traces = connection
-            .blocks_web3_dal()
-            .get_traces_for_l2_block(block_number)
-            .await
-            .map_err(DalError::generalize)?;
-
         let options = options.unwrap_or_default();
         let result = match options.tracer {
-            SupportedTracers::CallTracer => CallTracerBlockResult::CallTrace(
-                call_traces
-                    .into_iter()
-                    .map(|(call, _)| ResultDebugCall {
-                        result: Self::map_default_call(call, options.tracer_config.only_top_call),
-                    })
-                    .collect(),
-            ),
+            SupportedTracers::PrestateTracer => {
+                drop(connection);
+                let traces = self
+                    .trace_block_prestate(block_number, None, options.tracer_config.diff_mode)
+                    .await?;
+                CallTracerBlockResult::PrestateTrace(traces)
+            }
+            SupportedTracers::CallTracer => {
+                let call_traces = Self::block_call_traces(&mut connection, block_number).await?;
+                CallTracerBlockResult::CallTrace(
+                    call_traces
+                        .into_iter()
+                        .map(|(call, _)| ResultDebugCall {
+                            result: Self::map_default_call(
+                                call,
+                                options.tracer_config.only_top_call,
+                            ),
+                        })
+                        .collect(),
+                )
+            }
             SupportedTracers::FlatCallTracer => {
+                let call_traces = Self::block_call_traces(&mut connection, block_number).await?;
                 let res = call_traces
                     .into_iter()
                     .map(|(call, meta)| {
@@ -204,15 +389,35 @@ impl DebugNamespace {
         tx_hash: H256,
         options: Option<TracerConfig>,
     ) -> Result<Option<CallTracerResult>, Web3Error> {
+        let options = options.unwrap_or_default();
         let mut connection = self.state.acquire_connection().await?;
+        if let SupportedTracers::PrestateTracer = options.tracer {
+            let chain_id = self.state.api_config.l2_chain_id;
+            let tx = connection
+                .transactions_web3_dal()
+                .get_transaction_by_hash(tx_hash, chain_id)
+                .await
+                .map_err(DalError::generalize)?;
+            // Transactions that are not included into a block yet cannot be traced.
+            let Some(block_number) = tx.and_then(|tx| tx.block_number) else {
+                return Ok(None);
+            };
+            drop(connection);
+
+            let block_number = L2BlockNumber(block_number.as_u32());
+            let traces = self
+                .trace_block_prestate(block_number, Some(tx_hash), options.tracer_config.diff_mode)
+                .await?;
+            let trace = traces.into_iter().find(|trace| trace.tx_hash == tx_hash);
+            return Ok(trace.map(|trace| CallTracerResult::PrestateTrace(trace.result)));
+        }
+
         let call_trace = connection
             .transactions_dal()
             .get_call_trace(tx_hash)
             .await
             .map_err(DalError::generalize)?;
-        Ok(call_trace.map(|(call_trace, meta)| {
-            Self::map_call(call_trace, meta, options.unwrap_or_default())
-        }))
+        Ok(call_trace.and_then(|(call_trace, meta)| Self::map_call(call_trace, meta, options)))
     }
 
     pub async fn debug_trace_call_impl(
@@ -262,6 +467,18 @@ impl DebugNamespace {
             MAX_ENCODED_TX_SIZE,
             block_args.use_evm_emulator(),
         )?;
+        let factory_deps: HashMap<_, _> = call
+            .execute
+            .factory_deps
+            .iter()
+            .filter(|bytecode| validate_bytecode(bytecode).is_ok())
+            .map(|bytecode| {
+                (
+                    BytecodeHash::for_bytecode(bytecode).value(),
+                    bytecode.clone(),
+                )
+            })
+            .collect();
 
         let vm_permit = self
             .state
@@ -271,9 +488,18 @@ impl DebugNamespace {
             .await;
         let vm_permit = vm_permit.context("cannot acquire VM permit")?;
 
+        let prestate_mode = match options.tracer {
+            SupportedTracers::PrestateTracer if options.tracer_config.diff_mode => {
+                Some(PrestateTracingMode::Diff)
+            }
+            SupportedTracers::PrestateTracer => Some(PrestateTracingMode::Prestate),
+            SupportedTracers::CallTracer | SupportedTracers::FlatCallTracer => None,
+        };
         // We don't need properly trace if we only need top call
         let tracing_params = OneshotTracingParams {
-            trace_calls: !options.tracer_config.only_top_call,
+            trace_calls: prestate_mode.is_none() && !options.tracer_config.only_top_call,
+            trace_storage_access: false,
+            trace_prestate: prestate_mode,
         };
 
         let connection = self.state.acquire_connection().await?;
@@ -293,6 +519,18 @@ impl DebugNamespace {
             )
             .await?;
 
+        if let Some(prestate) = result.prestate {
+            let mut connection = self.state.acquire_connection().await?;
+            let trace = Self::map_prestate(
+                &mut connection,
+                prestate,
+                &factory_deps,
+                options.tracer_config.diff_mode,
+            )
+            .await?;
+            return Ok(CallTracerResult::PrestateTrace(trace));
+        }
+
         let (output, revert_reason) = match result.vm.result {
             ExecutionResult::Success { output, .. } => (output, None),
             ExecutionResult::Revert { output } => (vec![], Some(output.to_string())),
@@ -318,6 +556,8 @@ impl DebugNamespace {
             // It's a call request, it's safe to everything as default
             ..Default::default()
         };
-        Ok(Self::map_call(call, meta, options))
+        let trace = Self::map_call(call, meta, options)
+            .context("call traces cannot be mapped to the requested tracer")?;
+        Ok(trace)
     }
 }
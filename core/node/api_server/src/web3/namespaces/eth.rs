@@ -1,17 +1,28 @@
+use std::collections::BTreeMap;
+
 use anyhow::Context as _;
+use once_cell::sync::Lazy;
 use zksync_dal::{CoreDal, DalError};
+use zksync_multivm::interface::{ExecutionResult, OneshotTracingParams};
 use zksync_system_constants::DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE;
 use zksync_types::{
     api::{
-        state_override::StateOverride, BlockId, BlockNumber, FeeHistory, GetLogsFilter,
-        Transaction, TransactionId, TransactionReceipt, TransactionVariant,
+        simulate::{
+            SimulatePayload, SimulatedBlock, SimulatedCallError, SimulatedCallResult,
+            SIMULATED_CALL_HALTED_CODE, SIMULATED_CALL_REVERTED_CODE,
+        },
+        state_override::StateOverride,
+        AccessListResult, BlockId, BlockNumber, FeeHistory, GetLogsFilter, Transaction,
+        TransactionId, TransactionReceipt, TransactionVariant,
     },
     bytecode::{trim_padded_evm_bytecode, BytecodeMarker},
+    ethabi,
     l2::{L2Tx, TransactionType},
+    system_contracts::is_system_contract,
     transaction_request::CallRequest,
     u256_to_h256,
     utils::decompose_full_nonce,
-    web3::{self, Bytes, SyncInfo, SyncState},
+    web3::{self, AccessListItem, Bytes, SyncInfo, SyncState},
     AccountTreeId, L2BlockNumber, StorageKey, H256, L2_BASE_TOKEN_ADDRESS, U256,
 };
 use zksync_web3_decl::{
@@ -20,8 +31,10 @@ use zksync_web3_decl::{
 };
 
 use crate::{
-    execution_sandbox::BlockArgs,
-    tx_sender::BinarySearchKind,
+    execution_sandbox::{BlockArgs, SandboxExecutionError},
+    tx_sender::{
+        BinarySearchKind, SimulatedBlockOutput, SimulationBlock, SimulationCall, SubmitTxError,
+    },
     utils::open_readonly_transaction,
     web3::{backend_jsonrpsee::MethodTracer, metrics::API_METRICS, state::RpcState, TypedFilter},
 };
@@ -29,6 +42,18 @@ use crate::{
 pub const EVENT_TOPIC_NUMBER_LIMIT: usize = 4;
 pub const PROTOCOL_VERSION: &str = "zks/1";
 
+/// Topic of the ERC-20 `Transfer(address,address,uint256)` event. The L2 base token emits this event on each transfer.
+static TRANSFER_EVENT_TOPIC: Lazy<H256> = Lazy::new(|| {
+    ethabi::long_signature(
+        "Transfer",
+        &[
+            ethabi::ParamType::Address,
+            ethabi::ParamType::Address,
+            ethabi::ParamType::Uint(256),
+        ],
+    )
+});
+
 #[derive(Debug)]
 pub(crate) struct EthNamespace {
     state: RpcState,
@@ -94,6 +119,252 @@ impl EthNamespace {
         Ok(call_result.into())
     }
 
+    pub async fn create_access_list_impl(
+        &self,
+        mut request: CallRequest,
+        block_id: Option<BlockId>,
+        state_override: Option<StateOverride>,
+    ) -> Result<AccessListResult, Web3Error> {
+        let block_id = block_id.unwrap_or(BlockId::Number(BlockNumber::Pending));
+        self.current_method().set_block_id(block_id);
+
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = self
+            .state
+            .resolve_block_args(&mut connection, block_id)
+            .await?;
+        self.current_method().set_block_diff(
+            self.state
+                .last_sealed_l2_block
+                .diff_with_block_args(&block_args),
+        );
+        if request.gas.is_none() {
+            request.gas = Some(block_args.default_eth_call_gas(&mut connection).await?);
+        }
+        drop(connection);
+
+        let call_overrides = request.get_call_overrides()?;
+        let tx = L2Tx::from_request(
+            request.into(),
+            self.state.api_config.max_tx_size,
+            block_args.use_evm_emulator(),
+        )?;
+        // Like Geth, we exclude the sender, the recipient and precompiles from the access list. System contracts
+        // are excluded as well; among other things, they store the sender nonce and balance.
+        let excluded_addresses = [Some(tx.initiator_account()), tx.recipient_account()];
+
+        let tracing_params = OneshotTracingParams {
+            trace_storage_access: true,
+            ..OneshotTracingParams::default()
+        };
+        let output = self
+            .state
+            .tx_sender
+            .eth_call_with_tracing(
+                block_args,
+                call_overrides,
+                tx,
+                state_override,
+                tracing_params,
+            )
+            .await?;
+
+        let mut slots_by_address = BTreeMap::<_, Vec<_>>::new();
+        for key in &output.accessed_storage_keys {
+            let address = *key.address();
+            if !excluded_addresses.contains(&Some(address)) && !is_system_contract(address) {
+                slots_by_address
+                    .entry(address)
+                    .or_default()
+                    .push(*key.key());
+            }
+        }
+        let access_list = slots_by_address
+            .into_iter()
+            .map(|(address, storage_keys)| AccessListItem {
+                address,
+                storage_keys,
+            })
+            .collect();
+
+        let error = match output.vm.result {
+            ExecutionResult::Success { .. } => None,
+            ExecutionResult::Revert { output } => Some(output.to_string()),
+            ExecutionResult::Halt { reason } => Some(reason.to_string()),
+        };
+        Ok(AccessListResult {
+            access_list,
+            gas_used: output.vm.statistics.gas_used.into(),
+            error,
+        })
+    }
+
+    pub async fn simulate_v1_impl(
+        &self,
+        payload: SimulatePayload,
+        block_id: Option<BlockId>,
+    ) -> Result<Vec<SimulatedBlock>, Web3Error> {
+        let block_id = block_id.unwrap_or(BlockId::Number(BlockNumber::Pending));
+        self.current_method().set_block_id(block_id);
+
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = self
+            .state
+            .resolve_block_args(&mut connection, block_id)
+            .await?;
+        self.current_method().set_block_diff(
+            self.state
+                .last_sealed_l2_block
+                .diff_with_block_args(&block_args),
+        );
+        let default_gas = block_args.default_eth_call_gas(&mut connection).await?;
+        drop(connection);
+
+        let api_config = &self.state.api_config;
+        let blocks_limit = api_config.simulate_blocks_limit;
+        if payload.block_state_calls.len() > blocks_limit {
+            let err = format!("at most {blocks_limit} blocks can be simulated");
+            return Err(SubmitTxError::SimulationLimitExceeded(err).into());
+        }
+        let calls_limit = api_config.simulate_calls_limit;
+        let total_calls: usize = payload
+            .block_state_calls
+            .iter()
+            .map(|block| block.calls.len())
+            .sum();
+        if total_calls > calls_limit {
+            let err = format!("at most {calls_limit} calls can be simulated");
+            return Err(SubmitTxError::SimulationLimitExceeded(err).into());
+        }
+
+        // Calls without the gas limit specified get the default limit capped by the remaining gas budget.
+        let gas_limit = api_config.simulate_gas_limit;
+        let mut remaining_gas = gas_limit;
+        let mut blocks = Vec::with_capacity(payload.block_state_calls.len());
+        for block in payload.block_state_calls {
+            let mut calls = Vec::with_capacity(block.calls.len());
+            for mut request in block.calls {
+                let gas = match request.gas {
+                    Some(gas) => u64::try_from(gas).unwrap_or(u64::MAX),
+                    None => default_gas.as_u64().min(remaining_gas),
+                };
+                if gas == 0 || gas > remaining_gas {
+                    let err = format!("total gas limit of simulated calls exceeds {gas_limit}");
+                    return Err(SubmitTxError::SimulationLimitExceeded(err).into());
+                }
+                remaining_gas -= gas;
+                request.gas = Some(gas.into());
+                let has_nonce = request.nonce.is_some();
+                let overrides = request.get_call_overrides()?;
+                let tx = L2Tx::from_request(
+                    request.into(),
+                    self.state.api_config.max_tx_size,
+                    block_args.use_evm_emulator(),
+                )?;
+                calls.push(SimulationCall {
+                    tx,
+                    overrides,
+                    has_nonce,
+                });
+            }
+            blocks.push(SimulationBlock {
+                block_overrides: block.block_overrides.unwrap_or_default(),
+                state_override: block.state_overrides,
+                calls,
+            });
+        }
+
+        let outputs = self
+            .state
+            .tx_sender
+            .simulate_blocks(block_args, blocks, payload.validation)
+            .await?;
+        Ok(outputs
+            .into_iter()
+            .map(|output| Self::convert_simulated_block(output, payload.trace_transfers))
+            .collect())
+    }
+
+    fn convert_simulated_block(
+        output: SimulatedBlockOutput,
+        trace_transfers: bool,
+    ) -> SimulatedBlock {
+        let mut gas_used = 0_u64;
+        let mut block_log_index = 0_u64;
+        let mut calls = Vec::with_capacity(output.calls.len());
+        for (tx_index, (tx, vm)) in output.calls.into_iter().enumerate() {
+            gas_used += vm.statistics.gas_used;
+            let events = vm.logs.events.into_iter().filter(|event| {
+                trace_transfers
+                    || event.address != L2_BASE_TOKEN_ADDRESS
+                    || event.indexed_topics.first() != Some(&*TRANSFER_EVENT_TOPIC)
+            });
+            let logs = events
+                .enumerate()
+                .map(|(tx_log_index, event)| {
+                    let log = Log {
+                        address: event.address,
+                        topics: event.indexed_topics,
+                        data: event.value.into(),
+                        block_hash: Some(output.hash),
+                        block_number: Some(output.number.0.into()),
+                        l1_batch_number: None,
+                        transaction_hash: Some(tx.hash()),
+                        transaction_index: Some(tx_index.into()),
+                        log_index: Some(block_log_index.into()),
+                        transaction_log_index: Some(tx_log_index.into()),
+                        log_type: None,
+                        removed: Some(false),
+                        block_timestamp: Some(output.timestamp.into()),
+                    };
+                    block_log_index += 1;
+                    log
+                })
+                .collect();
+
+            let (return_data, error) = match vm.result {
+                ExecutionResult::Success { output } => (output, None),
+                ExecutionResult::Revert { output } => {
+                    let err = SubmitTxError::ExecutionReverted(
+                        output.to_user_friendly_string(),
+                        output.encoded_data(),
+                    );
+                    let error = SimulatedCallError {
+                        code: SIMULATED_CALL_REVERTED_CODE,
+                        message: err.to_string(),
+                        data: Some(err.data().into()),
+                    };
+                    (vec![], Some(error))
+                }
+                ExecutionResult::Halt { reason } => {
+                    let err = SubmitTxError::from(SandboxExecutionError::from(reason));
+                    let error = SimulatedCallError {
+                        code: SIMULATED_CALL_HALTED_CODE,
+                        message: err.to_string(),
+                        data: None,
+                    };
+                    (vec![], Some(error))
+                }
+            };
+            calls.push(SimulatedCallResult {
+                return_data: return_data.into(),
+                logs,
+                gas_used: vm.statistics.gas_used.into(),
+                status: u64::from(error.is_none()).into(),
+                error,
+            });
+        }
+
+        SimulatedBlock {
+            number: output.number.0.into(),
+            hash: output.hash,
+            timestamp: output.timestamp.into(),
+            gas_used: gas_used.into(),
+            base_fee_per_gas: output.base_fee.into(),
+            calls,
+        }
+    }
+
     pub async fn estimate_gas_impl(
         &self,
         request: CallRequest,
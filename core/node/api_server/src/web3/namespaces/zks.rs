@@ -10,7 +10,8 @@ use zksync_types::{
     address_to_h256,
     api::{
         state_override::StateOverride, BlockDetails, BridgeAddresses, GetLogsFilter,
-        L1BatchDetails, L2ToL1LogProof, Proof, ProtocolVersion, StorageProof, TransactionDetails,
+        L1BatchDataAvailabilityDetails, L1BatchDetails, L2ToL1LogProof, Proof, ProofFormat,
+        ProtocolVersion, StorageMultiProof, StorageProof, TransactionDetails,
     },
     fee::Fee,
     fee_model::{FeeParams, PubdataIndependentBatchFeeModelInput},
@@ -474,6 +475,23 @@ impl ZksNamespace {
             .map_err(DalError::generalize)?)
     }
 
+    pub async fn get_l1_batch_data_availability_details_impl(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> Result<Option<L1BatchDataAvailabilityDetails>, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        self.state
+            .start_info
+            .ensure_not_pruned(batch_number, &mut storage)
+            .await?;
+
+        Ok(storage
+            .data_availability_dal()
+            .get_l1_batch_da_details(batch_number)
+            .await
+            .map_err(DalError::generalize)?)
+    }
+
     pub async fn get_bytecode_by_hash_impl(
         &self,
         hash: H256,
@@ -523,13 +541,14 @@ impl ZksNamespace {
         address: Address,
         keys: Vec<H256>,
         l1_batch_number: L1BatchNumber,
+        format: ProofFormat,
     ) -> Result<Option<Proof>, Web3Error> {
         let mut storage = self.state.acquire_connection().await?;
         self.state
             .start_info
             .ensure_not_pruned(l1_batch_number, &mut storage)
             .await?;
-        let hashed_keys = keys
+        let hashed_keys: Vec<_> = keys
             .iter()
             .map(|key| StorageKey::new(AccountTreeId::new(address), *key).hashed_key_u256())
             .collect();
@@ -538,43 +557,81 @@ impl ZksNamespace {
             .tree_api
             .as_deref()
             .ok_or(Web3Error::MethodNotImplemented)?;
-        let proofs_result = tree_api.get_proofs(l1_batch_number, hashed_keys).await;
-        let proofs = match proofs_result {
-            Ok(proofs) => proofs,
-            Err(TreeApiError::NotReady(_)) => return Err(Web3Error::TreeApiUnavailable),
+        let proof_result = match format {
+            ProofFormat::Individual => {
+                tree_api
+                    .get_proofs(l1_batch_number, hashed_keys)
+                    .await
+                    .map(|proofs| {
+                        let storage_proof = proofs
+                            .into_iter()
+                            .zip(keys)
+                            .map(|(proof, key)| StorageProof {
+                                key,
+                                proof: proof.merkle_path,
+                                value: proof.value,
+                                index: proof.index,
+                            })
+                            .collect();
+                        Proof {
+                            address,
+                            storage_proof,
+                            multi_proof: None,
+                        }
+                    })
+            }
+            ProofFormat::Compact => tree_api
+                .get_multi_proof(l1_batch_number, hashed_keys.clone())
+                .await
+                .and_then(|proof| {
+                    let keys_by_hash: HashMap<_, _> = hashed_keys.into_iter().zip(keys).collect();
+                    let mut storage_proof = Vec::with_capacity(proof.entries.len());
+                    let mut merkle_path_lengths = Vec::with_capacity(proof.entries.len());
+                    for entry in proof.entries {
+                        let key = *keys_by_hash.get(&entry.hashed_key).ok_or_else(|| {
+                            TreeApiError::Internal(anyhow::anyhow!(
+                                "Merkle tree returned a multi-proof entry for unrequested hashed key {:#x}",
+                                entry.hashed_key
+                            ))
+                        })?;
+                        storage_proof.push(StorageProof {
+                            key,
+                            proof: vec![],
+                            value: entry.value,
+                            index: entry.index,
+                        });
+                        merkle_path_lengths.push(entry.merkle_path_length);
+                    }
+                    Ok(Proof {
+                        address,
+                        storage_proof,
+                        multi_proof: Some(StorageMultiProof {
+                            merkle_path_lengths,
+                            hashes: proof.hashes,
+                        }),
+                    })
+                }),
+        };
+        match proof_result {
+            Ok(proof) => Ok(Some(proof)),
+            Err(TreeApiError::NotReady(_)) => Err(Web3Error::TreeApiUnavailable),
             Err(TreeApiError::NoVersion(err)) => {
-                return if err.missing_version > err.version_count {
+                if err.missing_version > err.version_count {
                     Ok(None)
                 } else {
                     Err(Web3Error::InternalError(anyhow::anyhow!(
                         "L1 batch #{l1_batch_number} is pruned in Merkle tree, but not in Postgres"
                     )))
-                };
+                }
             }
-            Err(TreeApiError::Internal(err)) => return Err(Web3Error::InternalError(err)),
+            Err(TreeApiError::Internal(err)) => Err(Web3Error::InternalError(err)),
             Err(_) => {
                 // This branch is not expected to be executed, but has to be provided since the error is non-exhaustive.
-                return Err(Web3Error::InternalError(anyhow::anyhow!(
+                Err(Web3Error::InternalError(anyhow::anyhow!(
                     "Unspecified tree API error"
-                )));
+                )))
             }
-        };
-
-        let storage_proof = proofs
-            .into_iter()
-            .zip(keys)
-            .map(|(proof, key)| StorageProof {
-                key,
-                proof: proof.merkle_path,
-                value: proof.value,
-                index: proof.index,
-            })
-            .collect();
-
-        Ok(Some(Proof {
-            address,
-            storage_proof,
-        }))
+        }
     }
 
     pub fn get_base_token_l1_address_impl(&self) -> Result<Address, Web3Error> {
@@ -111,6 +111,9 @@ pub struct InternalApiConfig {
     pub l2_testnet_paymaster_addr: Option<Address>,
     pub req_entities_limit: usize,
     pub fee_history_limit: u64,
+    pub simulate_blocks_limit: usize,
+    pub simulate_calls_limit: usize,
+    pub simulate_gas_limit: u64,
     pub base_token_address: Option<Address>,
     pub filters_disabled: bool,
     pub dummy_verifier: bool,
@@ -165,6 +168,9 @@ impl InternalApiConfig {
             l2_testnet_paymaster_addr: contracts_config.l2_testnet_paymaster_addr,
             req_entities_limit: web3_config.req_entities_limit(),
             fee_history_limit: web3_config.fee_history_limit(),
+            simulate_blocks_limit: web3_config.simulate_blocks_limit(),
+            simulate_calls_limit: web3_config.simulate_calls_limit(),
+            simulate_gas_limit: web3_config.simulate_gas_limit(),
             base_token_address: contracts_config.base_token_addr,
             filters_disabled: web3_config.filters_disabled,
             dummy_verifier: genesis_config.dummy_verifier,
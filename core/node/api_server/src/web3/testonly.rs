@@ -109,6 +109,7 @@ pub struct TestServerBuilder {
     tx_executor: MockOneshotExecutor,
     executor_options: Option<SandboxExecutorOptions>,
     method_tracer: Arc<MethodTracer>,
+    max_connections: Option<usize>,
 }
 
 impl TestServerBuilder {
@@ -120,6 +121,7 @@ impl TestServerBuilder {
             tx_executor: MockOneshotExecutor::default(),
             executor_options: None,
             method_tracer: Arc::default(),
+            max_connections: None,
         }
     }
 
@@ -143,6 +145,13 @@ impl TestServerBuilder {
         self
     }
 
+    /// Sets the maximum number of concurrent connections to the server.
+    #[must_use]
+    pub fn with_max_connections(mut self, max_connections: usize) -> Self {
+        self.max_connections = Some(max_connections);
+        self
+    }
+
     /// Builds an HTTP server.
     pub async fn build_http(self, stop_receiver: watch::Receiver<bool>) -> ApiServerHandles {
         self.spawn_server(ApiTransportLabel::Http, None, stop_receiver)
@@ -176,6 +185,7 @@ impl TestServerBuilder {
             pool,
             api_config,
             method_tracer,
+            max_connections,
         } = self;
 
         let tx_executor = if let Some(options) = executor_options {
@@ -209,6 +219,11 @@ impl TestServerBuilder {
                 builder
             }
         };
+        let server_builder = if let Some(max_connections) = max_connections {
+            server_builder.with_max_connections(max_connections)
+        } else {
+            server_builder
+        };
         let server_handles = server_builder
             .with_polling_interval(POLL_INTERVAL)
             .with_tx_sender(tx_sender)
@@ -1,9 +1,12 @@
 //! Tests for the `debug` Web3 namespace.
 
-use zksync_multivm::interface::{Call, TransactionExecutionResult};
+use std::collections::BTreeMap;
+
+use zksync_multivm::interface::{Call, TransactionExecutionResult, VmExecutionResultAndLogs};
 use zksync_types::{
     api::{CallTracerConfig, SupportedTracers, TracerConfig},
-    BOOTLOADER_ADDRESS,
+    transaction_request::CallRequest,
+    StorageLogWithPreviousValue, BOOTLOADER_ADDRESS,
 };
 use zksync_web3_decl::{
     client::{DynClient, L2},
@@ -133,6 +136,7 @@ impl HttpTest for TraceBlockFlatTest {
                             tracer: SupportedTracers::FlatCallTracer,
                             tracer_config: CallTracerConfig {
                                 only_top_call: false,
+                                diff_mode: false,
                             },
                         }),
                     )
@@ -173,6 +177,7 @@ impl HttpTest for TraceBlockFlatTest {
                     tracer: SupportedTracers::FlatCallTracer,
                     tracer_config: CallTracerConfig {
                         only_top_call: false,
+                        diff_mode: false,
                     },
                 }),
             )
@@ -238,6 +243,135 @@ async fn tracing_transaction() {
     test_http_server(TraceTransactionTest).await;
 }
 
+#[derive(Debug)]
+struct TracePrestateTest;
+
+impl TracePrestateTest {
+    const CONTRACT: Address = Address::repeat_byte(0x42);
+
+    fn storage_logs() -> Vec<StorageLogWithPreviousValue> {
+        let written_key = StorageKey::new(AccountTreeId::new(Self::CONTRACT), H256::zero());
+        let read_key = StorageKey::new(AccountTreeId::new(Self::CONTRACT), H256::repeat_byte(1));
+        vec![
+            StorageLogWithPreviousValue {
+                log: StorageLog::new_read_log(read_key, H256::from_low_u64_be(5)),
+                previous_value: H256::from_low_u64_be(5),
+            },
+            StorageLogWithPreviousValue {
+                log: StorageLog::new_write_log(written_key, H256::from_low_u64_be(2)),
+                previous_value: H256::from_low_u64_be(1),
+            },
+        ]
+    }
+
+    fn options(diff_mode: bool) -> TracerConfig {
+        TracerConfig {
+            tracer: SupportedTracers::PrestateTracer,
+            tracer_config: CallTracerConfig {
+                only_top_call: false,
+                diff_mode,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl HttpTest for TracePrestateTest {
+    fn transaction_executor(&self) -> MockOneshotExecutor {
+        let mut tx_executor = MockOneshotExecutor::default();
+        tx_executor.set_full_call_responses(|_, _| {
+            let mut result = VmExecutionResultAndLogs::mock_success();
+            result.logs.storage_logs = Self::storage_logs();
+            result
+        });
+        tx_executor.set_full_tx_responses(|_, _| {
+            let mut result = VmExecutionResultAndLogs::mock_success();
+            result.logs.storage_logs = Self::storage_logs();
+            result
+        });
+        tx_executor
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let tx_results = [execute_l2_transaction(create_l2_transaction(1, 2))];
+        let tx_hash = tx_results[0].hash;
+        let mut storage = pool.connection().await?;
+        store_l2_block(&mut storage, L2BlockNumber(1), &tx_results).await?;
+        drop(storage);
+
+        let expected_prestate = BTreeMap::from([(
+            Self::CONTRACT,
+            api::PrestateAccount {
+                storage: BTreeMap::from([
+                    (H256::zero(), H256::from_low_u64_be(1)),
+                    (H256::repeat_byte(1), H256::from_low_u64_be(5)),
+                ]),
+                ..api::PrestateAccount::default()
+            },
+        )]);
+        let trace = client
+            .trace_transaction(tx_hash, Some(Self::options(false)))
+            .await?
+            .context("no transaction traces")?
+            .unwrap_prestate();
+        assert_eq!(
+            trace,
+            api::PrestateTrace::Prestate(expected_prestate.clone())
+        );
+
+        let trace = client
+            .trace_call(
+                CallRequest {
+                    from: Some(Address::repeat_byte(1)),
+                    to: Some(Self::CONTRACT),
+                    ..CallRequest::default()
+                },
+                None,
+                Some(Self::options(false)),
+            )
+            .await?
+            .unwrap_prestate();
+        assert_eq!(trace, api::PrestateTrace::Prestate(expected_prestate));
+
+        let trace = client
+            .trace_transaction(tx_hash, Some(Self::options(true)))
+            .await?
+            .context("no transaction traces")?
+            .unwrap_prestate();
+        let api::PrestateTrace::Diff { pre, post } = trace else {
+            panic!("unexpected trace: {trace:?}");
+        };
+        // Read-only slots are omitted in the diff mode.
+        let expected_pre = BTreeMap::from([(H256::zero(), H256::from_low_u64_be(1))]);
+        assert_eq!(pre[&Self::CONTRACT].storage, expected_pre);
+        let expected_post = BTreeMap::from([(H256::zero(), H256::from_low_u64_be(2))]);
+        assert_eq!(post[&Self::CONTRACT].storage, expected_post);
+
+        let block_traces = client
+            .trace_block_by_number(1_u32.into(), Some(Self::options(true)))
+            .await?
+            .unwrap_prestate();
+        assert_eq!(block_traces.len(), 1);
+        assert_eq!(block_traces[0].tx_hash, tx_hash);
+        assert_matches!(block_traces[0].result, api::PrestateTrace::Diff { .. });
+
+        let missing_trace = client
+            .trace_transaction(H256::repeat_byte(0xff), Some(Self::options(false)))
+            .await?;
+        assert!(missing_trace.is_none());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn tracing_prestate() {
+    test_http_server(TracePrestateTest).await;
+}
+
 #[derive(Debug)]
 struct TraceBlockTestWithSnapshotRecovery;
 
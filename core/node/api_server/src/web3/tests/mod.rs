@@ -7,7 +7,11 @@ use std::{
 
 use assert_matches::assert_matches;
 use async_trait::async_trait;
-use tokio::sync::watch;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    sync::watch,
+};
 use zksync_config::{
     configs::{
         api::Web3JsonRpcConfig,
@@ -509,6 +513,93 @@ async fn http_server_basics() {
     test_http_server(HttpServerBasicsTest).await;
 }
 
+/// Sends an `eth_chainId` request over a raw keep-alive HTTP connection and waits for the response.
+async fn send_raw_chain_id_request(stream: &mut TcpStream) {
+    const BODY: &str = r#"{"jsonrpc":"2.0","id":1,"method":"eth_chainId","params":[]}"#;
+
+    let request = format!(
+        "POST / HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{BODY}",
+        BODY.len()
+    );
+    stream.write_all(request.as_bytes()).await.unwrap();
+
+    let mut response = vec![];
+    let mut buffer = [0_u8; 1_024];
+    while !String::from_utf8_lossy(&response).contains("\"result\"") {
+        let read_bytes = stream.read(&mut buffer).await.unwrap();
+        assert!(
+            read_bytes > 0,
+            "connection closed by server; response so far: {}",
+            String::from_utf8_lossy(&response)
+        );
+        response.extend_from_slice(&buffer[..read_bytes]);
+    }
+}
+
+#[tokio::test]
+async fn http_server_with_connection_limit() {
+    const MAX_CONNECTIONS: usize = 2;
+
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    StorageInitialization::genesis()
+        .prepare_storage(&NetworkConfig::for_tests(), &mut storage)
+        .await
+        .unwrap();
+    drop(storage);
+
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let contracts_config = ContractsConfig::for_tests();
+    let web3_config = Web3JsonRpcConfig::for_tests();
+    let genesis = GenesisConfig::for_tests();
+    let api_config = InternalApiConfig::new(&web3_config, &contracts_config, &genesis);
+    let expected_chain_id = U64::from(api_config.l2_chain_id.as_u64());
+    let mut server_handles = TestServerBuilder::new(pool, api_config)
+        .with_max_connections(MAX_CONNECTIONS)
+        .build_http(stop_receiver)
+        .await;
+    let local_addr = server_handles.wait_until_ready().await;
+
+    // Occupy all connection permits with keep-alive connections.
+    let mut connections = vec![];
+    for _ in 0..MAX_CONNECTIONS {
+        let mut stream = TcpStream::connect(local_addr).await.unwrap();
+        send_raw_chain_id_request(&mut stream).await;
+        connections.push(stream);
+    }
+    // Requests over the established connections must still be served once the limit is reached.
+    for stream in &mut connections {
+        send_raw_chain_id_request(stream).await;
+    }
+
+    let client = Client::<L2>::http(format!("http://{local_addr}/").parse().unwrap())
+        .unwrap()
+        .build();
+    client.chain_id().await.unwrap_err();
+
+    // Closing a connection should free up a permit for new connections.
+    drop(connections.pop());
+    let chain_id = tokio::time::timeout(TEST_TIMEOUT, async {
+        loop {
+            match client.chain_id().await {
+                Ok(chain_id) => break chain_id,
+                Err(_) => tokio::time::sleep(POLL_INTERVAL).await,
+            }
+        }
+    })
+    .await
+    .expect("timed out waiting for a connection permit to be released");
+    assert_eq!(chain_id, expected_chain_id);
+
+    // Remaining connections are still functional.
+    for stream in &mut connections {
+        send_raw_chain_id_request(stream).await;
+    }
+
+    stop_sender.send_replace(true);
+    server_handles.shutdown().await;
+}
+
 #[derive(Debug)]
 struct BlockMethodsWithSnapshotRecovery;
 
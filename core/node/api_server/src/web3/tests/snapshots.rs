@@ -46,6 +46,7 @@ impl HttpTest for SnapshotBasicsTest {
             .add_snapshot(
                 SnapshotVersion::Version0,
                 L1BatchNumber(1),
+                None,
                 Self::CHUNK_COUNT,
                 "file:///factory_deps",
             )
@@ -78,6 +79,7 @@ impl HttpTest for SnapshotBasicsTest {
 
         assert_eq!(snapshot_header.l1_batch_number, L1BatchNumber(1));
         assert_eq!(snapshot_header.l2_block_number, L2BlockNumber(1));
+        assert_eq!(snapshot_header.base_l1_batch_number, None);
         assert_eq!(
             snapshot_header.factory_deps_filepath,
             "file:///factory_deps"
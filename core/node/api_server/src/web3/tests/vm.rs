@@ -8,16 +8,25 @@ use std::{
     },
 };
 
-use api::state_override::{OverrideAccount, StateOverride};
+use api::{
+    simulate::{BlockOverrides, SimulateBlock, SimulatePayload},
+    state_override::{OverrideAccount, StateOverride},
+};
 use test_casing::test_casing;
 use zksync_contracts::{BaseSystemContracts, BaseSystemContractsHashes};
 use zksync_multivm::interface::{
     ExecutionResult, OneshotEnv, VmExecutionLogs, VmExecutionResultAndLogs, VmRevertReason,
 };
 use zksync_types::{
-    api::ApiStorageLog, fee_model::BatchFeeInput, get_intrinsic_constants,
-    transaction_request::CallRequest, u256_to_h256, vm::FastVmMode, K256PrivateKey, L2ChainId,
-    PackedEthSignature, StorageLogKind, StorageLogWithPreviousValue, Transaction, U256,
+    api::ApiStorageLog,
+    fee_model::BatchFeeInput,
+    get_intrinsic_constants,
+    transaction_request::CallRequest,
+    u256_to_h256,
+    vm::FastVmMode,
+    web3::{keccak256, AccessListItem},
+    K256PrivateKey, L2ChainId, Nonce, PackedEthSignature, StorageLogKind,
+    StorageLogWithPreviousValue, Transaction, L2_BASE_TOKEN_ADDRESS, U256,
 };
 use zksync_vm_executor::oneshot::{
     BaseSystemContractsProvider, ContractsKind, MockOneshotExecutor, OneshotEnvParameters,
@@ -252,6 +261,275 @@ async fn call_method_basics() {
     test_http_server(CallTest::default()).await;
 }
 
+#[derive(Debug)]
+struct CreateAccessListTest;
+
+impl CreateAccessListTest {
+    const ACCESSED_CONTRACT: Address = Address::repeat_byte(3);
+
+    /// Returns storage slots accessed by a call writing to the recipient storage, with the written value
+    /// read from another contract.
+    fn accessed_slots(tx: &Transaction) -> Vec<StorageKey> {
+        let initiator = tx.initiator_account();
+        let recipient = tx.recipient_account().unwrap();
+        vec![
+            get_nonce_key(&initiator),
+            storage_key_for_eth_balance(&initiator),
+            get_code_key(&recipient),
+            StorageKey::new(AccountTreeId::new(recipient), H256::zero()),
+            StorageKey::new(
+                AccountTreeId::new(Self::ACCESSED_CONTRACT),
+                H256::from_low_u64_be(5),
+            ),
+            StorageKey::new(
+                AccountTreeId::new(Self::ACCESSED_CONTRACT),
+                H256::from_low_u64_be(1),
+            ),
+        ]
+    }
+}
+
+#[async_trait]
+impl HttpTest for CreateAccessListTest {
+    fn transaction_executor(&self) -> MockOneshotExecutor {
+        let mut tx_executor = MockOneshotExecutor::default();
+        tx_executor.set_full_call_responses(|tx, _| {
+            let storage_logs = Self::accessed_slots(tx)
+                .into_iter()
+                .map(|key| StorageLogWithPreviousValue {
+                    log: StorageLog::new_read_log(key, H256::zero()),
+                    previous_value: H256::zero(),
+                })
+                .collect();
+            let mut result = VmExecutionResultAndLogs::mock_success();
+            result.logs.storage_logs = storage_logs;
+            result.statistics.gas_used = 10_000;
+            result
+        });
+        tx_executor
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        // Store an additional L2 block because L2 block #0 has some special processing making it work incorrectly.
+        let mut connection = pool.connection().await?;
+        store_l2_block(&mut connection, L2BlockNumber(1), &[]).await?;
+
+        let result = client
+            .create_access_list(CallTest::call_request(b"pending"), None, None)
+            .await?;
+        // System contracts, the sender and the recipient must be excluded; slots must be sorted.
+        let expected_access_list = [AccessListItem {
+            address: Self::ACCESSED_CONTRACT,
+            storage_keys: vec![H256::from_low_u64_be(1), H256::from_low_u64_be(5)],
+        }];
+        assert_eq!(result.access_list, expected_access_list);
+        assert_eq!(result.gas_used, 10_000.into());
+        assert_eq!(result.error, None);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn create_access_list_basics() {
+    test_http_server(CreateAccessListTest).await;
+}
+
+#[derive(Debug)]
+struct SimulateTest;
+
+impl SimulateTest {
+    const FAR_FUTURE_TIMESTAMP: u64 = 10_000_000_000;
+
+    fn transfer_event(tx: &Transaction) -> VmEvent {
+        VmEvent {
+            location: (L1BatchNumber(1), 0),
+            address: L2_BASE_TOKEN_ADDRESS,
+            indexed_topics: vec![
+                H256(keccak256(b"Transfer(address,address,uint256)")),
+                u256_to_h256(U256::from_big_endian(tx.initiator_account().as_bytes())),
+                u256_to_h256(U256::from_big_endian(
+                    tx.recipient_account().unwrap().as_bytes(),
+                )),
+            ],
+            value: u256_to_h256(tx.execute.value).as_bytes().to_vec(),
+        }
+    }
+
+    fn custom_event() -> VmEvent {
+        VmEvent {
+            location: (L1BatchNumber(1), 1),
+            address: Address::repeat_byte(2),
+            indexed_topics: vec![H256::repeat_byte(0xaa)],
+            value: b"event".to_vec(),
+        }
+    }
+
+    fn payload(trace_transfers: bool) -> SimulatePayload {
+        SimulatePayload {
+            block_state_calls: vec![
+                SimulateBlock {
+                    calls: vec![
+                        CallTest::call_request(b"success"),
+                        CallTest::call_request(b"revert"),
+                    ],
+                    ..SimulateBlock::default()
+                },
+                SimulateBlock {
+                    block_overrides: Some(BlockOverrides {
+                        number: Some(10.into()),
+                        time: Some(Self::FAR_FUTURE_TIMESTAMP.into()),
+                        base_fee_per_gas: Some(123.into()),
+                    }),
+                    calls: vec![CallTest::call_request(b"overridden_block")],
+                    ..SimulateBlock::default()
+                },
+            ],
+            trace_transfers,
+            validation: false,
+        }
+    }
+}
+
+#[async_trait]
+impl HttpTest for SimulateTest {
+    fn transaction_executor(&self) -> MockOneshotExecutor {
+        let mut tx_executor = MockOneshotExecutor::default();
+        tx_executor.set_full_call_responses(|tx, env| {
+            let first_block = &env.l1_batch.first_l2_block;
+            let mut output = VmExecutionResultAndLogs::mock_success();
+            match tx.execute.calldata() {
+                b"success" => {
+                    assert_eq!(first_block.number, 2);
+                    assert_eq!(tx.nonce(), Some(Nonce(0)));
+                    output.result = ExecutionResult::Success {
+                        output: b"output".to_vec(),
+                    };
+                    output.logs.events = vec![Self::transfer_event(tx), Self::custom_event()];
+                }
+                b"revert" => {
+                    assert_eq!(first_block.number, 2);
+                    // The sender nonce must be advanced by the previous call.
+                    assert_eq!(tx.nonce(), Some(Nonce(1)));
+                    output.result = ExecutionResult::Revert {
+                        output: VmRevertReason::General {
+                            msg: "oops".to_owned(),
+                            data: vec![1, 2, 3],
+                        },
+                    };
+                }
+                b"overridden_block" => {
+                    assert_eq!(first_block.number, 10);
+                    assert_eq!(tx.nonce(), Some(Nonce(2)));
+                    assert_eq!(first_block.timestamp, Self::FAR_FUTURE_TIMESTAMP);
+                    assert_eq!(env.l1_batch.timestamp, Self::FAR_FUTURE_TIMESTAMP);
+                    assert_eq!(env.l1_batch.enforced_base_fee, Some(123));
+                    let current_block = env.current_block.unwrap();
+                    assert_eq!(current_block.number, 9);
+                    assert_eq!(current_block.timestamp, Self::FAR_FUTURE_TIMESTAMP - 1);
+                }
+                other => panic!("unexpected calldata: {other:?}"),
+            }
+            output.statistics.gas_used = 10_000;
+            output
+        });
+        tx_executor
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        // Store an additional L2 block because L2 block #0 has some special processing making it work incorrectly.
+        let mut connection = pool.connection().await?;
+        store_l2_block(&mut connection, L2BlockNumber(1), &[]).await?;
+
+        let blocks = client.simulate_v1(Self::payload(false), None).await?;
+        assert_eq!(blocks.len(), 2);
+        let [first_block, second_block] = blocks.as_slice() else {
+            unreachable!();
+        };
+        assert_eq!(first_block.number, 2.into());
+        assert_eq!(first_block.gas_used, 20_000.into());
+        assert_eq!(first_block.calls.len(), 2);
+
+        let success_call = &first_block.calls[0];
+        assert_eq!(success_call.status, 1.into());
+        assert_eq!(success_call.return_data.0, b"output");
+        assert_eq!(success_call.gas_used, 10_000.into());
+        assert_eq!(success_call.error, None);
+        // Base token transfers are filtered out without `trace_transfers`.
+        assert_eq!(success_call.logs.len(), 1);
+        let log = &success_call.logs[0];
+        assert_eq!(log.address, Address::repeat_byte(2));
+        assert_eq!(log.topics, [H256::repeat_byte(0xaa)]);
+        assert_eq!(log.block_number, Some(2.into()));
+        assert_eq!(log.block_hash, Some(first_block.hash));
+
+        let reverted_call = &first_block.calls[1];
+        assert_eq!(reverted_call.status, 0.into());
+        assert!(reverted_call.return_data.0.is_empty());
+        let error = reverted_call.error.as_ref().unwrap();
+        assert_eq!(error.code, 3);
+        assert!(error.message.contains("oops"), "{error:?}");
+        assert!(error.data.is_some());
+
+        assert_eq!(second_block.number, 10.into());
+        assert_eq!(second_block.timestamp, Self::FAR_FUTURE_TIMESTAMP.into());
+        assert_eq!(second_block.base_fee_per_gas, 123.into());
+        assert_eq!(second_block.calls.len(), 1);
+        assert_eq!(second_block.calls[0].status, 1.into());
+        assert_ne!(second_block.hash, first_block.hash);
+
+        let blocks = client.simulate_v1(Self::payload(true), None).await?;
+        let logs = &blocks[0].calls[0].logs;
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].address, L2_BASE_TOKEN_ADDRESS);
+        assert_eq!(logs[1].log_index, Some(1.into()));
+
+        // Block numbers must increase.
+        let mut payload = Self::payload(false);
+        payload.block_state_calls[1].block_overrides = Some(BlockOverrides {
+            number: Some(1.into()),
+            ..BlockOverrides::default()
+        });
+        let err = client.simulate_v1(payload, None).await.unwrap_err();
+        assert!(err.to_string().contains("invalid block overrides"), "{err}");
+        if let ClientError::Call(error) = &err {
+            assert_eq!(error.code(), ErrorCode::InvalidParams.code());
+        } else {
+            panic!("Unexpected error: {err:?}");
+        }
+
+        // Requests exceeding limits must be rejected.
+        let mut payload = Self::payload(false);
+        payload.block_state_calls[0].calls = vec![CallTest::call_request(b"success"); 1_001];
+        let err = client.simulate_v1(payload, None).await.unwrap_err();
+        assert!(
+            err.to_string().contains("simulation limit exceeded"),
+            "{err}"
+        );
+
+        let mut payload = Self::payload(false);
+        payload.block_state_calls[1].calls[0].gas = Some(u64::MAX.into());
+        let err = client.simulate_v1(payload, None).await.unwrap_err();
+        assert!(
+            err.to_string().contains("simulation limit exceeded"),
+            "{err}"
+        );
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn simulate_basics() {
+    test_http_server(SimulateTest).await;
+}
+
 fn evm_emulator_responses(tx: &Transaction, env: &OneshotEnv) -> ExecutionResult {
     assert!(env
         .system
@@ -1,18 +1,18 @@
-use std::{path::Path, sync::Arc, time::Duration};
+use std::{collections::BTreeMap, ops, path::Path, sync::Arc, time::Duration};
 
 use anyhow::Context as _;
 use serde::Serialize;
 use tokio::{fs, sync::Semaphore};
 use zksync_config::{ContractsConfig, EthConfig};
 use zksync_contracts::hyperchain_contract;
-use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_dal::{eth_sender_dal::EthTxBrief, Connection, ConnectionPool, Core, CoreDal};
 // Public re-export to simplify the API use.
 pub use zksync_eth_client as eth_client;
 use zksync_eth_client::{BoundEthInterface, CallFunctionArgs, EthInterface, Options};
-use zksync_merkle_tree::domain::ZkSyncTree;
-use zksync_object_store::{ObjectStore, ObjectStoreError};
-use zksync_state::RocksdbStorage;
-use zksync_storage::RocksDB;
+use zksync_merkle_tree::domain::{ZkSyncTree, ZkSyncTreeReader};
+use zksync_object_store::{ObjectStore, ObjectStoreError, StoredObject};
+use zksync_state::{RocksdbStorage, RocksdbStorageOptions};
+use zksync_storage::{RocksDB, RocksDBOptions};
 use zksync_types::{
     aggregated_operations::AggregatedActionType,
     ethabi::Token,
@@ -21,7 +21,7 @@ use zksync_types::{
         SnapshotStorageLogsStorageKey,
     },
     web3::BlockNumber,
-    Address, L1BatchNumber, L2ChainId, H160, H256, U256,
+    Address, L1BatchNumber, L2BlockNumber, L2ChainId, H160, H256, U256,
 };
 
 #[cfg(test)]
@@ -147,10 +147,7 @@ impl BlockReverter {
                 .blocks_dal()
                 .get_number_of_last_l1_batch_executed_on_eth()
                 .await?;
-            anyhow::ensure!(
-                Some(last_l1_batch_to_keep) >= last_executed_l1_batch,
-                "Attempt to roll back already executed L1 batches; the last executed batch is: {last_executed_l1_batch:?}"
-            );
+            ensure_not_executed(last_l1_batch_to_keep, last_executed_l1_batch)?;
         }
 
         // Tree needs to be rolled back first to keep the state recoverable
@@ -174,6 +171,198 @@ impl BlockReverter {
         Ok(())
     }
 
+    /// Computes what [`Self::roll_back()`] would do with the same configuration without modifying any state.
+    /// The returned plan should be checked with [`RollbackPlan::check()`] before rolling back.
+    pub async fn plan(&self, last_l1_batch_to_keep: L1BatchNumber) -> anyhow::Result<RollbackPlan> {
+        let mut storage = self.connection_pool.connection().await?;
+        let last_sealed_l1_batch = storage.blocks_dal().get_sealed_l1_batch_number().await?;
+        let last_executed_l1_batch = storage
+            .blocks_dal()
+            .get_number_of_last_l1_batch_executed_on_eth()
+            .await?;
+        let last_l2_block_to_keep = storage
+            .blocks_dal()
+            .get_l2_block_range_of_l1_batch(last_l1_batch_to_keep)
+            .await?
+            .map(|(_, last)| last);
+        let postgres_root_hash = storage
+            .blocks_dal()
+            .get_l1_batch_state_root(last_l1_batch_to_keep)
+            .await?;
+
+        let (postgres, snapshots) = if self.should_roll_back_postgres {
+            let last_l2_block_to_keep = last_l2_block_to_keep.with_context(|| {
+                format!("L1 batch #{last_l1_batch_to_keep} doesn't contain L2 blocks")
+            })?;
+            let snapshots = storage
+                .snapshots_dal()
+                .get_snapshots_after(last_l1_batch_to_keep)
+                .await?;
+            let mut postgres = self
+                .plan_postgres(&mut storage, last_l1_batch_to_keep, last_l2_block_to_keep)
+                .await?;
+            postgres
+                .row_counts
+                .insert("snapshots", snapshots.len() as u64);
+
+            let snapshots = snapshots
+                .iter()
+                .map(|snapshot| {
+                    SnapshotRollbackPlan::new(snapshot, self.snapshots_object_store.is_some())
+                })
+                .collect();
+            (Some(postgres), snapshots)
+        } else {
+            (None, vec![])
+        };
+        drop(storage);
+
+        let merkle_tree = if let Some(path) = &self.merkle_tree_path {
+            Some(Self::plan_tree(path, last_l1_batch_to_keep, postgres_root_hash).await?)
+        } else {
+            None
+        };
+        let mut storage_caches = Vec::with_capacity(self.storage_cache_paths.len());
+        for path in &self.storage_cache_paths {
+            storage_caches.push(Self::plan_storage_cache(path, last_l1_batch_to_keep).await?);
+        }
+
+        Ok(RollbackPlan {
+            last_l1_batch_to_keep,
+            last_l2_block_to_keep,
+            last_sealed_l1_batch,
+            executed_batches: ExecutedBatchesCheck {
+                last_executed_l1_batch,
+                rolls_back_executed_batches: Some(last_l1_batch_to_keep) < last_executed_l1_batch,
+                allowed: self.allow_rolling_back_executed_batches,
+            },
+            postgres,
+            merkle_tree,
+            storage_caches,
+            snapshots,
+        })
+    }
+
+    async fn plan_postgres(
+        &self,
+        storage: &mut Connection<'_, Core>,
+        last_l1_batch_to_keep: L1BatchNumber,
+        last_l2_block_to_keep: L2BlockNumber,
+    ) -> anyhow::Result<PostgresRollbackPlan> {
+        let counts = storage
+            .blocks_dal()
+            .get_rollback_row_counts(last_l1_batch_to_keep, last_l2_block_to_keep)
+            .await?;
+        let tokens = storage
+            .tokens_dal()
+            .get_tokens_to_roll_back(last_l2_block_to_keep)
+            .await?;
+        let eth_txs = storage
+            .eth_sender_dal()
+            .get_eth_txs_to_delete(last_l1_batch_to_keep)
+            .await?;
+        let row_counts = BTreeMap::from([
+            ("l1_batches", counts.l1_batches),
+            ("miniblocks", counts.l2_blocks),
+            ("transactions", counts.transactions),
+            ("call_traces", counts.call_traces),
+            ("events", counts.events),
+            ("l2_to_l1_logs", counts.l2_to_l1_logs),
+            ("tokens", tokens.len() as u64),
+            ("factory_deps", counts.factory_deps),
+            ("storage_logs", counts.storage_logs),
+            ("eth_txs", eth_txs.len() as u64),
+            ("initial_writes", counts.initial_writes),
+            (
+                "vm_runner_protective_reads",
+                counts.vm_runner_protective_reads,
+            ),
+            ("vm_runner_bwip", counts.vm_runner_bwip),
+        ]);
+        Ok(PostgresRollbackPlan {
+            last_l2_block_to_keep,
+            row_counts,
+            eth_txs: eth_txs.iter().map(EthTxRollbackPlan::from).collect(),
+            consensus_fork: self.node_role == NodeRole::Main,
+        })
+    }
+
+    async fn plan_tree(
+        path: &str,
+        last_l1_batch_to_keep: L1BatchNumber,
+        postgres_root_hash: Option<H256>,
+    ) -> anyhow::Result<MerkleTreeRollbackPlan> {
+        let mut plan = MerkleTreeRollbackPlan {
+            path: path.to_owned(),
+            exists: false,
+            next_l1_batch_number: None,
+            removed_versions: None,
+            root_hash: None,
+            postgres_root_hash,
+        };
+        plan.exists = fs::try_exists(path)
+            .await
+            .with_context(|| format!("cannot check whether Merkle tree path `{path}` exists"))?;
+        if !plan.exists {
+            return Ok(plan);
+        }
+
+        // Open the tree read-only, so that planning doesn't modify it or conflict with a running node.
+        let path = Path::new(path).to_path_buf();
+        let tree_info = tokio::task::spawn_blocking(move || {
+            let options = RocksDBOptions {
+                read_only: true,
+                ..RocksDBOptions::default()
+            };
+            let db = RocksDB::with_options(&path, options)
+                .context("failed opening RocksDB for Merkle tree in read-only mode")?;
+            let reader =
+                ZkSyncTreeReader::new(db.into()).context("failed initializing Merkle tree")?;
+            let root_hash = reader
+                .root_info(last_l1_batch_to_keep)
+                .map(|(root_hash, _)| root_hash);
+            anyhow::Ok((reader.next_l1_batch_number(), root_hash))
+        })
+        .await
+        .context("planning Merkle tree rollback panicked")??;
+
+        let (next_l1_batch_number, root_hash) = tree_info;
+        plan.next_l1_batch_number = Some(next_l1_batch_number);
+        if next_l1_batch_number > last_l1_batch_to_keep + 1 {
+            plan.removed_versions = Some((last_l1_batch_to_keep + 1)..=(next_l1_batch_number - 1));
+            plan.root_hash = root_hash;
+        }
+        Ok(plan)
+    }
+
+    async fn plan_storage_cache(
+        path: &str,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> anyhow::Result<StorageCacheRollbackPlan> {
+        let exists = fs::try_exists(path)
+            .await
+            .with_context(|| format!("cannot check whether storage cache path `{path}` exists"))?;
+        let next_l1_batch_number = if exists {
+            let options = RocksdbStorageOptions {
+                read_only: true,
+                ..RocksdbStorageOptions::default()
+            };
+            RocksdbStorage::builder_with_options(path.as_ref(), options)
+                .await
+                .context("failed opening storage cache in read-only mode")?
+                .l1_batch_number()
+                .await
+        } else {
+            None
+        };
+        Ok(StorageCacheRollbackPlan {
+            path: path.to_owned(),
+            exists,
+            next_l1_batch_number,
+            rolled_back: next_l1_batch_number > Some(last_l1_batch_to_keep + 1),
+        })
+    }
+
     async fn roll_back_rocksdb_instances(
         &self,
         last_l1_batch_to_keep: L1BatchNumber,
@@ -622,3 +811,175 @@ pub struct SuggestedRevertValues {
     pub nonce: u64,
     pub priority_fee: u64,
 }
+
+fn ensure_not_executed(
+    last_l1_batch_to_keep: L1BatchNumber,
+    last_executed_l1_batch: Option<L1BatchNumber>,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        Some(last_l1_batch_to_keep) >= last_executed_l1_batch,
+        "Attempt to roll back already executed L1 batches; the last executed batch is: {last_executed_l1_batch:?}"
+    );
+    Ok(())
+}
+
+/// Report on what [`BlockReverter::roll_back()`] would delete or rewind, produced by [`BlockReverter::plan()`].
+#[derive(Debug, Serialize)]
+pub struct RollbackPlan {
+    pub last_l1_batch_to_keep: L1BatchNumber,
+    /// Last L2 block in the target L1 batch; `None` if the batch doesn't contain L2 blocks in Postgres.
+    pub last_l2_block_to_keep: Option<L2BlockNumber>,
+    pub last_sealed_l1_batch: Option<L1BatchNumber>,
+    pub executed_batches: ExecutedBatchesCheck,
+    /// `None` if rolling back Postgres is not enabled.
+    pub postgres: Option<PostgresRollbackPlan>,
+    /// `None` if rolling back the Merkle tree is not enabled.
+    pub merkle_tree: Option<MerkleTreeRollbackPlan>,
+    pub storage_caches: Vec<StorageCacheRollbackPlan>,
+    /// Snapshots deleted from Postgres together with their files in the object store.
+    pub snapshots: Vec<SnapshotRollbackPlan>,
+}
+
+impl RollbackPlan {
+    /// Checks that the rollback is allowed and would succeed, using the same conditions as [`BlockReverter::roll_back()`].
+    pub fn check(&self) -> anyhow::Result<()> {
+        let last_l1_batch_to_keep = self.last_l1_batch_to_keep;
+        if !self.executed_batches.allowed {
+            ensure_not_executed(
+                last_l1_batch_to_keep,
+                self.executed_batches.last_executed_l1_batch,
+            )?;
+        }
+
+        if let Some(tree) = &self.merkle_tree {
+            if tree.exists {
+                anyhow::ensure!(
+                    tree.postgres_root_hash.is_some(),
+                    "no state root hash for target L1 batch"
+                );
+                if tree.removed_versions.is_some() {
+                    anyhow::ensure!(
+                        tree.root_hash == tree.postgres_root_hash,
+                        "Mismatch between the tree root hash {:?} and storage root hash {:?} for L1 batch #{last_l1_batch_to_keep}",
+                        tree.root_hash,
+                        tree.postgres_root_hash
+                    );
+                }
+            }
+        }
+        for cache in &self.storage_caches {
+            anyhow::ensure!(
+                cache.exists,
+                "Path with storage cache DB doesn't exist at `{}`",
+                cache.path
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Check whether the rollback affects L1 batches executed on L1.
+#[derive(Debug, Serialize)]
+pub struct ExecutedBatchesCheck {
+    pub last_executed_l1_batch: Option<L1BatchNumber>,
+    pub rolls_back_executed_batches: bool,
+    /// Whether rolling back executed batches is allowed by the reverter configuration.
+    pub allowed: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PostgresRollbackPlan {
+    pub last_l2_block_to_keep: L2BlockNumber,
+    /// Numbers of deleted rows per table. For `transactions`, this is the number of reset transactions.
+    pub row_counts: BTreeMap<&'static str, u64>,
+    /// Deleted L1 transactions, including pending ones.
+    pub eth_txs: Vec<EthTxRollbackPlan>,
+    /// Whether a consensus hard fork is performed (only on the main node).
+    pub consensus_fork: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EthTxRollbackPlan {
+    pub id: u32,
+    pub nonce: u64,
+    pub tx_type: String,
+    pub sent_at_block: Option<u32>,
+    /// Whether the transaction is neither confirmed nor failed, i.e., may still be mined on L1.
+    pub is_pending: bool,
+    pub is_confirmed: bool,
+    pub has_failed: bool,
+}
+
+impl From<&EthTxBrief> for EthTxRollbackPlan {
+    fn from(tx: &EthTxBrief) -> Self {
+        Self {
+            id: tx.id,
+            nonce: tx.nonce,
+            tx_type: tx.tx_type.to_string(),
+            sent_at_block: tx.sent_at_block,
+            is_pending: !tx.is_confirmed && !tx.has_failed,
+            is_confirmed: tx.is_confirmed,
+            has_failed: tx.has_failed,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MerkleTreeRollbackPlan {
+    pub path: String,
+    pub exists: bool,
+    /// Next L1 batch number (= tree version) in the tree. `None` if the tree doesn't exist.
+    pub next_l1_batch_number: Option<L1BatchNumber>,
+    /// Tree versions removed by the rollback. `None` if the tree is not rolled back.
+    pub removed_versions: Option<ops::RangeInclusive<L1BatchNumber>>,
+    /// Tree root hash for the target L1 batch. `None` if the tree is not rolled back, or the tree version is missing.
+    pub root_hash: Option<H256>,
+    /// State root hash for the target L1 batch in Postgres.
+    pub postgres_root_hash: Option<H256>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StorageCacheRollbackPlan {
+    pub path: String,
+    pub exists: bool,
+    /// Next L1 batch number to be processed by the cache.
+    pub next_l1_batch_number: Option<L1BatchNumber>,
+    pub rolled_back: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SnapshotRollbackPlan {
+    pub l1_batch_number: L1BatchNumber,
+    pub is_complete: bool,
+    /// Object store keys of removed files. Empty if rolling back snapshot objects is not enabled.
+    pub removed_files: Vec<String>,
+}
+
+impl SnapshotRollbackPlan {
+    fn new(snapshot: &SnapshotMetadata, removes_files: bool) -> Self {
+        let removed_files = if removes_files {
+            let factory_deps_key =
+                SnapshotFactoryDependencies::encode_key(snapshot.l1_batch_number);
+            let chunk_keys = (0_u64..)
+                .zip(&snapshot.storage_logs_filepaths)
+                .filter(|(_, path)| path.is_some())
+                .map(|(chunk_id, _)| {
+                    let key = SnapshotStorageLogsStorageKey {
+                        l1_batch_number: snapshot.l1_batch_number,
+                        chunk_id,
+                    };
+                    <SnapshotStorageLogsChunk>::encode_key(key)
+                });
+            std::iter::once(factory_deps_key)
+                .chain(chunk_keys)
+                .collect()
+        } else {
+            vec![]
+        };
+        Self {
+            l1_batch_number: snapshot.l1_batch_number,
+            is_complete: snapshot.is_complete(),
+            removed_files,
+        }
+    }
+}
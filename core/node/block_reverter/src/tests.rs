@@ -8,7 +8,7 @@ use test_casing::test_casing;
 use tokio::sync::watch;
 use zksync_dal::Connection;
 use zksync_merkle_tree::TreeInstruction;
-use zksync_object_store::{Bucket, MockObjectStore};
+use zksync_object_store::{Bucket, ListedKeys, MockObjectStore};
 use zksync_state::interface::ReadStorage;
 use zksync_types::{
     block::{L1BatchHeader, L2BlockHeader},
@@ -204,6 +204,163 @@ async fn block_reverter_basics(sync_merkle_tree: bool) {
     }
 }
 
+#[tokio::test]
+async fn planning_rollback() {
+    let storage_logs = gen_storage_logs();
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    setup_storage(&mut storage, &storage_logs).await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let merkle_tree_path = temp_dir.path().join("tree");
+    let l1_batch_hashes = initialize_merkle_tree(&merkle_tree_path, &storage_logs);
+    for (number, hash) in (0..).zip(l1_batch_hashes) {
+        storage
+            .blocks_dal()
+            .set_l1_batch_hash(L1BatchNumber(number), hash)
+            .await
+            .unwrap();
+    }
+    let object_store = MockObjectStore::arc();
+    create_mock_snapshot(&mut storage, &*object_store, L1BatchNumber(7), 0..2).await;
+    // Emulate a running node holding the tree and the state keeper cache open; planning must not conflict with it.
+    let running_tree = ZkSyncTree::new(RocksDB::new(&merkle_tree_path).unwrap().into()).unwrap();
+    let sk_cache_path = temp_dir.path().join("sk_cache");
+    let sk_cache = RocksdbStorage::builder(&sk_cache_path).await.unwrap();
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    let _running_sk_cache = sk_cache
+        .synchronize(&mut storage, &stop_receiver, None)
+        .await
+        .unwrap()
+        .expect("state keeper cache synchronization was interrupted");
+
+    let mut block_reverter = BlockReverter::new(NodeRole::External, pool.clone());
+    block_reverter
+        .enable_rolling_back_postgres()
+        .enable_rolling_back_merkle_tree(merkle_tree_path.to_str().unwrap().to_owned())
+        .add_rocksdb_storage_path_to_rollback(sk_cache_path.to_str().unwrap().to_owned())
+        .enable_rolling_back_snapshot_objects(object_store.clone());
+    let plan = block_reverter.plan(L1BatchNumber(5)).await.unwrap();
+    plan.check().unwrap();
+
+    assert_eq!(plan.last_l2_block_to_keep, Some(L2BlockNumber(5)));
+    assert_eq!(plan.last_sealed_l1_batch, Some(L1BatchNumber(9)));
+    assert!(!plan.executed_batches.rolls_back_executed_batches);
+
+    let postgres = plan.postgres.as_ref().unwrap();
+    assert_eq!(postgres.row_counts["l1_batches"], 4);
+    assert_eq!(postgres.row_counts["miniblocks"], 4);
+    assert_eq!(postgres.row_counts["storage_logs"], 4);
+    assert_eq!(postgres.row_counts["initial_writes"], 4);
+    assert_eq!(postgres.row_counts["snapshots"], 1);
+    assert!(postgres.eth_txs.is_empty());
+    assert!(!postgres.consensus_fork);
+
+    let tree = plan.merkle_tree.as_ref().unwrap();
+    assert!(tree.exists);
+    assert_eq!(tree.next_l1_batch_number, Some(L1BatchNumber(10)));
+    assert_eq!(
+        tree.removed_versions,
+        Some(L1BatchNumber(6)..=L1BatchNumber(9))
+    );
+    assert_eq!(tree.root_hash, tree.postgres_root_hash);
+
+    assert_eq!(plan.storage_caches.len(), 1);
+    assert!(plan.storage_caches[0].exists);
+    assert_eq!(
+        plan.storage_caches[0].next_l1_batch_number,
+        Some(L1BatchNumber(10))
+    );
+    assert!(plan.storage_caches[0].rolled_back);
+
+    assert_eq!(plan.snapshots.len(), 1);
+    assert_eq!(plan.snapshots[0].l1_batch_number, L1BatchNumber(7));
+    assert!(plan.snapshots[0].is_complete);
+    assert_eq!(plan.snapshots[0].removed_files.len(), 3);
+
+    // Check that planning hasn't modified the storage.
+    let last_l1_batch_number = storage
+        .blocks_dal()
+        .get_sealed_l1_batch_number()
+        .await
+        .unwrap();
+    assert_eq!(last_l1_batch_number, Some(L1BatchNumber(9)));
+    object_store
+        .get::<SnapshotFactoryDependencies>(L1BatchNumber(7))
+        .await
+        .unwrap();
+    assert_eq!(running_tree.next_l1_batch_number(), L1BatchNumber(10));
+}
+
+#[test_casing(2, [false, true])]
+#[tokio::test]
+async fn planning_rollback_of_executed_batches(allow_executed: bool) {
+    let storage_logs = gen_storage_logs();
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    setup_storage(&mut storage, &storage_logs).await;
+
+    let eth_tx = storage
+        .eth_sender_dal()
+        .save_eth_tx(
+            0,
+            vec![],
+            AggregatedActionType::Execute,
+            Address::default(),
+            0,
+            None,
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+    storage
+        .blocks_dal()
+        .set_eth_tx_id(
+            L1BatchNumber(1)..=L1BatchNumber(7),
+            eth_tx.id,
+            AggregatedActionType::Execute,
+        )
+        .await
+        .unwrap();
+    let tx_hash = H256::repeat_byte(1);
+    storage
+        .eth_sender_dal()
+        .insert_tx_history(eth_tx.id, 0, 0, None, tx_hash, &[], 0)
+        .await
+        .unwrap();
+    storage
+        .eth_sender_dal()
+        .confirm_tx(tx_hash, U256::zero())
+        .await
+        .unwrap();
+
+    let mut block_reverter = BlockReverter::new(NodeRole::External, pool.clone());
+    block_reverter.enable_rolling_back_postgres();
+    if allow_executed {
+        block_reverter.allow_rolling_back_executed_batches();
+    }
+    let plan = block_reverter.plan(L1BatchNumber(5)).await.unwrap();
+
+    assert_eq!(
+        plan.executed_batches.last_executed_l1_batch,
+        Some(L1BatchNumber(7))
+    );
+    assert!(plan.executed_batches.rolls_back_executed_batches);
+    let eth_txs = &plan.postgres.as_ref().unwrap().eth_txs;
+    assert_eq!(eth_txs.len(), 1);
+    assert_eq!(eth_txs[0].id, eth_tx.id);
+    assert!(eth_txs[0].is_confirmed);
+    assert!(!eth_txs[0].is_pending);
+
+    if allow_executed {
+        plan.check().unwrap();
+    } else {
+        let err = plan.check().unwrap_err().to_string();
+        assert!(err.contains("already executed"), "{err}");
+    }
+}
+
 async fn create_mock_snapshot(
     storage: &mut Connection<'_, Core>,
     object_store: &dyn ObjectStore,
@@ -230,6 +387,7 @@ async fn create_mock_snapshot(
         .add_snapshot(
             SnapshotVersion::Version0,
             l1_batch_number,
+            None,
             storage_logs_chunk_count,
             &factory_deps_key,
         )
@@ -390,6 +548,15 @@ impl ObjectStore for ErroneousStore {
         })
     }
 
+    async fn list_raw(
+        &self,
+        _bucket: Bucket,
+        _prefix: &str,
+        _page_token: Option<String>,
+    ) -> Result<ListedKeys, ObjectStoreError> {
+        unreachable!("not called by reverter")
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         bucket.to_string()
     }
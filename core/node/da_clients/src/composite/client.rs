@@ -0,0 +1,273 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use futures::future;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+
+use super::metrics::{BackendInclusionStatus, BACKEND_METRICS};
+
+/// Blob IDs returned by the composite client's backends, keyed by the backend name.
+type BackendBlobIds = BTreeMap<String, String>;
+
+/// Default number of retries for a backend failing to dispatch a blob with a retriable error.
+const DEFAULT_MAX_RETRIES: u16 = 3;
+/// Default initial backoff between dispatch retries; it's doubled after each retry.
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// [`DataAvailabilityClient`] dispatching each blob to several DA layers (backends).
+///
+/// None of the backends is mandatory: a blob is considered dispatched once it's accepted by at least `quorum`
+/// backends, and included once at least `quorum` of these backends confirm its inclusion. Backends are ordered
+/// by preference; inclusion data is taken from the first backend (in this order) that returns it, so a single
+/// unavailable backend doesn't halt finalization as long as the quorum is reached. Since inclusion data may come
+/// from any backend, the L1 DA validator must be able to verify inclusion data from each of them.
+/// Backends failing to dispatch a blob with a retriable error are retried with exponential backoff.
+///
+/// The blob ID returned by this client is a JSON object mapping names of the backends that have accepted the blob
+/// to the blob IDs returned by these backends, so per-backend blob IDs are persisted together with the batch.
+#[derive(Clone)]
+pub struct CompositeDAClient {
+    backends: Vec<(&'static str, Box<dyn DataAvailabilityClient>)>,
+    quorum: usize,
+    max_retries: u16,
+    initial_backoff: Duration,
+}
+
+impl fmt::Debug for CompositeDAClient {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let backend_names: Vec<_> = self.backends.iter().map(|(name, _)| *name).collect();
+        formatter
+            .debug_struct("CompositeDAClient")
+            .field("backends", &backend_names)
+            .field("quorum", &self.quorum)
+            .field("max_retries", &self.max_retries)
+            .field("initial_backoff", &self.initial_backoff)
+            .finish()
+    }
+}
+
+impl CompositeDAClient {
+    /// Creates a client from the named backends ordered by preference. Backend names are used as metric labels
+    /// and in blob IDs, so they must be unique and stable across node restarts.
+    pub fn new(
+        backends: Vec<(&'static str, Box<dyn DataAvailabilityClient>)>,
+        quorum: usize,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            (1..=backends.len()).contains(&quorum),
+            "quorum {quorum} must be in 1..={}",
+            backends.len()
+        );
+        for (i, (name, _)) in backends.iter().enumerate() {
+            anyhow::ensure!(
+                backends[..i].iter().all(|(other, _)| other != name),
+                "duplicate DA backend `{name}`"
+            );
+        }
+        Ok(Self {
+            backends,
+            quorum,
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+        })
+    }
+
+    /// Sets the retry policy for backends failing to dispatch a blob with a retriable error.
+    pub fn with_retries(mut self, max_retries: u16, initial_backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    fn parse_blob_ids(blob_id: &str) -> Result<BackendBlobIds, DAError> {
+        serde_json::from_str(blob_id)
+            .with_context(|| format!("invalid composite blob ID: {blob_id}"))
+            .map_err(|error| DAError {
+                error,
+                is_retriable: false,
+            })
+    }
+
+    async fn dispatch_to_backend(
+        &self,
+        name: &'static str,
+        client: &dyn DataAvailabilityClient,
+        batch_number: u32,
+        data: &[u8],
+    ) -> Result<DispatchResponse, DAError> {
+        let mut retries = 0;
+        let mut backoff = self.initial_backoff;
+        loop {
+            let started_at = Instant::now();
+            let result = client.dispatch_blob(batch_number, data.to_vec()).await;
+            let metrics = &BACKEND_METRICS;
+            match result {
+                Ok(response) => {
+                    metrics.dispatch_latency[&name].observe(started_at.elapsed());
+                    return Ok(response);
+                }
+                Err(err) => {
+                    metrics.dispatch_errors[&name].inc();
+                    if !err.is_retriable() || retries >= self.max_retries {
+                        tracing::warn!(
+                            "Failed dispatching blob for batch {batch_number} to DA backend `{name}`: {err}"
+                        );
+                        return Err(err);
+                    }
+
+                    retries += 1;
+                    tracing::warn!(
+                        "Failed dispatching blob for batch {batch_number} to DA backend `{name}` \
+                         (retry {retries}/{}), retrying in {backoff:?}: {err}",
+                        self.max_retries
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                }
+            }
+        }
+    }
+
+    /// Builds an error for the case when the quorum isn't reached among `candidates` backends. The error
+    /// is retriable if the quorum can still be reached after retrying, i.e. if enough backends don't have fatal errors.
+    fn quorum_error(
+        &self,
+        action: &str,
+        candidates: usize,
+        successes: usize,
+        errors: Vec<(&str, DAError)>,
+    ) -> DAError {
+        let fatal_errors = errors.iter().filter(|(_, err)| !err.is_retriable()).count();
+        let is_retriable = candidates - fatal_errors >= self.quorum;
+        let errors: Vec<_> = errors
+            .iter()
+            .map(|(name, err)| format!("{name}: {err}"))
+            .collect();
+        DAError {
+            error: anyhow::anyhow!(
+                "{action} succeeded on {successes} of {candidates} DA backends, while quorum is {}; errors: [{}]",
+                self.quorum,
+                errors.join(", ")
+            ),
+            is_retriable,
+        }
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for CompositeDAClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        let dispatches = self.backends.iter().map(|(name, client)| {
+            let data = &data;
+            async move {
+                let result = self
+                    .dispatch_to_backend(name, client.as_ref(), batch_number, data)
+                    .await;
+                (*name, result)
+            }
+        });
+
+        let mut blob_ids = BackendBlobIds::new();
+        let mut errors = vec![];
+        for (name, result) in future::join_all(dispatches).await {
+            match result {
+                Ok(response) => {
+                    blob_ids.insert(name.to_owned(), response.blob_id);
+                }
+                Err(err) => errors.push((name, err)),
+            }
+        }
+
+        if blob_ids.len() < self.quorum {
+            let candidates = self.backends.len();
+            return Err(self.quorum_error("dispatch", candidates, blob_ids.len(), errors));
+        }
+        let blob_id = serde_json::to_string(&blob_ids).expect("failed serializing blob IDs");
+        Ok(DispatchResponse { blob_id })
+    }
+
+    /// Returns the inclusion data of the first backend (in the preference order) that has confirmed inclusion,
+    /// once the quorum of backends that have accepted the blob confirm inclusion.
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let blob_ids = Self::parse_blob_ids(blob_id)?;
+        if let Some(name) = blob_ids
+            .keys()
+            .find(|name| self.backends.iter().all(|(backend, _)| backend != name))
+        {
+            return Err(DAError {
+                error: anyhow::anyhow!(
+                    "composite blob ID {blob_id} refers to unknown DA backend `{name}`"
+                ),
+                is_retriable: false,
+            });
+        }
+
+        let checks = self.backends.iter().filter_map(|(name, client)| {
+            let backend_blob_id = blob_ids.get(*name)?;
+            Some(async move {
+                let result = client.get_inclusion_data(backend_blob_id).await;
+                let status = match &result {
+                    Ok(Some(_)) => BackendInclusionStatus::Included,
+                    Ok(None) => BackendInclusionStatus::Pending,
+                    Err(err) => {
+                        tracing::warn!(
+                            "Failed getting inclusion data for blob `{backend_blob_id}` from DA backend `{name}`: {err}"
+                        );
+                        BackendInclusionStatus::Error
+                    }
+                };
+                BACKEND_METRICS.inclusion_checks[&(*name, status)].inc();
+                (*name, result)
+            })
+        });
+
+        // `join_all()` preserves the order of futures, so the first inclusion data is from the most preferred backend.
+        let mut inclusion_data = None;
+        let mut included_count = 0;
+        let mut pending_count = 0;
+        let mut errors = vec![];
+        for (name, result) in future::join_all(checks).await {
+            match result {
+                Ok(Some(data)) => {
+                    included_count += 1;
+                    inclusion_data.get_or_insert(data);
+                }
+                Ok(None) => pending_count += 1,
+                Err(err) => errors.push((name, err)),
+            }
+        }
+
+        if included_count + pending_count < self.quorum {
+            let candidates = blob_ids.len();
+            return Err(self.quorum_error("inclusion check", candidates, included_count, errors));
+        }
+        if included_count < self.quorum {
+            return Ok(None);
+        }
+        Ok(inclusion_data)
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    /// Returns the most restrictive limit among the backends.
+    fn blob_size_limit(&self) -> Option<usize> {
+        self.backends
+            .iter()
+            .filter_map(|(_, client)| client.blob_size_limit())
+            .min()
+    }
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use vise::{Buckets, Counter, EncodeLabelValue, Histogram, LabeledFamily, Metrics, Unit};
+
+/// Buckets for `dispatch_latency` (from 0.1 to 120 seconds).
+const DISPATCH_LATENCIES: Buckets =
+    Buckets::values(&[0.1, 0.5, 1.0, 2.0, 5.0, 10.0, 30.0, 60.0, 120.0]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
+#[metrics(rename_all = "snake_case")]
+pub(super) enum BackendInclusionStatus {
+    Included,
+    Pending,
+    Error,
+}
+
+/// Metrics for the individual DA layers used by the composite DA client.
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_da_composite_client_backend")]
+pub(super) struct DataAvailabilityBackendMetrics {
+    /// Latency of a successful blob dispatch to the DA backend.
+    #[metrics(buckets = DISPATCH_LATENCIES, unit = Unit::Seconds, labels = ["backend"])]
+    pub dispatch_latency: LabeledFamily<&'static str, Histogram<Duration>>,
+    /// Number of failed blob dispatches to the DA backend, including the retried ones.
+    #[metrics(labels = ["backend"])]
+    pub dispatch_errors: LabeledFamily<&'static str, Counter>,
+    /// Number of blob inclusion checks in the DA backend grouped by their outcome.
+    #[metrics(labels = ["backend", "status"])]
+    pub inclusion_checks: LabeledFamily<(&'static str, BackendInclusionStatus), Counter, 2>,
+}
+
+#[vise::register]
+pub(super) static BACKEND_METRICS: vise::Global<DataAvailabilityBackendMetrics> =
+    vise::Global::new();
@@ -0,0 +1,9 @@
+//! DA client dispatching each blob to several DA layers, so that an outage of a single DA layer
+//! doesn't halt finalization.
+
+mod client;
+mod metrics;
+#[cfg(test)]
+mod tests;
+
+pub use self::client::CompositeDAClient;
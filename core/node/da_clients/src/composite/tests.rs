@@ -0,0 +1,275 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+
+use super::CompositeDAClient;
+
+const BLOB_SIZE_LIMIT: usize = 100;
+
+#[derive(Debug, Default)]
+struct MockDAClientState {
+    dispatched_blobs: Vec<Vec<u8>>,
+    included_blobs: HashSet<String>,
+    failing_dispatches: usize,
+    is_failure_retriable: bool,
+    failing_inclusion_checks: bool,
+}
+
+/// DA client which uses sequential blob IDs and returns a blob ID as the inclusion data.
+#[derive(Debug, Clone, Default)]
+struct MockDAClient(Arc<Mutex<MockDAClientState>>);
+
+impl MockDAClient {
+    fn dispatched_blobs(&self) -> Vec<Vec<u8>> {
+        self.0.lock().unwrap().dispatched_blobs.clone()
+    }
+
+    fn include_blob(&self, blob_id: &str) {
+        self.0
+            .lock()
+            .unwrap()
+            .included_blobs
+            .insert(blob_id.to_owned());
+    }
+
+    fn fail_dispatches(&self, count: usize, is_retriable: bool) {
+        let mut state = self.0.lock().unwrap();
+        state.failing_dispatches = count;
+        state.is_failure_retriable = is_retriable;
+    }
+
+    fn fail_inclusion_checks(&self, fail: bool) {
+        self.0.lock().unwrap().failing_inclusion_checks = fail;
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for MockDAClient {
+    async fn dispatch_blob(
+        &self,
+        _batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        let mut state = self.0.lock().unwrap();
+        if state.failing_dispatches > 0 {
+            state.failing_dispatches -= 1;
+            return Err(DAError {
+                error: anyhow::anyhow!("DA layer is unavailable"),
+                is_retriable: state.is_failure_retriable,
+            });
+        }
+        let blob_id = state.dispatched_blobs.len().to_string();
+        state.dispatched_blobs.push(data);
+        Ok(DispatchResponse { blob_id })
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let state = self.0.lock().unwrap();
+        if state.failing_inclusion_checks {
+            return Err(DAError {
+                error: anyhow::anyhow!("DA layer is unavailable"),
+                is_retriable: true,
+            });
+        }
+        Ok(state
+            .included_blobs
+            .contains(blob_id)
+            .then(|| InclusionData {
+                data: blob_id.as_bytes().to_vec(),
+            }))
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        Some(BLOB_SIZE_LIMIT)
+    }
+}
+
+fn create_composite_client(
+    backends: &[(&'static str, &MockDAClient)],
+    quorum: usize,
+) -> CompositeDAClient {
+    let backends = backends
+        .iter()
+        .map(|&(name, client)| (name, client.clone_boxed()))
+        .collect();
+    CompositeDAClient::new(backends, quorum)
+        .unwrap()
+        .with_retries(2, Duration::ZERO)
+}
+
+#[test]
+fn composite_client_validates_quorum() {
+    let client = MockDAClient::default();
+    let backends = vec![
+        ("first", client.clone_boxed()),
+        ("second", client.clone_boxed()),
+    ];
+    CompositeDAClient::new(backends.clone(), 0).unwrap_err();
+    CompositeDAClient::new(backends.clone(), 3).unwrap_err();
+    CompositeDAClient::new(backends, 2).unwrap();
+
+    let duplicate_backends = vec![
+        ("first", client.clone_boxed()),
+        ("first", client.clone_boxed()),
+    ];
+    CompositeDAClient::new(duplicate_backends, 1).unwrap_err();
+}
+
+#[tokio::test]
+async fn composite_client_dispatches_to_all_backends() {
+    let (first, second) = (MockDAClient::default(), MockDAClient::default());
+    // Make blob IDs returned by backends differ.
+    first.dispatch_blob(0, vec![0]).await.unwrap();
+    let client = create_composite_client(&[("first", &first), ("second", &second)], 2);
+    assert_eq!(client.blob_size_limit(), Some(BLOB_SIZE_LIMIT));
+
+    let response = client.dispatch_blob(1, vec![1; 10]).await.unwrap();
+    assert_eq!(response.blob_id, r#"{"first":"1","second":"0"}"#);
+    assert_eq!(first.dispatched_blobs(), [vec![0], vec![1; 10]]);
+    assert_eq!(second.dispatched_blobs(), [vec![1; 10]]);
+
+    // Inclusion is only reported once the quorum is reached.
+    second.include_blob("0");
+    let inclusion_data = client.get_inclusion_data(&response.blob_id).await.unwrap();
+    assert!(inclusion_data.is_none());
+
+    first.include_blob("1");
+    let inclusion_data = client
+        .get_inclusion_data(&response.blob_id)
+        .await
+        .unwrap()
+        .expect("blob is not included");
+    // Inclusion data is taken from the first backend.
+    assert_eq!(inclusion_data.data, b"1");
+}
+
+#[tokio::test]
+async fn composite_client_takes_inclusion_data_from_first_available_backend() {
+    let (first, second) = (MockDAClient::default(), MockDAClient::default());
+    second.dispatch_blob(0, vec![0]).await.unwrap();
+    let client = create_composite_client(&[("first", &first), ("second", &second)], 1);
+
+    let response = client.dispatch_blob(1, vec![1; 10]).await.unwrap();
+    assert_eq!(response.blob_id, r#"{"first":"0","second":"1"}"#);
+
+    // Inclusion in the second backend is enough to reach the quorum.
+    second.include_blob("1");
+    let inclusion_data = client
+        .get_inclusion_data(&response.blob_id)
+        .await
+        .unwrap()
+        .expect("blob is not included");
+    assert_eq!(inclusion_data.data, b"1");
+
+    // Once the first backend confirms inclusion, its inclusion data is preferred.
+    first.include_blob("0");
+    let inclusion_data = client
+        .get_inclusion_data(&response.blob_id)
+        .await
+        .unwrap()
+        .expect("blob is not included");
+    assert_eq!(inclusion_data.data, b"0");
+
+    // Errors from the first backend don't prevent getting inclusion data from other backends.
+    first.fail_inclusion_checks(true);
+    let inclusion_data = client
+        .get_inclusion_data(&response.blob_id)
+        .await
+        .unwrap()
+        .expect("blob is not included");
+    assert_eq!(inclusion_data.data, b"1");
+
+    // ...unless they prevent reaching the quorum.
+    let client = create_composite_client(&[("first", &first), ("second", &second)], 2);
+    let Err(err) = client.get_inclusion_data(&response.blob_id).await else {
+        panic!("inclusion check succeeded without quorum");
+    };
+    assert!(err.is_retriable(), "{err}");
+    assert!(err.to_string().contains("first"), "{err}");
+}
+
+#[tokio::test]
+async fn composite_client_tolerates_secondary_backend_failures_within_quorum() {
+    let (first, second) = (MockDAClient::default(), MockDAClient::default());
+    second.fail_dispatches(usize::MAX, false);
+    let client = create_composite_client(&[("first", &first), ("second", &second)], 1);
+
+    let response = client.dispatch_blob(1, vec![1; 10]).await.unwrap();
+    assert_eq!(response.blob_id, r#"{"first":"0"}"#);
+    let inclusion_data = client.get_inclusion_data(&response.blob_id).await.unwrap();
+    assert!(inclusion_data.is_none());
+    first.include_blob("0");
+    let inclusion_data = client.get_inclusion_data(&response.blob_id).await.unwrap();
+    assert_eq!(inclusion_data.unwrap().data, b"0");
+
+    let client = create_composite_client(&[("first", &first), ("second", &second)], 2);
+    let Err(err) = client.dispatch_blob(2, vec![2; 10]).await else {
+        panic!("dispatch succeeded without quorum");
+    };
+    assert!(!err.is_retriable(), "{err}");
+    assert!(err.to_string().contains("second"), "{err}");
+}
+
+#[tokio::test]
+async fn composite_client_finalizes_batches_if_first_backend_fails() {
+    let (first, second) = (MockDAClient::default(), MockDAClient::default());
+    first.fail_dispatches(usize::MAX, false);
+    let client = create_composite_client(&[("first", &first), ("second", &second)], 1);
+
+    let response = client.dispatch_blob(1, vec![1; 10]).await.unwrap();
+    assert_eq!(response.blob_id, r#"{"second":"0"}"#);
+    assert!(first.dispatched_blobs().is_empty());
+
+    // The first backend is not polled since it hasn't accepted the blob.
+    first.fail_inclusion_checks(true);
+    let inclusion_data = client.get_inclusion_data(&response.blob_id).await.unwrap();
+    assert!(inclusion_data.is_none());
+    second.include_blob("0");
+    let inclusion_data = client
+        .get_inclusion_data(&response.blob_id)
+        .await
+        .unwrap()
+        .expect("blob is not included");
+    assert_eq!(inclusion_data.data, b"0");
+
+    let Err(err) = client.get_inclusion_data(r#"{"third":"0"}"#).await else {
+        panic!("inclusion check succeeded for unknown backend");
+    };
+    assert!(!err.is_retriable(), "{err}");
+    assert!(err.to_string().contains("third"), "{err}");
+}
+
+#[tokio::test]
+async fn composite_client_retries_failed_backends() {
+    let (first, second) = (MockDAClient::default(), MockDAClient::default());
+    second.fail_dispatches(2, true);
+    let client = create_composite_client(&[("first", &first), ("second", &second)], 1);
+
+    let response = client.dispatch_blob(1, vec![1; 10]).await.unwrap();
+    assert_eq!(response.blob_id, r#"{"first":"0","second":"0"}"#);
+    assert_eq!(second.dispatched_blobs(), [vec![1; 10]]);
+
+    // Retries are bounded.
+    second.fail_dispatches(3, true);
+    let response = client.dispatch_blob(2, vec![2; 10]).await.unwrap();
+    assert_eq!(response.blob_id, r#"{"first":"1"}"#);
+    assert_eq!(second.dispatched_blobs(), [vec![1; 10]]);
+
+    // Non-retriable errors are not retried.
+    second.fail_dispatches(1, false);
+    let response = client.dispatch_blob(3, vec![3; 10]).await.unwrap();
+    assert_eq!(response.blob_id, r#"{"first":"2"}"#);
+    assert_eq!(second.dispatched_blobs(), [vec![1; 10]]);
+}
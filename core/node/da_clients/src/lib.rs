@@ -1,6 +1,8 @@
 pub mod avail;
 pub mod celestia;
+pub mod composite;
 pub mod eigen;
 pub mod no_da;
 pub mod object_store;
+pub mod sidecar;
 mod utils;
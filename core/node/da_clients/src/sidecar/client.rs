@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use zksync_config::configs::da_client::sidecar::SidecarConfig;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+use zksync_types::url::SensitiveUrl;
+
+use super::protocol::{
+    DispatchBlobQuery, DispatchBlobResponse, ErrorResponse, InclusionDataQuery,
+    InclusionDataResponse, InfoResponse, BLOBS_PATH, INCLUSION_DATA_PATH, INFO_PATH,
+    PROTOCOL_VERSION,
+};
+use crate::utils::{to_non_retriable_da_error, to_retriable_da_error};
+
+/// An implementation of the `DataAvailabilityClient` trait that delegates to an external DA sidecar
+/// speaking the sidecar HTTP protocol.
+#[derive(Debug, Clone)]
+pub struct SidecarClient {
+    api_url: SensitiveUrl,
+    http_client: reqwest::Client,
+    blob_size_limit: Option<usize>,
+}
+
+impl SidecarClient {
+    /// Creates a client and checks that the sidecar supports the protocol version of the client.
+    pub async fn new(config: SidecarConfig) -> anyhow::Result<Self> {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .build()
+            .context("failed building HTTP client")?;
+        let mut this = Self {
+            api_url: config.api_url,
+            http_client,
+            blob_size_limit: None,
+        };
+
+        let info: InfoResponse = this
+            .send(this.http_client.get(this.url(INFO_PATH)))
+            .await
+            .map_err(|err| err.error)
+            .context("failed getting DA sidecar info")?;
+        anyhow::ensure!(
+            info.protocol_version == PROTOCOL_VERSION,
+            "DA sidecar at {:?} uses protocol version {}, while the client supports version {PROTOCOL_VERSION}",
+            this.api_url,
+            info.protocol_version
+        );
+        this.blob_size_limit = info.blob_size_limit;
+        Ok(this)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.api_url.expose_str().trim_end_matches('/'))
+    }
+
+    async fn send<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T, DAError> {
+        // Network errors and timeouts are transient from the client's point of view.
+        let response = request.send().await.map_err(to_retriable_da_error)?;
+        let status = response.status();
+        let body = response.bytes().await.map_err(to_retriable_da_error)?;
+
+        if !status.is_success() {
+            return Err(Self::error_from_response(status, &body));
+        }
+        serde_json::from_slice(&body)
+            .context("failed parsing DA sidecar response")
+            .map_err(to_non_retriable_da_error)
+    }
+
+    fn error_from_response(status: StatusCode, body: &[u8]) -> DAError {
+        let (message, retriable) = match serde_json::from_slice::<ErrorResponse>(body) {
+            Ok(response) => (response.error, response.retriable),
+            Err(_) => (String::from_utf8_lossy(body).into_owned(), None),
+        };
+        let is_retriable = retriable
+            .unwrap_or(status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS);
+        DAError {
+            error: anyhow::anyhow!("DA sidecar responded with {status}: {message}"),
+            is_retriable,
+        }
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for SidecarClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        let request = self
+            .http_client
+            .post(self.url(BLOBS_PATH))
+            .query(&DispatchBlobQuery { batch_number })
+            .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+            .body(data);
+        let response: DispatchBlobResponse = self.send(request).await?;
+        Ok(DispatchResponse {
+            blob_id: response.blob_id,
+        })
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let request =
+            self.http_client
+                .get(self.url(INCLUSION_DATA_PATH))
+                .query(&InclusionDataQuery {
+                    blob_id: blob_id.to_owned(),
+                });
+        let response: InclusionDataResponse = self.send(request).await?;
+        Ok(response
+            .inclusion_data
+            .map(|data| InclusionData { data: data.0 }))
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    /// Returns the limit reported by the sidecar when the client was created.
+    fn blob_size_limit(&self) -> Option<usize> {
+        self.blob_size_limit
+    }
+}
@@ -0,0 +1,11 @@
+//! Generic DA client communicating with an external DA sidecar via a small versioned HTTP protocol.
+//! The protocol is described in the `README.md` file of this module.
+
+mod client;
+pub mod protocol;
+#[cfg(any(test, feature = "testonly"))]
+pub mod testonly;
+#[cfg(test)]
+mod tests;
+
+pub use self::client::SidecarClient;
@@ -0,0 +1,59 @@
+//! Types of the DA sidecar HTTP protocol.
+
+use serde::{Deserialize, Serialize};
+use zksync_types::web3::Bytes;
+
+/// Version of the protocol implemented by [`SidecarClient`](super::SidecarClient). A sidecar must report
+/// the same version from the info endpoint; otherwise, the client refuses to work with it.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Path of the endpoint returning [`InfoResponse`].
+pub const INFO_PATH: &str = "/v1/info";
+/// Path of the endpoint accepting blobs and returning [`DispatchBlobResponse`].
+pub const BLOBS_PATH: &str = "/v1/blobs";
+/// Path of the endpoint returning [`InclusionDataResponse`].
+pub const INCLUSION_DATA_PATH: &str = "/v1/inclusion_data";
+
+/// Response of `GET /v1/info`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InfoResponse {
+    pub protocol_version: u32,
+    /// Max size of a blob accepted by the sidecar in bytes, or `null` if there's no limit.
+    pub blob_size_limit: Option<usize>,
+}
+
+/// Query of `POST /v1/blobs`. The request body is the raw blob (`application/octet-stream`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DispatchBlobQuery {
+    pub batch_number: u32,
+}
+
+/// Response of `POST /v1/blobs`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DispatchBlobResponse {
+    /// Opaque identifier of the dispatched blob used to query its inclusion data.
+    pub blob_id: String,
+}
+
+/// Query of `GET /v1/inclusion_data`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InclusionDataQuery {
+    pub blob_id: String,
+}
+
+/// Response of `GET /v1/inclusion_data`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InclusionDataResponse {
+    /// Hex-encoded inclusion data, or `null` if the blob isn't included yet.
+    pub inclusion_data: Option<Bytes>,
+}
+
+/// Body of all non-2xx responses.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub error: String,
+    /// Whether the request may succeed if retried. If not specified, 5xx and 429 responses
+    /// are considered retriable, and all other responses are not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retriable: Option<bool>,
+}
@@ -0,0 +1,179 @@
+//! Reference in-process implementation of the DA sidecar protocol, used in tests.
+
+use std::{
+    collections::{HashMap, HashSet},
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Context as _;
+use axum::{
+    body::Bytes,
+    extract::{DefaultBodyLimit, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use sha2::{Digest, Sha256};
+use tokio::net::TcpListener;
+
+use super::protocol::{
+    DispatchBlobQuery, DispatchBlobResponse, ErrorResponse, InclusionDataQuery,
+    InclusionDataResponse, InfoResponse, BLOBS_PATH, INCLUSION_DATA_PATH, INFO_PATH,
+    PROTOCOL_VERSION,
+};
+
+#[derive(Debug, Default)]
+struct SidecarState {
+    blobs: HashMap<String, Vec<u8>>,
+    included_blobs: HashSet<String>,
+    include_immediately: bool,
+    unavailable: bool,
+}
+
+/// Reference DA sidecar storing blobs in memory. The blob ID is the hex-encoded SHA-256 digest of the batch number
+/// and the blob, and the inclusion data is the SHA-256 digest of the blob.
+#[derive(Debug, Clone)]
+pub struct ReferenceSidecar {
+    protocol_version: u32,
+    blob_size_limit: Option<usize>,
+    state: Arc<Mutex<SidecarState>>,
+}
+
+impl Default for ReferenceSidecar {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl ReferenceSidecar {
+    /// Creates a sidecar that includes blobs immediately after they are dispatched.
+    pub fn new(blob_size_limit: Option<usize>) -> Self {
+        Self {
+            protocol_version: PROTOCOL_VERSION,
+            blob_size_limit,
+            state: Arc::new(Mutex::new(SidecarState {
+                include_immediately: true,
+                ..SidecarState::default()
+            })),
+        }
+    }
+
+    /// Overrides the protocol version reported by the sidecar.
+    pub fn with_protocol_version(mut self, protocol_version: u32) -> Self {
+        self.protocol_version = protocol_version;
+        self
+    }
+
+    /// Sets whether dispatched blobs are included immediately. If not, blobs are only included
+    /// via [`Self::include_pending_blobs()`].
+    pub fn set_include_immediately(&self, include_immediately: bool) {
+        self.state.lock().unwrap().include_immediately = include_immediately;
+    }
+
+    /// Includes all dispatched blobs.
+    pub fn include_pending_blobs(&self) {
+        let mut state = self.state.lock().unwrap();
+        let blob_ids: Vec<_> = state.blobs.keys().cloned().collect();
+        state.included_blobs.extend(blob_ids);
+    }
+
+    /// Makes blob endpoints respond with 503 Service Unavailable.
+    pub fn set_unavailable(&self, unavailable: bool) {
+        self.state.lock().unwrap().unavailable = unavailable;
+    }
+
+    /// Returns a dispatched blob by its ID.
+    pub fn blob(&self, blob_id: &str) -> Option<Vec<u8>> {
+        self.state.lock().unwrap().blobs.get(blob_id).cloned()
+    }
+
+    /// Returns the inclusion data the sidecar reports for the specified blob.
+    pub fn expected_inclusion_data(blob: &[u8]) -> Vec<u8> {
+        Sha256::digest(blob).to_vec()
+    }
+
+    /// Starts serving the sidecar API on a random local port. The server runs until the Tokio runtime is dropped.
+    pub async fn spawn(self) -> anyhow::Result<SocketAddr> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .context("failed binding DA sidecar listener")?;
+        let local_addr = listener.local_addr()?;
+        let app = Router::new()
+            .route(INFO_PATH, get(Self::info))
+            .route(BLOBS_PATH, post(Self::dispatch_blob))
+            .route(INCLUSION_DATA_PATH, get(Self::inclusion_data))
+            // Blob size is limited by the sidecar config instead.
+            .layer(DefaultBodyLimit::disable())
+            .with_state(self);
+        tokio::spawn(async move {
+            if let Err(err) = axum::serve(listener, app).await {
+                tracing::warn!("Reference DA sidecar stopped with error: {err}");
+            }
+        });
+        Ok(local_addr)
+    }
+
+    async fn info(State(this): State<Self>) -> Json<InfoResponse> {
+        Json(InfoResponse {
+            protocol_version: this.protocol_version,
+            blob_size_limit: this.blob_size_limit,
+        })
+    }
+
+    async fn dispatch_blob(
+        State(this): State<Self>,
+        Query(query): Query<DispatchBlobQuery>,
+        blob: Bytes,
+    ) -> Response {
+        if let Some(limit) = this.blob_size_limit {
+            if blob.len() > limit {
+                let error = format!("blob size {} exceeds limit {limit}", blob.len());
+                return error_response(StatusCode::PAYLOAD_TOO_LARGE, error);
+            }
+        }
+
+        let mut state = this.state.lock().unwrap();
+        if state.unavailable {
+            return error_response(StatusCode::SERVICE_UNAVAILABLE, "sidecar is unavailable");
+        }
+        let blob_id = Sha256::new()
+            .chain_update(query.batch_number.to_be_bytes())
+            .chain_update(&blob)
+            .finalize();
+        let blob_id = hex::encode(blob_id);
+        if state.include_immediately {
+            state.included_blobs.insert(blob_id.clone());
+        }
+        state.blobs.insert(blob_id.clone(), blob.to_vec());
+        Json(DispatchBlobResponse { blob_id }).into_response()
+    }
+
+    async fn inclusion_data(
+        State(this): State<Self>,
+        Query(query): Query<InclusionDataQuery>,
+    ) -> Response {
+        let state = this.state.lock().unwrap();
+        if state.unavailable {
+            return error_response(StatusCode::SERVICE_UNAVAILABLE, "sidecar is unavailable");
+        }
+        let Some(blob) = state.blobs.get(&query.blob_id) else {
+            let error = format!("unknown blob `{}`", query.blob_id);
+            return error_response(StatusCode::NOT_FOUND, error);
+        };
+        let inclusion_data = state
+            .included_blobs
+            .contains(&query.blob_id)
+            .then(|| Self::expected_inclusion_data(blob).into());
+        Json(InclusionDataResponse { inclusion_data }).into_response()
+    }
+}
+
+fn error_response(status: StatusCode, error: impl Into<String>) -> Response {
+    let body = ErrorResponse {
+        error: error.into(),
+        retriable: None,
+    };
+    (status, Json(body)).into_response()
+}
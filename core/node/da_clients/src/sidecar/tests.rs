@@ -0,0 +1,81 @@
+use zksync_config::configs::da_client::sidecar::SidecarConfig;
+use zksync_da_client::DataAvailabilityClient;
+
+use super::{protocol::PROTOCOL_VERSION, testonly::ReferenceSidecar, SidecarClient};
+
+async fn create_client(sidecar: ReferenceSidecar) -> anyhow::Result<SidecarClient> {
+    let addr = sidecar.spawn().await?;
+    SidecarClient::new(SidecarConfig {
+        api_url: format!("http://{addr}/").parse().unwrap(),
+        timeout_ms: 5_000,
+    })
+    .await
+}
+
+#[tokio::test]
+async fn dispatching_blob_and_getting_inclusion_data() {
+    let sidecar = ReferenceSidecar::new(Some(1_024));
+    let client = create_client(sidecar.clone()).await.unwrap();
+    assert_eq!(client.blob_size_limit(), Some(1_024));
+
+    let blob = vec![1, 2, 3, 255];
+    let response = client.dispatch_blob(1, blob.clone()).await.unwrap();
+    assert_eq!(sidecar.blob(&response.blob_id).unwrap(), blob);
+
+    let inclusion_data = client
+        .get_inclusion_data(&response.blob_id)
+        .await
+        .unwrap()
+        .expect("blob is not included");
+    assert_eq!(
+        inclusion_data.data,
+        ReferenceSidecar::expected_inclusion_data(&blob)
+    );
+}
+
+#[tokio::test]
+async fn getting_inclusion_data_for_pending_blob() {
+    let sidecar = ReferenceSidecar::default();
+    sidecar.set_include_immediately(false);
+    let client = create_client(sidecar.clone()).await.unwrap();
+    assert_eq!(client.blob_size_limit(), None);
+
+    let response = client.dispatch_blob(1, vec![1; 32]).await.unwrap();
+    let inclusion_data = client.get_inclusion_data(&response.blob_id).await.unwrap();
+    assert!(inclusion_data.is_none());
+
+    sidecar.include_pending_blobs();
+    let inclusion_data = client.get_inclusion_data(&response.blob_id).await.unwrap();
+    assert!(inclusion_data.is_some());
+}
+
+#[tokio::test]
+async fn sidecar_errors_are_classified() {
+    let sidecar = ReferenceSidecar::new(Some(16));
+    let client = create_client(sidecar.clone()).await.unwrap();
+
+    let Err(err) = client.dispatch_blob(1, vec![0; 17]).await else {
+        panic!("oversized blob was accepted");
+    };
+    assert!(!err.is_retriable(), "{err}");
+    let Err(err) = client.get_inclusion_data("unknown").await else {
+        panic!("inclusion data returned for unknown blob");
+    };
+    assert!(!err.is_retriable(), "{err}");
+
+    sidecar.set_unavailable(true);
+    let Err(err) = client.dispatch_blob(1, vec![0; 16]).await else {
+        panic!("unavailable sidecar accepted blob");
+    };
+    assert!(err.is_retriable(), "{err}");
+
+    sidecar.set_unavailable(false);
+    client.dispatch_blob(1, vec![0; 16]).await.unwrap();
+}
+
+#[tokio::test]
+async fn client_rejects_unsupported_protocol_version() {
+    let sidecar = ReferenceSidecar::default().with_protocol_version(PROTOCOL_VERSION + 1);
+    let err = create_client(sidecar).await.unwrap_err();
+    assert!(err.to_string().contains("protocol version"), "{err:#}");
+}
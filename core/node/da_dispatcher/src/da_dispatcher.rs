@@ -1,8 +1,10 @@
-use std::{future::Future, time::Duration};
+use std::{future::Future, sync::Mutex, time::Duration};
 
 use anyhow::Context;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
 use rand::Rng;
+use serde::Serialize;
 use tokio::sync::watch::Receiver;
 use zksync_config::DADispatcherConfig;
 use zksync_da_client::{
@@ -10,15 +12,61 @@ use zksync_da_client::{
     DataAvailabilityClient,
 };
 use zksync_dal::{ConnectionPool, Core, CoreDal};
-use zksync_types::L1BatchNumber;
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_types::{
+    ethabi,
+    pubdata_da::{DataAvailabilityBlob, DataAvailabilityBlobPart},
+    L1BatchNumber,
+};
 
 use crate::metrics::METRICS;
 
+/// Outcome of polling a single blob for inclusion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BlobInclusionStatus {
+    Included,
+    Pending,
+    /// The blob wasn't included within the inclusion timeout and will be dispatched again.
+    Abandoned,
+}
+
+#[derive(Debug, Serialize)]
+struct PendingBlobDetails {
+    l1_batch_number: L1BatchNumber,
+    blob_id: String,
+    sent_at: DateTime<Utc>,
+}
+
+impl From<&DataAvailabilityBlob> for PendingBlobDetails {
+    fn from(blob: &DataAvailabilityBlob) -> Self {
+        Self {
+            l1_batch_number: blob.l1_batch_number,
+            blob_id: blob.blob_id.clone(),
+            sent_at: blob.sent_at,
+        }
+    }
+}
+
+/// Health details reported by [`DataAvailabilityDispatcher`].
+#[derive(Debug, Default, Serialize)]
+struct DataAvailabilityDispatcherDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_dispatched_l1_batch: Option<L1BatchNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_included_l1_batch: Option<L1BatchNumber>,
+    /// Blobs awaiting inclusion as of the last inclusion poll.
+    pending_blobs: Vec<PendingBlobDetails>,
+    /// Number of blobs abandoned because of the inclusion timeout since the dispatcher start.
+    abandoned_blobs: u64,
+}
+
 #[derive(Debug)]
 pub struct DataAvailabilityDispatcher {
     client: Box<dyn DataAvailabilityClient>,
     pool: ConnectionPool<Core>,
     config: DADispatcherConfig,
+    health_details: Mutex<DataAvailabilityDispatcherDetails>,
+    health_updater: HealthUpdater,
 }
 
 impl DataAvailabilityDispatcher {
@@ -31,10 +79,18 @@ impl DataAvailabilityDispatcher {
             pool,
             config,
             client,
+            health_details: Mutex::default(),
+            health_updater: ReactiveHealthCheck::new("da_dispatcher").1,
         }
     }
 
+    /// Returns health check associated with this dispatcher.
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+
     pub async fn run(self, mut stop_receiver: Receiver<bool>) -> anyhow::Result<()> {
+        self.update_health(|_| {});
         loop {
             if *stop_receiver.borrow() {
                 break;
@@ -73,7 +129,7 @@ impl DataAvailabilityDispatcher {
     }
 
     /// Dispatches the blobs to the data availability layer, and saves the blob_id in the database.
-    async fn dispatch(&self) -> anyhow::Result<()> {
+    pub(crate) async fn dispatch(&self) -> anyhow::Result<()> {
         let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
         let batches = conn
             .data_availability_dal()
@@ -83,35 +139,46 @@ impl DataAvailabilityDispatcher {
 
         for batch in batches {
             let dispatch_latency = METRICS.blob_dispatch_latency.start();
-            let dispatch_response = retry(self.config.max_retries(), batch.l1_batch_number, || {
-                self.client
-                    .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
-            })
-            .await
-            .with_context(|| {
-                format!(
-                    "failed to dispatch a blob with batch_number: {}, pubdata_len: {}",
-                    batch.l1_batch_number,
-                    batch.pubdata.len()
-                )
-            })?;
+            let blob_id = match self.client.blob_size_limit() {
+                Some(limit)
+                    if batch.pubdata.len() > limit && self.config.split_oversized_blobs() =>
+                {
+                    self.dispatch_in_parts(batch.l1_batch_number, &batch.pubdata, limit)
+                        .await?
+                }
+                _ => {
+                    let dispatch_response =
+                        retry(self.config.max_retries(), batch.l1_batch_number, || {
+                            self.client
+                                .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
+                        })
+                        .await
+                        .with_context(|| {
+                            format!(
+                                "failed to dispatch a blob with batch_number: {}, pubdata_len: {}",
+                                batch.l1_batch_number,
+                                batch.pubdata.len()
+                            )
+                        })?;
+                    dispatch_response.blob_id
+                }
+            };
             let dispatch_latency_duration = dispatch_latency.observe();
 
             let sent_at = Utc::now().naive_utc();
 
             let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
             conn.data_availability_dal()
-                .insert_l1_batch_da(
-                    batch.l1_batch_number,
-                    dispatch_response.blob_id.as_str(),
-                    sent_at,
-                )
+                .insert_l1_batch_da(batch.l1_batch_number, blob_id.as_str(), sent_at)
                 .await?;
             drop(conn);
 
             METRICS
                 .last_dispatched_l1_batch
                 .set(batch.l1_batch_number.0 as usize);
+            self.update_health(|details| {
+                details.last_dispatched_l1_batch = Some(batch.l1_batch_number);
+            });
             METRICS.blob_size.observe(batch.pubdata.len());
             tracing::info!(
                 "Dispatched a DA for batch_number: {}, pubdata_size: {}, dispatch_latency: {dispatch_latency_duration:?}",
@@ -123,35 +190,88 @@ impl DataAvailabilityDispatcher {
         Ok(())
     }
 
-    /// Polls the data availability layer for inclusion data, and saves it in the database.
-    async fn poll_for_inclusion(&self) -> anyhow::Result<()> {
+    /// Polls the data availability layer for inclusion data of the blobs awaiting inclusion concurrently,
+    /// and saves it in the database. Blobs that are not included within the inclusion timeout are abandoned,
+    /// so that their pubdata is dispatched again.
+    pub(crate) async fn poll_for_inclusion(&self) -> anyhow::Result<()> {
         let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
-        let blob_info = conn
+        let blobs = conn
             .data_availability_dal()
-            .get_first_da_blob_awaiting_inclusion()
+            .get_da_blobs_awaiting_inclusion(self.config.max_rows_to_dispatch() as usize)
             .await?;
         drop(conn);
 
-        let Some(blob_info) = blob_info else {
-            return Ok(());
-        };
+        let statuses: Vec<_> = futures::stream::iter(&blobs)
+            .map(|blob| self.poll_blob_for_inclusion(blob))
+            .buffered(self.config.max_concurrent_inclusion_requests())
+            .collect()
+            .await;
+
+        let mut pending_blobs = vec![];
+        let mut first_error = None;
+        for (blob, status) in blobs.iter().zip(statuses) {
+            match status {
+                Ok(BlobInclusionStatus::Included) => {
+                    self.update_health(|details| {
+                        details.last_included_l1_batch = Some(blob.l1_batch_number);
+                    });
+                }
+                Ok(BlobInclusionStatus::Abandoned) => {
+                    self.update_health(|details| details.abandoned_blobs += 1);
+                }
+                Ok(BlobInclusionStatus::Pending) => {
+                    pending_blobs.push(PendingBlobDetails::from(blob))
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed polling inclusion for batch_number: {}: {err:?}",
+                        blob.l1_batch_number
+                    );
+                    pending_blobs.push(PendingBlobDetails::from(blob));
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+
+        METRICS.blobs_awaiting_inclusion.set(pending_blobs.len());
+        self.update_health(|details| details.pending_blobs = pending_blobs);
+        first_error.map_or(Ok(()), Err)
+    }
 
+    async fn poll_blob_for_inclusion(
+        &self,
+        blob_info: &DataAvailabilityBlob,
+    ) -> anyhow::Result<BlobInclusionStatus> {
         let inclusion_data = if self.config.use_dummy_inclusion_data() {
             Some(InclusionData { data: vec![] })
         } else {
-            self.client
-                .get_inclusion_data(blob_info.blob_id.as_str())
-                .await
-                .with_context(|| {
-                    format!(
-                        "failed to get inclusion data for blob_id: {}, batch_number: {}",
-                        blob_info.blob_id, blob_info.l1_batch_number
-                    )
-                })?
+            let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+            let parts = conn
+                .data_availability_dal()
+                .get_l1_batch_da_blob_parts(blob_info.l1_batch_number)
+                .await?;
+            drop(conn);
+
+            if parts.is_empty() {
+                self.client
+                    .get_inclusion_data(blob_info.blob_id.as_str())
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "failed to get inclusion data for blob_id: {}, batch_number: {}",
+                            blob_info.blob_id, blob_info.l1_batch_number
+                        )
+                    })?
+            } else {
+                self.get_parts_inclusion_data(parts).await?
+            }
         };
 
+        let inclusion_latency = Utc::now().signed_duration_since(blob_info.sent_at);
         let Some(inclusion_data) = inclusion_data else {
-            return Ok(());
+            return self
+                .abandon_blob_on_timeout(blob_info, inclusion_latency)
+                .await;
         };
 
         let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
@@ -163,7 +283,6 @@ impl DataAvailabilityDispatcher {
             .await?;
         drop(conn);
 
-        let inclusion_latency = Utc::now().signed_duration_since(blob_info.sent_at);
         if let Ok(latency) = inclusion_latency.to_std() {
             METRICS.inclusion_latency.observe(latency);
         }
@@ -177,7 +296,173 @@ impl DataAvailabilityDispatcher {
             inclusion_latency.num_seconds()
         );
 
-        Ok(())
+        Ok(BlobInclusionStatus::Included)
+    }
+
+    /// Polls inclusion of the parts of the L1 batch pubdata that was split into several blobs, saving inclusion data
+    /// for each included part in the database. Returns the combined inclusion data once all parts are included;
+    /// the combined data is an ABI-encoded `bytes[]` array with the inclusion data of the parts ordered by the part index.
+    async fn get_parts_inclusion_data(
+        &self,
+        parts: Vec<DataAvailabilityBlobPart>,
+    ) -> anyhow::Result<Option<InclusionData>> {
+        let parts_count = parts.len();
+        let mut parts_inclusion_data = Vec::with_capacity(parts_count);
+        for part in parts {
+            if let Some(data) = part.inclusion_data {
+                parts_inclusion_data.push(data);
+                continue;
+            }
+
+            let inclusion_data = self
+                .client
+                .get_inclusion_data(part.blob_id.as_str())
+                .await
+                .with_context(|| {
+                    format!(
+                        "failed to get inclusion data for part #{} with blob_id: {}, batch_number: {}",
+                        part.part_index, part.blob_id, part.l1_batch_number
+                    )
+                })?;
+            let Some(inclusion_data) = inclusion_data else {
+                continue;
+            };
+
+            let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+            conn.data_availability_dal()
+                .save_l1_batch_da_blob_part_inclusion_data(
+                    part.l1_batch_number,
+                    part.part_index,
+                    &inclusion_data.data,
+                )
+                .await?;
+            drop(conn);
+            parts_inclusion_data.push(inclusion_data.data);
+        }
+
+        if parts_inclusion_data.len() < parts_count {
+            return Ok(None);
+        }
+        let parts_inclusion_data = parts_inclusion_data
+            .into_iter()
+            .map(ethabi::Token::Bytes)
+            .collect();
+        let data = ethabi::encode(&[ethabi::Token::Array(parts_inclusion_data)]);
+        Ok(Some(InclusionData { data }))
+    }
+
+    /// Abandons a blob that wasn't included within the inclusion timeout, so that the pubdata is dispatched again.
+    /// The abandoned blob is kept in the dispatch attempts history.
+    async fn abandon_blob_on_timeout(
+        &self,
+        blob_info: &DataAvailabilityBlob,
+        elapsed: chrono::Duration,
+    ) -> anyhow::Result<BlobInclusionStatus> {
+        let Some(timeout) = self.config.inclusion_timeout() else {
+            return Ok(BlobInclusionStatus::Pending);
+        };
+        if elapsed.to_std().map_or(true, |elapsed| elapsed < timeout) {
+            return Ok(BlobInclusionStatus::Pending);
+        }
+
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let abandoned = conn
+            .data_availability_dal()
+            .abandon_l1_batch_da_blob(blob_info.l1_batch_number, &blob_info.blob_id)
+            .await?;
+        drop(conn);
+        if !abandoned {
+            return Ok(BlobInclusionStatus::Pending);
+        }
+
+        METRICS.redispatched_blobs.inc();
+        tracing::warn!(
+            "Blob {} for batch_number: {} wasn't included in {elapsed_seconds} seconds (timeout: {timeout:?}); \
+             it will be dispatched again",
+            blob_info.blob_id,
+            blob_info.l1_batch_number,
+            elapsed_seconds = elapsed.num_seconds()
+        );
+        Ok(BlobInclusionStatus::Abandoned)
+    }
+
+    /// Splits pubdata exceeding the blob size limit of the DA client into several blobs and dispatches them,
+    /// saving the blob_id of each part in the database. Parts that were dispatched before
+    /// (e.g., before a restart) are not dispatched again. Returns the blob_id of the first part,
+    /// which references the L1 batch blob; all parts are stored in the `data_availability_blob_parts` table
+    /// and are polled for inclusion separately.
+    async fn dispatch_in_parts(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        pubdata: &[u8],
+        blob_size_limit: usize,
+    ) -> anyhow::Result<String> {
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let mut parts = conn
+            .data_availability_dal()
+            .get_l1_batch_da_blob_parts(l1_batch_number)
+            .await?;
+        drop(conn);
+
+        let chunks: Vec<_> = pubdata.chunks(blob_size_limit.max(1)).collect();
+        tracing::info!(
+            "Pubdata for batch_number: {l1_batch_number} of size {} exceeds the blob size limit {blob_size_limit}, \
+             dispatching it in {} parts ({} already dispatched)",
+            pubdata.len(),
+            chunks.len(),
+            parts.len()
+        );
+        anyhow::ensure!(
+            parts.len() <= chunks.len(),
+            "L1 batch #{l1_batch_number} has {} dispatched DA blob parts, while its pubdata has only {} parts",
+            parts.len(),
+            chunks.len()
+        );
+
+        for (part_index, chunk) in chunks.iter().enumerate().skip(parts.len()) {
+            let dispatch_response = retry(self.config.max_retries(), l1_batch_number, || {
+                self.client
+                    .dispatch_blob(l1_batch_number.0, chunk.to_vec())
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "failed to dispatch part #{part_index} of a blob with batch_number: {l1_batch_number}, part_len: {}",
+                    chunk.len()
+                )
+            })?;
+
+            let sent_at = Utc::now();
+            let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+            conn.data_availability_dal()
+                .insert_l1_batch_da_blob_part(
+                    l1_batch_number,
+                    part_index as u32,
+                    dispatch_response.blob_id.as_str(),
+                    sent_at.naive_utc(),
+                )
+                .await?;
+            drop(conn);
+
+            parts.push(DataAvailabilityBlobPart {
+                l1_batch_number,
+                part_index: part_index as u32,
+                blob_id: dispatch_response.blob_id,
+                inclusion_data: None,
+                sent_at,
+            });
+        }
+
+        METRICS.blob_parts.observe(parts.len());
+        let first_part = parts.into_iter().next().context("pubdata has no parts")?;
+        Ok(first_part.blob_id)
+    }
+
+    fn update_health(&self, update: impl FnOnce(&mut DataAvailabilityDispatcherDetails)) {
+        let mut details = self.health_details.lock().unwrap();
+        update(&mut details);
+        self.health_updater
+            .update(Health::from(HealthStatus::Ready).with_details(&*details));
     }
 }
 
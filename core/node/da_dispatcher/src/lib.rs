@@ -2,3 +2,5 @@ pub use self::da_dispatcher::DataAvailabilityDispatcher;
 
 mod da_dispatcher;
 mod metrics;
+#[cfg(test)]
+mod tests;
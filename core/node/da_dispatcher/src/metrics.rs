@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use vise::{Buckets, Gauge, Histogram, Metrics, Unit};
+use vise::{Buckets, Counter, Gauge, Histogram, Metrics, Unit};
 
 /// Buckets for `blob_dispatch_latency` (from 0.1 to 120 seconds).
 const DISPATCH_LATENCIES: Buckets =
@@ -19,6 +19,9 @@ pub(super) struct DataAvailabilityDispatcherMetrics {
     /// Buckets are bytes ranging from 1 KB to 16 MB, which has to satisfy all blob size values.
     #[metrics(buckets = Buckets::exponential(1_024.0..=16.0 * 1_024.0 * 1_024.0, 2.0), unit = Unit::Bytes)]
     pub blob_size: Histogram<usize>,
+    /// Number of blobs the L1 batch pubdata was split into because it exceeded the blob size limit.
+    #[metrics(buckets = Buckets::linear(1.0..=16.0, 1.0))]
+    pub blob_parts: Histogram<usize>,
 
     /// Number of transactions resent by the DA dispatcher.
     #[metrics(buckets = Buckets::linear(0.0..=10.0, 1.0))]
@@ -27,6 +30,10 @@ pub(super) struct DataAvailabilityDispatcherMetrics {
     pub last_dispatched_l1_batch: Gauge<usize>,
     /// Last L1 batch that has its inclusion finalized by DA layer.
     pub last_included_l1_batch: Gauge<usize>,
+    /// Number of blobs awaiting inclusion as of the last inclusion poll.
+    pub blobs_awaiting_inclusion: Gauge<usize>,
+    /// Number of blobs abandoned and dispatched again because they weren't included within the inclusion timeout.
+    pub redispatched_blobs: Counter,
 }
 
 #[vise::register]
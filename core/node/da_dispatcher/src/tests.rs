@@ -0,0 +1,437 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+use assert_matches::assert_matches;
+use async_trait::async_trait;
+use zksync_config::DADispatcherConfig;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+use zksync_da_clients::composite::CompositeDAClient;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_health_check::{CheckHealth, HealthStatus};
+use zksync_node_test_utils::create_l1_batch;
+use zksync_types::{ethabi, L1BatchNumber, ProtocolVersion};
+
+use crate::DataAvailabilityDispatcher;
+
+const BLOB_SIZE_LIMIT: usize = 100;
+
+#[derive(Debug, Default)]
+struct MockDAClientState {
+    dispatched_blobs: Vec<Vec<u8>>,
+    included_blobs: HashSet<String>,
+    fail_dispatch_after: Option<usize>,
+}
+
+/// DA client with a blob size limit, which uses sequential blob IDs and returns a blob ID as the inclusion data.
+#[derive(Debug, Clone, Default)]
+struct MockDAClient(Arc<Mutex<MockDAClientState>>);
+
+impl MockDAClient {
+    fn dispatched_blobs(&self) -> Vec<Vec<u8>> {
+        self.0.lock().unwrap().dispatched_blobs.clone()
+    }
+
+    fn include_blob(&self, blob_id: &str) {
+        self.0
+            .lock()
+            .unwrap()
+            .included_blobs
+            .insert(blob_id.to_owned());
+    }
+
+    fn fail_dispatch_after(&self, dispatched_blobs: Option<usize>) {
+        self.0.lock().unwrap().fail_dispatch_after = dispatched_blobs;
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for MockDAClient {
+    async fn dispatch_blob(
+        &self,
+        _batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        if data.len() > BLOB_SIZE_LIMIT {
+            return Err(DAError {
+                error: anyhow::anyhow!("blob is too large"),
+                is_retriable: false,
+            });
+        }
+
+        let mut state = self.0.lock().unwrap();
+        if state.fail_dispatch_after == Some(state.dispatched_blobs.len()) {
+            return Err(DAError {
+                error: anyhow::anyhow!("DA layer is unavailable"),
+                is_retriable: false,
+            });
+        }
+        let blob_id = state.dispatched_blobs.len().to_string();
+        state.dispatched_blobs.push(data);
+        Ok(DispatchResponse { blob_id })
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let state = self.0.lock().unwrap();
+        Ok(state
+            .included_blobs
+            .contains(blob_id)
+            .then(|| InclusionData {
+                data: blob_id.as_bytes().to_vec(),
+            }))
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        Some(BLOB_SIZE_LIMIT)
+    }
+}
+
+async fn create_pool() -> ConnectionPool<Core> {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut conn = pool.connection().await.unwrap();
+    conn.protocol_versions_dal()
+        .save_protocol_version_with_tx(&ProtocolVersion::default())
+        .await
+        .unwrap();
+    drop(conn);
+    pool
+}
+
+async fn seal_l1_batch(pool: &ConnectionPool<Core>, number: u32, pubdata: Vec<u8>) {
+    let mut header = create_l1_batch(number);
+    header.pubdata_input = Some(pubdata);
+    let mut conn = pool.connection().await.unwrap();
+    conn.blocks_dal()
+        .insert_mock_l1_batch(&header)
+        .await
+        .unwrap();
+}
+
+fn create_dispatcher(
+    pool: &ConnectionPool<Core>,
+    client: &MockDAClient,
+) -> DataAvailabilityDispatcher {
+    let config = DADispatcherConfig {
+        max_retries: Some(0),
+        ..DADispatcherConfig::for_tests()
+    };
+    DataAvailabilityDispatcher::new(pool.clone(), config, Box::new(client.clone()))
+}
+
+fn create_splitting_dispatcher(
+    pool: &ConnectionPool<Core>,
+    client: &MockDAClient,
+) -> DataAvailabilityDispatcher {
+    let config = DADispatcherConfig {
+        max_retries: Some(0),
+        use_dummy_inclusion_data: Some(true),
+        split_oversized_blobs: Some(true),
+        ..DADispatcherConfig::for_tests()
+    };
+    DataAvailabilityDispatcher::new(pool.clone(), config, Box::new(client.clone()))
+}
+
+#[tokio::test]
+async fn small_pubdata_is_dispatched_as_single_blob() {
+    let pool = create_pool().await;
+    let client = MockDAClient::default();
+    seal_l1_batch(&pool, 1, vec![1; BLOB_SIZE_LIMIT]).await;
+
+    let dispatcher = create_dispatcher(&pool, &client);
+    dispatcher.dispatch().await.unwrap();
+    assert_eq!(client.dispatched_blobs(), [vec![1; BLOB_SIZE_LIMIT]]);
+
+    let mut conn = pool.connection().await.unwrap();
+    let parts = conn
+        .data_availability_dal()
+        .get_l1_batch_da_blob_parts(L1BatchNumber(1))
+        .await
+        .unwrap();
+    assert!(parts.is_empty());
+
+    client.include_blob("0");
+    dispatcher.poll_for_inclusion().await.unwrap();
+    let blob = conn
+        .data_availability_dal()
+        .get_first_da_blob_awaiting_inclusion()
+        .await
+        .unwrap();
+    assert!(blob.is_none());
+}
+
+#[tokio::test]
+async fn oversized_pubdata_is_not_split_without_opt_in() {
+    let pool = create_pool().await;
+    let client = MockDAClient::default();
+    seal_l1_batch(&pool, 1, vec![1; 3 * BLOB_SIZE_LIMIT]).await;
+
+    let dispatcher = create_dispatcher(&pool, &client);
+    let err = dispatcher.dispatch().await.unwrap_err();
+    assert!(format!("{err:#}").contains("blob is too large"), "{err:#}");
+    assert!(client.dispatched_blobs().is_empty());
+
+    let mut conn = pool.connection().await.unwrap();
+    let parts = conn
+        .data_availability_dal()
+        .get_l1_batch_da_blob_parts(L1BatchNumber(1))
+        .await
+        .unwrap();
+    assert!(parts.is_empty());
+}
+
+#[tokio::test]
+async fn oversized_pubdata_parts_are_polled_for_inclusion() {
+    let pool = create_pool().await;
+    let client = MockDAClient::default();
+    seal_l1_batch(&pool, 1, vec![1; 3 * BLOB_SIZE_LIMIT]).await;
+
+    let config = DADispatcherConfig {
+        max_retries: Some(0),
+        split_oversized_blobs: Some(true),
+        ..DADispatcherConfig::for_tests()
+    };
+    let dispatcher =
+        DataAvailabilityDispatcher::new(pool.clone(), config, Box::new(client.clone()));
+    dispatcher.dispatch().await.unwrap();
+    assert_eq!(client.dispatched_blobs().len(), 3);
+
+    // The batch is not included until all its parts are included.
+    client.include_blob("0");
+    client.include_blob("2");
+    dispatcher.poll_for_inclusion().await.unwrap();
+    let mut conn = pool.connection().await.unwrap();
+    let blob = conn
+        .data_availability_dal()
+        .get_first_da_blob_awaiting_inclusion()
+        .await
+        .unwrap()
+        .expect("no blob awaiting inclusion");
+    assert_eq!(blob.l1_batch_number, L1BatchNumber(1));
+    let parts = conn
+        .data_availability_dal()
+        .get_l1_batch_da_blob_parts(L1BatchNumber(1))
+        .await
+        .unwrap();
+    let parts_inclusion_data: Vec<_> = parts
+        .iter()
+        .map(|part| part.inclusion_data.as_deref())
+        .collect();
+    assert_eq!(
+        parts_inclusion_data,
+        [Some(b"0".as_slice()), None, Some(b"2".as_slice())]
+    );
+
+    client.include_blob("1");
+    dispatcher.poll_for_inclusion().await.unwrap();
+    let blob = conn
+        .data_availability_dal()
+        .get_l1_batch_da_blob(L1BatchNumber(1))
+        .await
+        .unwrap()
+        .expect("no blob for L1 batch");
+    let expected_inclusion_data = ethabi::encode(&[ethabi::Token::Array(vec![
+        ethabi::Token::Bytes(b"0".to_vec()),
+        ethabi::Token::Bytes(b"1".to_vec()),
+        ethabi::Token::Bytes(b"2".to_vec()),
+    ])]);
+    assert_eq!(blob.inclusion_data, Some(expected_inclusion_data));
+}
+
+#[tokio::test]
+async fn oversized_pubdata_is_split_into_several_blobs() {
+    let pool = create_pool().await;
+    let client = MockDAClient::default();
+    let pubdata: Vec<u8> = (0..250).map(|i| i as u8).collect();
+    seal_l1_batch(&pool, 1, pubdata.clone()).await;
+
+    let dispatcher = create_splitting_dispatcher(&pool, &client);
+    dispatcher.dispatch().await.unwrap();
+    let dispatched_blobs = client.dispatched_blobs();
+    assert_eq!(dispatched_blobs.len(), 3);
+    assert_eq!(dispatched_blobs.concat(), pubdata);
+
+    let mut conn = pool.connection().await.unwrap();
+    let parts = conn
+        .data_availability_dal()
+        .get_l1_batch_da_blob_parts(L1BatchNumber(1))
+        .await
+        .unwrap();
+    let blob_ids: Vec<_> = parts.iter().map(|part| part.blob_id.as_str()).collect();
+    assert_eq!(blob_ids, ["0", "1", "2"]);
+    let blob = conn
+        .data_availability_dal()
+        .get_first_da_blob_awaiting_inclusion()
+        .await
+        .unwrap()
+        .expect("no blob awaiting inclusion");
+    assert_eq!(blob.blob_id, "0");
+
+    dispatcher.poll_for_inclusion().await.unwrap();
+    let blob = conn
+        .data_availability_dal()
+        .get_l1_batch_da_blob(L1BatchNumber(1))
+        .await
+        .unwrap()
+        .expect("no blob for L1 batch");
+    assert_eq!(blob.inclusion_data.as_deref(), Some([].as_slice()));
+}
+
+#[tokio::test]
+async fn dispatched_parts_are_not_resent_after_failure() {
+    let pool = create_pool().await;
+    let client = MockDAClient::default();
+    seal_l1_batch(&pool, 1, vec![1; 3 * BLOB_SIZE_LIMIT]).await;
+
+    let dispatcher = create_splitting_dispatcher(&pool, &client);
+    client.fail_dispatch_after(Some(1));
+    dispatcher.dispatch().await.unwrap_err();
+    assert_eq!(client.dispatched_blobs().len(), 1);
+
+    let mut conn = pool.connection().await.unwrap();
+    let blob = conn
+        .data_availability_dal()
+        .get_first_da_blob_awaiting_inclusion()
+        .await
+        .unwrap();
+    assert!(blob.is_none(), "{blob:?}");
+
+    client.fail_dispatch_after(None);
+    dispatcher.dispatch().await.unwrap();
+    assert_eq!(client.dispatched_blobs().len(), 3);
+    let parts = conn
+        .data_availability_dal()
+        .get_l1_batch_da_blob_parts(L1BatchNumber(1))
+        .await
+        .unwrap();
+    assert_eq!(parts.len(), 3);
+    let blob = conn
+        .data_availability_dal()
+        .get_first_da_blob_awaiting_inclusion()
+        .await
+        .unwrap()
+        .expect("no blob awaiting inclusion");
+    assert_eq!(blob.blob_id, "0");
+}
+
+#[tokio::test]
+async fn dispatcher_with_composite_client() {
+    let pool = create_pool().await;
+    let (first, second) = (MockDAClient::default(), MockDAClient::default());
+    seal_l1_batch(&pool, 1, vec![1; BLOB_SIZE_LIMIT]).await;
+
+    let backends = vec![
+        ("first", first.clone_boxed()),
+        ("second", second.clone_boxed()),
+    ];
+    let client = CompositeDAClient::new(backends, 1).unwrap();
+    let config = DADispatcherConfig {
+        max_retries: Some(0),
+        ..DADispatcherConfig::for_tests()
+    };
+    let dispatcher = DataAvailabilityDispatcher::new(pool.clone(), config, Box::new(client));
+    dispatcher.dispatch().await.unwrap();
+
+    let mut conn = pool.connection().await.unwrap();
+    let blob = conn
+        .data_availability_dal()
+        .get_first_da_blob_awaiting_inclusion()
+        .await
+        .unwrap()
+        .expect("no blob awaiting inclusion");
+    assert_eq!(blob.blob_id, r#"{"first":"0","second":"0"}"#);
+
+    first.include_blob("0");
+    dispatcher.poll_for_inclusion().await.unwrap();
+    let blob = conn
+        .data_availability_dal()
+        .get_first_da_blob_awaiting_inclusion()
+        .await
+        .unwrap();
+    assert!(blob.is_none(), "{blob:?}");
+}
+
+#[tokio::test]
+async fn pending_blob_does_not_block_inclusion_of_later_blobs() {
+    let pool = create_pool().await;
+    let client = MockDAClient::default();
+    for number in 1..=3 {
+        seal_l1_batch(&pool, number, vec![number as u8; 10]).await;
+    }
+
+    let dispatcher = create_dispatcher(&pool, &client);
+    dispatcher.dispatch().await.unwrap();
+    client.include_blob("1");
+    client.include_blob("2");
+    dispatcher.poll_for_inclusion().await.unwrap();
+
+    let mut conn = pool.connection().await.unwrap();
+    let blobs = conn
+        .data_availability_dal()
+        .get_da_blobs_awaiting_inclusion(10)
+        .await
+        .unwrap();
+    let pending_batches: Vec<_> = blobs.iter().map(|blob| blob.l1_batch_number).collect();
+    assert_eq!(pending_batches, [L1BatchNumber(1)]);
+
+    let health = dispatcher.health_check().check_health().await;
+    assert_matches!(health.status(), HealthStatus::Ready);
+    let details = health.details().unwrap();
+    assert_eq!(details["last_dispatched_l1_batch"], 3);
+    assert_eq!(details["pending_blobs"].as_array().unwrap().len(), 1);
+    assert_eq!(details["pending_blobs"][0]["l1_batch_number"], 1);
+}
+
+#[tokio::test]
+async fn blob_is_redispatched_after_inclusion_timeout() {
+    let pool = create_pool().await;
+    let client = MockDAClient::default();
+    seal_l1_batch(&pool, 1, vec![1; 10]).await;
+
+    let config = DADispatcherConfig {
+        max_retries: Some(0),
+        inclusion_timeout_ms: Some(0),
+        ..DADispatcherConfig::for_tests()
+    };
+    let dispatcher =
+        DataAvailabilityDispatcher::new(pool.clone(), config, Box::new(client.clone()));
+    dispatcher.dispatch().await.unwrap();
+    dispatcher.poll_for_inclusion().await.unwrap();
+
+    let mut conn = pool.connection().await.unwrap();
+    let details = conn
+        .data_availability_dal()
+        .get_l1_batch_da_details(L1BatchNumber(1))
+        .await
+        .unwrap()
+        .expect("no DA details");
+    assert_eq!(details.blob_id, None);
+    assert_eq!(details.abandoned_blobs.len(), 1);
+    assert_eq!(details.abandoned_blobs[0].blob_id, "0");
+
+    // The batch must be dispatched again.
+    dispatcher.dispatch().await.unwrap();
+    assert_eq!(client.dispatched_blobs(), [vec![1; 10], vec![1; 10]]);
+    client.include_blob("1");
+    dispatcher.poll_for_inclusion().await.unwrap();
+
+    let details = conn
+        .data_availability_dal()
+        .get_l1_batch_da_details(L1BatchNumber(1))
+        .await
+        .unwrap()
+        .expect("no DA details");
+    assert_eq!(details.blob_id.as_deref(), Some("1"));
+    assert_eq!(details.inclusion_data.unwrap().0, b"1");
+    assert_eq!(details.abandoned_blobs.len(), 1);
+
+    let health = dispatcher.health_check().check_health().await;
+    assert_eq!(health.details().unwrap()["abandoned_blobs"], 1);
+}
@@ -31,11 +31,19 @@ pub(crate) struct L1BlockNumbers {
     pub latest: L1BlockNumber,
 }
 
+/// Type of the operator sending transactions. Each operator type has a separate nonce lane: transactions
+/// are resent and fee-bumped independently from transactions of other operator types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelSet, EncodeLabelValue)]
 #[metrics(label = "type", rename_all = "snake_case")]
 pub(crate) enum OperatorType {
+    /// Main operator; sends all transactions without a dedicated operator.
     NonBlob,
+    /// Dedicated commit operator (used for EIP-4844 blob commitments).
     Blob,
+    /// Dedicated operator for sending proofs.
+    Prove,
+    /// Dedicated operator for executing batches.
+    Execute,
     Gateway,
 }
 
@@ -68,7 +76,8 @@ pub(super) trait AbstractL1Interface: 'static + Sync + Send + fmt::Debug {
         operator_type: OperatorType,
     ) -> EnrichedClientResult<H256>;
 
-    fn get_blobs_operator_account(&self) -> Option<Address>;
+    /// Returns the address of the dedicated operator of the specified type, or `None` for the main operator.
+    fn get_operator_account(&self, operator_type: OperatorType) -> Option<Address>;
 
     async fn get_operator_nonce(
         &self,
@@ -96,25 +105,30 @@ pub(super) trait AbstractL1Interface: 'static + Sync + Send + fmt::Debug {
 pub(super) struct RealL1Interface {
     pub ethereum_gateway: Option<Box<dyn BoundEthInterface>>,
     pub ethereum_gateway_blobs: Option<Box<dyn BoundEthInterface>>,
+    pub ethereum_gateway_prove: Option<Box<dyn BoundEthInterface>>,
+    pub ethereum_gateway_execute: Option<Box<dyn BoundEthInterface>>,
     pub l2_gateway: Option<Box<dyn BoundEthInterface>>,
     pub wait_confirmations: Option<u64>,
 }
 
 impl RealL1Interface {
-    fn query_client(&self, operator_type: OperatorType) -> &dyn EthInterface {
+    fn client(&self, operator_type: OperatorType) -> Option<&dyn BoundEthInterface> {
         match operator_type {
-            OperatorType::NonBlob => self.ethereum_gateway.as_deref().unwrap().as_ref(),
-            OperatorType::Blob => self.ethereum_gateway_blobs.as_deref().unwrap().as_ref(),
-            OperatorType::Gateway => self.l2_gateway.as_deref().unwrap().as_ref(),
+            OperatorType::NonBlob => self.ethereum_gateway.as_deref(),
+            OperatorType::Blob => self.ethereum_gateway_blobs.as_deref(),
+            OperatorType::Prove => self.ethereum_gateway_prove.as_deref(),
+            OperatorType::Execute => self.ethereum_gateway_execute.as_deref(),
+            OperatorType::Gateway => self.l2_gateway.as_deref(),
         }
     }
 
+    fn query_client(&self, operator_type: OperatorType) -> &dyn EthInterface {
+        self.bound_query_client(operator_type).as_ref()
+    }
+
     fn bound_query_client(&self, operator_type: OperatorType) -> &dyn BoundEthInterface {
-        match operator_type {
-            OperatorType::NonBlob => self.ethereum_gateway.as_deref().unwrap(),
-            OperatorType::Blob => self.ethereum_gateway_blobs.as_deref().unwrap(),
-            OperatorType::Gateway => self.l2_gateway.as_deref().unwrap(),
-        }
+        self.client(operator_type)
+            .unwrap_or_else(|| panic!("client for {operator_type:?} operator is not configured"))
     }
 }
 
@@ -128,6 +142,12 @@ impl AbstractL1Interface for RealL1Interface {
         if self.ethereum_gateway_blobs.is_some() {
             result.push(OperatorType::Blob)
         }
+        if self.ethereum_gateway_prove.is_some() {
+            result.push(OperatorType::Prove);
+        }
+        if self.ethereum_gateway_execute.is_some() {
+            result.push(OperatorType::Execute);
+        }
         if self.ethereum_gateway.is_some() {
             result.push(OperatorType::NonBlob);
         }
@@ -175,11 +195,14 @@ impl AbstractL1Interface for RealL1Interface {
         self.query_client(operator_type).send_raw_tx(tx_bytes).await
     }
 
-    fn get_blobs_operator_account(&self) -> Option<Address> {
-        self.ethereum_gateway_blobs
-            .as_deref()
-            .as_ref()
-            .map(|s| s.sender_account())
+    fn get_operator_account(&self, operator_type: OperatorType) -> Option<Address> {
+        match operator_type {
+            // The main operator is represented by `from_addr = NULL` in the database.
+            OperatorType::NonBlob | OperatorType::Gateway => None,
+            OperatorType::Blob | OperatorType::Prove | OperatorType::Execute => self
+                .client(operator_type)
+                .map(BoundEthInterface::sender_account),
+        }
     }
 
     async fn get_operator_nonce(
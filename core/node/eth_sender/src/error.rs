@@ -1,5 +1,5 @@
 use zksync_eth_client::{ContractCallError, EnrichedClientError};
-use zksync_types::web3::contract;
+use zksync_types::{web3::contract, Address};
 
 #[derive(Debug, thiserror::Error)]
 pub enum EthSenderError {
@@ -9,6 +9,8 @@ pub enum EthSenderError {
     ContractCall(#[from] ContractCallError),
     #[error("Token parsing error: {0}")]
     Parse(#[from] contract::Error),
+    #[error("Transaction sender {0:?} doesn't match any configured operator")]
+    UnknownOperator(Address),
 }
 
 impl EthSenderError {
@@ -4,13 +4,16 @@ use std::{
     sync::Arc,
 };
 
-use zksync_eth_client::{ClientError, EnrichedClientError};
 use zksync_node_fee_model::l1_gas_price::TxParamsProvider;
 use zksync_types::eth_sender::TxHistory;
 
-use crate::{abstract_l1_interface::OperatorType, EthSenderError};
+use crate::{
+    abstract_l1_interface::OperatorType,
+    fee_bumping::{min_replacement_fees, FeeBumpingStrategy},
+    EthSenderError,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) struct EthFees {
     pub(crate) base_fee_per_gas: u64,
     pub(crate) priority_fee_per_gas: u64,
@@ -31,6 +34,7 @@ pub(crate) trait EthFeesOracle: 'static + Sync + Send + fmt::Debug {
 #[derive(Debug)]
 pub(crate) struct GasAdjusterFeesOracle {
     pub gas_adjuster: Arc<dyn TxParamsProvider>,
+    pub fee_bumping_strategy: Box<dyn FeeBumpingStrategy>,
     pub max_acceptable_priority_fee_in_gwei: u64,
     pub time_in_mempool_in_l1_blocks_cap: u32,
 }
@@ -44,123 +48,39 @@ impl GasAdjusterFeesOracle {
             );
         }
     }
-    fn calculate_fees_with_blob_sidecar(
-        &self,
-        previous_sent_tx: &Option<TxHistory>,
-    ) -> Result<EthFees, EthSenderError> {
+
+    fn suggested_fees_with_blob_sidecar(&self) -> EthFees {
         let base_fee_per_gas = self.gas_adjuster.get_blob_tx_base_fee();
         self.assert_fee_is_not_zero(base_fee_per_gas, "base");
         let priority_fee_per_gas = self.gas_adjuster.get_blob_tx_priority_fee();
         let blob_base_fee_per_gas = self.gas_adjuster.get_blob_tx_blob_base_fee();
         self.assert_fee_is_not_zero(blob_base_fee_per_gas, "blob");
-        let blob_base_fee_per_gas = Some(blob_base_fee_per_gas);
 
-        if let Some(previous_sent_tx) = previous_sent_tx {
-            // for blob transactions on re-sending need to double all gas prices
-            return Ok(EthFees {
-                base_fee_per_gas: max(previous_sent_tx.base_fee_per_gas * 2, base_fee_per_gas),
-                priority_fee_per_gas: max(
-                    previous_sent_tx.priority_fee_per_gas * 2,
-                    priority_fee_per_gas,
-                ),
-                blob_base_fee_per_gas: max(
-                    previous_sent_tx.blob_base_fee_per_gas.map(|v| v * 2),
-                    blob_base_fee_per_gas,
-                ),
-                pubdata_price: None,
-            });
-        }
-        Ok(EthFees {
+        EthFees {
             base_fee_per_gas,
             priority_fee_per_gas,
-            blob_base_fee_per_gas,
+            blob_base_fee_per_gas: Some(blob_base_fee_per_gas),
             pubdata_price: None,
-        })
+        }
     }
 
-    fn calculate_fees_no_blob_sidecar(
-        &self,
-        previous_sent_tx: &Option<TxHistory>,
-        time_in_mempool_in_l1_blocks: u32,
-    ) -> Result<EthFees, EthSenderError> {
+    fn suggested_fees_no_blob_sidecar(&self, time_in_mempool_in_l1_blocks: u32) -> EthFees {
         // we cap it to not allow nearly infinite values when a tx is stuck for a long time
         let capped_time_in_mempool_in_l1_blocks = min(
             time_in_mempool_in_l1_blocks,
             self.time_in_mempool_in_l1_blocks_cap,
         );
-        let mut base_fee_per_gas = self
+        let base_fee_per_gas = self
             .gas_adjuster
             .get_base_fee(capped_time_in_mempool_in_l1_blocks);
         self.assert_fee_is_not_zero(base_fee_per_gas, "base");
-        if let Some(previous_sent_tx) = previous_sent_tx {
-            self.verify_base_fee_not_too_low_on_resend(
-                previous_sent_tx.id,
-                previous_sent_tx.base_fee_per_gas,
-                base_fee_per_gas,
-            )?;
-        }
-
-        let mut priority_fee_per_gas = self.gas_adjuster.get_priority_fee();
-
-        if let Some(previous_sent_tx) = previous_sent_tx {
-            // Increase `priority_fee_per_gas` by at least 20% to prevent "replacement transaction under-priced" error.
-            priority_fee_per_gas = max(
-                priority_fee_per_gas,
-                (previous_sent_tx.priority_fee_per_gas * 6) / 5 + 1,
-            );
-
-            // same for base_fee_per_gas but 10%
-            base_fee_per_gas = max(
-                base_fee_per_gas,
-                previous_sent_tx.base_fee_per_gas + (previous_sent_tx.base_fee_per_gas / 10) + 1,
-            );
-        }
 
-        // Extra check to prevent sending transaction will extremely high priority fee.
-        if priority_fee_per_gas > self.max_acceptable_priority_fee_in_gwei {
-            panic!(
-                "Extremely high value of priority_fee_per_gas is suggested: {}, while max acceptable is {}",
-                priority_fee_per_gas,
-                self.max_acceptable_priority_fee_in_gwei
-            );
-        }
-
-        Ok(EthFees {
+        EthFees {
             base_fee_per_gas,
+            priority_fee_per_gas: self.gas_adjuster.get_priority_fee(),
             blob_base_fee_per_gas: None,
-            priority_fee_per_gas,
             pubdata_price: None,
-        })
-    }
-
-    fn verify_base_fee_not_too_low_on_resend(
-        &self,
-        tx_id: u32,
-        previous_base_fee: u64,
-        base_fee_to_use: u64,
-    ) -> Result<(), EthSenderError> {
-        let next_block_minimal_base_fee = self.gas_adjuster.get_next_block_minimal_base_fee();
-        if base_fee_to_use < min(next_block_minimal_base_fee, previous_base_fee) {
-            // If the base fee is lower than the previous used one
-            // or is lower than the minimal possible value for the next block, sending is skipped.
-            tracing::info!(
-                "Base fee too low for resend detected for tx {}, \
-                 suggested base_fee_per_gas {:?}, \
-                 previous_base_fee {:?}, \
-                 next_block_minimal_base_fee {:?}",
-                tx_id,
-                base_fee_to_use,
-                previous_base_fee,
-                next_block_minimal_base_fee
-            );
-            let err = ClientError::Custom("base_fee_per_gas is too low".into());
-            let err = EnrichedClientError::new(err, "increase_priority_fee")
-                .with_arg("base_fee_to_use", &base_fee_to_use)
-                .with_arg("previous_base_fee", &previous_base_fee)
-                .with_arg("next_block_minimal_base_fee", &next_block_minimal_base_fee);
-            return Err(err.into());
         }
-        Ok(())
     }
 }
 
@@ -172,10 +92,38 @@ impl EthFeesOracle for GasAdjusterFeesOracle {
         operator_type: OperatorType,
     ) -> Result<EthFees, EthSenderError> {
         let has_blob_sidecar = operator_type == OperatorType::Blob;
-        if has_blob_sidecar {
-            self.calculate_fees_with_blob_sidecar(previous_sent_tx)
+        let suggested_fees = if has_blob_sidecar {
+            self.suggested_fees_with_blob_sidecar()
         } else {
-            self.calculate_fees_no_blob_sidecar(previous_sent_tx, time_in_mempool_in_l1_blocks)
+            self.suggested_fees_no_blob_sidecar(time_in_mempool_in_l1_blocks)
+        };
+
+        let mut fees = self.fee_bumping_strategy.calculate_fees(
+            suggested_fees,
+            previous_sent_tx.as_ref(),
+            self.gas_adjuster.as_ref(),
+        )?;
+
+        if let Some(previous_sent_tx) = previous_sent_tx {
+            // Make sure the transaction can replace the previous one in the mempool,
+            // otherwise L1 nodes reject it with "replacement transaction under-priced" error.
+            let min_fees = min_replacement_fees(previous_sent_tx, has_blob_sidecar);
+            fees.base_fee_per_gas = max(fees.base_fee_per_gas, min_fees.base_fee_per_gas);
+            fees.priority_fee_per_gas =
+                max(fees.priority_fee_per_gas, min_fees.priority_fee_per_gas);
+            fees.blob_base_fee_per_gas =
+                max(fees.blob_base_fee_per_gas, min_fees.blob_base_fee_per_gas);
+        }
+
+        // Extra check to prevent sending transaction will extremely high priority fee.
+        if !has_blob_sidecar && fees.priority_fee_per_gas > self.max_acceptable_priority_fee_in_gwei
+        {
+            panic!(
+                "Extremely high value of priority_fee_per_gas is suggested: {}, while max acceptable is {}",
+                fees.priority_fee_per_gas,
+                self.max_acceptable_priority_fee_in_gwei
+            );
         }
+        Ok(fees)
     }
 }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use tokio::sync::watch;
 use zksync_config::configs::eth_sender::SenderConfig;
 use zksync_contracts::BaseSystemContractsHashes;
@@ -42,6 +44,53 @@ pub struct MulticallData {
     pub protocol_version_id: ProtocolVersionId,
 }
 
+/// Addresses of dedicated operators sending specific types of aggregated operations.
+/// `None` means that operations of the corresponding type are sent by the main operator.
+///
+/// Each dedicated operator has its own nonce, so transactions of different types don't block each other
+/// (e.g., a stuck proof transaction doesn't prevent sending commit transactions).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OperatorAddresses {
+    /// Operator for `Commit` operations. If set, the node operates in the 4844 mode.
+    pub commit: Option<Address>,
+    /// Operator for `PublishProofOnchain` operations.
+    pub prove: Option<Address>,
+    /// Operator for `Execute` operations.
+    pub execute: Option<Address>,
+}
+
+impl OperatorAddresses {
+    /// Returns the dedicated operator address for the specified operation type.
+    pub fn for_action(&self, action: AggregatedActionType) -> Option<Address> {
+        match action {
+            AggregatedActionType::Commit => self.commit,
+            AggregatedActionType::PublishProofOnchain => self.prove,
+            AggregatedActionType::Execute => self.execute,
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = Address> {
+        [self.commit, self.prove, self.execute]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Checks that all dedicated operators are distinct from each other and from the main operator.
+    /// Otherwise, several nonce lanes would compete for the same account nonce.
+    pub fn validate(&self, main_operator: Address) -> anyhow::Result<()> {
+        let mut seen = vec![main_operator];
+        for address in self.iter() {
+            anyhow::ensure!(
+                !seen.contains(&address),
+                "operator address {address:?} is used for several operation types; \
+                 dedicated operators must be distinct from each other and from the main operator"
+            );
+            seen.push(address);
+        }
+        Ok(())
+    }
+}
+
 /// The component is responsible for aggregating l1 batches into eth_txs:
 /// Such as CommitBlocks, PublishProofBlocksOnchain and ExecuteBlock
 /// These eth_txs will be used as a queue for generating signed txs and send them later
@@ -55,13 +104,12 @@ pub struct EthTxAggregator {
     pub(super) state_transition_chain_contract: Address,
     functions: ZkSyncFunctions,
     base_nonce: u64,
-    base_nonce_custom_commit_sender: Option<u64>,
+    /// Base nonces for dedicated operators, keyed by the operator address.
+    base_nonces_for_dedicated_operators: HashMap<Address, u64>,
     rollup_chain_id: L2ChainId,
-    /// If set to `Some` node is operating in the 4844 mode with two operator
-    /// addresses at play: the main one and the custom address for sending commit
-    /// transactions. The `Some` then contains the address of this custom operator
-    /// address.
-    custom_commit_sender_addr: Option<Address>,
+    /// Dedicated operators for specific operation types. If the commit operator is set, the node
+    /// is operating in the 4844 mode.
+    operator_addresses: OperatorAddresses,
     pool: ConnectionPool<Core>,
     settlement_mode: SettlementMode,
     sl_chain_id: SLChainId,
@@ -83,24 +131,23 @@ impl EthTxAggregator {
         l1_multicall3_address: Address,
         state_transition_chain_contract: Address,
         rollup_chain_id: L2ChainId,
-        custom_commit_sender_addr: Option<Address>,
+        operator_addresses: OperatorAddresses,
         settlement_mode: SettlementMode,
     ) -> Self {
         let eth_client = eth_client.for_component("eth_tx_aggregator");
         let functions = ZkSyncFunctions::default();
         let base_nonce = eth_client.pending_nonce().await.unwrap().as_u64();
 
-        let base_nonce_custom_commit_sender = match custom_commit_sender_addr {
-            Some(addr) => Some(
-                (*eth_client)
-                    .as_ref()
-                    .nonce_at_for_account(addr, BlockNumber::Pending)
-                    .await
-                    .unwrap()
-                    .as_u64(),
-            ),
-            None => None,
-        };
+        let mut base_nonces_for_dedicated_operators = HashMap::new();
+        for addr in operator_addresses.iter() {
+            let nonce = (*eth_client)
+                .as_ref()
+                .nonce_at_for_account(addr, BlockNumber::Pending)
+                .await
+                .unwrap()
+                .as_u64();
+            base_nonces_for_dedicated_operators.insert(addr, nonce);
+        }
 
         let sl_chain_id = (*eth_client).as_ref().fetch_chain_id().await.unwrap();
 
@@ -113,9 +160,9 @@ impl EthTxAggregator {
             state_transition_chain_contract,
             functions,
             base_nonce,
-            base_nonce_custom_commit_sender,
+            base_nonces_for_dedicated_operators,
             rollup_chain_id,
-            custom_commit_sender_addr,
+            operator_addresses,
             pool,
             settlement_mode,
             sl_chain_id,
@@ -598,12 +645,12 @@ impl EthTxAggregator {
     ) -> Result<EthTx, EthSenderError> {
         let mut transaction = storage.start_transaction().await.unwrap();
         let op_type = aggregated_op.get_action_type();
-        // We may be using a custom sender for commit transactions, so use this
-        // var whatever it actually is: a `None` for single-addr operator or `Some`
-        // for multi-addr operator in 4844 mode.
-        let sender_addr = match (op_type, is_gateway) {
-            (AggregatedActionType::Commit, false) => self.custom_commit_sender_addr,
-            (_, _) => None,
+        // We may be using a dedicated sender for this operation type: a `None` means
+        // the main operator. Dedicated operators are not used when settling on gateway.
+        let sender_addr = if is_gateway {
+            None
+        } else {
+            self.operator_addresses.for_action(op_type)
         };
         let nonce = self.get_next_nonce(&mut transaction, sender_addr).await?;
         let encoded_aggregated_op =
@@ -661,13 +708,13 @@ impl EthTxAggregator {
             .unwrap_or(0);
         // Between server starts we can execute some txs using operator account or remove some txs from the database
         // At the start we have to consider this fact and get the max nonce.
-        Ok(if from_addr.is_none() {
-            db_nonce.max(self.base_nonce)
-        } else {
-            db_nonce.max(
-                self.base_nonce_custom_commit_sender
-                    .expect("custom base nonce is expected to be initialized; qed"),
-            )
-        })
+        let base_nonce = match from_addr {
+            None => self.base_nonce,
+            Some(addr) => *self
+                .base_nonces_for_dedicated_operators
+                .get(&addr)
+                .expect("base nonce for dedicated operator is expected to be initialized; qed"),
+        };
+        Ok(db_nonce.max(base_nonce))
     }
 }
@@ -19,6 +19,7 @@ use crate::{
         AbstractL1Interface, L1BlockNumbers, OperatorNonce, OperatorType, RealL1Interface,
     },
     eth_fees_oracle::{EthFees, EthFeesOracle, GasAdjusterFeesOracle},
+    fee_bumping::FeeBumpingStrategy,
     metrics::TransactionType,
 };
 
@@ -36,25 +37,37 @@ pub struct EthTxManager {
 }
 
 impl EthTxManager {
+    /// Creates a new manager. Besides the main operator, the manager may use dedicated operators
+    /// for commit (blob), prove and execute transactions; each operator has its own nonce lane.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         pool: ConnectionPool<Core>,
         config: SenderConfig,
         gas_adjuster: Arc<dyn TxParamsProvider>,
         ethereum_gateway: Option<Box<dyn BoundEthInterface>>,
         ethereum_gateway_blobs: Option<Box<dyn BoundEthInterface>>,
+        ethereum_gateway_prove: Option<Box<dyn BoundEthInterface>>,
+        ethereum_gateway_execute: Option<Box<dyn BoundEthInterface>>,
         l2_gateway: Option<Box<dyn BoundEthInterface>>,
     ) -> Self {
         let ethereum_gateway = ethereum_gateway.map(|eth| eth.for_component("eth_tx_manager"));
         let ethereum_gateway_blobs =
             ethereum_gateway_blobs.map(|eth| eth.for_component("eth_tx_manager"));
+        let ethereum_gateway_prove =
+            ethereum_gateway_prove.map(|eth| eth.for_component("eth_tx_manager"));
+        let ethereum_gateway_execute =
+            ethereum_gateway_execute.map(|eth| eth.for_component("eth_tx_manager"));
         let fees_oracle = GasAdjusterFeesOracle {
             gas_adjuster,
+            fee_bumping_strategy: <dyn FeeBumpingStrategy>::from_config(&config),
             max_acceptable_priority_fee_in_gwei: config.max_acceptable_priority_fee_in_gwei,
             time_in_mempool_in_l1_blocks_cap: config.time_in_mempool_in_l1_blocks_cap,
         };
         let l1_interface = Box::new(RealL1Interface {
             ethereum_gateway,
             ethereum_gateway_blobs,
+            ethereum_gateway_prove,
+            ethereum_gateway_execute,
             l2_gateway,
             wait_confirmations: config.wait_confirmations,
         });
@@ -80,6 +93,7 @@ impl EthTxManager {
         storage: &mut Connection<'_, Core>,
         op: &EthTx,
     ) -> Result<Option<ExecutedTxStatus>, EthSenderError> {
+        let operator_type = self.operator_type(op)?;
         // Checking history items, starting from most recently sent.
         for history_item in storage
             .eth_sender_dal()
@@ -92,7 +106,7 @@ impl EthTxManager {
             // which means we might miss the transaction that actually succeeded.
             match self
                 .l1_interface
-                .get_tx_status(history_item.tx_hash, self.operator_type(op))
+                .get_tx_status(history_item.tx_hash, operator_type)
                 .await
             {
                 Ok(Some(s)) => return Ok(Some(s)),
@@ -123,6 +137,7 @@ impl EthTxManager {
             .await
             .unwrap();
 
+        let operator_type = self.operator_type(tx)?;
         let EthFees {
             base_fee_per_gas,
             priority_fee_per_gas,
@@ -131,11 +146,9 @@ impl EthTxManager {
         } = self.fees_oracle.calculate_fees(
             &previous_sent_tx,
             time_in_mempool_in_l1_blocks,
-            self.operator_type(tx),
+            operator_type,
         )?;
 
-        let operator_type = self.operator_type(tx);
-
         if let Some(previous_sent_tx) = previous_sent_tx {
             METRICS.transaction_resent.inc();
             tracing::info!(
@@ -265,12 +278,20 @@ impl EthTxManager {
     }
 
     pub(crate) fn operator_address(&self, operator_type: OperatorType) -> Option<Address> {
-        if operator_type == OperatorType::Blob {
-            self.l1_interface.get_blobs_operator_account()
-        } else {
-            None
-        }
+        self.l1_interface.get_operator_account(operator_type)
     }
+
+    /// Returns the maximum number of in-flight transactions for the specified operator lane.
+    fn max_txs_in_flight(&self, operator_type: OperatorType) -> u64 {
+        let lane_limit = match operator_type {
+            OperatorType::Blob => self.config.max_commit_txs_in_flight,
+            OperatorType::Prove => self.config.max_prove_txs_in_flight,
+            OperatorType::Execute => self.config.max_execute_txs_in_flight,
+            OperatorType::NonBlob | OperatorType::Gateway => None,
+        };
+        lane_limit.unwrap_or(self.config.max_txs_in_flight)
+    }
+
     // Monitors the in-flight transactions, marks mined ones as confirmed,
     // returns the one that has to be resent (if there is one).
     pub(super) async fn monitor_inflight_transactions_single_operator(
@@ -431,14 +452,30 @@ impl EthTxManager {
         }
     }
 
-    fn operator_type(&self, tx: &EthTx) -> OperatorType {
+    fn operator_type(&self, tx: &EthTx) -> Result<OperatorType, EthSenderError> {
         if tx.is_gateway {
-            OperatorType::Gateway
-        } else if tx.from_addr.is_none() {
-            OperatorType::NonBlob
-        } else {
-            OperatorType::Blob
+            return Ok(OperatorType::Gateway);
         }
+        let Some(from_addr) = tx.from_addr else {
+            return Ok(OperatorType::NonBlob);
+        };
+        // A custom sender not matching any configured dedicated operator most probably means that operators
+        // were reconfigured while the transaction was in flight. We cannot check or resend such a transaction
+        // since we don't know which key it was signed with.
+        [
+            OperatorType::Blob,
+            OperatorType::Prove,
+            OperatorType::Execute,
+        ]
+        .into_iter()
+        .find(|&ty| self.operator_address(ty) == Some(from_addr))
+        .ok_or_else(|| {
+            tracing::error!(
+                "Sender {from_addr:?} of eth tx {} doesn't match any configured operator",
+                tx.id
+            );
+            EthSenderError::UnknownOperator(from_addr)
+        })
     }
 
     pub async fn fail_tx(
@@ -452,10 +489,14 @@ impl EthTxManager {
             .mark_failed_transaction(tx.id)
             .await
             .unwrap();
-        let failure_reason = self
-            .l1_interface
-            .failure_reason(tx_status.receipt.transaction_hash, self.operator_type(tx))
-            .await;
+        let failure_reason = match self.operator_type(tx) {
+            Ok(operator_type) => {
+                self.l1_interface
+                    .failure_reason(tx_status.receipt.transaction_hash, operator_type)
+                    .await
+            }
+            Err(_) => None,
+        };
 
         tracing::error!(
             "Eth tx failed {:?}, {:?}, failure reason {:?}",
@@ -560,8 +601,7 @@ impl EthTxManager {
             .unwrap()
             .len();
         let number_of_available_slots_for_eth_txs = self
-            .config
-            .max_txs_in_flight
+            .max_txs_in_flight(operator_type)
             .saturating_sub(number_inflight_txs as u64);
 
         if number_of_available_slots_for_eth_txs > 0 {
@@ -652,9 +692,9 @@ impl EthTxManager {
         self.assert_there_are_no_pre_gateway_txs_with_gateway_enabled(storage)
             .await;
 
-        // We can treat blob and non-blob operators independently as they have different nonces and
+        // We can treat all operators independently as they have different nonces and
         // aggregator makes sure that corresponding Commit transaction is confirmed before creating
-        // a PublishProof transaction
+        // a PublishProof transaction (and similarly for Execute transactions)
         for operator_type in self.l1_interface.supported_operator_types() {
             let l1_block_numbers = self
                 .l1_interface
@@ -671,7 +711,7 @@ impl EthTxManager {
                 .update_statuses_and_resend_if_needed(storage, l1_block_numbers, operator_type)
                 .await;
 
-            //We don't want an error in sending non-blob transactions interrupt sending blob txs
+            // We don't want an error in sending transactions for one operator to interrupt other operators
             if let Err(error) = result {
                 // Web3 API request failures can cause this,
                 // and anything more important is already properly reported.
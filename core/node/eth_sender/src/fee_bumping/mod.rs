@@ -0,0 +1,301 @@
+//! Strategies used to compute fees for L1 transactions on the first send and on resends.
+
+use std::{
+    cmp::{max, min},
+    fmt,
+};
+
+use zksync_config::configs::eth_sender::{FeeBumpingMode, SenderConfig};
+use zksync_eth_client::{ClientError, EnrichedClientError};
+use zksync_node_fee_model::l1_gas_price::TxParamsProvider;
+use zksync_types::eth_sender::TxHistory;
+
+use crate::{eth_fees_oracle::EthFees, EthSenderError};
+
+#[cfg(test)]
+mod tests;
+
+/// Strategy computing fees for an L1 transaction.
+///
+/// Strategies receive fees suggested by the gas adjuster for the current L1 state and adjust them,
+/// taking the previously sent transaction into account (if it's a resend). Regardless of the strategy,
+/// resent fees are raised to the minimum required by L1 nodes to replace a transaction in the mempool,
+/// so strategies don't need to enforce it themselves.
+pub(crate) trait FeeBumpingStrategy: 'static + Sync + Send + fmt::Debug {
+    fn calculate_fees(
+        &self,
+        suggested_fees: EthFees,
+        previous_sent_tx: Option<&TxHistory>,
+        gas_adjuster: &dyn TxParamsProvider,
+    ) -> Result<EthFees, EthSenderError>;
+}
+
+impl dyn FeeBumpingStrategy {
+    pub(crate) fn from_config(config: &SenderConfig) -> Box<Self> {
+        match config.fee_bumping_mode {
+            FeeBumpingMode::Legacy => Box::new(LegacyFeeBumping),
+            FeeBumpingMode::Linear => Box::new(LinearFeeBumping {
+                step_percent: config.fee_bump_percent,
+            }),
+            FeeBumpingMode::Exponential => Box::new(ExponentialFeeBumping {
+                step_percent: config.fee_bump_percent,
+                max_multiplier: config.max_fee_bump_multiplier,
+            }),
+            FeeBumpingMode::Percentile => Box::new(PercentileFeeBumping {
+                percentile: config.fee_bump_percentile,
+            }),
+        }
+    }
+}
+
+/// Returns the minimum fees allowed for a transaction replacing `previous_sent_tx` in the mempool.
+/// L1 nodes require fees to be increased by at least 10% for regular transactions and by 100% for blob ones.
+pub(crate) fn min_replacement_fees(
+    previous_sent_tx: &TxHistory,
+    has_blob_sidecar: bool,
+) -> EthFees {
+    let bump = |fee: u64| {
+        if has_blob_sidecar {
+            fee.saturating_mul(2)
+        } else {
+            fee.saturating_add(fee / 10).saturating_add(1)
+        }
+    };
+    EthFees {
+        base_fee_per_gas: bump(previous_sent_tx.base_fee_per_gas),
+        priority_fee_per_gas: bump(previous_sent_tx.priority_fee_per_gas),
+        blob_base_fee_per_gas: previous_sent_tx.blob_base_fee_per_gas.map(bump),
+        pubdata_price: None,
+    }
+}
+
+fn scale(fee: u64, percent: u32) -> u64 {
+    (u128::from(fee) * u128::from(percent) / 100)
+        .try_into()
+        .unwrap_or(u64::MAX)
+}
+
+/// Fee bumping used before strategies became configurable. Blob transactions get their fees doubled
+/// on resend; for other transactions, base fee is increased by 10% and priority fee by 20%.
+#[derive(Debug)]
+pub(crate) struct LegacyFeeBumping;
+
+impl LegacyFeeBumping {
+    fn verify_base_fee_not_too_low_on_resend(
+        gas_adjuster: &dyn TxParamsProvider,
+        tx_id: u32,
+        previous_base_fee: u64,
+        base_fee_to_use: u64,
+    ) -> Result<(), EthSenderError> {
+        let next_block_minimal_base_fee = gas_adjuster.get_next_block_minimal_base_fee();
+        if base_fee_to_use < min(next_block_minimal_base_fee, previous_base_fee) {
+            // If the base fee is lower than the previous used one
+            // or is lower than the minimal possible value for the next block, sending is skipped.
+            tracing::info!(
+                "Base fee too low for resend detected for tx {}, \
+                 suggested base_fee_per_gas {:?}, \
+                 previous_base_fee {:?}, \
+                 next_block_minimal_base_fee {:?}",
+                tx_id,
+                base_fee_to_use,
+                previous_base_fee,
+                next_block_minimal_base_fee
+            );
+            let err = ClientError::Custom("base_fee_per_gas is too low".into());
+            let err = EnrichedClientError::new(err, "increase_priority_fee")
+                .with_arg("base_fee_to_use", &base_fee_to_use)
+                .with_arg("previous_base_fee", &previous_base_fee)
+                .with_arg("next_block_minimal_base_fee", &next_block_minimal_base_fee);
+            return Err(err.into());
+        }
+        Ok(())
+    }
+}
+
+impl FeeBumpingStrategy for LegacyFeeBumping {
+    fn calculate_fees(
+        &self,
+        suggested_fees: EthFees,
+        previous_sent_tx: Option<&TxHistory>,
+        gas_adjuster: &dyn TxParamsProvider,
+    ) -> Result<EthFees, EthSenderError> {
+        let Some(previous_sent_tx) = previous_sent_tx else {
+            return Ok(suggested_fees);
+        };
+
+        if suggested_fees.blob_base_fee_per_gas.is_some() {
+            // for blob transactions on re-sending need to double all gas prices
+            return Ok(EthFees {
+                base_fee_per_gas: max(
+                    previous_sent_tx.base_fee_per_gas * 2,
+                    suggested_fees.base_fee_per_gas,
+                ),
+                priority_fee_per_gas: max(
+                    previous_sent_tx.priority_fee_per_gas * 2,
+                    suggested_fees.priority_fee_per_gas,
+                ),
+                blob_base_fee_per_gas: max(
+                    previous_sent_tx.blob_base_fee_per_gas.map(|v| v * 2),
+                    suggested_fees.blob_base_fee_per_gas,
+                ),
+                pubdata_price: None,
+            });
+        }
+
+        Self::verify_base_fee_not_too_low_on_resend(
+            gas_adjuster,
+            previous_sent_tx.id,
+            previous_sent_tx.base_fee_per_gas,
+            suggested_fees.base_fee_per_gas,
+        )?;
+
+        Ok(EthFees {
+            // Increase `priority_fee_per_gas` by at least 20% to prevent "replacement transaction under-priced" error.
+            priority_fee_per_gas: max(
+                suggested_fees.priority_fee_per_gas,
+                (previous_sent_tx.priority_fee_per_gas * 6) / 5 + 1,
+            ),
+            // same for base_fee_per_gas but 10%
+            base_fee_per_gas: max(
+                suggested_fees.base_fee_per_gas,
+                previous_sent_tx.base_fee_per_gas + (previous_sent_tx.base_fee_per_gas / 10) + 1,
+            ),
+            ..suggested_fees
+        })
+    }
+}
+
+/// Increases previously used fees by a fixed fraction of the suggested fees on each resend.
+#[derive(Debug)]
+pub(crate) struct LinearFeeBumping {
+    pub step_percent: u32,
+}
+
+impl FeeBumpingStrategy for LinearFeeBumping {
+    fn calculate_fees(
+        &self,
+        suggested_fees: EthFees,
+        previous_sent_tx: Option<&TxHistory>,
+        _gas_adjuster: &dyn TxParamsProvider,
+    ) -> Result<EthFees, EthSenderError> {
+        let Some(previous_sent_tx) = previous_sent_tx else {
+            return Ok(suggested_fees);
+        };
+        let bump = |previous: u64, suggested: u64| {
+            max(
+                suggested,
+                previous.saturating_add(scale(suggested, self.step_percent)),
+            )
+        };
+
+        Ok(EthFees {
+            base_fee_per_gas: bump(
+                previous_sent_tx.base_fee_per_gas,
+                suggested_fees.base_fee_per_gas,
+            ),
+            priority_fee_per_gas: bump(
+                previous_sent_tx.priority_fee_per_gas,
+                suggested_fees.priority_fee_per_gas,
+            ),
+            blob_base_fee_per_gas: suggested_fees.blob_base_fee_per_gas.map(|suggested| {
+                bump(
+                    previous_sent_tx.blob_base_fee_per_gas.unwrap_or(0),
+                    suggested,
+                )
+            }),
+            pubdata_price: None,
+        })
+    }
+}
+
+/// Multiplies previously used fees by a fixed factor on each resend. Fees are capped
+/// at `max_multiplier` times the suggested fees; once the cap doesn't allow replacing
+/// the previous transaction, resends are skipped.
+#[derive(Debug)]
+pub(crate) struct ExponentialFeeBumping {
+    pub step_percent: u32,
+    pub max_multiplier: u32,
+}
+
+impl FeeBumpingStrategy for ExponentialFeeBumping {
+    fn calculate_fees(
+        &self,
+        suggested_fees: EthFees,
+        previous_sent_tx: Option<&TxHistory>,
+        _gas_adjuster: &dyn TxParamsProvider,
+    ) -> Result<EthFees, EthSenderError> {
+        let Some(previous_sent_tx) = previous_sent_tx else {
+            return Ok(suggested_fees);
+        };
+        let has_blob_sidecar = suggested_fees.blob_base_fee_per_gas.is_some();
+        let min_fees = min_replacement_fees(previous_sent_tx, has_blob_sidecar);
+        let max_percent = self.max_multiplier.saturating_mul(100);
+
+        let bump = |previous: u64, suggested: u64, min_fee: u64, fee_type: &'static str| {
+            let cap = scale(suggested, max_percent);
+            if min_fee > cap {
+                tracing::info!(
+                    "Fee bump cap reached for tx {}: {fee_type} fee cannot exceed {cap}, \
+                     while at least {min_fee} is required to replace the previous transaction",
+                    previous_sent_tx.id
+                );
+                let err = ClientError::Custom(format!("{fee_type} fee bump cap reached"));
+                let err = EnrichedClientError::new(err, "bump_fees")
+                    .with_arg("cap", &cap)
+                    .with_arg("min_fee", &min_fee);
+                return Err(EthSenderError::from(err));
+            }
+            let bumped = scale(previous, self.step_percent.saturating_add(100));
+            Ok(min(max(suggested, bumped), cap))
+        };
+
+        Ok(EthFees {
+            base_fee_per_gas: bump(
+                previous_sent_tx.base_fee_per_gas,
+                suggested_fees.base_fee_per_gas,
+                min_fees.base_fee_per_gas,
+                "base",
+            )?,
+            priority_fee_per_gas: bump(
+                previous_sent_tx.priority_fee_per_gas,
+                suggested_fees.priority_fee_per_gas,
+                min_fees.priority_fee_per_gas,
+                "priority",
+            )?,
+            blob_base_fee_per_gas: suggested_fees
+                .blob_base_fee_per_gas
+                .map(|suggested| {
+                    bump(
+                        previous_sent_tx.blob_base_fee_per_gas.unwrap_or(0),
+                        suggested,
+                        min_fees.blob_base_fee_per_gas.unwrap_or(0),
+                        "blob",
+                    )
+                })
+                .transpose()?,
+            pubdata_price: None,
+        })
+    }
+}
+
+/// Uses a percentile of base fees over recent L1 blocks as the base fee if it exceeds the suggested one.
+/// Compared to the median used by the gas adjuster, high percentiles react to fee spikes faster.
+#[derive(Debug)]
+pub(crate) struct PercentileFeeBumping {
+    pub percentile: u32,
+}
+
+impl FeeBumpingStrategy for PercentileFeeBumping {
+    fn calculate_fees(
+        &self,
+        suggested_fees: EthFees,
+        _previous_sent_tx: Option<&TxHistory>,
+        gas_adjuster: &dyn TxParamsProvider,
+    ) -> Result<EthFees, EthSenderError> {
+        let percentile_base_fee = gas_adjuster.get_base_fee_percentile(self.percentile);
+        Ok(EthFees {
+            base_fee_per_gas: max(suggested_fees.base_fee_per_gas, percentile_base_fee),
+            ..suggested_fees
+        })
+    }
+}
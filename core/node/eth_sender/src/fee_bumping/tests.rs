@@ -0,0 +1,376 @@
+//! Tests for fee bumping strategies, including a simulation harness replaying recorded L1 fee histories
+//! through `GasAdjuster`.
+
+use std::sync::Arc;
+
+use test_casing::{test_casing, Product};
+use zksync_config::{
+    configs::eth_sender::{FeeBumpingMode, SenderConfig},
+    EthConfig, GasAdjusterConfig,
+};
+use zksync_eth_client::{clients::MockSettlementLayer, BaseFees};
+use zksync_node_fee_model::l1_gas_price::{GasAdjuster, GasAdjusterClient, TxParamsProvider};
+use zksync_types::{
+    commitment::L1BatchCommitmentMode, eth_sender::TxHistory, pubdata_da::PubdataSendingMode, H256,
+};
+
+use super::*;
+use crate::{
+    abstract_l1_interface::OperatorType,
+    eth_fees_oracle::{EthFeesOracle, GasAdjusterFeesOracle},
+};
+
+const GWEI: u64 = 1_000_000_000;
+const MAX_BASE_FEE_SAMPLES: usize = 10;
+const PRIORITY_FEE: u64 = GWEI;
+const ALL_MODES: [FeeBumpingMode; 4] = [
+    FeeBumpingMode::Legacy,
+    FeeBumpingMode::Linear,
+    FeeBumpingMode::Exponential,
+    FeeBumpingMode::Percentile,
+];
+
+fn sender_config(mode: FeeBumpingMode) -> SenderConfig {
+    SenderConfig {
+        fee_bumping_mode: mode,
+        fee_bump_percent: 25,
+        max_fee_bump_multiplier: 4,
+        fee_bump_percentile: 90,
+        ..EthConfig::for_tests().sender.unwrap()
+    }
+}
+
+fn previous_tx(fees: EthFees) -> TxHistory {
+    TxHistory {
+        id: 1,
+        eth_tx_id: 1,
+        base_fee_per_gas: fees.base_fee_per_gas,
+        priority_fee_per_gas: fees.priority_fee_per_gas,
+        blob_base_fee_per_gas: fees.blob_base_fee_per_gas,
+        tx_hash: H256::zero(),
+        signed_raw_tx: vec![],
+        sent_at_block: None,
+    }
+}
+
+/// Fee history where the base fee stays at 10 gwei, then grows by the maximum possible 12.5% per block
+/// for 20 blocks, and then stays at the peak value.
+fn spike_history() -> Vec<u64> {
+    let mut history = vec![10 * GWEI; 20];
+    let mut fee = 10 * GWEI;
+    for _ in 0..20 {
+        fee = fee * 9 / 8;
+        history.push(fee);
+    }
+    history.extend([fee; 40]);
+    history
+}
+
+/// Fee history of a cheap settlement layer with the base fee slightly fluctuating around 1 gwei.
+fn flat_history() -> Vec<u64> {
+    (0..80).map(|i| GWEI + (i % 3) * GWEI / 100).collect()
+}
+
+/// Replays a fee history block by block, sending a transaction and resending it until it's included.
+struct FeeSimulation {
+    client: MockSettlementLayer,
+    gas_adjuster: Arc<GasAdjuster>,
+    oracle: GasAdjusterFeesOracle,
+    history: Vec<u64>,
+}
+
+#[derive(Debug)]
+struct SimulationOutcome {
+    /// Number of L1 blocks between the first send and inclusion; `None` if the transaction was never included.
+    blocks_to_inclusion: Option<usize>,
+    /// Number of times the transaction was sent, including the first send.
+    sends: usize,
+    /// Base fee of the transaction that was eventually included (or last sent).
+    last_base_fee_per_gas: u64,
+    /// Suggested base fees observed by the oracle at each send.
+    max_suggested_base_fee_per_gas: u64,
+}
+
+impl FeeSimulation {
+    async fn new(mode: FeeBumpingMode, history: Vec<u64>) -> Self {
+        let config = sender_config(mode);
+        let fee_history = history
+            .iter()
+            .map(|&base_fee_per_gas| BaseFees {
+                base_fee_per_gas,
+                base_fee_per_blob_gas: 1.into(),
+                l2_pubdata_price: 0.into(),
+            })
+            .collect();
+        let client = MockSettlementLayer::builder()
+            .with_fee_history(fee_history)
+            .build();
+        // Start with the full window of samples available to the gas adjuster.
+        client.advance_block_number(MAX_BASE_FEE_SAMPLES as u64 + 1);
+
+        let gas_adjuster = GasAdjuster::new(
+            GasAdjusterClient::from_l1(Box::new(client.clone().into_client())),
+            GasAdjusterConfig {
+                default_priority_fee_per_gas: PRIORITY_FEE,
+                max_base_fee_samples: MAX_BASE_FEE_SAMPLES,
+                pricing_formula_parameter_a: 1.1,
+                pricing_formula_parameter_b: 1.001,
+                ..EthConfig::for_tests().gas_adjuster.unwrap()
+            },
+            PubdataSendingMode::Calldata,
+            L1BatchCommitmentMode::Rollup,
+        )
+        .await
+        .unwrap();
+        let gas_adjuster = Arc::new(gas_adjuster);
+
+        let oracle = GasAdjusterFeesOracle {
+            gas_adjuster: gas_adjuster.clone(),
+            fee_bumping_strategy: <dyn FeeBumpingStrategy>::from_config(&config),
+            max_acceptable_priority_fee_in_gwei: config.max_acceptable_priority_fee_in_gwei,
+            time_in_mempool_in_l1_blocks_cap: config.time_in_mempool_in_l1_blocks_cap,
+        };
+        Self {
+            client,
+            gas_adjuster,
+            oracle,
+            history,
+        }
+    }
+
+    fn current_block(&self) -> usize {
+        self.client.advance_block_number(0) as usize
+    }
+
+    async fn advance_block(&self) {
+        self.client.advance_block_number(1);
+        self.gas_adjuster.keep_updated().await.unwrap();
+    }
+
+    /// Sends a transaction once `first_send_block` is reached and resends it each block until it's included
+    /// (i.e., its base fee covers the base fee of the next block).
+    async fn run(self, first_send_block: usize) -> SimulationOutcome {
+        while self.current_block() < first_send_block {
+            self.advance_block().await;
+        }
+
+        let mut previous_sent_tx: Option<TxHistory> = None;
+        let mut sends = 0;
+        let mut max_suggested_base_fee_per_gas = 0;
+        let mut time_in_mempool = 0;
+        while self.current_block() + 1 < self.history.len() {
+            max_suggested_base_fee_per_gas =
+                max_suggested_base_fee_per_gas.max(self.gas_adjuster.get_base_fee(time_in_mempool));
+            match self.oracle.calculate_fees(
+                &previous_sent_tx,
+                time_in_mempool,
+                OperatorType::NonBlob,
+            ) {
+                Ok(fees) => {
+                    previous_sent_tx = Some(previous_tx(fees));
+                    sends += 1;
+                }
+                Err(err) => assert!(previous_sent_tx.is_some(), "{err}"),
+            }
+
+            let next_block_base_fee = self.history[self.current_block() + 1];
+            let last_base_fee_per_gas = previous_sent_tx.as_ref().unwrap().base_fee_per_gas;
+            if last_base_fee_per_gas >= next_block_base_fee {
+                return SimulationOutcome {
+                    blocks_to_inclusion: Some(time_in_mempool as usize + 1),
+                    sends,
+                    last_base_fee_per_gas,
+                    max_suggested_base_fee_per_gas,
+                };
+            }
+            self.advance_block().await;
+            time_in_mempool += 1;
+        }
+
+        SimulationOutcome {
+            blocks_to_inclusion: None,
+            sends,
+            last_base_fee_per_gas: previous_sent_tx.map_or(0, |tx| tx.base_fee_per_gas),
+            max_suggested_base_fee_per_gas,
+        }
+    }
+}
+
+async fn simulate(
+    mode: FeeBumpingMode,
+    history: Vec<u64>,
+    first_send_block: usize,
+) -> SimulationOutcome {
+    let outcome = FeeSimulation::new(mode, history)
+        .await
+        .run(first_send_block)
+        .await;
+    tracing::info!("Simulation outcome for {mode:?}: {outcome:?}");
+    outcome
+}
+
+#[test]
+fn min_replacement_fees_are_computed_correctly() {
+    let fees = EthFees {
+        base_fee_per_gas: 100,
+        priority_fee_per_gas: 10,
+        blob_base_fee_per_gas: None,
+        pubdata_price: None,
+    };
+    let min_fees = min_replacement_fees(&previous_tx(fees), false);
+    assert_eq!(min_fees.base_fee_per_gas, 111);
+    assert_eq!(min_fees.priority_fee_per_gas, 12);
+    assert_eq!(min_fees.blob_base_fee_per_gas, None);
+
+    let fees = EthFees {
+        blob_base_fee_per_gas: Some(5),
+        ..fees
+    };
+    let min_fees = min_replacement_fees(&previous_tx(fees), true);
+    assert_eq!(min_fees.base_fee_per_gas, 200);
+    assert_eq!(min_fees.priority_fee_per_gas, 20);
+    assert_eq!(min_fees.blob_base_fee_per_gas, Some(10));
+
+    let fees = EthFees {
+        base_fee_per_gas: u64::MAX,
+        priority_fee_per_gas: u64::MAX - 1,
+        blob_base_fee_per_gas: Some(u64::MAX),
+        pubdata_price: None,
+    };
+    for has_blob_sidecar in [false, true] {
+        let min_fees = min_replacement_fees(&previous_tx(fees), has_blob_sidecar);
+        assert_eq!(min_fees.base_fee_per_gas, u64::MAX);
+        assert_eq!(min_fees.priority_fee_per_gas, u64::MAX);
+        assert_eq!(min_fees.blob_base_fee_per_gas, Some(u64::MAX));
+    }
+}
+
+#[test_casing(8, Product((ALL_MODES, [false, true])))]
+#[tokio::test]
+async fn resent_fees_can_replace_previous_tx(mode: FeeBumpingMode, has_blob_sidecar: bool) {
+    let operator_type = if has_blob_sidecar {
+        OperatorType::Blob
+    } else {
+        OperatorType::NonBlob
+    };
+    let simulation = FeeSimulation::new(mode, flat_history()).await;
+    let first_fees = simulation
+        .oracle
+        .calculate_fees(&None, 0, operator_type)
+        .unwrap();
+    assert_eq!(first_fees.blob_base_fee_per_gas.is_some(), has_blob_sidecar);
+
+    let previous_sent_tx = Some(previous_tx(first_fees));
+    let resent_fees = simulation
+        .oracle
+        .calculate_fees(&previous_sent_tx, 1, operator_type)
+        .unwrap();
+    let min_fees = min_replacement_fees(previous_sent_tx.as_ref().unwrap(), has_blob_sidecar);
+    assert!(resent_fees.base_fee_per_gas >= min_fees.base_fee_per_gas);
+    assert!(resent_fees.priority_fee_per_gas >= min_fees.priority_fee_per_gas);
+    assert!(resent_fees.blob_base_fee_per_gas >= min_fees.blob_base_fee_per_gas);
+}
+
+#[tokio::test]
+async fn linear_strategy_adds_fixed_step() {
+    let simulation = FeeSimulation::new(FeeBumpingMode::Linear, flat_history()).await;
+    let suggested_base_fee = simulation.gas_adjuster.get_base_fee(0);
+    let mut previous_sent_tx = None;
+    let mut base_fees = vec![];
+    for _ in 0..4 {
+        let fees = simulation
+            .oracle
+            .calculate_fees(&previous_sent_tx, 0, OperatorType::NonBlob)
+            .unwrap();
+        base_fees.push(fees.base_fee_per_gas);
+        previous_sent_tx = Some(previous_tx(fees));
+    }
+
+    let step = suggested_base_fee / 4;
+    assert_eq!(base_fees[0], suggested_base_fee);
+    for window in base_fees.windows(2) {
+        assert_eq!(window[1] - window[0], step, "{base_fees:?}");
+    }
+}
+
+#[tokio::test]
+async fn exponential_strategy_respects_cap() {
+    let simulation = FeeSimulation::new(FeeBumpingMode::Exponential, flat_history()).await;
+    let suggested_base_fee = simulation.gas_adjuster.get_base_fee(0);
+    let mut previous_sent_tx = None;
+    let mut skipped = false;
+    for _ in 0..20 {
+        match simulation
+            .oracle
+            .calculate_fees(&previous_sent_tx, 0, OperatorType::NonBlob)
+        {
+            Ok(fees) => {
+                assert!(fees.base_fee_per_gas <= suggested_base_fee * 4);
+                previous_sent_tx = Some(previous_tx(fees));
+            }
+            Err(_) => {
+                skipped = true;
+                break;
+            }
+        }
+    }
+    assert!(skipped, "resends must be skipped once the cap is reached");
+}
+
+#[tokio::test]
+async fn percentile_strategy_uses_recent_base_fees() {
+    let history = spike_history();
+    let simulation = FeeSimulation::new(FeeBumpingMode::Percentile, history).await;
+    // Move to the middle of the spike, so that the median lags behind the recent base fees.
+    while simulation.current_block() < 30 {
+        simulation.advance_block().await;
+    }
+
+    let fees = simulation
+        .oracle
+        .calculate_fees(&None, 0, OperatorType::NonBlob)
+        .unwrap();
+    let percentile_base_fee = simulation.gas_adjuster.get_base_fee_percentile(90);
+    assert!(percentile_base_fee > simulation.gas_adjuster.get_base_fee(0));
+    assert_eq!(fees.base_fee_per_gas, percentile_base_fee);
+}
+
+#[test_casing(4, ALL_MODES)]
+#[tokio::test]
+async fn tx_is_included_on_cheap_settlement_layer(mode: FeeBumpingMode) {
+    let outcome = simulate(mode, flat_history(), 20).await;
+    assert_eq!(outcome.blocks_to_inclusion, Some(1), "{outcome:?}");
+    assert_eq!(outcome.sends, 1);
+}
+
+#[test_casing(4, ALL_MODES)]
+#[tokio::test]
+async fn tx_is_included_during_fee_spike(mode: FeeBumpingMode) {
+    // The transaction is first sent right at the start of the spike.
+    let outcome = simulate(mode, spike_history(), 20).await;
+    assert!(outcome.blocks_to_inclusion.is_some(), "{outcome:?}");
+}
+
+#[tokio::test]
+async fn aggressive_strategies_get_tx_included_faster_during_fee_spike() {
+    let legacy = simulate(FeeBumpingMode::Legacy, spike_history(), 20).await;
+    let legacy_blocks = legacy.blocks_to_inclusion.unwrap();
+
+    for mode in [FeeBumpingMode::Exponential, FeeBumpingMode::Percentile] {
+        let outcome = simulate(mode, spike_history(), 20).await;
+        let blocks = outcome.blocks_to_inclusion.unwrap();
+        assert!(
+            blocks < legacy_blocks,
+            "{mode:?} strategy ({outcome:?}) is not faster than legacy one ({legacy:?})"
+        );
+    }
+}
+
+#[tokio::test]
+async fn exponential_strategy_does_not_overpay_beyond_cap() {
+    let outcome = simulate(FeeBumpingMode::Exponential, spike_history(), 20).await;
+    assert!(
+        outcome.last_base_fee_per_gas <= outcome.max_suggested_base_fee_per_gas * 4,
+        "{outcome:?}"
+    );
+}
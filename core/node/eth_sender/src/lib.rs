@@ -11,6 +11,7 @@ mod zksync_functions;
 mod abstract_l1_interface;
 
 mod eth_fees_oracle;
+mod fee_bumping;
 #[cfg(test)]
 mod tests;
 
@@ -18,6 +19,8 @@ mod tests;
 mod tester;
 
 pub use self::{
-    aggregator::Aggregator, error::EthSenderError, eth_tx_aggregator::EthTxAggregator,
+    aggregator::Aggregator,
+    error::EthSenderError,
+    eth_tx_aggregator::{EthTxAggregator, OperatorAddresses},
     eth_tx_manager::EthTxManager,
 };
@@ -20,10 +20,12 @@ use crate::{
     abstract_l1_interface::{L1BlockNumbers, OperatorType},
     aggregated_operations::AggregatedOperation,
     tests::{default_l1_batch_metadata, l1_batch_with_metadata},
-    Aggregator, EthTxAggregator, EthTxManager,
+    Aggregator, EthTxAggregator, EthTxManager, OperatorAddresses,
 };
 
 pub(super) const STATE_TRANSITION_CONTRACT_ADDRESS: Address = Address::repeat_byte(0xa0);
+/// Account of the dedicated prove operator; must differ from the default mock sender account.
+pub(super) const PROVE_OPERATOR_ADDRESS: Address = Address::repeat_byte(0x33);
 
 // Alias to conveniently call static methods of `ETHSender`.
 type MockEthTxManager = EthTxManager;
@@ -113,11 +115,17 @@ pub(crate) struct EthSenderTester {
     pub conn: ConnectionPool<Core>,
     pub gateway: Box<MockSettlementLayer>,
     pub gateway_blobs: Box<MockSettlementLayer>,
+    /// Client of the dedicated prove operator. Only used after calling [`Self::use_dedicated_prove_operator()`].
+    pub gateway_prove: Box<MockSettlementLayer>,
     pub l2_gateway: Box<MockSettlementLayer>,
     pub manager: MockEthTxManager,
     pub aggregator: EthTxAggregator,
     pub gas_adjuster: Arc<GasAdjuster>,
     pub pubdata_sending_mode: PubdataSendingMode,
+    aggregator_config: SenderConfig,
+    aggregator_operate_4844_mode: bool,
+    commitment_mode: L1BatchCommitmentMode,
+    operator_addresses: OperatorAddresses,
     next_l1_batch_number_to_seal: L1BatchNumber,
     next_l1_batch_number_to_commit: L1BatchNumber,
     next_l1_batch_number_to_prove: L1BatchNumber,
@@ -208,7 +216,7 @@ impl EthSenderTester {
                     l2_pubdata_price: 0.into(),
                 })
                 .take(Self::WAIT_CONFIRMATIONS as usize)
-                .chain(history)
+                .chain(history.clone())
                 .collect(),
             )
             .with_non_ordering_confirmation(non_ordering_confirmations)
@@ -220,6 +228,23 @@ impl EthSenderTester {
         gateway_blobs.advance_block_number(Self::WAIT_CONFIRMATIONS);
         let gateway_blobs = Box::new(gateway_blobs);
 
+        let gateway_prove = MockSettlementLayer::builder()
+            .with_fee_history(
+                std::iter::repeat_with(|| BaseFees {
+                    base_fee_per_gas: 1,
+                    base_fee_per_blob_gas: 1.into(),
+                    l2_pubdata_price: 0.into(),
+                })
+                .take(Self::WAIT_CONFIRMATIONS as usize)
+                .chain(history)
+                .collect(),
+            )
+            .with_non_ordering_confirmation(non_ordering_confirmations)
+            .with_sender_account(PROVE_OPERATOR_ADDRESS)
+            .build();
+        gateway_prove.advance_block_number(Self::WAIT_CONFIRMATIONS);
+        let gateway_prove = Box::new(gateway_prove);
+
         let gas_adjuster = Arc::new(
             GasAdjuster::new(
                 GasAdjusterClient::from_l1(Box::new(gateway.clone().into_client())),
@@ -238,35 +263,24 @@ impl EthSenderTester {
 
         let eth_sender = eth_sender_config.sender.clone().unwrap();
 
-        let custom_commit_sender_addr =
-            if aggregator_operate_4844_mode && commitment_mode == L1BatchCommitmentMode::Rollup {
+        let operator_addresses = OperatorAddresses {
+            commit: if aggregator_operate_4844_mode
+                && commitment_mode == L1BatchCommitmentMode::Rollup
+            {
                 Some(gateway_blobs.sender_account())
             } else {
                 None
-            };
+            },
+            ..OperatorAddresses::default()
+        };
 
-        let aggregator = EthTxAggregator::new(
+        let aggregator = Self::create_aggregator(
             connection_pool.clone(),
-            SenderConfig {
-                proof_sending_mode: ProofSendingMode::SkipEveryProof,
-                pubdata_sending_mode,
-                ..eth_sender.clone()
-            },
-            // Aggregator - unused
-            Aggregator::new(
-                aggregator_config.clone(),
-                MockObjectStore::arc(),
-                aggregator_operate_4844_mode,
-                commitment_mode,
-            ),
+            aggregator_config.clone(),
+            aggregator_operate_4844_mode,
+            commitment_mode,
             gateway.clone(),
-            // ZKsync contract address
-            Address::random(),
-            contracts_config.l1_multicall3_addr,
-            STATE_TRANSITION_CONTRACT_ADDRESS,
-            Default::default(),
-            custom_commit_sender_addr,
-            SettlementMode::SettlesToL1,
+            operator_addresses,
         )
         .await;
 
@@ -277,6 +291,8 @@ impl EthSenderTester {
             Some(gateway.clone()),
             Some(gateway_blobs.clone()),
             None,
+            None,
+            None,
         );
 
         let connection_pool_clone = connection_pool.clone();
@@ -290,12 +306,17 @@ impl EthSenderTester {
         Self {
             gateway,
             gateway_blobs,
+            gateway_prove,
             l2_gateway,
             manager,
             aggregator,
             gas_adjuster,
             conn: connection_pool,
             pubdata_sending_mode,
+            aggregator_config,
+            aggregator_operate_4844_mode,
+            commitment_mode,
+            operator_addresses,
             next_l1_batch_number_to_seal: L1BatchNumber(0),
             next_l1_batch_number_to_commit: L1BatchNumber(1),
             next_l1_batch_number_to_execute: L1BatchNumber(1),
@@ -305,6 +326,66 @@ impl EthSenderTester {
         }
     }
 
+    async fn create_aggregator(
+        pool: ConnectionPool<Core>,
+        aggregator_config: SenderConfig,
+        aggregator_operate_4844_mode: bool,
+        commitment_mode: L1BatchCommitmentMode,
+        gateway: Box<MockSettlementLayer>,
+        operator_addresses: OperatorAddresses,
+    ) -> EthTxAggregator {
+        EthTxAggregator::new(
+            pool,
+            SenderConfig {
+                proof_sending_mode: ProofSendingMode::SkipEveryProof,
+                ..aggregator_config.clone()
+            },
+            // Aggregator - unused
+            Aggregator::new(
+                aggregator_config.clone(),
+                MockObjectStore::arc(),
+                aggregator_operate_4844_mode,
+                commitment_mode,
+            ),
+            gateway,
+            // ZKsync contract address
+            Address::random(),
+            ContractsConfig::for_tests().l1_multicall3_addr,
+            STATE_TRANSITION_CONTRACT_ADDRESS,
+            Default::default(),
+            operator_addresses,
+            SettlementMode::SettlesToL1,
+        )
+        .await
+    }
+
+    /// Switches to sending proofs from a dedicated operator (`gateway_prove`) with its own nonce lane.
+    pub async fn use_dedicated_prove_operator(&mut self, max_prove_txs_in_flight: Option<u64>) {
+        self.operator_addresses.prove = Some(self.gateway_prove.sender_account());
+        self.aggregator = Self::create_aggregator(
+            self.conn.clone(),
+            self.aggregator_config.clone(),
+            self.aggregator_operate_4844_mode,
+            self.commitment_mode,
+            self.gateway.clone(),
+            self.operator_addresses,
+        )
+        .await;
+        self.manager = EthTxManager::new(
+            self.conn.clone(),
+            SenderConfig {
+                max_prove_txs_in_flight,
+                ..EthConfig::for_tests().sender.unwrap()
+            },
+            self.gas_adjuster.clone(),
+            Some(self.gateway.clone()),
+            Some(self.gateway_blobs.clone()),
+            Some(self.gateway_prove.clone()),
+            None,
+            None,
+        );
+    }
+
     pub fn switch_to_using_gateway(&mut self) {
         self.manager = EthTxManager::new(
             self.conn.clone(),
@@ -312,6 +393,8 @@ impl EthSenderTester {
             self.gas_adjuster.clone(),
             None,
             None,
+            None,
+            None,
             Some(self.l2_gateway.clone()),
         );
         self.is_l2 = true;
@@ -383,19 +466,45 @@ impl EthSenderTester {
             .await
             .unwrap();
         if !self.is_l2 {
-            let (gateway, other) = if tx.blob_base_fee_per_gas.is_some() {
-                (self.gateway_blobs.as_ref(), self.gateway.as_ref())
+            let operator_type = if tx.blob_base_fee_per_gas.is_some() {
+                OperatorType::Blob
+            } else if operation_type == AggregatedActionType::PublishProofOnchain
+                && self.operator_addresses.prove.is_some()
+            {
+                OperatorType::Prove
             } else {
-                (self.gateway.as_ref(), self.gateway_blobs.as_ref())
+                OperatorType::NonBlob
             };
-            gateway.execute_tx(tx.tx_hash, success, confirmations);
-            other.advance_block_number(confirmations);
+            self.execute_on_l1(operator_type, tx.tx_hash, success, confirmations);
         } else {
             self.l2_gateway
                 .execute_tx(tx.tx_hash, success, confirmations);
         }
     }
 
+    /// Executes a transaction on the L1 mock corresponding to `operator_type`, advancing other L1 mocks
+    /// by the same number of blocks.
+    fn execute_on_l1(
+        &self,
+        operator_type: OperatorType,
+        tx_hash: H256,
+        success: bool,
+        confirmations: u64,
+    ) {
+        let l1_mocks = [
+            (OperatorType::NonBlob, &self.gateway),
+            (OperatorType::Blob, &self.gateway_blobs),
+            (OperatorType::Prove, &self.gateway_prove),
+        ];
+        for (mock_operator_type, mock) in l1_mocks {
+            if mock_operator_type == operator_type {
+                mock.execute_tx(tx_hash, success, confirmations);
+            } else {
+                mock.advance_block_number(confirmations);
+            }
+        }
+    }
+
     pub async fn seal_l1_batch(&mut self) -> L1BatchHeader {
         let header = self
             .insert_l1_batch(self.next_l1_batch_number_to_seal)
@@ -453,17 +562,20 @@ impl EthSenderTester {
     pub async fn run_eth_sender_tx_manager_iteration_after_n_blocks(&mut self, n: u64) {
         self.gateway.advance_block_number(n);
         self.gateway_blobs.advance_block_number(n);
+        self.gateway_prove.advance_block_number(n);
         self.l2_gateway.advance_block_number(n);
-        let tx_sent_before = self.gateway.sent_tx_count()
-            + self.gateway_blobs.sent_tx_count()
-            + self.l2_gateway.sent_tx_count();
+        let tx_sent_before = self.sent_tx_count();
         self.manager
             .loop_iteration(&mut self.conn.connection().await.unwrap())
             .await;
-        self.tx_sent_in_last_iteration_count = (self.gateway.sent_tx_count()
+        self.tx_sent_in_last_iteration_count = self.sent_tx_count() - tx_sent_before;
+    }
+
+    fn sent_tx_count(&self) -> usize {
+        self.gateway.sent_tx_count()
             + self.gateway_blobs.sent_tx_count()
-            + self.l2_gateway.sent_tx_count())
-            - tx_sent_before;
+            + self.gateway_prove.sent_tx_count()
+            + self.l2_gateway.sent_tx_count()
     }
 
     pub async fn run_eth_sender_tx_manager_iteration(&mut self) {
@@ -534,20 +646,26 @@ impl EthSenderTester {
             .unwrap();
 
         if confirm {
-            self.confirm_tx(hash, tx.blob_sidecar.is_some()).await;
+            let operator_type = if tx.blob_sidecar.is_some() {
+                OperatorType::Blob
+            } else if tx.from_addr.is_some() && tx.from_addr == self.operator_addresses.prove {
+                OperatorType::Prove
+            } else {
+                OperatorType::NonBlob
+            };
+            self.confirm_tx(hash, operator_type).await;
         }
         hash
     }
 
-    pub async fn confirm_tx(&mut self, hash: H256, is_blob: bool) {
+    pub async fn confirm_tx(&mut self, hash: H256, operator_type: OperatorType) {
         if !self.is_l2 {
-            let (gateway, other) = if is_blob {
-                (self.gateway_blobs.as_ref(), self.gateway.as_ref())
-            } else {
-                (self.gateway.as_ref(), self.gateway_blobs.as_ref())
-            };
-            gateway.execute_tx(hash, true, EthSenderTester::WAIT_CONFIRMATIONS);
-            other.advance_block_number(EthSenderTester::WAIT_CONFIRMATIONS);
+            self.execute_on_l1(
+                operator_type,
+                hash,
+                true,
+                EthSenderTester::WAIT_CONFIRMATIONS,
+            );
         } else {
             self.l2_gateway
                 .execute_tx(hash, true, EthSenderTester::WAIT_CONFIRMATIONS);
@@ -599,21 +717,18 @@ impl EthSenderTester {
         let inflight_count = if !self.is_l2 {
             //sanity check
             assert!(self.manager.operator_address(OperatorType::Blob).is_some());
-            self.storage()
-                .await
-                .eth_sender_dal()
-                .get_inflight_txs(self.manager.operator_address(OperatorType::NonBlob), false)
-                .await
-                .unwrap()
-                .len()
-                + self
+            let mut count = 0;
+            for operator_type in self.manager.l1_interface().supported_operator_types() {
+                count += self
                     .storage()
                     .await
                     .eth_sender_dal()
-                    .get_inflight_txs(self.manager.operator_address(OperatorType::Blob), false)
+                    .get_inflight_txs(self.manager.operator_address(operator_type), false)
                     .await
                     .unwrap()
-                    .len()
+                    .len();
+            }
+            count
         } else {
             self.storage()
                 .await
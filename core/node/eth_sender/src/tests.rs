@@ -16,13 +16,15 @@ use zksync_types::{
     helpers::unix_timestamp_ms,
     web3,
     web3::contract::Error,
-    Address, ProtocolVersionId, H256,
+    Address, Nonce, ProtocolVersionId, H256,
 };
 
 use crate::{
     abstract_l1_interface::OperatorType,
     aggregated_operations::AggregatedOperation,
-    tester::{EthSenderTester, TestL1Batch, STATE_TRANSITION_CONTRACT_ADDRESS},
+    tester::{
+        EthSenderTester, TestL1Batch, PROVE_OPERATOR_ADDRESS, STATE_TRANSITION_CONTRACT_ADDRESS,
+    },
     zksync_functions::ZkSyncFunctions,
     EthSenderError,
 };
@@ -452,6 +454,130 @@ async fn blob_transactions_are_resent_independently_of_non_blob_txs() {
     tester.assert_just_sent_tx_count_equals(2).await;
 }
 
+#[test_log::test(tokio::test)]
+async fn prove_transactions_are_resent_independently_with_dedicated_operator() {
+    let mut tester = EthSenderTester::new(
+        ConnectionPool::<Core>::test_pool().await,
+        vec![100; 100],
+        false,
+        false,
+        L1BatchCommitmentMode::Validium,
+    )
+    .await;
+    tester.use_dedicated_prove_operator(None).await;
+
+    let _genesis_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let first_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let second_l1_batch = TestL1Batch::sealed(&mut tester).await;
+
+    first_l1_batch.save_commit_tx(&mut tester).await;
+    second_l1_batch.save_commit_tx(&mut tester).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_just_sent_tx_count_equals(2).await;
+
+    first_l1_batch.execute_commit_tx(&mut tester).await;
+    let prove_tx = tester.save_prove_tx(first_l1_batch.number).await;
+    // The prove operator has its own nonce sequence.
+    assert_eq!(prove_tx.from_addr, Some(PROVE_OPERATOR_ADDRESS));
+    assert_eq!(prove_tx.nonce, Nonce(0));
+
+    tester.run_eth_sender_tx_manager_iteration().await;
+    // sends the prove tx and resends the second commit tx
+    tester.assert_just_sent_tx_count_equals(2).await;
+    assert_eq!(tester.gateway_prove.sent_tx_count(), 1);
+
+    tester.run_eth_sender_tx_manager_iteration().await;
+    // the stuck commit tx doesn't block resending the prove tx
+    tester.assert_just_sent_tx_count_equals(2).await;
+    assert_eq!(tester.gateway_prove.sent_tx_count(), 2);
+
+    first_l1_batch.execute_prove_tx(&mut tester).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    // only the second commit tx remains in flight
+    tester.assert_inflight_txs_count_equals(1).await;
+}
+
+#[test_log::test(tokio::test)]
+async fn max_txs_in_flight_is_applied_per_operator() {
+    let mut tester = EthSenderTester::new(
+        ConnectionPool::<Core>::test_pool().await,
+        vec![100; 100],
+        false,
+        false,
+        L1BatchCommitmentMode::Validium,
+    )
+    .await;
+    tester.use_dedicated_prove_operator(Some(1)).await;
+
+    let _genesis_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let first_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let second_l1_batch = TestL1Batch::sealed(&mut tester).await;
+
+    first_l1_batch.save_commit_tx(&mut tester).await;
+    second_l1_batch.save_commit_tx(&mut tester).await;
+    first_l1_batch.save_prove_tx(&mut tester).await;
+    second_l1_batch.save_prove_tx(&mut tester).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    // both commit txs are sent, but only a single prove tx fits into the prove operator limit
+    tester.assert_just_sent_tx_count_equals(3).await;
+    assert_eq!(tester.gateway_prove.sent_tx_count(), 1);
+    let second_prove_tx = tester
+        .storage()
+        .await
+        .eth_sender_dal()
+        .get_last_sent_eth_tx_hash(
+            second_l1_batch.number,
+            AggregatedActionType::PublishProofOnchain,
+        )
+        .await;
+    assert!(second_prove_tx.is_none());
+
+    first_l1_batch.execute_prove_tx(&mut tester).await;
+    // the first iteration confirms the first prove tx, the second one sends the next prove tx
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester
+        .assert_tx_was_sent_in_last_iteration(
+            second_l1_batch.number,
+            AggregatedActionType::PublishProofOnchain,
+        )
+        .await;
+}
+
+#[test_log::test(tokio::test)]
+async fn transactions_from_unknown_operator_are_not_sent() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut tester = EthSenderTester::new(
+        pool.clone(),
+        vec![100; 100],
+        false,
+        false,
+        L1BatchCommitmentMode::Validium,
+    )
+    .await;
+    tester.use_dedicated_prove_operator(None).await;
+
+    let _genesis_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let l1_batch = TestL1Batch::sealed(&mut tester).await;
+    l1_batch.save_commit_tx(&mut tester).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    l1_batch.execute_commit_tx(&mut tester).await;
+    let mut prove_tx = tester.save_prove_tx(l1_batch.number).await;
+
+    // Emulate operators reconfigured while the transaction is in flight.
+    let unknown_operator = Address::repeat_byte(0xee);
+    prove_tx.from_addr = Some(unknown_operator);
+    let latest_block = tester.get_block_numbers().await.latest;
+    let mut storage = pool.connection().await.unwrap();
+    let err = tester
+        .manager
+        .send_eth_tx(&mut storage, &prove_tx, 0, latest_block)
+        .await
+        .unwrap_err();
+    assert_matches!(err, EthSenderError::UnknownOperator(addr) if addr == unknown_operator);
+    assert_eq!(tester.gateway_prove.sent_tx_count(), 0);
+}
+
 #[test_log::test(tokio::test)]
 async fn transactions_are_not_resent_on_the_same_block() {
     let mut tester = EthSenderTester::new(
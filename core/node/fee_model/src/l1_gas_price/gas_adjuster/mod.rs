@@ -334,6 +334,10 @@ impl TxParamsProvider for GasAdjuster {
         new_fee as u64
     }
 
+    fn get_base_fee_percentile(&self, percentile: u32) -> u64 {
+        self.base_fee_statistics.percentile(percentile)
+    }
+
     fn get_next_block_minimal_base_fee(&self) -> u64 {
         let last_block_base_fee = self.base_fee_statistics.last_added_value();
 
@@ -412,6 +416,18 @@ impl<T: Ord + Copy + Default> GasStatisticsInner<T> {
         self.samples.back().copied().unwrap_or(self.median_cached)
     }
 
+    /// Returns the value at the specified percentile (0..=100) of the collected samples.
+    fn percentile(&self, percentile: u32) -> T {
+        if self.samples.is_empty() {
+            return self.median_cached;
+        }
+        let mut samples: Vec<_> = self.samples.iter().copied().collect();
+        let percentile = percentile.min(100) as usize;
+        let index = (samples.len() - 1) * percentile / 100;
+        let (_, &mut value, _) = samples.select_nth_unstable(index);
+        value
+    }
+
     fn add_samples(&mut self, fees: impl IntoIterator<Item = T>) {
         let old_len = self.samples.len();
         self.samples.extend(fees);
@@ -450,6 +466,10 @@ impl<T: Ord + Copy + Default> GasStatistics<T> {
         self.0.read().unwrap().last_added_value()
     }
 
+    pub fn percentile(&self, percentile: u32) -> T {
+        self.0.read().unwrap().percentile(percentile)
+    }
+
     pub fn add_samples(&self, fees: impl IntoIterator<Item = T>) {
         self.0.write().unwrap().add_samples(fees)
     }
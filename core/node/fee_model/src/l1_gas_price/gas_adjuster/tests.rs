@@ -20,6 +20,18 @@ fn median() {
     assert_eq!(GasStatisticsInner::new(4, 4, [8, 4, 4, 10]).median(), 8);
 }
 
+/// Check that we compute percentiles correctly
+#[test]
+fn percentile() {
+    // sorted: 4 4 6 7 8
+    let stats = GasStatisticsInner::new(5, 5, [6, 4, 7, 8, 4]);
+    assert_eq!(stats.percentile(0), 4);
+    assert_eq!(stats.percentile(50), 6);
+    assert_eq!(stats.percentile(75), 7);
+    assert_eq!(stats.percentile(100), 8);
+    assert_eq!(stats.percentile(1_000), 8);
+}
+
 /// Check that we properly manage the block base fee queue
 #[test]
 fn samples_queue() {
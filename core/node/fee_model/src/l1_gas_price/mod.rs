@@ -18,6 +18,9 @@ pub trait TxParamsProvider: fmt::Debug + 'static + Send + Sync {
     /// Returns the recommended `max_fee_per_gas` value (EIP1559).
     fn get_base_fee(&self, time_in_mempool_in_l1_blocks: u32) -> u64;
 
+    /// Returns the given percentile (0..=100) of base fees over recently observed L1 blocks.
+    fn get_base_fee_percentile(&self, percentile: u32) -> u64;
+
     /// Returns the recommended `max_priority_fee_per_gas` value (EIP1559).
     fn get_priority_fee(&self) -> u64;
 
@@ -16,7 +16,7 @@ use zksync_crypto_primitives::hasher::blake2::Blake2Hasher;
 use zksync_health_check::{CheckHealth, Health, HealthStatus};
 use zksync_merkle_tree::{
     unstable::{NodeKey, RawNode},
-    NoVersionError, ValueHash,
+    NoVersionError, TreeDiffError, ValueHash,
 };
 use zksync_types::{u256_to_h256, web3, L1BatchNumber, H256, U256};
 
@@ -80,6 +80,121 @@ impl TreeEntryWithProof {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct TreeMultiProofResponse {
+    proof: TreeMultiProof,
+}
+
+/// Entry in a [`TreeMultiProof`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TreeMultiProofEntry {
+    pub hashed_key: U256,
+    #[serde(default, skip_serializing_if = "H256::is_zero")]
+    pub value: H256,
+    #[serde(default, skip_serializing_if = "TreeEntryWithProof::is_zero")]
+    pub index: u64,
+    /// Length of the Merkle path for this entry. Sibling hashes below this length are implied to be
+    /// empty subtree hashes.
+    pub merkle_path_length: usize,
+}
+
+/// Compact proof for multiple entries with sibling hashes shared among entries.
+///
+/// Unlike [`TreeEntryWithProof`], hashes use leaf-to-root enumeration direction: they are ordered by tree level
+/// starting from the leaf level, and by the hashed key within a level.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TreeMultiProof {
+    /// Entries ordered by the hashed key, without duplicates.
+    pub entries: Vec<TreeMultiProofEntry>,
+    pub hashes: Vec<H256>,
+}
+
+impl TreeMultiProof {
+    fn new(src: zksync_merkle_tree::TreeMultiProof) -> Self {
+        let entries = src.entries.into_iter().zip(src.merkle_path_lengths).map(
+            |(entry, merkle_path_length)| TreeMultiProofEntry {
+                hashed_key: entry.key,
+                value: entry.value,
+                index: entry.leaf_index,
+                merkle_path_length,
+            },
+        );
+        Self {
+            entries: entries.collect(),
+            hashes: src.hashes,
+        }
+    }
+
+    /// Verifies all entries in this proof.
+    pub fn verify(&self, trusted_root_hash: H256) -> anyhow::Result<()> {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| zksync_merkle_tree::TreeEntry {
+                key: entry.hashed_key,
+                value: entry.value,
+                leaf_index: entry.index,
+            });
+        zksync_merkle_tree::TreeMultiProof {
+            entries: entries.collect(),
+            merkle_path_lengths: self
+                .entries
+                .iter()
+                .map(|entry| entry.merkle_path_length)
+                .collect(),
+            hashes: self.hashes.clone(),
+        }
+        .verify(&Blake2Hasher, trusted_root_hash)
+    }
+}
+
+/// Maximum number of entries returned on a single page of a tree diff.
+const MAX_DIFF_PAGE_SIZE: usize = 1_000;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TreeDiffRequest {
+    old_l1_batch_number: L1BatchNumber,
+    new_l1_batch_number: L1BatchNumber,
+    /// Minimum hashed key of returned entries.
+    #[serde(default)]
+    start_key: Option<U256>,
+    /// Maximum number of returned entries. Clamped to `1..=`[`MAX_DIFF_PAGE_SIZE`].
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+/// Change of a tree entry between two L1 batches.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TreeEntryDiff {
+    pub hashed_key: U256,
+    pub old_value: H256,
+    pub old_index: u64,
+    pub new_value: H256,
+    pub new_index: u64,
+}
+
+impl From<zksync_merkle_tree::TreeEntryDiff> for TreeEntryDiff {
+    fn from(diff: zksync_merkle_tree::TreeEntryDiff) -> Self {
+        Self {
+            hashed_key: diff.new.key,
+            old_value: diff.old.value,
+            old_index: diff.old.leaf_index,
+            new_value: diff.new.value,
+            new_index: diff.new.leaf_index,
+        }
+    }
+}
+
+/// Page of changed tree entries between two L1 batches.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TreeDiffPage {
+    /// Changed entries ordered by the hashed key.
+    pub entries: Vec<TreeEntryDiff>,
+    /// Start key for the next page. `None` if this is the last page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_start_key: Option<U256>,
+}
+
 #[derive(Debug, PartialEq, Eq, Hash)]
 struct HexNodeKey(NodeKey);
 
@@ -195,6 +310,16 @@ struct StaleKeysResponse {
 #[derive(Debug)]
 enum TreeApiServerError {
     NoTreeVersion(NoVersionError),
+    Internal(anyhow::Error),
+}
+
+impl From<TreeDiffError> for TreeApiServerError {
+    fn from(err: TreeDiffError) -> Self {
+        match err {
+            TreeDiffError::NoVersion(err) => Self::NoTreeVersion(err),
+            err => Self::Internal(err.into()),
+        }
+    }
 }
 
 // Contains the same fields as `NoVersionError` and is serializable.
@@ -247,6 +372,15 @@ impl IntoResponse for TreeApiServerError {
                 };
                 (StatusCode::NOT_FOUND, headers, Json(body)).into_response()
             }
+            Self::Internal(err) => {
+                let body = Problem {
+                    r#type: "/errors#internal",
+                    title: "Internal error",
+                    detail: format!("{err:#}"),
+                    data: (),
+                };
+                (StatusCode::INTERNAL_SERVER_ERROR, headers, Json(body)).into_response()
+            }
         }
     }
 }
@@ -289,6 +423,24 @@ pub trait TreeApiClient: 'static + Send + Sync + fmt::Debug {
         l1_batch_number: L1BatchNumber,
         hashed_keys: Vec<U256>,
     ) -> Result<Vec<TreeEntryWithProof>, TreeApiError>;
+
+    /// Obtains a compact multi-proof for the specified `hashed_keys` at the specified tree version (= L1 batch number).
+    async fn get_multi_proof(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        hashed_keys: Vec<U256>,
+    ) -> Result<TreeMultiProof, TreeApiError>;
+
+    /// Obtains a page of entries changed between the specified tree versions (= L1 batch numbers).
+    /// Entries are ordered by the hashed key and start from `start_key`. `limit` is clamped to be positive
+    /// and not exceed the server-side page size limit, so each non-final page contains at least one entry.
+    async fn get_diff(
+        &self,
+        old_l1_batch_number: L1BatchNumber,
+        new_l1_batch_number: L1BatchNumber,
+        start_key: U256,
+        limit: usize,
+    ) -> Result<TreeDiffPage, TreeApiError>;
 }
 
 /// In-memory client implementation.
@@ -316,6 +468,40 @@ impl TreeApiClient for LazyAsyncTreeReader {
             Err(TreeApiError::NotReady(None))
         }
     }
+
+    async fn get_multi_proof(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        hashed_keys: Vec<U256>,
+    ) -> Result<TreeMultiProof, TreeApiError> {
+        if let Some(reader) = self.read() {
+            reader
+                .get_multi_proof_inner(l1_batch_number, hashed_keys)
+                .await
+                .map_err(TreeApiError::NoVersion)
+        } else {
+            Err(TreeApiError::NotReady(None))
+        }
+    }
+
+    async fn get_diff(
+        &self,
+        old_l1_batch_number: L1BatchNumber,
+        new_l1_batch_number: L1BatchNumber,
+        start_key: U256,
+        limit: usize,
+    ) -> Result<TreeDiffPage, TreeApiError> {
+        let Some(reader) = self.read() else {
+            return Err(TreeApiError::NotReady(None));
+        };
+        reader
+            .get_diff_inner(old_l1_batch_number, new_l1_batch_number, start_key, limit)
+            .await
+            .map_err(|err| match err {
+                TreeDiffError::NoVersion(err) => TreeApiError::NoVersion(err),
+                err => TreeApiError::Internal(err.into()),
+            })
+    }
 }
 
 /// [`TreeApiClient`] implementation requesting data from a Merkle tree API server.
@@ -324,6 +510,8 @@ pub struct TreeApiHttpClient {
     inner: reqwest::Client,
     info_url: String,
     proofs_url: String,
+    multi_proof_url: String,
+    diff_url: String,
 }
 
 impl TreeApiHttpClient {
@@ -338,6 +526,8 @@ impl TreeApiHttpClient {
             inner: client,
             info_url: url_base.to_owned(),
             proofs_url: format!("{url_base}/proofs"),
+            multi_proof_url: format!("{url_base}/proofs/compact"),
+            diff_url: format!("{url_base}/diff"),
         }
     }
 }
@@ -384,21 +574,67 @@ impl TreeApiClient for TreeApiHttpClient {
         l1_batch_number: L1BatchNumber,
         hashed_keys: Vec<U256>,
     ) -> Result<Vec<TreeEntryWithProof>, TreeApiError> {
+        let request = TreeProofsRequest {
+            l1_batch_number,
+            hashed_keys,
+        };
+        let description = format!("proofs for L1 batch #{l1_batch_number}");
+        let response: TreeProofsResponse = self
+            .post_request(&self.proofs_url, &request, &description)
+            .await?;
+        Ok(response.entries)
+    }
+
+    async fn get_multi_proof(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        hashed_keys: Vec<U256>,
+    ) -> Result<TreeMultiProof, TreeApiError> {
+        let request = TreeProofsRequest {
+            l1_batch_number,
+            hashed_keys,
+        };
+        let description = format!("multi-proof for L1 batch #{l1_batch_number}");
+        let response: TreeMultiProofResponse = self
+            .post_request(&self.multi_proof_url, &request, &description)
+            .await?;
+        Ok(response.proof)
+    }
+
+    async fn get_diff(
+        &self,
+        old_l1_batch_number: L1BatchNumber,
+        new_l1_batch_number: L1BatchNumber,
+        start_key: U256,
+        limit: usize,
+    ) -> Result<TreeDiffPage, TreeApiError> {
+        let request = TreeDiffRequest {
+            old_l1_batch_number,
+            new_l1_batch_number,
+            start_key: Some(start_key),
+            limit: Some(limit),
+        };
+        let description =
+            format!("tree diff for L1 batches #{old_l1_batch_number}..#{new_l1_batch_number}");
+        self.post_request(&self.diff_url, &request, &description)
+            .await
+    }
+}
+
+impl TreeApiHttpClient {
+    async fn post_request<R: de::DeserializeOwned>(
+        &self,
+        url: &str,
+        request: &impl Serialize,
+        request_description: &str,
+    ) -> Result<R, TreeApiError> {
         let response = self
             .inner
-            .post(&self.proofs_url)
-            .json(&TreeProofsRequest {
-                l1_batch_number,
-                hashed_keys,
-            })
+            .post(url)
+            .json(request)
             .send()
             .await
-            .map_err(|err| {
-                TreeApiError::for_request(
-                    err,
-                    format_args!("proofs for L1 batch #{l1_batch_number}"),
-                )
-            })?;
+            .map_err(|err| TreeApiError::for_request(err, request_description))?;
 
         let is_problem = response
             .headers()
@@ -414,12 +650,13 @@ impl TreeApiClient for TreeApiHttpClient {
         }
 
         let response = response.error_for_status().with_context(|| {
-            format!("requesting proofs for L1 batch #{l1_batch_number} returned non-OK response")
+            format!("requesting {request_description} returned non-OK response")
         })?;
-        let response: TreeProofsResponse = response.json().await.with_context(|| {
-            format!("failed deserializing proofs for L1 batch #{l1_batch_number}")
-        })?;
-        Ok(response.entries)
+        let response = response
+            .json()
+            .await
+            .with_context(|| format!("failed deserializing {request_description}"))?;
+        Ok(response)
     }
 }
 
@@ -457,6 +694,80 @@ impl AsyncTreeReader {
         Ok(Json(response))
     }
 
+    async fn get_multi_proof_inner(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        hashed_keys: Vec<U256>,
+    ) -> Result<TreeMultiProof, NoVersionError> {
+        let proof = self
+            .clone()
+            .entries_with_multi_proof(l1_batch_number, hashed_keys)
+            .await?;
+        Ok(TreeMultiProof::new(proof))
+    }
+
+    async fn get_multi_proof_handler(
+        State(this): State<Self>,
+        Json(request): Json<TreeProofsRequest>,
+    ) -> Result<Json<TreeMultiProofResponse>, TreeApiServerError> {
+        let latency = API_METRICS.latency[&MerkleTreeApiMethod::GetMultiProof].start();
+        let proof = this
+            .get_multi_proof_inner(request.l1_batch_number, request.hashed_keys)
+            .await
+            .map_err(TreeApiServerError::NoTreeVersion)?;
+        let response = TreeMultiProofResponse { proof };
+        latency.observe();
+        Ok(Json(response))
+    }
+
+    async fn get_diff_inner(
+        &self,
+        old_l1_batch_number: L1BatchNumber,
+        new_l1_batch_number: L1BatchNumber,
+        start_key: U256,
+        limit: usize,
+    ) -> Result<TreeDiffPage, TreeDiffError> {
+        // A zero limit would return an empty page pointing to `start_key` as the next page start,
+        // which would make clients paginating the diff loop forever.
+        let limit = limit.clamp(1, MAX_DIFF_PAGE_SIZE);
+        // Request an additional entry to determine the start key for the next page.
+        let mut entries = self
+            .clone()
+            .diff(
+                old_l1_batch_number,
+                new_l1_batch_number,
+                start_key,
+                limit + 1,
+            )
+            .await?;
+        let next_start_key = if entries.len() > limit {
+            entries.pop().map(|diff| diff.new.key)
+        } else {
+            None
+        };
+        Ok(TreeDiffPage {
+            entries: entries.into_iter().map(TreeEntryDiff::from).collect(),
+            next_start_key,
+        })
+    }
+
+    async fn get_diff_handler(
+        State(this): State<Self>,
+        Json(request): Json<TreeDiffRequest>,
+    ) -> Result<Json<TreeDiffPage>, TreeApiServerError> {
+        let latency = API_METRICS.latency[&MerkleTreeApiMethod::GetDiff].start();
+        let page = this
+            .get_diff_inner(
+                request.old_l1_batch_number,
+                request.new_l1_batch_number,
+                request.start_key.unwrap_or_default(),
+                request.limit.unwrap_or(MAX_DIFF_PAGE_SIZE),
+            )
+            .await?;
+        latency.observe();
+        Ok(Json(page))
+    }
+
     async fn get_nodes_handler(
         State(this): State<Self>,
         Json(request): Json<TreeNodesRequest>,
@@ -507,6 +818,11 @@ impl AsyncTreeReader {
         let app = Router::new()
             .route("/", routing::get(Self::info_handler))
             .route("/proofs", routing::post(Self::get_proofs_handler))
+            .route(
+                "/proofs/compact",
+                routing::post(Self::get_multi_proof_handler),
+            )
+            .route("/diff", routing::post(Self::get_diff_handler))
             .route("/debug/nodes", routing::post(Self::get_nodes_handler))
             .route(
                 "/debug/stale-keys",
@@ -52,16 +52,41 @@ async fn merkle_tree_api() {
     hashed_keys.extend((0_u8..10).map(|byte| U256::from_big_endian(&[byte; 32])));
 
     let proofs = api_client
-        .get_proofs(L1BatchNumber(5), hashed_keys)
+        .get_proofs(L1BatchNumber(5), hashed_keys.clone())
         .await
         .unwrap();
     assert_eq!(proofs.len(), 20);
-    for (i, proof) in proofs.into_iter().enumerate() {
+    for (i, proof) in proofs.iter().enumerate() {
         let should_be_present = i < 10;
         assert_eq!(proof.index == 0, !should_be_present);
         assert!(!proof.merkle_path.is_empty());
+        proof.verify(hashed_keys[i], tree_info.root_hash).unwrap();
     }
 
+    let multi_proof = api_client
+        .get_multi_proof(L1BatchNumber(5), hashed_keys.clone())
+        .await
+        .unwrap();
+    assert_eq!(multi_proof.entries.len(), 20);
+    multi_proof.verify(tree_info.root_hash).unwrap();
+    for entry in &multi_proof.entries {
+        let i = hashed_keys
+            .iter()
+            .position(|key| *key == entry.hashed_key)
+            .unwrap();
+        assert_eq!(entry.value, proofs[i].value);
+        assert_eq!(entry.index, proofs[i].index);
+    }
+    let individual_hash_count: usize = proofs.iter().map(|proof| proof.merkle_path.len()).sum();
+    assert!(multi_proof.hashes.len() < individual_hash_count);
+
+    let empty_multi_proof = api_client
+        .get_multi_proof(L1BatchNumber(5), vec![])
+        .await
+        .unwrap();
+    assert!(empty_multi_proof.entries.is_empty());
+    assert!(empty_multi_proof.hashes.is_empty());
+
     let err = api_client
         .get_proofs(L1BatchNumber(10), vec![])
         .await
@@ -72,6 +97,67 @@ async fn merkle_tree_api() {
     assert_eq!(err.version_count, 6);
     assert_eq!(err.missing_version, 10);
 
+    let err = api_client
+        .get_multi_proof(L1BatchNumber(10), vec![])
+        .await
+        .unwrap_err();
+    assert_matches!(err, TreeApiError::NoVersion(err) if err.missing_version == 10);
+
+    let full_diff = api_client
+        .get_diff(L1BatchNumber(4), L1BatchNumber(5), U256::zero(), 1_000)
+        .await
+        .unwrap();
+    assert!(full_diff.next_start_key.is_none());
+    assert!(!full_diff.entries.is_empty());
+    assert!(full_diff
+        .entries
+        .windows(2)
+        .all(|window| window[0].hashed_key < window[1].hashed_key));
+
+    let mut paginated_entries = vec![];
+    let mut start_key = U256::zero();
+    loop {
+        let page = api_client
+            .get_diff(L1BatchNumber(4), L1BatchNumber(5), start_key, 3)
+            .await
+            .unwrap();
+        assert!(page.entries.len() <= 3);
+        paginated_entries.extend(page.entries);
+        match page.next_start_key {
+            Some(key) => start_key = key,
+            None => break,
+        }
+    }
+    assert_eq!(paginated_entries, full_diff.entries);
+
+    // Zero limit is treated as 1, so that pagination always makes progress.
+    let page = api_client
+        .get_diff(L1BatchNumber(4), L1BatchNumber(5), U256::zero(), 0)
+        .await
+        .unwrap();
+    assert_eq!(page.entries, full_diff.entries[..1]);
+    assert_eq!(page.next_start_key, Some(full_diff.entries[1].hashed_key));
+
+    let diff_keys: Vec<_> = full_diff
+        .entries
+        .iter()
+        .map(|entry| entry.hashed_key)
+        .collect();
+    let new_proofs = api_client
+        .get_proofs(L1BatchNumber(5), diff_keys)
+        .await
+        .unwrap();
+    for (entry, proof) in full_diff.entries.iter().zip(&new_proofs) {
+        assert_eq!(entry.new_value, proof.value);
+        assert_eq!(entry.new_index, proof.index);
+    }
+
+    let err = api_client
+        .get_diff(L1BatchNumber(4), L1BatchNumber(10), U256::zero(), 10)
+        .await
+        .unwrap_err();
+    assert_matches!(err, TreeApiError::NoVersion(err) if err.missing_version == 10);
+
     let raw_nodes_response = api_client
         .inner
         .post(format!("http://{local_addr}/debug/nodes"))
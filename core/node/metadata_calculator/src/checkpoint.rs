@@ -0,0 +1,898 @@
+//! Merkle tree checkpoints: periodic export of the tree RocksDB to the object store, and restoring the tree from it.
+
+use std::{
+    collections::HashSet,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+use tiny_keccak::{Hasher, Keccak};
+use tokio::{
+    fs,
+    io::{AsyncReadExt, AsyncWriteExt},
+    sync::watch,
+};
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_merkle_tree::{domain::ZkSyncTreeReader, RocksDBWrapper};
+use zksync_object_store::{
+    Bucket, ObjectStore, ObjectStoreError, StoredObject, _reexports::BoxedError,
+};
+use zksync_types::{L1BatchNumber, H256};
+
+use crate::{metrics::CHECKPOINT_METRICS, LazyAsyncTreeReader};
+
+/// Maximum size of a single object in the object store. Larger checkpoint files are split into several parts,
+/// so that a file is never loaded into memory as a whole.
+const MAX_FILE_PART_SIZE: u64 = 64 << 20; // 64 MiB
+
+/// Information about a single file in a [`MerkleTreeCheckpointManifest`].
+///
+/// Files are stored in the object store under keys derived from the file name and hash, so unchanged files
+/// (e.g., immutable RocksDB SST files) are shared among consecutive checkpoints and are uploaded only once.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleTreeCheckpointFile {
+    /// File name relative to the RocksDB directory.
+    pub name: String,
+    /// File size in bytes.
+    pub size: u64,
+    /// Keccak-256 hash of the file contents.
+    pub hash: H256,
+    /// Number of parts the file is split into in the object store. Parts should be concatenated in order
+    /// to get the file contents.
+    pub part_count: u64,
+}
+
+impl MerkleTreeCheckpointFile {
+    fn part_key(&self, part: u64) -> String {
+        format!(
+            "merkle_tree_checkpoint_file_{:x}_{}_part_{part}",
+            self.hash, self.name
+        )
+    }
+
+    fn part_keys(&self) -> impl Iterator<Item = String> + '_ {
+        (0..self.part_count).map(|part| self.part_key(part))
+    }
+}
+
+/// Manifest of a Merkle tree checkpoint stored in the object store. The manifest is uploaded after all checkpoint files,
+/// so its presence means that the checkpoint is complete.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleTreeCheckpointManifest {
+    /// Latest tree version in the checkpoint, which is equal to the latest L1 batch processed by the tree.
+    pub l1_batch_number: L1BatchNumber,
+    /// Root hash of the tree after processing `l1_batch_number`.
+    pub root_hash: H256,
+    /// Files comprising the checkpoint.
+    pub files: Vec<MerkleTreeCheckpointFile>,
+}
+
+/// Manifests are stored twice: for a specific L1 batch, and as the latest manifest (corresponds to the `None` key).
+impl StoredObject for MerkleTreeCheckpointManifest {
+    const BUCKET: Bucket = Bucket::MerkleTreeCheckpoints;
+    type Key<'a> = Option<L1BatchNumber>;
+
+    fn encode_key(key: Self::Key<'_>) -> String {
+        match key {
+            Some(l1_batch_number) => {
+                format!("merkle_tree_checkpoint_l1_batch_{l1_batch_number}_manifest.json")
+            }
+            None => "merkle_tree_checkpoint_latest_manifest.json".to_owned(),
+        }
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, BoxedError> {
+        serde_json::to_vec(self).map_err(From::from)
+    }
+
+    fn deserialize(bytes: Vec<u8>) -> Result<Self, BoxedError> {
+        serde_json::from_slice(&bytes).map_err(From::from)
+    }
+}
+
+impl MerkleTreeCheckpointManifest {
+    fn total_size(&self) -> u64 {
+        self.files.iter().map(|file| file.size).sum()
+    }
+}
+
+/// Opens the tree at `path` and returns its latest L1 batch number and root hash, or `None` if the tree is empty.
+fn read_tree_state(path: &Path) -> anyhow::Result<Option<(L1BatchNumber, H256)>> {
+    let db = RocksDBWrapper::new(path).context("failed opening Merkle tree RocksDB")?;
+    let reader = ZkSyncTreeReader::new(db)?;
+    let Some(l1_batch_number) = reader.next_l1_batch_number().0.checked_sub(1) else {
+        return Ok(None);
+    };
+    let l1_batch_number = L1BatchNumber(l1_batch_number);
+    let (root_hash, _) = reader
+        .root_info(l1_batch_number)
+        .with_context(|| format!("no root for L1 batch #{l1_batch_number}"))?;
+    Ok(Some((l1_batch_number, root_hash)))
+}
+
+/// Creates a checkpoint of `db` at `path` and returns the state of the checkpointed tree.
+fn create_checkpoint(
+    db: RocksDBWrapper,
+    path: &Path,
+) -> anyhow::Result<Option<(L1BatchNumber, H256)>> {
+    if path.exists() {
+        tracing::info!(
+            "Removing stale Merkle tree checkpoint at `{}`",
+            path.display()
+        );
+        std::fs::remove_dir_all(path).context("failed removing stale checkpoint")?;
+    }
+    db.into_inner()
+        .create_checkpoint(path)
+        .context("failed creating RocksDB checkpoint")?;
+    // Reading the tree state from the checkpoint (rather than from the live tree) guarantees that the state
+    // corresponds to the checkpointed data. Opening the checkpoint may modify RocksDB metadata files;
+    // this is fine since files are listed only after the checkpoint is closed.
+    read_tree_state(path)
+}
+
+/// Computes the size and Keccak-256 hash of the file at `path` without loading it into memory.
+fn hash_file(path: &Path) -> io::Result<(u64, H256)> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Keccak::v256();
+    let mut buffer = vec![0_u8; 1 << 20];
+    let mut size = 0;
+    loop {
+        let read_bytes = file.read(&mut buffer)?;
+        if read_bytes == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read_bytes]);
+        size += read_bytes as u64;
+    }
+    let mut hash = H256::zero();
+    hasher.finalize(&mut hash.0);
+    Ok((size, hash))
+}
+
+async fn is_empty_dir(path: &Path) -> anyhow::Result<bool> {
+    let mut entries = fs::read_dir(path).await?;
+    Ok(entries.next_entry().await?.is_none())
+}
+
+/// Task periodically exporting consistent checkpoints of the Merkle tree RocksDB to the object store.
+///
+/// Each checkpoint is uploaded file-by-file, followed by a [`MerkleTreeCheckpointManifest`], which is then
+/// also uploaded as the latest manifest. Files present in the previous checkpoint are not re-uploaded;
+/// other files of the previous checkpoint are removed afterwards. If an upload fails, the files uploaded for it
+/// are removed. Checkpoints can be restored using [`restore_tree_from_checkpoint()`].
+#[derive(Debug)]
+#[must_use = "Task should `run()` in a managed Tokio task"]
+pub struct MerkleTreeCheckpointTask {
+    tree_reader: LazyAsyncTreeReader,
+    object_store: Arc<dyn ObjectStore>,
+    checkpoint_path: PathBuf,
+    interval: Duration,
+}
+
+impl MerkleTreeCheckpointTask {
+    pub(super) fn new(
+        tree_reader: LazyAsyncTreeReader,
+        object_store: Arc<dyn ObjectStore>,
+        db_path: &Path,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            tree_reader,
+            object_store,
+            // The checkpoint directory must reside on the same filesystem as the tree so that SST files
+            // are hard-linked rather than copied.
+            checkpoint_path: db_path.with_extension("checkpoint"),
+            interval,
+        }
+    }
+
+    /// Runs this task indefinitely.
+    #[tracing::instrument(skip_all)]
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let db = tokio::select! {
+            res = self.tree_reader.clone().wait() => {
+                match res {
+                    Some(reader) => reader.into_db(),
+                    None => {
+                        tracing::info!("Merkle tree dropped; shutting down checkpoint task");
+                        return Ok(());
+                    }
+                }
+            }
+            _ = stop_receiver.changed() => {
+                tracing::info!("Stop signal received before Merkle tree is initialized; shutting down checkpoint task");
+                return Ok(());
+            }
+        };
+
+        let mut latest_manifest = self.latest_manifest().await?;
+        tracing::info!(
+            "Started Merkle tree checkpoint task with {:?} interval; latest checkpoint is for L1 batch {:?}",
+            self.interval,
+            latest_manifest.as_ref().map(|manifest| manifest.l1_batch_number)
+        );
+        while !*stop_receiver.borrow() {
+            match self
+                .export_checkpoint(&db, latest_manifest.as_ref(), &stop_receiver)
+                .await
+            {
+                Ok(Some(manifest)) => latest_manifest = Some(manifest),
+                Ok(None) => { /* no new data, or the task is stopped */ }
+                Err(err) => tracing::warn!("Failed exporting Merkle tree checkpoint: {err:#}"),
+            }
+            // Error here corresponds to a timeout w/o `stop_receiver` changed; we're OK with this.
+            tokio::time::timeout(self.interval, stop_receiver.changed())
+                .await
+                .ok();
+        }
+        tracing::info!("Stop signal received, Merkle tree checkpoint task is shutting down");
+        Ok(())
+    }
+
+    async fn latest_manifest(&self) -> anyhow::Result<Option<MerkleTreeCheckpointManifest>> {
+        match self.object_store.get(None).await {
+            Ok(manifest) => Ok(Some(manifest)),
+            Err(ObjectStoreError::KeyNotFound(_)) => Ok(None),
+            Err(err) => Err(anyhow::Error::from(err)
+                .context("failed getting latest Merkle tree checkpoint manifest")),
+        }
+    }
+
+    /// Exports a checkpoint if the tree has progressed since `prev_manifest`. Returns the manifest of the exported checkpoint,
+    /// or `None` if a checkpoint wasn't exported.
+    async fn export_checkpoint(
+        &self,
+        db: &RocksDBWrapper,
+        prev_manifest: Option<&MerkleTreeCheckpointManifest>,
+        stop_receiver: &watch::Receiver<bool>,
+    ) -> anyhow::Result<Option<MerkleTreeCheckpointManifest>> {
+        let started_at = Instant::now();
+        let tree_reader = ZkSyncTreeReader::new(db.clone())?;
+        let next_l1_batch_number = tree_reader.next_l1_batch_number();
+        let prev_l1_batch_number = prev_manifest.map(|manifest| manifest.l1_batch_number);
+        if next_l1_batch_number == L1BatchNumber(0)
+            || prev_l1_batch_number.is_some_and(|number| number + 1 >= next_l1_batch_number)
+        {
+            tracing::debug!("No new L1 batches since the latest checkpoint ({prev_l1_batch_number:?}); skipping");
+            return Ok(None);
+        }
+
+        let checkpoint_path = self.checkpoint_path.clone();
+        let db = db.clone();
+        let tree_state =
+            tokio::task::spawn_blocking(move || create_checkpoint(db, &checkpoint_path))
+                .await
+                .context("panicked creating Merkle tree checkpoint")??;
+        let Some((l1_batch_number, root_hash)) = tree_state else {
+            return Ok(None);
+        };
+        tracing::info!(
+            "Created Merkle tree checkpoint for L1 batch #{l1_batch_number} with root hash {root_hash:?} in {:?}",
+            started_at.elapsed()
+        );
+
+        let upload_result = self
+            .upload_checkpoint(l1_batch_number, root_hash, prev_manifest, stop_receiver)
+            .await;
+        fs::remove_dir_all(&self.checkpoint_path)
+            .await
+            .context("failed removing Merkle tree checkpoint")?;
+        let Some(manifest) = upload_result? else {
+            tracing::info!("Stop signal received, checkpoint upload is interrupted");
+            return Ok(None);
+        };
+
+        let latency = started_at.elapsed();
+        CHECKPOINT_METRICS.export_latency.observe(latency);
+        CHECKPOINT_METRICS
+            .exported_l1_batch
+            .set(l1_batch_number.0.into());
+        CHECKPOINT_METRICS.exported_size.set(manifest.total_size());
+        tracing::info!(
+            "Exported Merkle tree checkpoint for L1 batch #{l1_batch_number} ({} files, {}B) in {latency:?}",
+            manifest.files.len(),
+            manifest.total_size()
+        );
+
+        if let Some(prev_manifest) = prev_manifest {
+            self.remove_checkpoint(prev_manifest, &manifest).await;
+        }
+        Ok(Some(manifest))
+    }
+
+    /// Uploads the checkpoint and returns its manifest, or `None` if the upload was interrupted by a stop signal.
+    /// If the upload doesn't succeed, files uploaded for it are removed from the object store.
+    async fn upload_checkpoint(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        root_hash: H256,
+        prev_manifest: Option<&MerkleTreeCheckpointManifest>,
+        stop_receiver: &watch::Receiver<bool>,
+    ) -> anyhow::Result<Option<MerkleTreeCheckpointManifest>> {
+        let mut uploaded_keys = vec![];
+        let upload_result = self
+            .upload_checkpoint_files(prev_manifest, &mut uploaded_keys, stop_receiver)
+            .await;
+        let files = match upload_result {
+            Ok(Some(files)) => files,
+            Ok(None) => {
+                self.remove_objects(&uploaded_keys).await;
+                return Ok(None);
+            }
+            Err(err) => {
+                self.remove_objects(&uploaded_keys).await;
+                return Err(err);
+            }
+        };
+
+        let manifest = MerkleTreeCheckpointManifest {
+            l1_batch_number,
+            root_hash,
+            files,
+        };
+        let put_result = self
+            .object_store
+            .put(Some(l1_batch_number), &manifest)
+            .await
+            .context("failed uploading checkpoint manifest");
+        if let Err(err) = put_result {
+            self.remove_objects(&uploaded_keys).await;
+            return Err(err);
+        }
+        // If this upload fails, the latest manifest may still have been updated, so uploaded files are not removed.
+        self.object_store
+            .put(None, &manifest)
+            .await
+            .context("failed uploading latest checkpoint manifest")?;
+        Ok(Some(manifest))
+    }
+
+    /// Uploads checkpoint files that are not present in `prev_manifest`. Keys of all uploaded objects are added
+    /// to `uploaded_keys`, so that they can be cleaned up if the upload fails.
+    async fn upload_checkpoint_files(
+        &self,
+        prev_manifest: Option<&MerkleTreeCheckpointManifest>,
+        uploaded_keys: &mut Vec<String>,
+        stop_receiver: &watch::Receiver<bool>,
+    ) -> anyhow::Result<Option<Vec<MerkleTreeCheckpointFile>>> {
+        let mut file_names = vec![];
+        let mut entries = fs::read_dir(&self.checkpoint_path).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if !entry.file_type().await?.is_file() {
+                continue;
+            }
+            let name = entry.file_name().into_string().map_err(|name| {
+                anyhow::anyhow!("non-UTF-8 file name in RocksDB checkpoint: {name:?}")
+            })?;
+            // Skip RocksDB info logs; they are not needed to open the database.
+            if !name.starts_with("LOG") {
+                file_names.push(name);
+            }
+        }
+        file_names.sort_unstable();
+
+        let prev_files = prev_manifest.map_or(&[][..], |manifest| &manifest.files);
+        let mut files = Vec::with_capacity(file_names.len());
+        let mut reused_count = 0;
+        for name in file_names {
+            if *stop_receiver.borrow() {
+                return Ok(None);
+            }
+            let path = self.checkpoint_path.join(&name);
+            let (size, hash) = tokio::task::spawn_blocking(move || hash_file(&path))
+                .await
+                .context("panicked hashing checkpoint file")?
+                .with_context(|| format!("failed hashing checkpoint file `{name}`"))?;
+
+            let prev_file = prev_files
+                .iter()
+                .find(|file| file.name == name && file.size == size && file.hash == hash);
+            if let Some(prev_file) = prev_file {
+                files.push(prev_file.clone());
+                reused_count += 1;
+                continue;
+            }
+
+            let file = MerkleTreeCheckpointFile {
+                part_count: size.div_ceil(MAX_FILE_PART_SIZE).max(1),
+                name,
+                size,
+                hash,
+            };
+            self.upload_file(&file, uploaded_keys).await?;
+            files.push(file);
+        }
+        tracing::debug!(
+            "Reused {reused_count} files from the previous checkpoint, uploaded {} files",
+            files.len() - reused_count
+        );
+        Ok(Some(files))
+    }
+
+    async fn upload_file(
+        &self,
+        file: &MerkleTreeCheckpointFile,
+        uploaded_keys: &mut Vec<String>,
+    ) -> anyhow::Result<()> {
+        let path = self.checkpoint_path.join(&file.name);
+        let mut reader = fs::File::open(&path)
+            .await
+            .with_context(|| format!("failed opening checkpoint file `{}`", file.name))?;
+        for key in file.part_keys() {
+            let mut contents = vec![];
+            (&mut reader)
+                .take(MAX_FILE_PART_SIZE)
+                .read_to_end(&mut contents)
+                .await
+                .with_context(|| format!("failed reading checkpoint file `{}`", file.name))?;
+            self.object_store
+                .put_raw(Bucket::MerkleTreeCheckpoints, &key, contents)
+                .await
+                .with_context(|| format!("failed uploading checkpoint file `{key}`"))?;
+            uploaded_keys.push(key);
+        }
+        Ok(())
+    }
+
+    /// Removes raw objects with the specified keys. Errors are logged, but are not propagated.
+    async fn remove_objects(&self, keys: &[String]) {
+        for key in keys {
+            let remove_result = self
+                .object_store
+                .remove_raw(Bucket::MerkleTreeCheckpoints, key)
+                .await;
+            if let Err(err) = remove_result {
+                tracing::warn!("Failed removing checkpoint file `{key}`: {err}");
+            }
+        }
+    }
+
+    /// Removes objects for a checkpoint that is no longer the latest one, except for files shared with
+    /// the `latest_manifest`. Errors are logged, but are not propagated.
+    async fn remove_checkpoint(
+        &self,
+        manifest: &MerkleTreeCheckpointManifest,
+        latest_manifest: &MerkleTreeCheckpointManifest,
+    ) {
+        let l1_batch_number = manifest.l1_batch_number;
+        let remove_result = self
+            .object_store
+            .remove::<MerkleTreeCheckpointManifest>(Some(l1_batch_number))
+            .await;
+        if let Err(err) = remove_result {
+            tracing::warn!("Failed removing manifest for checkpoint #{l1_batch_number}: {err}");
+            return;
+        }
+
+        let latest_keys: HashSet<_> = latest_manifest
+            .files
+            .iter()
+            .flat_map(MerkleTreeCheckpointFile::part_keys)
+            .collect();
+        let outdated_keys: Vec<_> = manifest
+            .files
+            .iter()
+            .flat_map(MerkleTreeCheckpointFile::part_keys)
+            .filter(|key| !latest_keys.contains(key))
+            .collect();
+        self.remove_objects(&outdated_keys).await;
+        tracing::info!(
+            "Removed outdated Merkle tree checkpoint for L1 batch #{l1_batch_number} ({} objects)",
+            outdated_keys.len()
+        );
+    }
+}
+
+/// Restores the Merkle tree RocksDB at `db_path` from the latest checkpoint in the object store. The checkpoint
+/// is verified against Postgres: the root hash in its manifest must match the state root hash of the corresponding L1 batch,
+/// and the restored tree must have the same root hash as specified in the manifest.
+///
+/// Returns the manifest of the restored checkpoint, or `None` if the tree wasn't restored: if there are no checkpoints,
+/// if the checkpoint cannot be verified because Postgres doesn't have the L1 batch, or if a stop signal was received.
+/// In this case, the tree will be initialized as usual by the metadata calculator.
+///
+/// # Errors
+///
+/// Returns an error if `db_path` contains a non-empty directory, on verification failure, or on I/O errors.
+#[tracing::instrument(skip_all, fields(db_path = %db_path.display()))]
+pub async fn restore_tree_from_checkpoint(
+    object_store: &dyn ObjectStore,
+    pool: &ConnectionPool<Core>,
+    db_path: &Path,
+    stop_receiver: &watch::Receiver<bool>,
+) -> anyhow::Result<Option<MerkleTreeCheckpointManifest>> {
+    if db_path.exists() {
+        anyhow::ensure!(
+            is_empty_dir(db_path).await?,
+            "cannot restore Merkle tree from a checkpoint: directory `{}` is not empty",
+            db_path.display()
+        );
+    }
+
+    let manifest: MerkleTreeCheckpointManifest = match object_store.get(None).await {
+        Ok(manifest) => manifest,
+        Err(ObjectStoreError::KeyNotFound(_)) => {
+            tracing::warn!(
+                "No Merkle tree checkpoints in the object store; check that it points to the same bucket \
+                 the main node exports checkpoints to"
+            );
+            return Ok(None);
+        }
+        Err(err) => {
+            return Err(anyhow::Error::from(err)
+                .context("failed getting latest Merkle tree checkpoint manifest"));
+        }
+    };
+    let l1_batch_number = manifest.l1_batch_number;
+    tracing::info!(
+        "Found Merkle tree checkpoint for L1 batch #{l1_batch_number} with root hash {:?} ({} files, {}B)",
+        manifest.root_hash,
+        manifest.files.len(),
+        manifest.total_size()
+    );
+
+    let mut storage = pool.connection_tagged("metadata_calculator").await?;
+    let postgres_root_hash = storage
+        .blocks_dal()
+        .get_l1_batch_state_root(l1_batch_number)
+        .await?;
+    drop(storage);
+    let Some(postgres_root_hash) = postgres_root_hash else {
+        tracing::warn!(
+            "Postgres doesn't have the state root hash for L1 batch #{l1_batch_number}, so the Merkle tree checkpoint \
+             cannot be verified; skipping restoring the tree from the checkpoint"
+        );
+        return Ok(None);
+    };
+    anyhow::ensure!(
+        postgres_root_hash == manifest.root_hash,
+        "Merkle tree checkpoint root hash {:?} for L1 batch #{l1_batch_number} differs from the root hash in Postgres {postgres_root_hash:?}",
+        manifest.root_hash
+    );
+
+    let started_at = Instant::now();
+    let staging_path = db_path.with_extension("restore");
+    if staging_path.exists() {
+        fs::remove_dir_all(&staging_path)
+            .await
+            .context("failed removing stale Merkle tree restore directory")?;
+    }
+    fs::create_dir_all(&staging_path).await?;
+    let restore_result =
+        restore_checkpoint_files(object_store, &manifest, &staging_path, stop_receiver).await;
+    let is_restored = match restore_result {
+        Ok(is_restored) => is_restored,
+        Err(err) => {
+            fs::remove_dir_all(&staging_path).await.ok();
+            return Err(err);
+        }
+    };
+    if !is_restored {
+        tracing::info!("Stop signal received, Merkle tree restoration is interrupted");
+        fs::remove_dir_all(&staging_path).await?;
+        return Ok(None);
+    }
+
+    if db_path.exists() {
+        fs::remove_dir(db_path).await?; // The directory is empty as checked above
+    }
+    fs::rename(&staging_path, db_path)
+        .await
+        .context("failed moving restored Merkle tree to the target directory")?;
+    tracing::info!(
+        "Restored Merkle tree from checkpoint for L1 batch #{l1_batch_number} in {:?}",
+        started_at.elapsed()
+    );
+    Ok(Some(manifest))
+}
+
+/// Downloads a single checkpoint file part-by-part and verifies its size and hash. Returns `Ok(false)`
+/// if a stop signal was received.
+async fn restore_checkpoint_file(
+    object_store: &dyn ObjectStore,
+    file: &MerkleTreeCheckpointFile,
+    staging_path: &Path,
+    stop_receiver: &watch::Receiver<bool>,
+) -> anyhow::Result<bool> {
+    let path = staging_path.join(&file.name);
+    let mut writer = fs::File::create(&path)
+        .await
+        .with_context(|| format!("failed creating checkpoint file `{}`", file.name))?;
+    let mut hasher = Keccak::v256();
+    let mut size = 0;
+    for key in file.part_keys() {
+        if *stop_receiver.borrow() {
+            return Ok(false);
+        }
+        let contents = object_store
+            .get_raw(Bucket::MerkleTreeCheckpoints, &key)
+            .await
+            .with_context(|| format!("failed downloading checkpoint file `{key}`"))?;
+        size += contents.len() as u64;
+        anyhow::ensure!(
+            size <= file.size,
+            "size mismatch for checkpoint file `{}`: expected {}B, got at least {size}B",
+            file.name,
+            file.size
+        );
+        hasher.update(&contents);
+        writer
+            .write_all(&contents)
+            .await
+            .with_context(|| format!("failed writing checkpoint file `{}`", file.name))?;
+    }
+    writer
+        .sync_all()
+        .await
+        .with_context(|| format!("failed flushing checkpoint file `{}`", file.name))?;
+
+    anyhow::ensure!(
+        size == file.size,
+        "size mismatch for checkpoint file `{}`: expected {}B, got {size}B",
+        file.name,
+        file.size
+    );
+    let mut hash = H256::zero();
+    hasher.finalize(&mut hash.0);
+    anyhow::ensure!(
+        hash == file.hash,
+        "hash mismatch for checkpoint file `{}`: expected {:?}, got {hash:?}",
+        file.name,
+        file.hash
+    );
+    Ok(true)
+}
+
+/// Returns `Ok(false)` if a stop signal was received.
+async fn restore_checkpoint_files(
+    object_store: &dyn ObjectStore,
+    manifest: &MerkleTreeCheckpointManifest,
+    staging_path: &Path,
+    stop_receiver: &watch::Receiver<bool>,
+) -> anyhow::Result<bool> {
+    let l1_batch_number = manifest.l1_batch_number;
+    for file in &manifest.files {
+        // Guard against path traversal.
+        anyhow::ensure!(
+            Path::new(&file.name).file_name() == Some(file.name.as_ref()),
+            "invalid file name in checkpoint manifest: {:?}",
+            file.name
+        );
+
+        let is_restored =
+            restore_checkpoint_file(object_store, file, staging_path, stop_receiver).await?;
+        if !is_restored {
+            return Ok(false);
+        }
+    }
+
+    let path = staging_path.to_owned();
+    let tree_state = tokio::task::spawn_blocking(move || read_tree_state(&path))
+        .await
+        .context("panicked reading restored Merkle tree")??;
+    let expected_state = (l1_batch_number, manifest.root_hash);
+    anyhow::ensure!(
+        tree_state == Some(expected_state),
+        "restored Merkle tree has unexpected state: expected {expected_state:?}, got {tree_state:?}"
+    );
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+    use zksync_object_store::MockObjectStore;
+
+    use super::*;
+    use crate::tests::{
+        extend_db_state, gen_storage_logs, reset_db_state, run_calculator, setup_calculator,
+    };
+
+    #[tokio::test]
+    async fn exporting_and_restoring_checkpoint() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let temp_dir = TempDir::new().expect("failed get temporary directory for RocksDB");
+        let (calculator, _) = setup_calculator(temp_dir.path(), pool.clone(), true).await;
+        reset_db_state(&pool, 5).await;
+        let root_hash = run_calculator(calculator).await;
+
+        let object_store = MockObjectStore::arc();
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+        let restored_path = temp_dir.path().join("restored");
+        let restored =
+            restore_tree_from_checkpoint(&*object_store, &pool, &restored_path, &stop_receiver)
+                .await
+                .unwrap();
+        assert!(restored.is_none());
+        assert!(!restored_path.exists());
+
+        let db_path = temp_dir.path().join("new");
+        let db = RocksDBWrapper::new(&db_path).unwrap();
+        let task = MerkleTreeCheckpointTask::new(
+            LazyAsyncTreeReader(watch::channel(None).1),
+            object_store.clone(),
+            &db_path,
+            Duration::from_secs(60),
+        );
+        let manifest = task
+            .export_checkpoint(&db, None, &stop_receiver)
+            .await
+            .unwrap()
+            .expect("checkpoint not exported");
+        assert_eq!(manifest.l1_batch_number, L1BatchNumber(5));
+        assert_eq!(manifest.root_hash, root_hash);
+        assert!(!manifest.files.is_empty());
+        assert!(!task.checkpoint_path.exists());
+        assert_eq!(
+            task.latest_manifest().await.unwrap(),
+            Some(manifest.clone())
+        );
+
+        // No new L1 batches were processed, so the checkpoint shouldn't be exported.
+        let new_manifest = task
+            .export_checkpoint(&db, Some(&manifest), &stop_receiver)
+            .await
+            .unwrap();
+        assert!(new_manifest.is_none());
+        drop(db);
+
+        let restored =
+            restore_tree_from_checkpoint(&*object_store, &pool, &restored_path, &stop_receiver)
+                .await
+                .unwrap();
+        assert_eq!(restored, Some(manifest.clone()));
+        let restored_db = RocksDBWrapper::new(&restored_path).unwrap();
+        let reader = ZkSyncTreeReader::new(restored_db).unwrap();
+        assert_eq!(reader.next_l1_batch_number(), L1BatchNumber(6));
+        assert_eq!(reader.root_info(L1BatchNumber(5)).unwrap().0, root_hash);
+        drop(reader);
+
+        // Restoring into a non-empty directory should fail.
+        let err =
+            restore_tree_from_checkpoint(&*object_store, &pool, &restored_path, &stop_receiver)
+                .await
+                .unwrap_err();
+        assert!(format!("{err:#}").contains("not empty"), "{err:#}");
+
+        // Corrupt one of the checkpoint files.
+        let file = &manifest.files[0];
+        assert_eq!(file.part_count, 1);
+        let key = file.part_key(0);
+        let mut contents = vec![0; file.size as usize];
+        contents[0] = 1;
+        object_store
+            .put_raw(Bucket::MerkleTreeCheckpoints, &key, contents)
+            .await
+            .unwrap();
+        let corrupted_path = temp_dir.path().join("corrupted");
+        let err =
+            restore_tree_from_checkpoint(&*object_store, &pool, &corrupted_path, &stop_receiver)
+                .await
+                .unwrap_err();
+        assert!(format!("{err:#}").contains("hash mismatch"), "{err:#}");
+        assert!(!corrupted_path.exists());
+        assert!(!corrupted_path.with_extension("restore").exists());
+    }
+
+    #[tokio::test]
+    async fn unchanged_checkpoint_files_are_reused() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let temp_dir = TempDir::new().expect("failed get temporary directory for RocksDB");
+        let (calculator, _) = setup_calculator(temp_dir.path(), pool.clone(), true).await;
+        reset_db_state(&pool, 5).await;
+        run_calculator(calculator).await;
+
+        let object_store = MockObjectStore::arc();
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+        let db_path = temp_dir.path().join("new");
+        let task = MerkleTreeCheckpointTask::new(
+            LazyAsyncTreeReader(watch::channel(None).1),
+            object_store.clone(),
+            &db_path,
+            Duration::from_secs(60),
+        );
+        let db = RocksDBWrapper::new(&db_path).unwrap();
+        let manifest = task
+            .export_checkpoint(&db, None, &stop_receiver)
+            .await
+            .unwrap()
+            .expect("checkpoint not exported");
+        drop(db);
+
+        let mut storage = pool.connection().await.unwrap();
+        extend_db_state(&mut storage, gen_storage_logs(100..200, 2)).await;
+        drop(storage);
+        let (calculator, _) = setup_calculator(temp_dir.path(), pool.clone(), true).await;
+        let root_hash = run_calculator(calculator).await;
+
+        let db = RocksDBWrapper::new(&db_path).unwrap();
+        let new_manifest = task
+            .export_checkpoint(&db, Some(&manifest), &stop_receiver)
+            .await
+            .unwrap()
+            .expect("checkpoint not exported");
+        drop(db);
+        assert_eq!(new_manifest.l1_batch_number, L1BatchNumber(7));
+        assert_eq!(new_manifest.root_hash, root_hash);
+
+        // SST files of the previous checkpoint should be reused; other files should be removed.
+        let (reused_files, outdated_files): (Vec<_>, Vec<_>) = manifest
+            .files
+            .iter()
+            .partition(|&file| new_manifest.files.contains(file));
+        assert!(
+            reused_files.iter().any(|file| file.name.ends_with(".sst")),
+            "{reused_files:?}"
+        );
+        assert!(!outdated_files.is_empty());
+        for file in outdated_files {
+            let key = file.part_key(0);
+            let exists = object_store
+                .exists_raw(Bucket::MerkleTreeCheckpoints, &key)
+                .await
+                .unwrap();
+            assert!(!exists, "{key}");
+        }
+        object_store
+            .get::<MerkleTreeCheckpointManifest>(Some(L1BatchNumber(5)))
+            .await
+            .unwrap_err();
+
+        let restored_path = temp_dir.path().join("restored");
+        let restored =
+            restore_tree_from_checkpoint(&*object_store, &pool, &restored_path, &stop_receiver)
+                .await
+                .unwrap();
+        assert_eq!(restored, Some(new_manifest));
+        let restored_db = RocksDBWrapper::new(&restored_path).unwrap();
+        let reader = ZkSyncTreeReader::new(restored_db).unwrap();
+        assert_eq!(reader.next_l1_batch_number(), L1BatchNumber(8));
+        assert_eq!(reader.root_info(L1BatchNumber(7)).unwrap().0, root_hash);
+    }
+
+    #[tokio::test]
+    async fn restoring_checkpoint_with_mismatched_root_hash() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let temp_dir = TempDir::new().expect("failed get temporary directory for RocksDB");
+        let (calculator, _) = setup_calculator(temp_dir.path(), pool.clone(), true).await;
+        reset_db_state(&pool, 1).await;
+        run_calculator(calculator).await;
+
+        let object_store = MockObjectStore::arc();
+        let manifest = MerkleTreeCheckpointManifest {
+            l1_batch_number: L1BatchNumber(1),
+            root_hash: H256::repeat_byte(0xff),
+            files: vec![],
+        };
+        object_store.put(None, &manifest).await.unwrap();
+
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+        let restored_path = temp_dir.path().join("restored");
+        let err =
+            restore_tree_from_checkpoint(&*object_store, &pool, &restored_path, &stop_receiver)
+                .await
+                .unwrap_err();
+        assert!(
+            format!("{err:#}").contains("differs from the root hash in Postgres"),
+            "{err:#}"
+        );
+        assert!(!restored_path.exists());
+
+        // Checkpoint for an L1 batch missing from Postgres is skipped.
+        let manifest = MerkleTreeCheckpointManifest {
+            l1_batch_number: L1BatchNumber(100),
+            ..manifest
+        };
+        object_store.put(None, &manifest).await.unwrap();
+        let restored =
+            restore_tree_from_checkpoint(&*object_store, &pool, &restored_path, &stop_receiver)
+                .await
+                .unwrap();
+        assert!(restored.is_none());
+    }
+}
@@ -24,8 +24,8 @@ use zksync_merkle_tree::{
     recovery::{MerkleTreeRecovery, PersistenceThreadHandle},
     repair::StaleKeysRepairTask,
     unstable::{NodeKey, RawNode},
-    Database, Key, MerkleTreeColumnFamily, NoVersionError, RocksDBWrapper, TreeEntry,
-    TreeEntryWithProof, TreeInstruction,
+    Database, Key, MerkleTreeColumnFamily, NoVersionError, RocksDBWrapper, TreeDiffError,
+    TreeEntry, TreeEntryDiff, TreeEntryWithProof, TreeInstruction, TreeMultiProof,
 };
 use zksync_storage::{RocksDB, RocksDBOptions, StalledWritesRetries, WeakRocksDB};
 use zksync_types::{
@@ -166,6 +166,7 @@ fn create_db_sync(config: &MetadataCalculatorConfig) -> anyhow::Result<RocksDBWr
             large_memtable_capacity: Some(memtable_capacity),
             stalled_writes_retries: StalledWritesRetries::new(stalled_writes_timeout),
             max_open_files,
+            read_only: false,
         },
     )?;
     if cfg!(test) {
@@ -410,6 +411,37 @@ impl AsyncTreeReader {
             .unwrap()
     }
 
+    pub async fn entries_with_multi_proof(
+        self,
+        l1_batch_number: L1BatchNumber,
+        keys: Vec<Key>,
+    ) -> Result<TreeMultiProof, NoVersionError> {
+        tokio::task::spawn_blocking(move || {
+            self.inner.entries_with_multi_proof(l1_batch_number, &keys)
+        })
+        .await
+        .unwrap()
+    }
+
+    /// Returns up to `limit` entries that differ between the specified L1 batches, starting from `start_key`.
+    pub(crate) async fn diff(
+        self,
+        old_l1_batch_number: L1BatchNumber,
+        new_l1_batch_number: L1BatchNumber,
+        start_key: Key,
+        limit: usize,
+    ) -> Result<Vec<TreeEntryDiff>, TreeDiffError> {
+        tokio::task::spawn_blocking(move || {
+            self.inner
+                .diff(old_l1_batch_number, new_l1_batch_number)?
+                .starting_from(start_key)
+                .take(limit)
+                .collect()
+        })
+        .await
+        .unwrap()
+    }
+
     pub(crate) async fn raw_nodes(self, keys: Vec<NodeKey>) -> Vec<Option<RawNode>> {
         tokio::task::spawn_blocking(move || self.inner.raw_nodes(&keys))
             .await
@@ -453,7 +485,7 @@ impl WeakAsyncTreeReader {
 }
 
 /// Lazily initialized [`AsyncTreeReader`].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LazyAsyncTreeReader(pub(super) watch::Receiver<Option<AsyncTreeReader>>);
 
 impl LazyAsyncTreeReader {
@@ -3,6 +3,7 @@
 
 use std::{
     num::{NonZeroU32, NonZeroUsize},
+    path::Path,
     sync::Arc,
     time::{Duration, Instant},
 };
@@ -17,20 +18,25 @@ use zksync_dal::{ConnectionPool, Core};
 use zksync_health_check::{CheckHealth, HealthUpdater, ReactiveHealthCheck};
 use zksync_object_store::ObjectStore;
 
+pub use self::{
+    checkpoint::{
+        restore_tree_from_checkpoint, MerkleTreeCheckpointFile, MerkleTreeCheckpointManifest,
+        MerkleTreeCheckpointTask,
+    },
+    helpers::{AsyncTreeReader, LazyAsyncTreeReader, MerkleTreeInfo},
+    pruning::MerkleTreePruningTask,
+    repair::StaleKeysRepairTask,
+};
 use self::{
     helpers::{create_db, Delayer, GenericAsyncTree, MerkleTreeHealth, MerkleTreeHealthCheck},
     metrics::{ConfigLabels, METRICS},
     pruning::PruningHandles,
     updater::TreeUpdater,
 };
-pub use self::{
-    helpers::{AsyncTreeReader, LazyAsyncTreeReader, MerkleTreeInfo},
-    pruning::MerkleTreePruningTask,
-    repair::StaleKeysRepairTask,
-};
 use crate::helpers::create_readonly_db;
 
 pub mod api_server;
+mod checkpoint;
 mod helpers;
 mod metrics;
 mod pruning;
@@ -210,6 +216,21 @@ impl MetadataCalculator {
         StaleKeysRepairTask::new(self.tree_reader())
     }
 
+    /// Returns a task that periodically exports checkpoints of the Merkle tree to the provided object store.
+    /// This method should be called once.
+    pub fn checkpoint_task(
+        &self,
+        object_store: Arc<dyn ObjectStore>,
+        interval: Duration,
+    ) -> MerkleTreeCheckpointTask {
+        MerkleTreeCheckpointTask::new(
+            self.tree_reader(),
+            object_store,
+            Path::new(&self.config.db_path),
+            interval,
+        )
+    }
+
     async fn create_tree(&self) -> anyhow::Result<GenericAsyncTree> {
         self.health_updater
             .update(MerkleTreeHealth::Initialization.into());
@@ -260,3 +260,21 @@ pub(super) struct MetadataCalculatorRecoveryMetrics {
 #[vise::register]
 pub(super) static RECOVERY_METRICS: vise::Global<MetadataCalculatorRecoveryMetrics> =
     vise::Global::new();
+
+/// Metrics for exporting Merkle tree checkpoints.
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_metadata_calculator_checkpoint")]
+pub(super) struct MerkleTreeCheckpointMetrics {
+    /// Latest L1 batch included into an exported checkpoint.
+    pub exported_l1_batch: Gauge<u64>,
+    /// Total size of files in the latest exported checkpoint.
+    #[metrics(unit = Unit::Bytes)]
+    pub exported_size: Gauge<u64>,
+    /// Latency of exporting a checkpoint, including uploading it to the object store.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub export_latency: Histogram<Duration>,
+}
+
+#[vise::register]
+pub(super) static CHECKPOINT_METRICS: vise::Global<MerkleTreeCheckpointMetrics> =
+    vise::Global::new();
@@ -0,0 +1,101 @@
+use anyhow::Context as _;
+use zksync_config::configs::{
+    da_client::{
+        composite::{CompositeConfig, CompositeSecrets},
+        DAClientConfig,
+    },
+    secrets::DataAvailabilitySecrets,
+};
+use zksync_da_client::DataAvailabilityClient;
+use zksync_da_clients::{
+    avail::AvailClient, celestia::CelestiaClient, composite::CompositeDAClient, eigen::EigenClient,
+    object_store::ObjectStoreDAClient, sidecar::SidecarClient,
+};
+
+use crate::{
+    implementations::resources::da_client::DAClientResource,
+    wiring_layer::{WiringError, WiringLayer},
+    IntoContext,
+};
+
+/// Wiring layer for the DA client dispatching blobs to several DA layers at once.
+#[derive(Debug)]
+pub struct CompositeDAClientWiringLayer {
+    config: CompositeConfig,
+    secrets: CompositeSecrets,
+}
+
+impl CompositeDAClientWiringLayer {
+    pub fn new(config: CompositeConfig, secrets: CompositeSecrets) -> Self {
+        Self { config, secrets }
+    }
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    pub client: DAClientResource,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for CompositeDAClientWiringLayer {
+    type Input = ();
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "composite_da_client_layer"
+    }
+
+    async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+        let secrets = self.secrets.secrets;
+        if secrets.len() != self.config.clients.len() {
+            return Err(WiringError::Configuration(format!(
+                "composite DA client has {} backends, but secrets are provided for {}",
+                self.config.clients.len(),
+                secrets.len()
+            )));
+        }
+
+        let mut backends = Vec::with_capacity(self.config.clients.len());
+        for (i, (config, secrets)) in self.config.clients.into_iter().zip(secrets).enumerate() {
+            let backend = create_backend(config, secrets)
+                .await
+                .with_context(|| format!("failed creating composite DA client backend #{i}"))?;
+            backends.push(backend);
+        }
+        let client = CompositeDAClient::new(backends, self.config.quorum)?;
+
+        Ok(Output {
+            client: DAClientResource(Box::new(client)),
+        })
+    }
+}
+
+async fn create_backend(
+    config: DAClientConfig,
+    secrets: Option<DataAvailabilitySecrets>,
+) -> anyhow::Result<(&'static str, Box<dyn DataAvailabilityClient>)> {
+    Ok(match (config, secrets) {
+        (DAClientConfig::Avail(config), Some(DataAvailabilitySecrets::Avail(secrets))) => {
+            ("avail", Box::new(AvailClient::new(config, secrets).await?))
+        }
+        (DAClientConfig::Celestia(config), Some(DataAvailabilitySecrets::Celestia(secrets))) => (
+            "celestia",
+            Box::new(CelestiaClient::new(config, secrets).await?),
+        ),
+        (DAClientConfig::Eigen(config), Some(DataAvailabilitySecrets::Eigen(secrets))) => {
+            ("eigen", Box::new(EigenClient::new(config, secrets).await?))
+        }
+        (DAClientConfig::ObjectStore(config), _) => (
+            "object_store",
+            Box::new(ObjectStoreDAClient::new(config).await?),
+        ),
+        (DAClientConfig::Sidecar(config), _) => {
+            ("sidecar", Box::new(SidecarClient::new(config).await?))
+        }
+        (DAClientConfig::Composite(_), _) => {
+            anyhow::bail!("nested composite DA clients are not supported")
+        }
+        _ => anyhow::bail!("invalid pair of da_client and da_secrets"),
+    })
+}
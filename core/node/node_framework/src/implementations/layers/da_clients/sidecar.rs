@@ -0,0 +1,45 @@
+use zksync_config::SidecarConfig;
+use zksync_da_client::DataAvailabilityClient;
+use zksync_da_clients::sidecar::SidecarClient;
+
+use crate::{
+    implementations::resources::da_client::DAClientResource,
+    wiring_layer::{WiringError, WiringLayer},
+    IntoContext,
+};
+
+#[derive(Debug)]
+pub struct SidecarWiringLayer {
+    config: SidecarConfig,
+}
+
+impl SidecarWiringLayer {
+    pub fn new(config: SidecarConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    pub client: DAClientResource,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for SidecarWiringLayer {
+    type Input = ();
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "sidecar_da_layer"
+    }
+
+    async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+        let client: Box<dyn DataAvailabilityClient> =
+            Box::new(SidecarClient::new(self.config).await?);
+
+        Ok(Output {
+            client: DAClientResource(client),
+        })
+    }
+}
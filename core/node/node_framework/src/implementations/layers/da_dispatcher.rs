@@ -4,6 +4,7 @@ use zksync_da_dispatcher::DataAvailabilityDispatcher;
 use crate::{
     implementations::resources::{
         da_client::DAClientResource,
+        healthcheck::AppHealthCheckResource,
         pools::{MasterPool, PoolResource},
     },
     service::StopReceiver,
@@ -24,6 +25,8 @@ pub struct DataAvailabilityDispatcherLayer {
 pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
     pub da_client: DAClientResource,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
 }
 
 #[derive(Debug, IntoContext)]
@@ -58,15 +61,28 @@ impl WiringLayer for DataAvailabilityDispatcherLayer {
 
         if let Some(limit) = da_client.blob_size_limit() {
             if self.state_keeper_config.max_pubdata_per_batch > limit as u64 {
-                return Err(WiringError::Configuration(format!(
-                    "Max pubdata per batch is greater than the blob size limit: {} > {}",
-                    self.state_keeper_config.max_pubdata_per_batch, limit
-                )));
+                if !self.da_config.split_oversized_blobs() {
+                    return Err(WiringError::Configuration(format!(
+                        "Max pubdata per batch is greater than the blob size limit: {} > {}",
+                        self.state_keeper_config.max_pubdata_per_batch, limit
+                    )));
+                }
+                tracing::info!(
+                    "Max pubdata per batch is greater than the blob size limit: {} > {}; \
+                     oversized pubdata will be split into several blobs",
+                    self.state_keeper_config.max_pubdata_per_batch,
+                    limit
+                );
             }
         }
 
         let da_dispatcher_task =
             DataAvailabilityDispatcher::new(master_pool, self.da_config, da_client);
+        input
+            .app_health
+            .0
+            .insert_component(da_dispatcher_task.health_check())
+            .map_err(WiringError::internal)?;
 
         Ok(Output { da_dispatcher_task })
     }
@@ -1,14 +1,16 @@
 use anyhow::Context;
 use zksync_circuit_breaker::l1_txs::FailedL1TransactionChecker;
 use zksync_config::configs::{eth_sender::EthConfig, ContractsConfig};
-use zksync_eth_client::BoundEthInterface;
-use zksync_eth_sender::{Aggregator, EthTxAggregator};
+use zksync_eth_sender::{Aggregator, EthTxAggregator, OperatorAddresses};
 use zksync_types::{commitment::L1BatchCommitmentMode, settlement::SettlementMode, L2ChainId};
 
 use crate::{
     implementations::resources::{
         circuit_breakers::CircuitBreakersResource,
-        eth_interface::{BoundEthInterfaceForBlobsResource, BoundEthInterfaceResource},
+        eth_interface::{
+            BoundEthInterfaceForBlobsResource, BoundEthInterfaceForExecuteResource,
+            BoundEthInterfaceForProveResource, BoundEthInterfaceResource,
+        },
         object_store::ObjectStoreResource,
         pools::{MasterPool, PoolResource, ReplicaPool},
     },
@@ -30,6 +32,8 @@ use crate::{
 /// - `PoolResource<ReplicaPool>`
 /// - `BoundEthInterfaceResource`
 /// - `BoundEthInterfaceForBlobsResource` (optional)
+/// - `BoundEthInterfaceForProveResource` (optional)
+/// - `BoundEthInterfaceForExecuteResource` (optional)
 /// - `ObjectStoreResource`
 /// - `CircuitBreakersResource` (adds a circuit breaker)
 ///
@@ -52,6 +56,8 @@ pub struct Input {
     pub replica_pool: PoolResource<ReplicaPool>,
     pub eth_client: Option<BoundEthInterfaceResource>,
     pub eth_client_blobs: Option<BoundEthInterfaceForBlobsResource>,
+    pub eth_client_prove: Option<BoundEthInterfaceForProveResource>,
+    pub eth_client_execute: Option<BoundEthInterfaceForExecuteResource>,
     pub object_store: ObjectStoreResource,
     #[context(default)]
     pub circuit_breakers: CircuitBreakersResource,
@@ -96,19 +102,22 @@ impl WiringLayer for EthTxAggregatorLayer {
         let master_pool = input.master_pool.get().await.unwrap();
         let replica_pool = input.replica_pool.get().await.unwrap();
 
-        let eth_client_blobs = input.eth_client_blobs.map(|c| c.0);
+        let eth_client = input.eth_client.context("eth_client")?.0;
         let object_store = input.object_store.0;
 
         // Create and add tasks.
-        let eth_client_blobs_addr = eth_client_blobs
-            .as_deref()
-            .map(BoundEthInterface::sender_account);
+        let operator_addresses = OperatorAddresses {
+            commit: input.eth_client_blobs.map(|c| c.0.sender_account()),
+            prove: input.eth_client_prove.map(|c| c.0.sender_account()),
+            execute: input.eth_client_execute.map(|c| c.0.sender_account()),
+        };
+        operator_addresses.validate(eth_client.sender_account())?;
 
         let config = self.eth_sender_config.sender.context("sender")?;
         let aggregator = Aggregator::new(
             config.clone(),
             object_store,
-            eth_client_blobs_addr.is_some(),
+            operator_addresses.commit.is_some(),
             self.l1_batch_commit_data_generator_mode,
         );
 
@@ -116,12 +125,12 @@ impl WiringLayer for EthTxAggregatorLayer {
             master_pool.clone(),
             config.clone(),
             aggregator,
-            input.eth_client.unwrap().0,
+            eth_client,
             self.contracts_config.validator_timelock_addr,
             self.contracts_config.l1_multicall3_addr,
             self.contracts_config.diamond_proxy_addr,
             self.zksync_network_id,
-            eth_client_blobs_addr,
+            operator_addresses,
             self.settlement_mode,
         )
         .await;
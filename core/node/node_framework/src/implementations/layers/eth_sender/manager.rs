@@ -6,7 +6,10 @@ use zksync_eth_sender::EthTxManager;
 use crate::{
     implementations::resources::{
         circuit_breakers::CircuitBreakersResource,
-        eth_interface::{BoundEthInterfaceForBlobsResource, BoundEthInterfaceResource},
+        eth_interface::{
+            BoundEthInterfaceForBlobsResource, BoundEthInterfaceForExecuteResource,
+            BoundEthInterfaceForProveResource, BoundEthInterfaceResource,
+        },
         gas_adjuster::GasAdjusterResource,
         pools::{MasterPool, PoolResource, ReplicaPool},
     },
@@ -27,6 +30,8 @@ use crate::{
 /// - `PoolResource<ReplicaPool>`
 /// - `BoundEthInterfaceResource`
 /// - `BoundEthInterfaceForBlobsResource` (optional)
+/// - `BoundEthInterfaceForProveResource` (optional)
+/// - `BoundEthInterfaceForExecuteResource` (optional)
 /// - `TxParamsResource`
 /// - `CircuitBreakersResource` (adds a circuit breaker)
 ///
@@ -45,6 +50,8 @@ pub struct Input {
     pub replica_pool: PoolResource<ReplicaPool>,
     pub eth_client: BoundEthInterfaceResource,
     pub eth_client_blobs: Option<BoundEthInterfaceForBlobsResource>,
+    pub eth_client_prove: Option<BoundEthInterfaceForProveResource>,
+    pub eth_client_execute: Option<BoundEthInterfaceForExecuteResource>,
     pub gas_adjuster: GasAdjusterResource,
     #[context(default)]
     pub circuit_breakers: CircuitBreakersResource,
@@ -80,6 +87,8 @@ impl WiringLayer for EthTxManagerLayer {
         let settlement_mode = self.eth_sender_config.gas_adjuster.unwrap().settlement_mode;
         let eth_client = input.eth_client.0.clone();
         let eth_client_blobs = input.eth_client_blobs.map(|c| c.0);
+        let eth_client_prove = input.eth_client_prove.map(|c| c.0);
+        let eth_client_execute = input.eth_client_execute.map(|c| c.0);
         let l2_client = input.eth_client.0;
 
         let config = self.eth_sender_config.sender.context("sender")?;
@@ -100,6 +109,16 @@ impl WiringLayer for EthTxManagerLayer {
             } else {
                 None
             },
+            if !settlement_mode.is_gateway() {
+                eth_client_prove
+            } else {
+                None
+            },
+            if !settlement_mode.is_gateway() {
+                eth_client_execute
+            } else {
+                None
+            },
             if settlement_mode.is_gateway() {
                 Some(l2_client)
             } else {
@@ -3,7 +3,7 @@ use std::{num::NonZeroUsize, sync::Arc};
 use anyhow::Context;
 use zksync_node_sync::MainNodeHealthCheck;
 use zksync_types::{url::SensitiveUrl, L2ChainId};
-use zksync_web3_decl::client::{Client, DynClient, L2};
+use zksync_web3_decl::client::{Client, DynClient, FailoverClient, L2};
 
 use crate::{
     implementations::resources::{
@@ -17,6 +17,7 @@ use crate::{
 #[derive(Debug)]
 pub struct MainNodeClientLayer {
     url: SensitiveUrl,
+    fallback_urls: Vec<SensitiveUrl>,
     rate_limit_rps: NonZeroUsize,
     l2_chain_id: L2ChainId,
 }
@@ -38,10 +39,18 @@ impl MainNodeClientLayer {
     pub fn new(url: SensitiveUrl, rate_limit_rps: NonZeroUsize, l2_chain_id: L2ChainId) -> Self {
         Self {
             url,
+            fallback_urls: vec![],
             rate_limit_rps,
             l2_chain_id,
         }
     }
+
+    /// Specifies fallback main node URLs (in the order of preference) the client fails over to if the main URL
+    /// is unavailable. The rate limit is applied to each URL separately.
+    pub fn with_fallback_urls(mut self, fallback_urls: Vec<SensitiveUrl>) -> Self {
+        self.fallback_urls = fallback_urls;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -54,13 +63,23 @@ impl WiringLayer for MainNodeClientLayer {
     }
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
-        let main_node_client = Client::http(self.url)
-            .context("failed creating JSON-RPC client for main node")?
-            .for_network(self.l2_chain_id.into())
-            .with_allowed_requests_per_second(self.rate_limit_rps)
-            .build();
-
-        let client = Box::new(main_node_client) as Box<DynClient<L2>>;
+        let client: Box<DynClient<L2>> = if self.fallback_urls.is_empty() {
+            let main_node_client = Client::http(self.url)
+                .context("failed creating JSON-RPC client for main node")?
+                .for_network(self.l2_chain_id.into())
+                .with_allowed_requests_per_second(self.rate_limit_rps)
+                .build();
+            Box::new(main_node_client)
+        } else {
+            let mut urls = vec![self.url];
+            urls.extend(self.fallback_urls);
+            let main_node_client = FailoverClient::http(urls)
+                .context("failed creating failover JSON-RPC client for main node")?
+                .for_network(self.l2_chain_id.into())
+                .with_allowed_requests_per_second(self.rate_limit_rps)
+                .build();
+            Box::new(main_node_client)
+        };
 
         // Insert healthcheck
         input
@@ -7,8 +7,8 @@ use std::{
 use anyhow::Context as _;
 use zksync_config::configs::{api::MerkleTreeApiConfig, database::MerkleTreeMode};
 use zksync_metadata_calculator::{
-    LazyAsyncTreeReader, MerkleTreePruningTask, MerkleTreeReaderConfig, MetadataCalculator,
-    MetadataCalculatorConfig, StaleKeysRepairTask, TreeReaderTask,
+    LazyAsyncTreeReader, MerkleTreeCheckpointTask, MerkleTreePruningTask, MerkleTreeReaderConfig,
+    MetadataCalculator, MetadataCalculatorConfig, StaleKeysRepairTask, TreeReaderTask,
 };
 use zksync_storage::RocksDB;
 
@@ -32,6 +32,7 @@ pub struct MetadataCalculatorLayer {
     tree_api_config: Option<MerkleTreeApiConfig>,
     pruning_config: Option<Duration>,
     stale_keys_repair_enabled: bool,
+    checkpoint_interval: Option<Duration>,
 }
 
 #[derive(Debug, FromContext)]
@@ -39,7 +40,7 @@ pub struct MetadataCalculatorLayer {
 pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
     pub replica_pool: PoolResource<ReplicaPool>,
-    /// Only needed for `MerkleTreeMode::Full`
+    /// Only needed for `MerkleTreeMode::Full` or if checkpoints are enabled.
     pub object_store: Option<ObjectStoreResource>,
     #[context(default)]
     pub app_health: AppHealthCheckResource,
@@ -60,6 +61,9 @@ pub struct Output {
     /// Only provided if enabled in the config.
     #[context(task)]
     pub stale_keys_repair_task: Option<StaleKeysRepairTask>,
+    /// Only provided if enabled in the config.
+    #[context(task)]
+    pub checkpoint_task: Option<MerkleTreeCheckpointTask>,
     pub rocksdb_shutdown_hook: ShutdownHook,
 }
 
@@ -70,6 +74,7 @@ impl MetadataCalculatorLayer {
             tree_api_config: None,
             pruning_config: None,
             stale_keys_repair_enabled: false,
+            checkpoint_interval: None,
         }
     }
 
@@ -87,6 +92,12 @@ impl MetadataCalculatorLayer {
         self.stale_keys_repair_enabled = true;
         self
     }
+
+    /// Enables periodically exporting Merkle tree checkpoints to the object store.
+    pub fn with_checkpoint_interval(mut self, interval: Duration) -> Self {
+        self.checkpoint_interval = Some(interval);
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -105,6 +116,17 @@ impl WiringLayer for MetadataCalculatorLayer {
         let recovery_pool = input.replica_pool.get_custom(10).await?;
         let app_health = input.app_health.0;
 
+        let checkpoint_object_store = match self.checkpoint_interval {
+            Some(_) => {
+                let store = input.object_store.as_ref().ok_or_else(|| {
+                    WiringError::Configuration(
+                        "Object store is required for Merkle tree checkpoints".into(),
+                    )
+                })?;
+                Some(store.0.clone())
+            }
+            None => None,
+        };
         let object_store = match self.config.mode {
             MerkleTreeMode::Lightweight => None,
             MerkleTreeMode::Full => {
@@ -157,6 +179,10 @@ impl WiringLayer for MetadataCalculatorLayer {
             None
         };
 
+        let checkpoint_task = self.checkpoint_interval.zip(checkpoint_object_store).map(
+            |(interval, object_store)| metadata_calculator.checkpoint_task(object_store, interval),
+        );
+
         let tree_api_client = TreeApiClientResource(Arc::new(metadata_calculator.tree_reader()));
 
         let rocksdb_shutdown_hook = ShutdownHook::new("rocksdb_terminaton", async {
@@ -172,6 +198,7 @@ impl WiringLayer for MetadataCalculatorLayer {
             tree_api_task,
             pruning_task,
             stale_keys_repair_task,
+            checkpoint_task,
             rocksdb_shutdown_hook,
         })
     }
@@ -224,6 +251,17 @@ impl Task for StaleKeysRepairTask {
     }
 }
 
+#[async_trait::async_trait]
+impl Task for MerkleTreeCheckpointTask {
+    fn id(&self) -> TaskId {
+        "merkle_tree_checkpoint_task".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
+
 #[async_trait::async_trait]
 impl Task for MerkleTreePruningTask {
     fn id(&self) -> TaskId {
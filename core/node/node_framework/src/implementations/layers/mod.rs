@@ -29,6 +29,7 @@ pub mod prometheus_exporter;
 pub mod proof_data_handler;
 pub mod pruning;
 pub mod query_eth_client;
+pub mod remote_signing_eth_client;
 pub mod reorg_detector;
 pub mod sigint;
 pub mod state_keeper;
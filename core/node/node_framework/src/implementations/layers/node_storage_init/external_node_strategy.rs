@@ -1,11 +1,11 @@
 use std::{num::NonZeroUsize, sync::Arc};
 
-// Re-export to initialize the layer without having to depend on the crate directly.
-pub use zksync_node_storage_init::SnapshotRecoveryConfig;
 use zksync_node_storage_init::{
     external_node::{ExternalNodeGenesis, ExternalNodeReverter, ExternalNodeSnapshotRecovery},
-    InitializeStorage, NodeInitializationStrategy, RevertStorage,
+    InitializeStorage, MerkleTreeCheckpointRestore, NodeInitializationStrategy, RevertStorage,
 };
+// Re-export to initialize the layer without having to depend on the crate directly.
+pub use zksync_node_storage_init::{SnapshotRecoveryConfig, TreeCheckpointRestoreConfig};
 use zksync_types::L2ChainId;
 
 use super::NodeInitializationStrategyResource;
@@ -26,6 +26,11 @@ pub struct ExternalNodeInitStrategyLayer {
     pub l2_chain_id: L2ChainId,
     pub max_postgres_concurrency: NonZeroUsize,
     pub snapshot_recovery_config: Option<SnapshotRecoveryConfig>,
+    /// If set, the Merkle tree will be restored from the latest checkpoint if it's not initialized.
+    pub tree_checkpoint_restore_config: Option<TreeCheckpointRestoreConfig>,
+    /// Maximum number of L1 batches that can be reverted on a reorg. If not set, the number of reverted batches
+    /// is not limited.
+    pub max_rollback_depth: Option<u32>,
 }
 
 #[derive(Debug, FromContext)]
@@ -92,15 +97,23 @@ impl WiringLayer for ExternalNodeInitStrategyLayer {
             }
             None => None,
         };
+        let tree_checkpoint = self.tree_checkpoint_restore_config.map(|restore_config| {
+            Arc::new(MerkleTreeCheckpointRestore {
+                pool: pool.clone(),
+                restore_config,
+            }) as Arc<dyn InitializeStorage>
+        });
         // We always want to detect reorgs, even if we can't roll them back.
         let block_reverter = Some(Arc::new(ExternalNodeReverter {
             client,
             pool: pool.clone(),
             reverter: block_reverter,
+            max_rollback_depth: self.max_rollback_depth,
         }) as Arc<dyn RevertStorage>);
         let strategy = NodeInitializationStrategy {
             genesis,
             snapshot_recovery,
+            tree_checkpoint,
             block_reverter,
         };
 
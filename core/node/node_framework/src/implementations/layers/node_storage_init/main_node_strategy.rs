@@ -54,6 +54,7 @@ impl WiringLayer for MainNodeInitStrategyLayer {
         let strategy = NodeInitializationStrategy {
             genesis,
             snapshot_recovery: None,
+            tree_checkpoint: None,
             block_reverter: None,
         };
 
@@ -4,11 +4,12 @@ use zksync_config::{
     EthConfig,
 };
 use zksync_eth_client::clients::PKSigningClient;
-use zksync_types::SLChainId;
+use zksync_types::{K256PrivateKey, SLChainId};
 
 use crate::{
     implementations::resources::eth_interface::{
-        BoundEthInterfaceForBlobsResource, BoundEthInterfaceResource, EthInterfaceResource,
+        BoundEthInterfaceForBlobsResource, BoundEthInterfaceForExecuteResource,
+        BoundEthInterfaceForProveResource, BoundEthInterfaceResource, EthInterfaceResource,
     },
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
@@ -35,6 +36,10 @@ pub struct Output {
     pub signing_client: BoundEthInterfaceResource,
     /// Only provided if the blob operator key is provided to the layer.
     pub signing_client_for_blobs: Option<BoundEthInterfaceForBlobsResource>,
+    /// Only provided if the prove operator key is provided to the layer.
+    pub signing_client_for_prove: Option<BoundEthInterfaceForProveResource>,
+    /// Only provided if the execute operator key is provided to the layer.
+    pub signing_client_for_execute: Option<BoundEthInterfaceForExecuteResource>,
 }
 
 impl PKSigningEthClientLayer {
@@ -71,30 +76,32 @@ impl WiringLayer for PKSigningEthClientLayer {
             .context("gas_adjuster config is missing")?;
         let EthInterfaceResource(query_client) = input.eth_client;
 
-        let signing_client = PKSigningClient::new_raw(
-            private_key.clone(),
-            self.contracts_config.diamond_proxy_addr,
-            gas_adjuster_config.default_priority_fee_per_gas,
-            self.sl_chain_id,
-            query_client.clone(),
-        );
-        let signing_client = BoundEthInterfaceResource(Box::new(signing_client));
-
-        let signing_client_for_blobs = self.wallets.blob_operator.map(|blob_operator| {
-            let private_key = blob_operator.private_key();
-            let signing_client_for_blobs = PKSigningClient::new_raw(
+        let create_client = |private_key: &K256PrivateKey| {
+            Box::new(PKSigningClient::new_raw(
                 private_key.clone(),
                 self.contracts_config.diamond_proxy_addr,
                 gas_adjuster_config.default_priority_fee_per_gas,
                 self.sl_chain_id,
-                query_client,
-            );
-            BoundEthInterfaceForBlobsResource(Box::new(signing_client_for_blobs))
+                query_client.clone(),
+            ))
+        };
+
+        let signing_client = BoundEthInterfaceResource(create_client(private_key));
+        let signing_client_for_blobs = self.wallets.blob_operator.map(|blob_operator| {
+            BoundEthInterfaceForBlobsResource(create_client(blob_operator.private_key()))
+        });
+        let signing_client_for_prove = self.wallets.prove_operator.map(|prove_operator| {
+            BoundEthInterfaceForProveResource(create_client(prove_operator.private_key()))
+        });
+        let signing_client_for_execute = self.wallets.execute_operator.map(|execute_operator| {
+            BoundEthInterfaceForExecuteResource(create_client(execute_operator.private_key()))
         });
 
         Ok(Output {
             signing_client,
             signing_client_for_blobs,
+            signing_client_for_prove,
+            signing_client_for_execute,
         })
     }
 }
@@ -1,6 +1,6 @@
 use anyhow::Context;
 use zksync_types::{settlement::SettlementMode, url::SensitiveUrl, L2ChainId, SLChainId};
-use zksync_web3_decl::client::Client;
+use zksync_web3_decl::client::{Client, DynClient, FailoverClient, Network, L1, L2};
 
 use crate::{
     implementations::resources::eth_interface::{EthInterfaceResource, L2InterfaceResource},
@@ -13,6 +13,8 @@ use crate::{
 pub struct QueryEthClientLayer {
     chain_id: SLChainId,
     web3_url: SensitiveUrl,
+    fallback_web3_urls: Vec<SensitiveUrl>,
+    cross_check_block_hashes: bool,
     settlement_mode: SettlementMode,
 }
 
@@ -25,9 +27,42 @@ impl QueryEthClientLayer {
         Self {
             chain_id,
             web3_url,
+            fallback_web3_urls: vec![],
+            cross_check_block_hashes: false,
             settlement_mode,
         }
     }
+
+    /// Specifies fallback URLs (in the order of preference) the clients fail over to if the provider
+    /// at the main URL is unavailable, and whether block hashes should be cross-checked among providers.
+    pub fn with_fallback_urls(
+        mut self,
+        fallback_web3_urls: Vec<SensitiveUrl>,
+        cross_check_block_hashes: bool,
+    ) -> Self {
+        self.fallback_web3_urls = fallback_web3_urls;
+        self.cross_check_block_hashes = cross_check_block_hashes;
+        self
+    }
+
+    fn build_client<Net: Network>(&self, network: Net) -> anyhow::Result<Box<DynClient<Net>>> {
+        if self.fallback_web3_urls.is_empty() {
+            let client = Client::http(self.web3_url.clone())
+                .context("Client::new()")?
+                .for_network(network)
+                .build();
+            return Ok(Box::new(client));
+        }
+
+        let mut urls = vec![self.web3_url.clone()];
+        urls.extend(self.fallback_web3_urls.iter().cloned());
+        let client = FailoverClient::http(urls)
+            .context("FailoverClient::http()")?
+            .for_network(network)
+            .with_block_hash_cross_check(self.cross_check_block_hashes)
+            .build();
+        Ok(Box::new(client))
+    }
 }
 
 #[derive(Debug, IntoContext)]
@@ -47,21 +82,12 @@ impl WiringLayer for QueryEthClientLayer {
     }
 
     async fn wire(self, _input: Self::Input) -> Result<Output, WiringError> {
-        // Both the L1 and L2 client have the same URL, but provide different type guarantees.
+        // Both the L1 and L2 client have the same URLs, but provide different type guarantees.
         Ok(Output {
-            query_client_l1: EthInterfaceResource(Box::new(
-                Client::http(self.web3_url.clone())
-                    .context("Client::new()")?
-                    .for_network(self.chain_id.into())
-                    .build(),
-            )),
+            query_client_l1: EthInterfaceResource(self.build_client(L1::from(self.chain_id))?),
             query_client_l2: if self.settlement_mode.is_gateway() {
-                Some(L2InterfaceResource(Box::new(
-                    Client::http(self.web3_url.clone())
-                        .context("Client::new()")?
-                        .for_network(L2ChainId::try_from(self.chain_id.0).unwrap().into())
-                        .build(),
-                )))
+                let network = L2::from(L2ChainId::try_from(self.chain_id.0).unwrap());
+                Some(L2InterfaceResource(self.build_client(network)?))
             } else {
                 None
             },
@@ -0,0 +1,167 @@
+use anyhow::Context as _;
+use zksync_config::{
+    configs::{eth_sender::RemoteSignerConfig, ContractsConfig},
+    EthConfig,
+};
+use zksync_eth_client::{clients::RemoteSigningClient, BoundEthInterface};
+use zksync_eth_signer::RemoteSigner;
+use zksync_types::{Address, SLChainId};
+use zksync_web3_decl::client::{DynClient, L1};
+
+use crate::{
+    implementations::resources::eth_interface::{
+        BoundEthInterfaceForBlobsResource, BoundEthInterfaceForExecuteResource,
+        BoundEthInterfaceForProveResource, BoundEthInterfaceResource, EthInterfaceResource,
+    },
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer for [`RemoteSigningClient`]. Unlike [`PKSigningEthClientLayer`](super::pk_signing_eth_client::PKSigningEthClientLayer),
+/// operator keys are not loaded into the node; signing is delegated to a remote signer specified in the config.
+#[derive(Debug)]
+pub struct RemoteSigningEthClientLayer {
+    eth_sender_config: EthConfig,
+    contracts_config: ContractsConfig,
+    sl_chain_id: SLChainId,
+}
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    pub eth_client: EthInterfaceResource,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    pub signing_client: BoundEthInterfaceResource,
+    /// Only provided if the blob operator address is specified in the remote signer config.
+    pub signing_client_for_blobs: Option<BoundEthInterfaceForBlobsResource>,
+    /// Only provided if the prove operator address is specified in the remote signer config.
+    pub signing_client_for_prove: Option<BoundEthInterfaceForProveResource>,
+    /// Only provided if the execute operator address is specified in the remote signer config.
+    pub signing_client_for_execute: Option<BoundEthInterfaceForExecuteResource>,
+}
+
+impl RemoteSigningEthClientLayer {
+    pub fn new(
+        eth_sender_config: EthConfig,
+        contracts_config: ContractsConfig,
+        sl_chain_id: SLChainId,
+    ) -> Self {
+        Self {
+            eth_sender_config,
+            contracts_config,
+            sl_chain_id,
+        }
+    }
+
+    async fn create_signer(
+        config: &RemoteSignerConfig,
+        address: Address,
+    ) -> anyhow::Result<RemoteSigner> {
+        let signer = RemoteSigner::new(config.url.clone(), address, config.request_timeout());
+        signer
+            .check_account()
+            .await
+            .with_context(|| format!("failed checking account {address:?} in remote signer"))?;
+        Ok(signer)
+    }
+
+    async fn create_client(
+        &self,
+        config: &RemoteSignerConfig,
+        address: Address,
+        query_client: Box<DynClient<L1>>,
+    ) -> anyhow::Result<Box<dyn BoundEthInterface>> {
+        let gas_adjuster_config = self
+            .eth_sender_config
+            .gas_adjuster
+            .as_ref()
+            .context("gas_adjuster config is missing")?;
+        let signer = Self::create_signer(config, address).await?;
+        Ok(Box::new(RemoteSigningClient::new_remote(
+            signer,
+            self.contracts_config.diamond_proxy_addr,
+            gas_adjuster_config.default_priority_fee_per_gas,
+            self.sl_chain_id,
+            query_client,
+        )))
+    }
+
+    async fn create_optional_client(
+        &self,
+        config: &RemoteSignerConfig,
+        address: Option<Address>,
+        query_client: &DynClient<L1>,
+    ) -> anyhow::Result<Option<Box<dyn BoundEthInterface>>> {
+        Ok(match address {
+            Some(address) => Some(
+                self.create_client(config, address, query_client.clone_boxed())
+                    .await?,
+            ),
+            None => None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for RemoteSigningEthClientLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "remote_signing_eth_client_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let remote_signer_config = self
+            .eth_sender_config
+            .remote_signer
+            .as_ref()
+            .context("remote_signer config is missing")?;
+        let EthInterfaceResource(query_client) = input.eth_client;
+
+        let signing_client = self
+            .create_client(
+                remote_signer_config,
+                remote_signer_config.operator_address,
+                query_client.clone(),
+            )
+            .await?;
+        let signing_client = BoundEthInterfaceResource(signing_client);
+
+        let signing_client_for_blobs = self
+            .create_optional_client(
+                remote_signer_config,
+                remote_signer_config.blob_operator_address,
+                &query_client,
+            )
+            .await?
+            .map(BoundEthInterfaceForBlobsResource);
+        let signing_client_for_prove = self
+            .create_optional_client(
+                remote_signer_config,
+                remote_signer_config.prove_operator_address,
+                &query_client,
+            )
+            .await?
+            .map(BoundEthInterfaceForProveResource);
+        let signing_client_for_execute = self
+            .create_optional_client(
+                remote_signer_config,
+                remote_signer_config.execute_operator_address,
+                &query_client,
+            )
+            .await?
+            .map(BoundEthInterfaceForExecuteResource);
+
+        Ok(Output {
+            signing_client,
+            signing_client_for_blobs,
+            signing_client_for_prove,
+            signing_client_for_execute,
+        })
+    }
+}
@@ -2,7 +2,7 @@ use std::{num::NonZeroU32, time::Duration};
 
 use tokio::{sync::oneshot, task::JoinHandle};
 use zksync_circuit_breaker::replication_lag::ReplicationLagChecker;
-use zksync_config::configs::api::MaxResponseSize;
+use zksync_config::configs::api::{MaxResponseSize, RateLimitMethodCosts};
 use zksync_node_api_server::web3::{
     state::{BridgeAddressesHandle, InternalApiConfig, SealedL2BlockNumber},
     ApiBuilder, ApiServer, Namespace,
@@ -40,6 +40,9 @@ pub struct Web3ServerOptionalConfig {
     pub batch_request_size_limit: Option<usize>,
     pub response_body_size_limit: Option<MaxResponseSize>,
     pub websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    pub client_requests_per_minute_limit: Option<NonZeroU32>,
+    pub rate_limit_method_costs: Option<RateLimitMethodCosts>,
+    pub trust_forwarded_for_header: bool,
     pub with_extended_tracing: bool,
     // Used by circuit breaker.
     pub replication_lag_limit: Option<Duration>,
@@ -72,6 +75,16 @@ impl Web3ServerOptionalConfig {
             api_builder = api_builder
                 .with_websocket_requests_per_minute_limit(websocket_requests_per_minute_limit);
         }
+        if let Some(client_requests_per_minute_limit) = self.client_requests_per_minute_limit {
+            let method_costs = self
+                .rate_limit_method_costs
+                .unwrap_or_else(RateLimitMethodCosts::empty);
+            api_builder =
+                api_builder.with_client_rate_limit(client_requests_per_minute_limit, method_costs);
+        }
+        if self.trust_forwarded_for_header {
+            api_builder = api_builder.with_trusted_forwarded_for_header();
+        }
         if let Some(polling_interval) = self.polling_interval {
             api_builder = api_builder.with_polling_interval(polling_interval);
         }
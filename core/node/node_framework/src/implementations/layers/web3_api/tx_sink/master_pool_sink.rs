@@ -10,7 +10,19 @@ use crate::{
 };
 
 /// Wiring layer for [`MasterPoolSink`], [`TxSink`](zksync_node_api_server::tx_sender::tx_sink::TxSink) implementation.
-pub struct MasterPoolSinkLayer;
+#[derive(Debug, Default)]
+pub struct MasterPoolSinkLayer {
+    replacement_fee_bump_percent: Option<u32>,
+}
+
+impl MasterPoolSinkLayer {
+    /// Requires replacement transactions to bump fees by at least `percent`; see [`MasterPoolSink`] for details.
+    #[must_use]
+    pub fn with_replacement_fee_bump_percent(mut self, percent: u32) -> Self {
+        self.replacement_fee_bump_percent = Some(percent);
+        self
+    }
+}
 
 #[derive(Debug, FromContext)]
 #[context(crate = crate)]
@@ -35,8 +47,12 @@ impl WiringLayer for MasterPoolSinkLayer {
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
         let pool = input.master_pool.get().await?;
+        let mut tx_sink = MasterPoolSink::new(pool);
+        if let Some(percent) = self.replacement_fee_bump_percent {
+            tx_sink = tx_sink.with_replacement_fee_bump_percent(percent);
+        }
         Ok(Output {
-            tx_sink: MasterPoolSink::new(pool).into(),
+            tx_sink: tx_sink.into(),
         })
     }
 }
@@ -1,8 +1,9 @@
 use anyhow::Context as _;
 use tokio::sync::watch;
 use zksync_block_reverter::BlockReverter;
-use zksync_dal::{ConnectionPool, Core};
+use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_reorg_detector::ReorgDetector;
+use zksync_shared_metrics::EN_METRICS;
 use zksync_types::L1BatchNumber;
 use zksync_web3_decl::client::{DynClient, L2};
 
@@ -13,6 +14,9 @@ pub struct ExternalNodeReverter {
     pub client: Box<DynClient<L2>>,
     pub pool: ConnectionPool<Core>,
     pub reverter: Option<BlockReverter>,
+    /// Maximum number of L1 batches that can be reverted without operator intervention. If not set,
+    /// the number of reverted batches is not limited.
+    pub max_rollback_depth: Option<u32>,
 }
 
 #[async_trait::async_trait]
@@ -28,8 +32,26 @@ impl RevertStorage for ExternalNodeReverter {
             );
         };
 
-        tracing::info!("Reverting to l1 batch number {to_batch}");
+        let mut storage = self.pool.connection_tagged("en").await?;
+        let last_l1_batch = storage
+            .blocks_dal()
+            .get_sealed_l1_batch_number()
+            .await?
+            .context("no L1 batches in storage")?;
+        drop(storage);
+        let rollback_depth = last_l1_batch.0.saturating_sub(to_batch.0);
+        if let Some(max_depth) = self.max_rollback_depth {
+            anyhow::ensure!(
+                rollback_depth <= max_depth,
+                "Reverting to L1 batch #{to_batch} requires reverting {rollback_depth} L1 batches, \
+                 while the max rollback depth is {max_depth}; revert the node manually using the block reverter"
+            );
+        }
+
+        tracing::info!("Reverting to l1 batch number {to_batch} ({rollback_depth} L1 batches)");
         block_reverter.roll_back(to_batch).await?;
+        EN_METRICS.reorg_rollbacks.inc();
+        EN_METRICS.reorg_rollback_depth.set(rollback_depth.into());
         tracing::info!("Revert successfully completed");
         Ok(())
     }
@@ -54,3 +76,127 @@ impl RevertStorage for ExternalNodeReverter {
         Ok(batch)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use zksync_block_reverter::NodeRole;
+    use zksync_dal::Connection;
+    use zksync_types::{
+        block::{L1BatchHeader, L2BlockHeader},
+        Address, L2BlockNumber, ProtocolVersion, ProtocolVersionId, H256,
+    };
+    use zksync_web3_decl::client::MockClient;
+
+    use super::*;
+
+    async fn seal_l1_batches(storage: &mut Connection<'_, Core>, count: u32) {
+        storage
+            .protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        for number in 0..count {
+            let l2_block_header = L2BlockHeader {
+                number: L2BlockNumber(number),
+                timestamp: number.into(),
+                hash: H256::from_low_u64_be(number.into()),
+                l1_tx_count: 0,
+                l2_tx_count: 0,
+                fee_account_address: Address::default(),
+                base_fee_per_gas: 0,
+                batch_fee_input: Default::default(),
+                gas_per_pubdata_limit: 0,
+                base_system_contracts_hashes: Default::default(),
+                protocol_version: Some(ProtocolVersionId::latest()),
+                virtual_blocks: 1,
+                gas_limit: 0,
+                logs_bloom: Default::default(),
+                pubdata_params: Default::default(),
+            };
+            storage
+                .blocks_dal()
+                .insert_l2_block(&l2_block_header)
+                .await
+                .unwrap();
+            let l1_batch_header = L1BatchHeader::new(
+                L1BatchNumber(number),
+                number.into(),
+                Default::default(),
+                ProtocolVersionId::latest(),
+            );
+            storage
+                .blocks_dal()
+                .insert_mock_l1_batch(&l1_batch_header)
+                .await
+                .unwrap();
+            storage
+                .blocks_dal()
+                .mark_l2_blocks_as_executed_in_l1_batch(l1_batch_header.number)
+                .await
+                .unwrap();
+        }
+    }
+
+    async fn create_reverter(max_rollback_depth: Option<u32>) -> ExternalNodeReverter {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        seal_l1_batches(&mut pool.connection().await.unwrap(), 6).await;
+        let mut reverter = BlockReverter::new(NodeRole::External, pool.clone());
+        reverter.enable_rolling_back_postgres();
+        ExternalNodeReverter {
+            client: Box::new(MockClient::builder(L2::default()).build()),
+            pool,
+            reverter: Some(reverter),
+            max_rollback_depth,
+        }
+    }
+
+    async fn last_sealed_l1_batch(pool: &ConnectionPool<Core>) -> L1BatchNumber {
+        let mut storage = pool.connection().await.unwrap();
+        storage
+            .blocks_dal()
+            .get_sealed_l1_batch_number()
+            .await
+            .unwrap()
+            .expect("no L1 batches")
+    }
+
+    #[tokio::test]
+    async fn rollback_deeper_than_max_depth_is_refused() {
+        let reverter = create_reverter(Some(2)).await;
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+
+        let err = reverter
+            .revert_storage(L1BatchNumber(2), stop_receiver)
+            .await
+            .unwrap_err();
+        let err = format!("{err:#}");
+        assert!(err.contains("requires reverting 3 L1 batches"), "{err}");
+        assert!(err.contains("max rollback depth is 2"), "{err}");
+        assert_eq!(last_sealed_l1_batch(&reverter.pool).await, L1BatchNumber(5));
+    }
+
+    #[tokio::test]
+    async fn rollback_within_max_depth_is_performed() {
+        let reverter = create_reverter(Some(3)).await;
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+
+        reverter
+            .revert_storage(L1BatchNumber(2), stop_receiver)
+            .await
+            .unwrap();
+        assert_eq!(last_sealed_l1_batch(&reverter.pool).await, L1BatchNumber(2));
+    }
+
+    #[tokio::test]
+    async fn rollback_depth_is_not_limited_without_max_depth() {
+        let reverter = create_reverter(None).await;
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+
+        reverter
+            .revert_storage(L1BatchNumber(0), stop_receiver)
+            .await
+            .unwrap();
+        assert_eq!(last_sealed_l1_batch(&reverter.pool).await, L1BatchNumber(0));
+    }
+}
@@ -5,11 +5,15 @@ use zksync_config::ObjectStoreConfig;
 use zksync_dal::{ConnectionPool, Core, CoreDal as _};
 use zksync_types::L1BatchNumber;
 
-pub use crate::traits::{InitializeStorage, RevertStorage};
+pub use crate::{
+    traits::{InitializeStorage, RevertStorage},
+    tree_checkpoint::MerkleTreeCheckpointRestore,
+};
 
 pub mod external_node;
 pub mod main_node;
 mod traits;
+mod tree_checkpoint;
 
 #[derive(Debug)]
 pub struct SnapshotRecoveryConfig {
@@ -19,6 +23,14 @@ pub struct SnapshotRecoveryConfig {
     pub object_store_config: Option<ObjectStoreConfig>,
 }
 
+#[derive(Debug)]
+pub struct TreeCheckpointRestoreConfig {
+    /// Path to the Merkle tree RocksDB directory.
+    pub db_path: String,
+    /// Object store with Merkle tree checkpoints.
+    pub object_store_config: ObjectStoreConfig,
+}
+
 #[derive(Debug, Clone, Copy)]
 enum InitDecision {
     /// Perform or check genesis.
@@ -31,6 +43,8 @@ enum InitDecision {
 pub struct NodeInitializationStrategy {
     pub genesis: Arc<dyn InitializeStorage>,
     pub snapshot_recovery: Option<Arc<dyn InitializeStorage>>,
+    /// Restores the Merkle tree from a checkpoint. Run after Postgres is initialized.
+    pub tree_checkpoint: Option<Arc<dyn InitializeStorage>>,
     pub block_reverter: Option<Arc<dyn RevertStorage>>,
 }
 
@@ -124,6 +138,20 @@ impl NodeStorageInitializer {
             }
         }
 
+        // Restore the Merkle tree after Postgres is initialized, since the checkpoint is verified against Postgres.
+        if let Some(tree_checkpoint) = &self.strategy.tree_checkpoint {
+            if tree_checkpoint.is_initialized().await? {
+                tracing::info!(
+                    "Merkle tree is already initialized; not restoring it from checkpoint"
+                );
+            } else {
+                tracing::info!("Restoring Merkle tree from checkpoint");
+                tree_checkpoint
+                    .initialize_storage(stop_receiver.clone())
+                    .await?;
+            }
+        }
+
         // Now we may check whether we're in the invalid state and should perform a rollback.
         if let Some(reverter) = &self.strategy.block_reverter {
             if let Some(to_batch) = reverter
@@ -165,6 +193,17 @@ impl NodeStorageInitializer {
     }
 
     async fn is_database_initialized(&self) -> anyhow::Result<bool> {
+        if !self.is_postgres_initialized().await? {
+            return Ok(false);
+        }
+        // The Merkle tree must not be accessed while it's being restored.
+        if let Some(tree_checkpoint) = &self.strategy.tree_checkpoint {
+            return tree_checkpoint.is_initialized().await;
+        }
+        Ok(true)
+    }
+
+    async fn is_postgres_initialized(&self) -> anyhow::Result<bool> {
         // We're fine if the database is initialized in any meaningful way we can check.
         if self.strategy.genesis.is_initialized().await? {
             return Ok(true);
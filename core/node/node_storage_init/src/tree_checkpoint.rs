@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_dal::{ConnectionPool, Core};
+use zksync_metadata_calculator::restore_tree_from_checkpoint;
+use zksync_object_store::ObjectStoreFactory;
+
+use crate::{InitializeStorage, TreeCheckpointRestoreConfig};
+
+/// Restores the Merkle tree from the latest checkpoint in the object store if the tree is not initialized.
+/// Should be run after Postgres is initialized, since the checkpoint is verified against Postgres.
+///
+/// If the tree cannot be restored (e.g., there are no checkpoints, or Postgres doesn't have the L1 batch of the checkpoint yet),
+/// an empty tree directory is created, and the tree is initialized by the metadata calculator as usual.
+#[derive(Debug)]
+pub struct MerkleTreeCheckpointRestore {
+    pub pool: ConnectionPool<Core>,
+    pub restore_config: TreeCheckpointRestoreConfig,
+}
+
+#[async_trait::async_trait]
+impl InitializeStorage for MerkleTreeCheckpointRestore {
+    async fn initialize_storage(&self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let object_store = ObjectStoreFactory::new(self.restore_config.object_store_config.clone())
+            .create_store()
+            .await?;
+        let db_path = Path::new(&self.restore_config.db_path);
+        let manifest =
+            restore_tree_from_checkpoint(&*object_store, &self.pool, db_path, &stop_receiver)
+                .await
+                .context("failed restoring Merkle tree from checkpoint")?;
+        if let Some(manifest) = manifest {
+            tracing::info!(
+                "Restored Merkle tree from checkpoint for L1 batch #{}; the tree will catch up with Postgres",
+                manifest.l1_batch_number
+            );
+        } else if !*stop_receiver.borrow() {
+            tracing::info!(
+                "Merkle tree wasn't restored from checkpoint; it will be initialized from scratch"
+            );
+            tokio::fs::create_dir_all(db_path)
+                .await
+                .context("failed creating Merkle tree directory")?;
+        }
+        Ok(())
+    }
+
+    async fn is_initialized(&self) -> anyhow::Result<bool> {
+        Ok(tokio::fs::try_exists(&self.restore_config.db_path).await?)
+    }
+}
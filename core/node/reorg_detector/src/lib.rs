@@ -177,6 +177,12 @@ trait HandleReorgDetectorEvent: fmt::Debug + Send + Sync {
 
     fn report_divergence(&mut self, diverged_l1_batch: L1BatchNumber);
 
+    fn report_reorg(
+        &mut self,
+        diverged_l1_batch: L1BatchNumber,
+        last_correct_l1_batch: L1BatchNumber,
+    );
+
     fn start_shutting_down(&mut self);
 }
 
@@ -220,6 +226,19 @@ impl HandleReorgDetectorEvent for HealthUpdater {
         self.update(Health::from(HealthStatus::Affected).with_details(health_details));
     }
 
+    fn report_reorg(
+        &mut self,
+        diverged_l1_batch: L1BatchNumber,
+        last_correct_l1_batch: L1BatchNumber,
+    ) {
+        EN_METRICS.detected_reorgs.inc();
+        let health_details = serde_json::json!({
+            "diverged_l1_batch": diverged_l1_batch,
+            "last_correct_l1_batch": last_correct_l1_batch,
+        });
+        self.update(Health::from(HealthStatus::Affected).with_details(health_details));
+    }
+
     fn start_shutting_down(&mut self) {
         self.update(HealthStatus::ShuttingDown.into());
     }
@@ -237,8 +256,8 @@ impl HandleReorgDetectorEvent for HealthUpdater {
 /// and revert all batches after it, to keep being consistent with the main node.
 ///
 /// This is the only component that is expected to finish its execution
-/// in the event of re-org, since we have to restart the node after a rollback is performed,
-/// and is special-cased in the `zksync_external_node` crate.
+/// in the event of re-org, since the rollback is performed on node start, before any other components
+/// are launched. The external node can be configured to restart automatically in this case.
 #[derive(Debug)]
 pub struct ReorgDetector {
     client: Box<dyn MainNodeClient>,
@@ -324,6 +343,8 @@ impl ReorgDetector {
         tracing::info!("Searching for the first diverged L1 batch");
         let last_correct_l1_batch = self.detect_reorg(first_l1_batch, diverged_l1_batch).await?;
         tracing::info!("Reorg localized: last correct L1 batch is #{last_correct_l1_batch}");
+        self.event_handler
+            .report_reorg(diverged_l1_batch, last_correct_l1_batch);
         Err(Error::ReorgDetected(last_correct_l1_batch))
     }
 
@@ -12,6 +12,7 @@ use assert_matches::assert_matches;
 use test_casing::{test_casing, Product};
 use tokio::sync::mpsc;
 use zksync_dal::{Connection, CoreDal};
+use zksync_health_check::CheckHealth;
 use zksync_node_genesis::{insert_genesis_batch, GenesisParams};
 use zksync_node_test_utils::{create_l1_batch, create_l2_block};
 use zksync_types::{
@@ -154,6 +155,14 @@ impl HandleReorgDetectorEvent for mpsc::UnboundedSender<(L2BlockNumber, L1BatchN
         // Do nothing
     }
 
+    fn report_reorg(
+        &mut self,
+        _diverged_l1_batch: L1BatchNumber,
+        _last_correct_l1_batch: L1BatchNumber,
+    ) {
+        // Do nothing
+    }
+
     fn start_shutting_down(&mut self) {
         // Do nothing
     }
@@ -318,6 +327,16 @@ async fn reorg_is_detected_on_batch_hash_mismatch() {
         detector.check_consistency().await,
         Err(Error::ReorgDetected(L1BatchNumber(1)))
     );
+
+    let health = detector.health_check().check_health().await;
+    assert_matches!(health.status(), HealthStatus::Affected);
+    assert_eq!(
+        health.details().unwrap(),
+        &serde_json::json!({
+            "diverged_l1_batch": 2,
+            "last_correct_l1_batch": 1,
+        })
+    );
 }
 
 #[tokio::test]
@@ -192,6 +192,12 @@ pub struct ExternalNodeMetrics {
     pub last_correct_batch: Family<CheckerComponent, Gauge<u64>>,
     /// Number of the last L2 block checked by the re-org detector.
     pub last_correct_l2_block: Family<CheckerComponent, Gauge<u64>>,
+    /// Number of reorgs detected by the re-org detector.
+    pub detected_reorgs: Counter,
+    /// Number of storage rollbacks performed after a reorg was detected.
+    pub reorg_rollbacks: Counter,
+    /// Number of L1 batches reverted by the latest storage rollback.
+    pub reorg_rollback_depth: Gauge<u64>,
 }
 
 #[vise::register]
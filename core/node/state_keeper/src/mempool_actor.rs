@@ -6,7 +6,7 @@ use tokio::sync::mpsc;
 use tokio::sync::watch;
 use zksync_config::configs::chain::MempoolConfig;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
-use zksync_mempool::L2TxFilter;
+use zksync_mempool::{L2TxFilter, RejectedTransaction};
 use zksync_multivm::utils::derive_base_fee_and_gas_per_pubdata;
 use zksync_node_fee_model::BatchFeeModelInputProvider;
 #[cfg(test)]
@@ -137,7 +137,10 @@ impl MempoolFetcher {
                 self.transaction_hashes_sender.send(transaction_hashes).ok();
             }
             let all_transactions_loaded = transactions.len() < self.sync_batch_size;
-            self.mempool.insert(transactions_with_constraints, nonces);
+            let rejected_txs = self.mempool.insert(transactions_with_constraints, nonces);
+            if !rejected_txs.is_empty() {
+                self.mark_txs_as_rejected(&rejected_txs).await?;
+            }
             latency.observe();
 
             if all_transactions_loaded {
@@ -146,6 +149,29 @@ impl MempoolFetcher {
         }
         Ok(())
     }
+
+    /// Marks transactions that violate per-account mempool limits as rejected, so that they are not stuck
+    /// in the pending state forever. Unlike the checks in the API server, the mempool has an authoritative view
+    /// of pending transactions, so it enforces limits even if concurrent submissions race past API checks.
+    async fn mark_txs_as_rejected(
+        &self,
+        rejected_txs: &[RejectedTransaction],
+    ) -> anyhow::Result<()> {
+        let mut storage = self.pool.connection_tagged("state_keeper").await?;
+        for tx in rejected_txs {
+            tracing::info!(
+                "L2 transaction {:?} is rejected by mempool: {}",
+                tx.hash,
+                tx.reason
+            );
+            storage
+                .transactions_dal()
+                .mark_tx_as_rejected(tx.hash, &format!("rejected by mempool: {}", tx.reason))
+                .await
+                .with_context(|| format!("failed marking transaction {:?} as rejected", tx.hash))?;
+        }
+        Ok(())
+    }
 }
 
 /// Loads nonces for all distinct `transactions` initiators from the storage.
@@ -181,6 +207,7 @@ async fn get_transaction_nonces(
 
 #[cfg(test)]
 mod tests {
+    use zksync_config::configs::chain::MempoolOrdering;
     use zksync_multivm::interface::{tracer::ValidationTraces, TransactionExecutionMetrics};
     use zksync_node_fee_model::MockBatchFeeParamsProvider;
     use zksync_node_genesis::{insert_genesis_batch, GenesisParams};
@@ -198,6 +225,9 @@ mod tests {
         stuck_tx_timeout: 0,
         remove_stuck_txs: false,
         delay_interval: 10,
+        ordering: MempoolOrdering::Fifo,
+        max_pending_txs_per_account: None,
+        replacement_fee_bump_percent: None,
     };
 
     #[tokio::test]
@@ -288,6 +318,74 @@ mod tests {
         fetcher_task.await.unwrap().expect("fetcher errored");
     }
 
+    #[tokio::test]
+    async fn rejecting_transactions_exceeding_account_limit() {
+        let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+        let mut storage = pool.connection().await.unwrap();
+        insert_genesis_batch(&mut storage, &GenesisParams::mock())
+            .await
+            .unwrap();
+        let mempool_config = MempoolConfig {
+            max_pending_txs_per_account: Some(1),
+            ..TEST_MEMPOOL_CONFIG
+        };
+        let mempool = MempoolGuard::from_storage(&mut storage, &mempool_config).await;
+        drop(storage);
+
+        let fee_params_provider: Arc<dyn BatchFeeModelInputProvider> =
+            Arc::new(MockBatchFeeParamsProvider::default());
+        let fee_input = fee_params_provider.get_batch_fee_input().await.unwrap();
+        let (base_fee, gas_per_pubdata) =
+            derive_base_fee_and_gas_per_pubdata(fee_input, ProtocolVersionId::latest().into());
+        let fetcher = MempoolFetcher::new(
+            mempool.clone(),
+            fee_params_provider,
+            &mempool_config,
+            pool.clone(),
+        );
+
+        // Insert 2 transactions with a nonce gap, so that the second one exceeds the limit.
+        let initiator = Address::repeat_byte(1);
+        let mut storage = pool.connection().await.unwrap();
+        for nonce in [1, 2] {
+            let mut transaction = create_l2_transaction(base_fee, gas_per_pubdata);
+            transaction.common_data.initiator_address = initiator;
+            transaction.common_data.nonce = Nonce(nonce);
+            storage
+                .transactions_dal()
+                .insert_transaction_l2(
+                    &transaction,
+                    TransactionExecutionMetrics::default(),
+                    ValidationTraces::default(),
+                )
+                .await
+                .unwrap();
+        }
+        drop(storage);
+
+        let (stop_sender, stop_receiver) = watch::channel(false);
+        let fetcher_task = tokio::spawn(fetcher.run(stop_receiver));
+        let pending_txs = loop {
+            let mut storage = pool.connection().await.unwrap();
+            let pending_txs = storage
+                .transactions_web3_dal()
+                .pending_transactions_by_initiator_account(initiator, 0)
+                .await
+                .unwrap();
+            if pending_txs.len() < 2 {
+                break pending_txs;
+            }
+            drop(storage);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        };
+        let pending_nonces: Vec<_> = pending_txs.into_iter().map(|(nonce, _)| nonce).collect();
+        assert_eq!(pending_nonces, [Nonce(1)]);
+        assert_eq!(mempool.stats().l2_transaction_count, 1);
+
+        stop_sender.send_replace(true);
+        fetcher_task.await.unwrap().expect("fetcher errored");
+    }
+
     async fn wait_for_new_transactions(
         tx_hashes_receiver: &mut mpsc::UnboundedReceiver<Vec<H256>>,
     ) -> Vec<H256> {
@@ -3,8 +3,9 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use zksync_config::configs::chain::MempoolConfig;
 use zksync_dal::{Connection, Core, CoreDal};
-use zksync_mempool::{L2TxFilter, MempoolInfo, MempoolStore};
+use zksync_mempool::{L2TxFilter, MempoolInfo, MempoolStore, RejectedTransaction};
 use zksync_multivm::interface::{VmExecutionMetrics, VmExecutionResultAndLogs};
 use zksync_types::{
     block::BlockGasCount, Address, Nonce, PriorityOpId, Transaction, TransactionTimeRangeConstraint,
@@ -19,12 +20,20 @@ use super::{
 pub struct MempoolGuard(Arc<Mutex<MempoolStore>>);
 
 impl MempoolGuard {
-    pub async fn from_storage(storage_processor: &mut Connection<'_, Core>, capacity: u64) -> Self {
+    pub async fn from_storage(
+        storage_processor: &mut Connection<'_, Core>,
+        config: &MempoolConfig,
+    ) -> Self {
         let next_priority_id = storage_processor
             .transactions_dal()
             .next_priority_id()
             .await;
-        Self::new(next_priority_id, capacity)
+        let mut store =
+            MempoolStore::new(next_priority_id, config.capacity).with_ordering(config.ordering);
+        if let Some(limit) = config.max_pending_txs_per_account {
+            store = store.with_max_pending_txs_per_account(limit as usize);
+        }
+        Self(Arc::new(Mutex::new(store)))
     }
 
     pub(super) fn new(next_priority_id: PriorityOpId, capacity: u64) -> Self {
@@ -36,11 +45,11 @@ impl MempoolGuard {
         &mut self,
         transactions: Vec<(Transaction, TransactionTimeRangeConstraint)>,
         nonces: HashMap<Address, Nonce>,
-    ) {
+    ) -> Vec<RejectedTransaction> {
         self.0
             .lock()
             .expect("failed to acquire mempool lock")
-            .insert(transactions, nonces);
+            .insert(transactions, nonces)
     }
 
     #[cfg(test)]
@@ -48,14 +57,14 @@ impl MempoolGuard {
         &mut self,
         transactions: Vec<Transaction>,
         nonces: HashMap<Address, Nonce>,
-    ) {
+    ) -> Vec<RejectedTransaction> {
         self.insert(
             transactions
                 .into_iter()
                 .map(|x| (x, TransactionTimeRangeConstraint::default()))
                 .collect(),
             nonces,
-        );
+        )
     }
 
     pub fn has_next(&self, filter: &L2TxFilter) -> bool {
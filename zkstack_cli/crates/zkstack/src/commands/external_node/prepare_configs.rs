@@ -74,8 +74,12 @@ fn prepare_configs(
                 .http_url,
         )?,
         main_node_rate_limit_rps: None,
+        main_node_fallback_urls: vec![],
         gateway_url: None,
         bridge_addresses_refresh_interval_sec: None,
+        auto_rollback_on_reorg: None,
+        max_auto_rollback_depth: None,
+        merkle_tree_restore_from_checkpoint: None,
     };
     let mut general_en = general.clone();
     general_en.consensus_config = None;
@@ -112,6 +116,8 @@ fn prepare_configs(
         }),
         l1: Some(L1Secrets {
             l1_rpc_url: SensitiveUrl::from_str(&args.l1_rpc_url).context("l1_rpc_url")?,
+            fallback_l1_rpc_urls: vec![],
+            cross_check_l1_block_hashes: false,
         }),
         data_availability: None,
     };
@@ -36,6 +36,8 @@ pub(crate) fn create_gcs_bucket(
         },
         max_retries: PROVER_STORE_MAX_RETRIES,
         local_mirror_path: None,
+        compression_level: None,
+        compressed_buckets: vec![],
     })
 }
 